@@ -80,6 +80,7 @@ fn build_snapshot(dir: &std::path::Path) -> AppSetSnapshot {
     (SnapshotBuilder {
         workspace_root: dir.to_path_buf(),
         local_providers: vec![],
+        referenced_dependencies_only: false,
     })
     .build()
     .expect("snapshot build (perf corpus workspace)")
@@ -117,14 +117,14 @@ fn bench_queries(c: &mut Criterion) {
     // per other generated file, each via its own declared `Hub` variable —
     // see perf_support module doc).
     let hub_file = perf_support::file_name(perf_support::HUB_INDEX);
-    let hub_proc0 = ItemData {
-        node: snap.decls_by_file[&hub_file]
+    let hub_proc0 = ItemData::new(
+        snap.decls_by_file[&hub_file]
             .iter()
             .find(|d| d.name == "Proc0")
             .expect("hub Proc0 decl")
             .id
             .clone(),
-    };
+    );
     group.bench_function("incoming", |b| {
         b.iter(|| {
             black_box(handlers::incoming(
@@ -137,14 +137,14 @@ fn bench_queries(c: &mut Criterion) {
 
     // outgoing: any non-hub file's Proc0 has real fan-out (3 callees).
     let file1 = perf_support::file_name(1);
-    let file1_proc0 = ItemData {
-        node: snap.decls_by_file[&file1]
+    let file1_proc0 = ItemData::new(
+        snap.decls_by_file[&file1]
             .iter()
             .find(|d| d.name == "Proc0")
             .expect("file-1 Proc0 decl")
             .id
             .clone(),
-    };
+    );
     group.bench_function("outgoing", |b| {
         b.iter(|| {
             black_box(handlers::outgoing(
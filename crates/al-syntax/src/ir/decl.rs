@@ -219,5 +219,11 @@ pub struct VarDecl {
     pub name: String,
     pub ty: Option<String>,
     pub temporary: bool,
+    /// A `Label`/`TextConst` variable's text (`Msg: Label 'Posted %1', Comment
+    /// = '%1 = No.', Locked = true` → `Posted %1`): the value string alone,
+    /// outer quotes stripped and `''` unescaped — its `Comment`/`Locked`/
+    /// `MaxLength` properties are never part of it (nor of `ty`). `None` for
+    /// every other variable.
+    pub label: Option<String>,
     pub origin: Origin,
 }
@@ -146,4 +146,7 @@ pub struct AlFile {
     pub ir: Ir,
     pub issues: Vec<SyntaxIssue>,
     pub parse_status: ParseStatus,
+    /// Byte ranges of every `//` and `/* */` comment, delimiters included,
+    /// in document order.
+    pub comments: Vec<Range<usize>>,
 }
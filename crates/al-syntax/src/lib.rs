@@ -15,7 +15,7 @@ pub mod schema;
 pub mod symbol_props;
 
 pub use casing::{IdentifierFoldExt, eq_fold_identifier, fold_identifier};
-pub use parse::parse;
+pub use parse::{RetainedParse, SourceEdit, dump_tree, parse, parse_retained};
 pub use symbol_props::{
     SymbolDeclKind, SymbolProperties, SymbolProperty, lookup_symbol_properties,
 };
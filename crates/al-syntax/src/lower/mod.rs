@@ -57,11 +57,26 @@ pub fn lower_file(root: RawNode, source: &str) -> AlFile {
     let mut issues = Vec::new();
     let mut objects = Vec::new();
     collect_objects(root, source, &mut ir, &mut issues, &mut objects);
+    let mut comments = Vec::new();
+    collect_comments(root, &mut comments);
     AlFile {
         objects,
         ir,
         issues,
         parse_status,
+        comments,
+    }
+}
+
+/// Byte ranges of the `comment` / `multiline_comment` nodes under `node`, in
+/// document order. Comments are grammar extras, so they may hang off any
+/// node — the whole tree is walked.
+fn collect_comments(node: RawNode, out: &mut Vec<std::ops::Range<usize>>) {
+    for child in node.named_children() {
+        match child.kind() {
+            RawKind::Comment | RawKind::MultilineComment => out.push(child.byte_range()),
+            _ => collect_comments(child, out),
+        }
     }
 }
 
@@ -968,11 +983,13 @@ fn extract_var_section(section: RawNode, source: &str, out: &mut Vec<VarDecl>) {
                     // single unnamed-by-field fallback: skip (no name to record)
                     continue;
                 }
+                let label = label_value(decl, source);
                 for nm in names {
                     out.push(VarDecl {
                         name: ident_text(nm, source),
                         ty: ty.clone(),
                         temporary,
+                        label: label.clone(),
                         origin: origin_of(decl),
                     });
                 }
@@ -986,11 +1003,13 @@ fn extract_var_section(section: RawNode, source: &str, out: &mut Vec<VarDecl>) {
                         let temporary = type_node
                             .map(|t| contains_kind(t, RawKind::TemporaryKeyword))
                             .unwrap_or(false);
+                        let label = label_value(c, source);
                         for nm in c.children_by_field(FieldName::Name) {
                             out.push(VarDecl {
                                 name: ident_text(nm, source),
                                 ty: ty.clone(),
                                 temporary,
+                                label: label.clone(),
                                 origin: origin_of(c),
                             });
                         }
@@ -1002,6 +1021,20 @@ fn extract_var_section(section: RawNode, source: &str, out: &mut Vec<VarDecl>) {
     }
 }
 
+/// A `variable_declaration`'s `value` string (a `Label`/`TextConst`), unquoted.
+/// Only the `value` field is read, so the trailing `label_attribute` children
+/// (`Comment = '...'`, `Locked = true`, `MaxLength = 50`) are skipped whatever
+/// their order.
+fn label_value(decl: RawNode, source: &str) -> Option<String> {
+    let text = decl.field(FieldName::Value)?.text(source).trim();
+    let text = text.strip_prefix('@').unwrap_or(text);
+    let inner = text
+        .strip_prefix('\'')
+        .and_then(|t| t.strip_suffix('\''))
+        .unwrap_or(text);
+    Some(inner.replace("''", "'"))
+}
+
 // ---- body lowering (statements + expressions) ----
 //
 // First cut: preproc-wrapped statements are FLATTENED in document order (legacy
@@ -1941,6 +1974,32 @@ mod tests {
         panic!("no Case statement lowered");
     }
 
+    /// A label's `Comment`/`Locked`/`MaxLength` properties must not leak into
+    /// the declared name, type, or text, nor swallow the next declaration.
+    #[test]
+    fn label_properties_are_skipped_around_name_and_text() {
+        let src = r#"
+codeunit 50002 T
+{
+    var
+        PostedMsg: Label 'Document %1 isn''t posted', Comment = '%1 = Document No.', Locked = true, MaxLength = 80;
+        "Plain Lbl": Label 'Plain';
+        Counter: Integer;
+}
+"#;
+        let af = parse(src);
+        let globals = &af.objects[0].globals;
+        let names: Vec<&str> = globals.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, ["PostedMsg", "Plain Lbl", "Counter"]);
+        assert_eq!(globals[0].ty.as_deref(), Some("Label"));
+        assert_eq!(
+            globals[0].label.as_deref(),
+            Some("Document %1 isn't posted")
+        );
+        assert_eq!(globals[1].label.as_deref(), Some("Plain"));
+        assert_eq!(globals[2].label, None);
+    }
+
     /// Regression for the case-pattern field-pollution grammar fix: `case 1, 2:` must
     /// yield TWO value patterns (the `,` separator is NOT a pattern), and lowering must
     /// not panic / emit Unknown on the comma. Pre-fix, `field('pattern', …)` spread over
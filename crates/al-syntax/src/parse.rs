@@ -1,6 +1,9 @@
 //! Parse entry point: AL source → owned [`AlFile`]. The tree-sitter `Tree` lives
 //! only for the duration of lowering; everything the engine needs is copied into
-//! the owned IR before it drops.
+//! the owned IR before it drops. The one exception is [`RetainedParse`], which
+//! keeps the tree (opaquely) so an edited file can be reparsed incrementally.
+
+use std::ops::Range;
 
 use crate::ir::AlFile;
 use crate::lower;
@@ -8,14 +11,134 @@ use crate::raw::RawNode;
 
 /// Parse + lower one AL source file.
 pub fn parse(source: &str) -> AlFile {
+    let tree = parse_tree(source);
+    lower::lower_file(RawNode::new(tree.root_node()), source)
+}
+
+/// The raw parse tree of `source` as an indented S-expression of its named
+/// nodes, each tagged with its byte range: `(procedure [20..60] ...)`.
+/// Error-recovery nodes show up as `ERROR` / `(MISSING kind ...)`. A
+/// debugging aid for grammar and query bug reports (`--dump-tree`); the
+/// format is not stable.
+pub fn dump_tree(source: &str) -> String {
+    let tree = parse_tree(source);
+    let mut out = String::new();
+    write_sexp(RawNode::new(tree.root_node()), 0, &mut out);
+    out.push('\n');
+    out
+}
+
+/// One replacement in a source text: the bytes in `range` (of the text as it
+/// stands when the edit is applied) become `text`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceEdit {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+/// A parse whose tree is kept for incremental reparsing: record each change
+/// with [`Self::edit`], then [`Self::reparse`] re-lowers the file, letting
+/// tree-sitter reuse every subtree the edits did not touch. The resulting
+/// [`AlFile`] is the same as [`parse`] of the edited text.
+pub struct RetainedParse {
+    tree: tree_sitter::Tree,
+}
+
+/// [`parse`] that also returns the tree for later incremental reparses.
+pub fn parse_retained(source: &str) -> (AlFile, RetainedParse) {
+    let tree = parse_tree_from(source, None);
+    let file = lower::lower_file(RawNode::new(tree.root_node()), source);
+    (file, RetainedParse { tree })
+}
+
+impl RetainedParse {
+    /// Apply `edit` to `text` (the source this tree was parsed from, with
+    /// every earlier edit applied) and to the retained tree. A range that is
+    /// out of bounds or not on char boundaries is clamped outward to the
+    /// nearest valid one.
+    pub fn edit(&mut self, text: &mut String, edit: &SourceEdit) {
+        let start = floor_char_boundary(text, edit.range.start.min(text.len()));
+        let old_end = ceil_char_boundary(text, edit.range.end.clamp(start, text.len()));
+        let start_position = point_at(text, start);
+        let old_end_position = point_at(text, old_end);
+        text.replace_range(start..old_end, &edit.text);
+        let new_end = start + edit.text.len();
+        self.tree.edit(&tree_sitter::InputEdit {
+            start_byte: start,
+            old_end_byte: old_end,
+            new_end_byte: new_end,
+            start_position,
+            old_end_position,
+            new_end_position: point_at(text, new_end),
+        });
+    }
+
+    /// Reparse `text` (the edited source) against the retained tree and
+    /// lower it; the new tree replaces the old one.
+    pub fn reparse(&mut self, text: &str) -> AlFile {
+        self.tree = parse_tree_from(text, Some(&self.tree));
+        lower::lower_file(RawNode::new(self.tree.root_node()), text)
+    }
+}
+
+/// The tree-sitter point (row, byte column) of byte `offset` in `text`.
+fn point_at(text: &str, offset: usize) -> tree_sitter::Point {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    tree_sitter::Point {
+        row: before.matches('\n').count(),
+        column: offset - line_start,
+    }
+}
+
+fn floor_char_boundary(text: &str, mut i: usize) -> usize {
+    while !text.is_char_boundary(i) {
+        i -= 1;
+    }
+    i
+}
+
+fn ceil_char_boundary(text: &str, mut i: usize) -> usize {
+    while !text.is_char_boundary(i) {
+        i += 1;
+    }
+    i
+}
+
+fn parse_tree(source: &str) -> tree_sitter::Tree {
+    parse_tree_from(source, None)
+}
+
+fn parse_tree_from(source: &str, old: Option<&tree_sitter::Tree>) -> tree_sitter::Tree {
     let mut parser = tree_sitter::Parser::new();
     parser
         .set_language(&crate::language::language())
         .expect("load AL grammar");
-    let tree = parser
-        .parse(source, None)
-        .expect("tree-sitter parse returned None");
-    lower::lower_file(RawNode::new(tree.root_node()), source)
+    parser
+        .parse(source, old)
+        .expect("tree-sitter parse returned None")
+}
+
+fn write_sexp(node: RawNode, depth: usize, out: &mut String) {
+    if depth > 0 {
+        out.push('\n');
+        out.push_str(&"  ".repeat(depth));
+    }
+    let range = node.byte_range();
+    out.push('(');
+    if node.is_missing() {
+        out.push_str("MISSING ");
+    }
+    out.push_str(&format!(
+        "{} [{}..{}]",
+        node.kind_str(),
+        range.start,
+        range.end
+    ));
+    for child in node.named_children() {
+        write_sexp(child, depth + 1, out);
+    }
+    out.push(')');
 }
 
 #[cfg(test)]
@@ -29,6 +152,97 @@ mod tests {
         assert_eq!(f.parse_status, ParseStatus::Clean);
     }
 
+    #[test]
+    fn dump_tree_shows_named_nodes_with_byte_ranges() {
+        let src = "codeunit 50000 Foo\n{\n    procedure Bar()\n    begin\n    end;\n}\n";
+        let dump = super::dump_tree(src);
+        assert!(dump.starts_with("(source_file [0.."), "{dump}");
+        assert!(dump.contains("\n  (codeunit_declaration [0.."), "{dump}");
+        assert!(dump.contains("(procedure ["), "{dump}");
+        assert!(!dump.contains("ERROR"), "{dump}");
+        assert_eq!(
+            dump.matches('(').count(),
+            dump.matches(')').count(),
+            "balanced: {dump}"
+        );
+    }
+
+    #[test]
+    fn comments_are_recorded_wherever_they_sit() {
+        let src = "// header\ncodeunit 50000 Foo\n{\n    procedure Bar()\n    begin\n        \
+                   Message('// not a comment'); /* block\n */\n    end;\n}\n";
+        let f = parse(src);
+        let comments: Vec<&str> = f.comments.iter().map(|r| &src[r.clone()]).collect();
+        assert_eq!(comments, ["// header", "/* block\n */"]);
+    }
+
+    /// Objects and routines with their kinds, names and byte spans — what a
+    /// declaration index is built from.
+    fn definitions(f: &crate::ir::AlFile) -> Vec<String> {
+        f.objects
+            .iter()
+            .flat_map(|o| {
+                let object = format!("{:?} {:?} {} {:?}", o.kind, o.id, o.name, o.origin.byte);
+                std::iter::once(object).chain(o.routines.iter().map(|r| {
+                    format!(
+                        "{:?} {} {:?} {:?} {}",
+                        r.kind,
+                        r.name,
+                        r.origin.byte,
+                        r.name_origin.byte,
+                        r.params.len()
+                    )
+                }))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn incremental_reparse_matches_a_full_parse() {
+        use super::{SourceEdit, parse_retained};
+
+        let mut text = String::from(
+            "codeunit 50000 Foo\n{\n    procedure Bar()\n    begin\n    end;\n\n    \
+             procedure Baz(X: Integer)\n    begin\n        Bar();\n    end;\n}\n",
+        );
+        let (first, mut retained) = parse_retained(&text);
+        assert_eq!(definitions(&first), definitions(&parse(&text)));
+
+        // Each edit's range is taken from the text as it stands after the
+        // previous one, the way an editor's change events arrive.
+        let bar = text.find("Bar()").unwrap();
+        retained.edit(
+            &mut text,
+            &SourceEdit {
+                range: bar..bar + 3,
+                text: "Renamed".into(),
+            },
+        );
+        let body = text.find("    end;").unwrap();
+        retained.edit(
+            &mut text,
+            &SourceEdit {
+                range: body..body,
+                text: "        Message('é');\n".into(),
+            },
+        );
+        let close = text.rfind('}').unwrap();
+        retained.edit(
+            &mut text,
+            &SourceEdit {
+                range: close..close,
+                text: "\n    trigger OnRun()\n    begin\n    end;\n".into(),
+            },
+        );
+
+        let incremental = retained.reparse(&text);
+        let full = parse(&text);
+        assert!(text.contains("procedure Renamed()"), "{text}");
+        assert_eq!(definitions(&incremental), definitions(&full));
+        assert_eq!(incremental.objects[0].routines.len(), 3);
+        assert_eq!(incremental.parse_status, full.parse_status);
+    }
+
     #[test]
     fn flags_recovery_on_broken_source() {
         let f = parse("codeunit 50000 Foo\n{\n    procedure Bar(  @@@ \n");
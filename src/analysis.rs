@@ -1,6 +1,7 @@
 //! Code quality analysis - cyclomatic complexity and metrics
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 
 /// Metrics for a single procedure/trigger
 #[derive(Debug, Clone, Serialize)]
@@ -13,11 +14,20 @@ pub struct ProcedureMetrics {
     pub complexity: u32,
     pub line_count: u32,
     pub parameter_count: u32,
+    /// Number of by-reference (`var`) parameters — see [`var_param_count_ir`].
+    pub var_param_count: u32,
+    /// Number of distinct other objects the procedure calls into, from the
+    /// resolved call graph (see [`apply_coupling`]); 0 until that pass runs.
+    pub coupling: usize,
+    /// Whether a page or report trigger reaches the procedure through the
+    /// resolved call graph (see [`apply_ui_reachability`]); `None` until
+    /// that pass runs.
+    pub ui_reachable: Option<bool>,
     pub quality_score: f32,
 }
 
 /// A finding/issue detected during analysis
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Finding {
     pub category: String,
     pub severity: String,
@@ -36,15 +46,445 @@ pub struct AnalysisSummary {
     pub warning_findings: usize,
 }
 
+/// A procedure's "blast radius": how many distinct procedures transitively
+/// call it, i.e. could be affected if it changes. See
+/// [`transitive_caller_counts`].
+#[derive(Debug, Clone, Serialize)]
+pub struct BlastRadius {
+    /// `Object.Procedure`, as in [`Finding::procedure`].
+    pub procedure: String,
+    /// `file:line`, as in [`Finding::location`].
+    pub location: String,
+    pub score: usize,
+}
+
+/// A procedure's outgoing calls grouped by the object they call into — see
+/// [`crate::lsp::snapshot::LspSnapshot::outgoing_calls_by_object`].
+#[derive(Debug, Clone, Serialize)]
+pub struct CalleeBreakdown {
+    /// `Object.Procedure`, as in [`Finding::procedure`].
+    pub procedure: String,
+    /// `file:line`, as in [`Finding::location`].
+    pub location: String,
+    /// Call sites per called object, most first; the procedure's own object
+    /// is `local`.
+    pub objects: Vec<ObjectCalls>,
+}
+
+/// The number of call sites a procedure has into one object.
+#[derive(Debug, Clone, Serialize)]
+pub struct ObjectCalls {
+    pub object: String,
+    pub calls: usize,
+}
+
+impl std::fmt::Display for CalleeBreakdown {
+    /// `Customer (3), Vendor (1), local (2)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, o) in self.objects.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{} ({})", o.object, o.calls)?;
+        }
+        Ok(())
+    }
+}
+
+/// A procedure's "churn risk": complex code that many call sites depend on,
+/// the first place to look when prioritizing refactoring. See
+/// [`churn_risk_ranking`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ChurnRisk {
+    /// `Object.Procedure`, as in [`Finding::procedure`].
+    pub procedure: String,
+    /// `file:line`, as in [`Finding::location`].
+    pub location: String,
+    pub complexity: u32,
+    pub incoming_calls: usize,
+    /// `complexity * ln(1 + incoming_calls)`.
+    pub score: f64,
+}
+
 /// Complete analysis result
 #[derive(Debug, Clone, Serialize)]
 pub struct AnalysisResult {
     pub metrics: Vec<ProcedureMetrics>,
     pub findings: Vec<Finding>,
+    /// Procedures with at least one transitive caller, highest score first.
+    pub blast_radius: Vec<BlastRadius>,
+    /// Procedures with at least one incoming call, highest score first.
+    pub churn_risk: Vec<ChurnRisk>,
+    /// Procedures with at least one resolved outgoing call, those calling
+    /// into the most other objects first.
+    pub callees_by_object: Vec<CalleeBreakdown>,
     pub summary: AnalysisSummary,
 }
 
+impl AnalysisResult {
+    /// Put `metrics` and `findings` in a run-independent order — by file,
+    /// then line, then procedure (then category/description for findings
+    /// sharing a location) — so parallel parsing and directory walk order
+    /// never reorder the report between runs.
+    pub fn sort_for_output(&mut self) {
+        self.metrics.sort_by(|a, b| {
+            (&a.file, a.line, &a.procedure_name, &a.object_name).cmp(&(
+                &b.file,
+                b.line,
+                &b.procedure_name,
+                &b.object_name,
+            ))
+        });
+        self.findings.sort_by(|a, b| {
+            let (a_file, a_line) = split_location(&a.location);
+            let (b_file, b_line) = split_location(&b.location);
+            (a_file, a_line, &a.procedure, &a.category, &a.description).cmp(&(
+                b_file,
+                b_line,
+                &b.procedure,
+                &b.category,
+                &b.description,
+            ))
+        });
+    }
+
+    /// Drop every finding the `baseline` already records and recount the
+    /// summary over what is left, so only new findings are reported.
+    pub fn retain_new(&mut self, baseline: &Baseline) {
+        self.findings.retain(|f| !baseline.contains(f));
+        self.summary = build_summary(&self.metrics, &self.findings);
+    }
+
+    /// Keep only what belongs to procedures in `files` (report paths, as in
+    /// [`ProcedureMetrics::file`]) and recount the summary —
+    /// `--since-git-ref`'s changed-file scope. The analysis itself still ran
+    /// over the whole project, so cross-file facts such as callers and
+    /// duplicates stay complete for the procedures that remain.
+    pub fn retain_files(&mut self, files: &HashSet<String>) {
+        let in_scope = |location: &str| files.contains(split_location(location).0);
+        self.metrics.retain(|m| files.contains(&m.file));
+        self.findings.retain(|f| in_scope(&f.location));
+        self.blast_radius.retain(|b| in_scope(&b.location));
+        self.churn_risk.retain(|c| in_scope(&c.location));
+        self.callees_by_object.retain(|c| in_scope(&c.location));
+        self.summary = build_summary(&self.metrics, &self.findings);
+    }
+
+    /// Rewrite every report path — [`ProcedureMetrics::file`], each
+    /// `location`, and the member list of `duplicate_code` descriptions —
+    /// through `display` (report path → printed form); paths it doesn't name
+    /// are left alone. `--analyze` joins and scopes its results on
+    /// project-relative paths, since bare file names collide across folders,
+    /// and only shortens them for display once that is done.
+    pub fn rename_files(&mut self, display: &HashMap<String, String>) {
+        let relocate = |location: &mut String| {
+            if let Some((file, line)) = location.rsplit_once(':')
+                && let Some(shown) = display.get(file)
+            {
+                *location = format!("{shown}:{line}");
+            }
+        };
+        for m in &mut self.metrics {
+            if let Some(shown) = display.get(&m.file) {
+                m.file = shown.clone();
+            }
+        }
+        for f in &mut self.findings {
+            relocate(&mut f.location);
+            if f.category == "duplicate_code" {
+                for (file, shown) in display {
+                    f.description = f
+                        .description
+                        .replace(&format!("({file}:"), &format!("({shown}:"));
+                }
+            }
+        }
+        for b in &mut self.blast_radius {
+            relocate(&mut b.location);
+        }
+        for c in &mut self.churn_risk {
+            relocate(&mut c.location);
+        }
+        for c in &mut self.callees_by_object {
+            relocate(&mut c.location);
+        }
+    }
+
+    /// The JSON document printed by `--analyze --format json`: the whole result,
+    /// or with `summary_only` just the [`AnalysisSummary`] object (no per-procedure
+    /// `metrics`/`findings` arrays — compact output for dashboards on huge repos).
+    pub fn to_json(&self, summary_only: bool) -> serde_json::Result<String> {
+        if summary_only {
+            serde_json::to_string_pretty(&self.summary)
+        } else {
+            serde_json::to_string_pretty(self)
+        }
+    }
+
+    /// The self-contained HTML page printed by `--analyze --format html`: a
+    /// summary header, then (unless `summary_only`) a procedures table and a
+    /// findings table, each sortable by clicking a column header. No
+    /// external assets; every text value is escaped.
+    pub fn to_html(&self, title: &str, summary_only: bool) -> String {
+        let s = &self.summary;
+        let summary = render_template(
+            HTML_SUMMARY,
+            &[
+                ("procedures", s.total_procedures.to_string()),
+                ("avg_complexity", format!("{:.1}", s.avg_complexity)),
+                ("avg_quality", format!("{:.1}", s.avg_quality_score)),
+                ("critical", s.critical_findings.to_string()),
+                ("warning", s.warning_findings.to_string()),
+            ],
+        );
+        let mut body = summary;
+        if !summary_only {
+            let procedures: Vec<Vec<String>> = self
+                .metrics
+                .iter()
+                .map(|m| {
+                    vec![
+                        format!("{}.{}", m.object_name, m.procedure_name),
+                        m.object_type.clone(),
+                        format!("{}:{}", m.file, m.line),
+                        m.complexity.to_string(),
+                        m.line_count.to_string(),
+                        m.parameter_count.to_string(),
+                        format!("{:.1}", m.quality_score),
+                    ]
+                })
+                .collect();
+            body.push_str(&html_table(
+                "Procedures",
+                &[
+                    "Procedure",
+                    "Type",
+                    "Location",
+                    "CC",
+                    "Lines",
+                    "Params",
+                    "Score",
+                ],
+                &procedures,
+            ));
+            let findings: Vec<Vec<String>> = self
+                .findings
+                .iter()
+                .map(|f| {
+                    vec![
+                        f.severity.clone(),
+                        f.category.clone(),
+                        f.location.clone(),
+                        f.procedure.clone(),
+                        f.description.clone(),
+                    ]
+                })
+                .collect();
+            body.push_str(&html_table(
+                "Findings",
+                &[
+                    "Severity",
+                    "Category",
+                    "Location",
+                    "Procedure",
+                    "Description",
+                ],
+                &findings,
+            ));
+        }
+        render_template(HTML_PAGE, &[("title", html_escape(title)), ("body", body)])
+    }
+}
+
+const HTML_PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Code Quality Analysis: {{title}}</title>
+<style>
+body { font-family: system-ui, sans-serif; margin: 2em; }
+table { border-collapse: collapse; margin-bottom: 2em; }
+th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: left; }
+th { background: #f0f0f0; cursor: pointer; user-select: none; }
+td.num { text-align: right; }
+</style>
+</head>
+<body>
+<h1>Code Quality Analysis: {{title}}</h1>
+{{body}}<script>
+document.querySelectorAll("table.sortable th").forEach(function (th, _, all) {
+  th.addEventListener("click", function () {
+    var table = th.closest("table"), body = table.tBodies[0];
+    var col = Array.prototype.indexOf.call(th.parentNode.children, th);
+    var asc = th.dataset.order !== "asc";
+    th.parentNode.querySelectorAll("th").forEach(function (h) { delete h.dataset.order; });
+    th.dataset.order = asc ? "asc" : "desc";
+    var rows = Array.prototype.slice.call(body.rows);
+    rows.sort(function (a, b) {
+      var x = a.cells[col].textContent, y = b.cells[col].textContent;
+      var nx = parseFloat(x), ny = parseFloat(y);
+      var c = !isNaN(nx) && !isNaN(ny) ? nx - ny : x.localeCompare(y);
+      return asc ? c : -c;
+    });
+    rows.forEach(function (row) { body.appendChild(row); });
+  });
+});
+</script>
+</body>
+</html>
+"#;
+
+const HTML_SUMMARY: &str = r#"<table class="summary">
+<tr><th>Procedures</th><td class="num">{{procedures}}</td></tr>
+<tr><th>Average complexity</th><td class="num">{{avg_complexity}}</td></tr>
+<tr><th>Average quality score</th><td class="num">{{avg_quality}}</td></tr>
+<tr><th>Critical findings</th><td class="num">{{critical}}</td></tr>
+<tr><th>Warning findings</th><td class="num">{{warning}}</td></tr>
+</table>
+"#;
+
+/// Replace each `{{key}}` in `template` with its value, in one pass over the
+/// template (a value is never re-scanned for placeholders). Values are
+/// inserted verbatim; escape them first where they are text.
+fn render_template(template: &str, values: &[(&str, String)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            rest = &rest[start..];
+            break;
+        };
+        let key = &after[..end];
+        match values.iter().find(|(k, _)| *k == key) {
+            Some((_, value)) => out.push_str(value),
+            None => out.push_str(&rest[start..start + 2 + end + 2]),
+        }
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// A sortable table under an `<h2>` heading; cells that parse as numbers
+/// are right-aligned.
+fn html_table(heading: &str, columns: &[&str], rows: &[Vec<String>]) -> String {
+    let mut out = format!(
+        "<h2>{} ({})</h2>\n<table class=\"sortable\">\n<thead><tr>",
+        html_escape(heading),
+        rows.len()
+    );
+    for c in columns {
+        out.push_str(&format!("<th>{}</th>", html_escape(c)));
+    }
+    out.push_str("</tr></thead>\n<tbody>\n");
+    for row in rows {
+        out.push_str("<tr>");
+        for cell in row {
+            let class = if cell.parse::<f64>().is_ok() {
+                " class=\"num\""
+            } else {
+                ""
+            };
+            out.push_str(&format!("<td{class}>{}</td>", html_escape(cell)));
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str("</tbody>\n</table>\n");
+    out
+}
+
+fn html_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// A previously exported set of findings (`--baseline`): an `--analyze
+/// --format json` report, or a bare array of its findings. A finding is in
+/// the baseline when one there has the same category, location and
+/// procedure.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    keys: HashSet<(String, String, String)>,
+}
+
+impl Baseline {
+    /// Parse a baseline document. A summary-only report has no findings to
+    /// match and is rejected.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Document {
+            Report { findings: Vec<Finding> },
+            Findings(Vec<Finding>),
+        }
+        let (Document::Report { findings } | Document::Findings(findings)) =
+            serde_json::from_str(json)?;
+        Ok(Self {
+            keys: findings
+                .into_iter()
+                .map(|f| (f.category, f.location, f.procedure))
+                .collect(),
+        })
+    }
+
+    /// `true` when `finding` is already recorded in the baseline.
+    pub fn contains(&self, finding: &Finding) -> bool {
+        self.keys.contains(&(
+            finding.category.clone(),
+            finding.location.clone(),
+            finding.procedure.clone(),
+        ))
+    }
+}
+
 use crate::config::DiagnosticConfig;
+use std::path::Path;
+
+/// Split a `file:line` location into its file and numeric line (`0` when
+/// the suffix is not a number), so `a.al:10` sorts after `a.al:9`.
+fn split_location(location: &str) -> (&str, u32) {
+    match location.rsplit_once(':') {
+        Some((file, line)) => match line.parse() {
+            Ok(n) => (file, n),
+            Err(_) => (location, 0),
+        },
+        None => (location, 0),
+    }
+}
+
+/// The `file` string reported for `path` in analysis output. By default just
+/// the file name (compact, but ambiguous across folders); with
+/// `workspace_root` set, the path relative to that root with `/` separators,
+/// so a report is both unambiguous and portable across machines. A path
+/// outside `workspace_root` falls back to its full display form.
+pub fn report_path(path: &Path, workspace_root: Option<&Path>) -> String {
+    match workspace_root {
+        Some(root) => match path.strip_prefix(root) {
+            Ok(rel) => rel
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+            Err(_) => path.display().to_string(),
+        },
+        None => path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string()),
+    }
+}
 
 /// Calculate quality score on a 0-10 scale
 /// Based on tree-sitter-mcp's quality score formula
@@ -147,8 +587,8 @@ pub fn generate_findings(metrics: &ProcedureMetrics, config: &DiagnosticConfig)
         findings.push(Finding {
             category: "too_many_parameters".to_string(),
             severity: "warning".to_string(),
-            location,
-            procedure,
+            location: location.clone(),
+            procedure: procedure.clone(),
             description: format!(
                 "Parameter count {} exceeds warning threshold of {}",
                 metrics.parameter_count, config.params_warning
@@ -156,9 +596,522 @@ pub fn generate_findings(metrics: &ProcedureMetrics, config: &DiagnosticConfig)
         });
     }
 
+    // By-reference (`var`) parameter findings
+    if config.var_params_enabled && metrics.var_param_count >= config.var_params_warning {
+        findings.push(Finding {
+            category: "many_var_parameters".to_string(),
+            severity: "warning".to_string(),
+            location,
+            procedure,
+            description: format!(
+                "{} by-reference (var) parameters exceed warning threshold of {}",
+                metrics.var_param_count, config.var_params_warning
+            ),
+        });
+    }
+
+    findings
+}
+
+/// Generate findings that need the routine's IR rather than just its
+/// [`ProcedureMetrics`]: unchecked risky record calls
+/// (`config.unchecked_risky_calls`, opt-in), one `warning` per offending
+/// call; a procedure that recurses before any base case
+/// (`config.unbounded_recursion`); a return-type procedure that can fall
+/// off its end (`config.missing_return`, opt-in); and one `warning` per
+/// `else if` chain of at least `config.long_if_chain_warning` branches.
+pub fn generate_ir_findings(
+    metrics: &ProcedureMetrics,
+    ir: &ir::Ir,
+    r: &RoutineDecl,
+    config: &DiagnosticConfig,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let procedure = format!("{}.{}", metrics.object_name, metrics.procedure_name);
+
+    if config.unchecked_risky_calls {
+        for call in unchecked_risky_calls_ir(ir, r) {
+            findings.push(Finding {
+                category: "unchecked_risky_call".to_string(),
+                severity: "warning".to_string(),
+                location: format!("{}:{}", metrics.file, call.line),
+                procedure: procedure.clone(),
+                description: format!(
+                    "Result of {}() is not checked; a failure raises a runtime error \
+                     (wrap in `if ... then` or a [TryFunction])",
+                    call.method
+                ),
+            });
+        }
+    }
+
+    if config.unbounded_recursion
+        && let Some(line) = unguarded_self_recursion_ir(ir, r)
+    {
+        findings.push(Finding {
+            category: "unbounded_recursion".to_string(),
+            severity: "warning".to_string(),
+            location: format!("{}:{}", metrics.file, line),
+            procedure: procedure.clone(),
+            description: format!(
+                "{} calls itself with no `exit` or `Error(...)` before the call \
+                 and no condition around it; every call recurses again until the \
+                 stack overflows",
+                metrics.procedure_name
+            ),
+        });
+    }
+
+    if config.missing_return && falls_through_without_exit_ir(ir, r) {
+        findings.push(Finding {
+            category: "missing_return".to_string(),
+            severity: "warning".to_string(),
+            location: format!("{}:{}", metrics.file, metrics.line),
+            procedure: procedure.clone(),
+            description: format!(
+                "{} declares a return type but some path reaches `end` without \
+                 `exit(...)`; it silently returns the type's default value",
+                metrics.procedure_name
+            ),
+        });
+    }
+
+    let implicit_rec = matches!(metrics.object_type.as_str(), "Table" | "Tableextension");
+    for write in unexpected_writes_ir(ir, r, &config.read_only_prefixes, implicit_rec) {
+        findings.push(Finding {
+            category: "unexpected_write".to_string(),
+            severity: "info".to_string(),
+            location: format!("{}:{}", metrics.file, write.line),
+            procedure: procedure.clone(),
+            description: format!(
+                "{} reads like a query but calls {}(); callers may not expect it \
+                 to change data",
+                metrics.procedure_name, write.method
+            ),
+        });
+    }
+
+    let boolean_params = boolean_param_count_ir(r);
+    if config.boolean_params_enabled && boolean_params > config.boolean_params_warning {
+        findings.push(Finding {
+            category: "boolean_parameter_overload".to_string(),
+            severity: "warning".to_string(),
+            location: format!("{}:{}", metrics.file, metrics.line),
+            procedure: procedure.clone(),
+            description: format!(
+                "{} Boolean parameters exceed warning threshold of {}; call sites \
+                 like `(true, false, true)` are hard to read",
+                boolean_params, config.boolean_params_warning
+            ),
+        });
+    }
+
+    if config.long_if_chain_enabled {
+        for chain in long_if_chains_ir(ir, r) {
+            if chain.branches >= config.long_if_chain_warning {
+                findings.push(Finding {
+                    category: "long_if_chain".to_string(),
+                    severity: "warning".to_string(),
+                    location: format!("{}:{}", metrics.file, chain.line),
+                    procedure: procedure.clone(),
+                    description: format!(
+                        "if/else-if chain has {} branches (exceeds warning threshold of {}); \
+                         consider a `case` statement",
+                        chain.branches, config.long_if_chain_warning
+                    ),
+                });
+            }
+        }
+    }
+
     findings
 }
 
+/// Rank procedures by churn risk, `complexity * ln(1 + incoming_calls)`:
+/// complexity weighs how hard a change is, the damped fan-in how far it
+/// reaches. `incoming_calls` maps a metric's `file:line` location to its
+/// number of incoming call sites; procedures without any score zero and are
+/// omitted. Highest score first, ties by procedure then location.
+pub fn churn_risk_ranking(
+    metrics: &[ProcedureMetrics],
+    incoming_calls: &std::collections::HashMap<String, usize>,
+) -> Vec<ChurnRisk> {
+    let mut out: Vec<ChurnRisk> = metrics
+        .iter()
+        .filter_map(|m| {
+            let location = format!("{}:{}", m.file, m.line);
+            let incoming = *incoming_calls.get(&location)?;
+            (incoming > 0).then(|| ChurnRisk {
+                procedure: format!("{}.{}", m.object_name, m.procedure_name),
+                location,
+                complexity: m.complexity,
+                incoming_calls: incoming,
+                score: f64::from(m.complexity) * (incoming as f64).ln_1p(),
+            })
+        })
+        .collect();
+    out.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.procedure.cmp(&b.procedure))
+            .then_with(|| a.location.cmp(&b.location))
+    });
+    out
+}
+
+/// Fill each metric's [`ProcedureMetrics::coupling`] from `coupling`, keyed
+/// by the metric's `file:line` location like [`churn_risk_ranking`]'s input;
+/// a procedure missing from it calls no other object.
+pub fn apply_coupling(
+    metrics: &mut [ProcedureMetrics],
+    coupling: &std::collections::HashMap<String, usize>,
+) {
+    for m in metrics {
+        m.coupling = coupling
+            .get(&format!("{}:{}", m.file, m.line))
+            .copied()
+            .unwrap_or(0);
+    }
+}
+
+/// Fill each metric's [`ProcedureMetrics::ui_reachable`] from `reachable`,
+/// keyed by `file:line` location like [`apply_coupling`]; a procedure
+/// missing from it stays `None`.
+pub fn apply_ui_reachability(
+    metrics: &mut [ProcedureMetrics],
+    reachable: &std::collections::HashMap<String, bool>,
+) {
+    for m in metrics {
+        m.ui_reachable = reachable.get(&format!("{}:{}", m.file, m.line)).copied();
+    }
+}
+
+/// One `high_coupling` warning per procedure calling into more distinct
+/// objects than `config.coupling_warning`.
+pub fn coupling_findings(metrics: &[ProcedureMetrics], config: &DiagnosticConfig) -> Vec<Finding> {
+    if !config.coupling_enabled {
+        return Vec::new();
+    }
+    metrics
+        .iter()
+        .filter(|m| m.coupling > config.coupling_warning)
+        .map(|m| Finding {
+            category: "high_coupling".to_string(),
+            severity: "warning".to_string(),
+            location: format!("{}:{}", m.file, m.line),
+            procedure: format!("{}.{}", m.object_name, m.procedure_name),
+            description: format!(
+                "Calls into {} other objects (exceeds warning threshold of {}); \
+                 consider splitting it up",
+                m.coupling, config.coupling_warning
+            ),
+        })
+        .collect()
+}
+
+/// For every node of a call graph given as `callers[i]` = the direct callers
+/// of node `i`, the number of distinct nodes that reach `i` through one or
+/// more calls (its transitive caller set, excluding `i` itself even when it
+/// is recursive).
+///
+/// Reverse reachability is memoized per strongly connected component: an
+/// iterative Tarjan pass emits each component only after every component
+/// its callers belong to, so a component's ancestor set is its own members
+/// plus the already-computed sets of its callers' components — each set is
+/// built once and shared by all members of a cycle.
+pub fn transitive_caller_counts(callers: &[Vec<usize>]) -> Vec<usize> {
+    use std::collections::BTreeSet;
+
+    const UNVISITED: usize = usize::MAX;
+    let n = callers.len();
+    let mut index = vec![UNVISITED; n];
+    let mut low = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut component = vec![UNVISITED; n];
+    let mut stack = Vec::new();
+    let mut reach: Vec<BTreeSet<usize>> = Vec::new();
+    let mut next = 0;
+
+    for root in 0..n {
+        if index[root] != UNVISITED {
+            continue;
+        }
+        index[root] = next;
+        low[root] = next;
+        next += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        let mut work = vec![(root, 0usize)];
+
+        while let Some(&(v, i)) = work.last() {
+            if let Some(&w) = callers[v].get(i) {
+                work.last_mut().expect("non-empty").1 += 1;
+                if index[w] == UNVISITED {
+                    index[w] = next;
+                    low[w] = next;
+                    next += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    work.push((w, 0));
+                } else if on_stack[w] {
+                    low[v] = low[v].min(index[w]);
+                }
+                continue;
+            }
+
+            work.pop();
+            if let Some(&(parent, _)) = work.last() {
+                low[parent] = low[parent].min(low[v]);
+            }
+            if low[v] != index[v] {
+                continue;
+            }
+            let c = reach.len();
+            let mut members = Vec::new();
+            loop {
+                let w = stack.pop().expect("v is on the stack");
+                on_stack[w] = false;
+                component[w] = c;
+                members.push(w);
+                if w == v {
+                    break;
+                }
+            }
+            let mut set: BTreeSet<usize> = members.iter().copied().collect();
+            for &m in &members {
+                for &w in &callers[m] {
+                    if component[w] != c {
+                        set.extend(reach[component[w]].iter().copied());
+                    }
+                }
+            }
+            reach.push(set);
+        }
+    }
+
+    (0..n).map(|v| reach[component[v]].len() - 1).collect()
+}
+
+/// A procedure body's normalized-token fingerprint, collected per file and
+/// grouped project-wide by [`duplicate_code_findings`].
+#[derive(Debug, Clone)]
+pub struct BodyFingerprint {
+    /// `Object.Procedure`, as in [`Finding::procedure`].
+    pub procedure: String,
+    /// `file:line`, as in [`Finding::location`].
+    pub location: String,
+    pub hash: u64,
+}
+
+/// One `duplicate_code` warning per group of two or more procedures whose
+/// bodies share a [`BodyFingerprint::hash`], located at the group's first
+/// member and listing every member. Members are ordered by file, then line,
+/// then procedure, and groups by their first member, so the output does not
+/// depend on the order files were read in.
+pub fn duplicate_code_findings(fingerprints: &[BodyFingerprint]) -> Vec<Finding> {
+    let mut groups: Vec<Vec<&BodyFingerprint>> = Vec::new();
+    let mut by_hash: std::collections::HashMap<u64, usize> = std::collections::HashMap::new();
+    for fp in fingerprints {
+        match by_hash.get(&fp.hash) {
+            Some(&i) => groups[i].push(fp),
+            None => {
+                by_hash.insert(fp.hash, groups.len());
+                groups.push(vec![fp]);
+            }
+        }
+    }
+
+    let order = |a: &&BodyFingerprint, b: &&BodyFingerprint| {
+        (split_location(&a.location), &a.procedure)
+            .cmp(&(split_location(&b.location), &b.procedure))
+    };
+    for g in &mut groups {
+        g.sort_by(order);
+    }
+    groups.retain(|g| g.len() > 1);
+    groups.sort_by(|a, b| order(&a[0], &b[0]));
+    groups
+        .into_iter()
+        .map(|g| {
+            let members: Vec<String> = g
+                .iter()
+                .map(|fp| format!("{} ({})", fp.procedure, fp.location))
+                .collect();
+            Finding {
+                category: "duplicate_code".to_string(),
+                severity: "warning".to_string(),
+                location: g[0].location.clone(),
+                procedure: g[0].procedure.clone(),
+                description: format!(
+                    "{} procedures have identical bodies: {}",
+                    g.len(),
+                    members.join(", ")
+                ),
+            }
+        })
+        .collect()
+}
+
+/// How many leading lines [`is_generated_source`] searches for the marker.
+pub const GENERATED_HEADER_LINES: usize = 10;
+
+/// Whether `source` declares itself generated: one of its first
+/// [`GENERATED_HEADER_LINES`] lines is a comment (`//`, `/*` or a `*`
+/// continuation) containing `marker`, case-insensitively — e.g.
+/// `// <auto-generated>`. An empty marker never matches.
+pub fn is_generated_source(source: &str, marker: &str) -> bool {
+    if marker.is_empty() {
+        return false;
+    }
+    let marker = marker.to_lowercase();
+    source
+        .lines()
+        .take(GENERATED_HEADER_LINES)
+        .map(|line| line.trim_start_matches('\u{feff}').trim_start())
+        .filter(|line| line.starts_with("//") || line.starts_with("/*") || line.starts_with('*'))
+        .any(|line| line.to_lowercase().contains(&marker))
+}
+
+/// A comment line carrying one of the configured markers (`TODO`, `FIXME`,
+/// `HACK`, ...), found by [`marker_comments`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkerComment {
+    /// The marker as configured.
+    pub marker: String,
+    /// The comment text from the marker to the end of its line, e.g.
+    /// `TODO: fix this`.
+    pub text: String,
+    /// 0-based row of the marker.
+    pub row: u32,
+    /// Byte column of the marker within `row`.
+    pub column: u32,
+    /// Byte column where `text` ends.
+    pub end_column: u32,
+}
+
+/// The comments of `f` ([`ir::AlFile::comments`]) containing one of
+/// `config.comment_markers` as a whole, case-sensitive word. A block comment
+/// is checked line by line; each line yields at most one (the earliest)
+/// marker. Comments inside an object of an excluded type are skipped, as are
+/// those outside every object when all the file's objects are excluded.
+/// Empty unless `config.todo_comments` is on.
+pub fn marker_comments(
+    source: &str,
+    f: &ir::AlFile,
+    config: &DiagnosticConfig,
+) -> Vec<MarkerComment> {
+    let mut out = Vec::new();
+    if !config.todo_comments || config.comment_markers.is_empty() {
+        return out;
+    }
+    let all_excluded = !f.objects.is_empty() && f.objects.iter().all(|o| config.excludes(o.kind));
+    for range in &f.comments {
+        let excluded = match f
+            .objects
+            .iter()
+            .find(|o| o.origin.byte.start <= range.start && range.end <= o.origin.byte.end)
+        {
+            Some(obj) => config.excludes(obj.kind),
+            None => all_excluded,
+        };
+        if excluded {
+            continue;
+        }
+        let text = &source[range.clone()];
+        let (start, end) = match text.strip_prefix("/*") {
+            Some(body) => (
+                range.start + 2,
+                range.start + 2 + body.strip_suffix("*/").unwrap_or(body).len(),
+            ),
+            None => (range.start + 2, range.end),
+        };
+        scan_comment_lines(source, start, end, &config.comment_markers, &mut out);
+    }
+    out
+}
+
+/// Check each line of the comment body `source[start..end]` for a marker.
+fn scan_comment_lines(
+    source: &str,
+    start: usize,
+    end: usize,
+    markers: &[String],
+    out: &mut Vec<MarkerComment>,
+) {
+    let mut line_start = start;
+    for line in source[start..end].split('\n') {
+        if let Some((marker, at)) = find_marker(line, markers) {
+            let text = line[at..].trim_end();
+            let abs = line_start + at;
+            let row_start = source[..abs].rfind('\n').map_or(0, |n| n + 1);
+            let row = source[..row_start].matches('\n').count() as u32;
+            let column = (abs - row_start) as u32;
+            out.push(MarkerComment {
+                marker: marker.to_string(),
+                text: text.to_string(),
+                row,
+                column,
+                end_column: column + text.len() as u32,
+            });
+        }
+        line_start += line.len() + 1;
+    }
+}
+
+/// The earliest whole-word occurrence of any marker in `line`.
+fn find_marker<'m>(line: &str, markers: &'m [String]) -> Option<(&'m str, usize)> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    markers
+        .iter()
+        .filter(|m| !m.is_empty())
+        .filter_map(|m| {
+            line.match_indices(m.as_str())
+                .find(|&(at, _)| {
+                    let before = line[..at].chars().next_back();
+                    let after = line[at + m.len()..].chars().next();
+                    !before.is_some_and(is_word) && !after.is_some_and(is_word)
+                })
+                .map(|(at, _)| (m.as_str(), at))
+        })
+        .min_by_key(|&(_, at)| at)
+}
+
+/// One `info` finding (`todo_comment`) per [`MarkerComment`] in `source`,
+/// attributed to the enclosing `Object.Procedure` (or just the object for a
+/// comment outside any routine).
+pub fn marker_comment_findings(
+    source: &str,
+    f: &ir::AlFile,
+    file: &str,
+    config: &DiagnosticConfig,
+) -> Vec<Finding> {
+    marker_comments(source, f, config)
+        .into_iter()
+        .map(|c| {
+            let within = |o: &ir::Origin| o.start.row <= c.row && c.row <= o.end.row;
+            let procedure = f
+                .objects
+                .iter()
+                .find(|obj| within(&obj.origin))
+                .map(|obj| {
+                    let object_name = obj.name.trim_matches('"');
+                    match obj.routines.iter().find(|r| within(&r.origin)) {
+                        Some(r) => format!("{object_name}.{}", r.name.trim_matches('"')),
+                        None => object_name.to_string(),
+                    }
+                })
+                .unwrap_or_default();
+            Finding {
+                category: "todo_comment".to_string(),
+                severity: "info".to_string(),
+                location: format!("{}:{}", file, c.row + 1),
+                procedure,
+                description: c.text,
+            }
+        })
+        .collect()
+}
+
 /// Build analysis summary from metrics and findings
 pub fn build_summary(metrics: &[ProcedureMetrics], findings: &[Finding]) -> AnalysisSummary {
     let total = metrics.len();
@@ -196,6 +1149,8 @@ pub fn build_summary(metrics: &[ProcedureMetrics], findings: &[Finding]) -> Anal
 
 use al_syntax::ir::{self, BinaryOp, BlockId, BlockItem, ExprId, ExprKind, RoutineDecl, StmtKind};
 
+use crate::program::resolve::extract::object_run_kind;
+
 /// Cyclomatic complexity over the IR body. Base 1; +1 per if (+1 more if it has an
 /// else), +1 per loop, +1 per case branch, +1 per `and`/`or`. The canonical
 /// complexity metric (the tree-sitter `analysis::calculate_complexity` is retired).
@@ -321,6 +1276,75 @@ fn complexity_expr(ir: &ir::Ir, eid: ExprId, c: &mut u32) {
     for_each_subexpr(ir, eid, &mut |sub| complexity_expr(ir, sub, c));
 }
 
+/// Guard clauses in `r`'s body: an `if` without `else` whose only statement
+/// is an `exit` (`if not Found then exit;`). They keep the rest of the body
+/// flat rather than adding a path through it, so `discountGuardClauses`
+/// takes their branch point back out of the complexity score
+/// ([`routine_complexity_for`]).
+pub fn guard_clause_count_ir(ir: &ir::Ir, r: &RoutineDecl) -> u32 {
+    let Some(body) = r.body else {
+        return 0;
+    };
+    let range = ir.block(body).origin.byte.clone();
+    ir.iter_stmts()
+        .filter(|s| range.start <= s.origin.byte.start && s.origin.byte.end <= range.end)
+        .filter(|s| {
+            matches!(
+                &s.kind,
+                StmtKind::If {
+                    then_block,
+                    else_block: None,
+                    ..
+                } if block_is_lone_exit(ir, *then_block)
+            )
+        })
+        .count() as u32
+}
+
+fn block_is_lone_exit(ir: &ir::Ir, bid: BlockId) -> bool {
+    match ir.block(bid).items.as_slice() {
+        [BlockItem::Stmt(sid)] => match &ir.stmt(*sid).kind {
+            StmtKind::Exit(_) => true,
+            StmtKind::Block(b) => block_is_lone_exit(ir, *b),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// [`routine_complexity_ir`] as `config` asks for it: less one per guard
+/// clause ([`guard_clause_count_ir`]) under `discountGuardClauses`.
+pub fn routine_complexity_for(ir: &ir::Ir, r: &RoutineDecl, config: &DiagnosticConfig) -> u32 {
+    let complexity = routine_complexity_ir(ir, r);
+    if config.discount_guard_clauses {
+        complexity.saturating_sub(guard_clause_count_ir(ir, r))
+    } else {
+        complexity
+    }
+}
+
+/// Number of by-reference (`var`) parameters in a routine's signature. Many
+/// `var` parameters mean the routine hands results back through its
+/// arguments rather than a return value or a record, which usually points at
+/// a procedure doing several jobs at once.
+pub fn var_param_count_ir(r: &RoutineDecl) -> u32 {
+    r.params.iter().filter(|p| p.by_ref).count() as u32
+}
+
+/// Number of `Boolean` parameters in a routine's signature. Several of them
+/// make call sites such as `Post(true, false, true)` unreadable without
+/// looking up the declaration; an option/enum or separate procedures usually
+/// say more.
+pub fn boolean_param_count_ir(r: &RoutineDecl) -> u32 {
+    r.params
+        .iter()
+        .filter(|p| {
+            p.ty.as_deref()
+                .is_some_and(|ty| ty.trim().eq_ignore_ascii_case("boolean"))
+        })
+        .count() as u32
+}
+
 /// Visit the direct sub-expressions of an expression (one level). The caller
 /// recurses; this just enumerates children so the two walkers (`parser.rs`'s
 /// call-site walker, and this module's complexity walker) share one
@@ -359,132 +1383,1736 @@ pub(crate) fn for_each_subexpr(ir: &ir::Ir, eid: ExprId, f: &mut dyn FnMut(ExprI
     }
 }
 
-/// True for AL attributes whose procedure is invoked by a framework (the test
-/// runner or test framework) rather than by an explicit call, so the procedure
-/// must not be reported as unused. AL attribute names are case-insensitive.
-/// Event publishers/subscribers are handled separately and are not listed here.
-pub(crate) fn is_framework_invocation_attribute(name: &str) -> bool {
-    matches!(
-        name.to_ascii_lowercase().as_str(),
-        "test"
-            | "confirmhandler"
-            | "messagehandler"
-            | "pagehandler"
-            | "modalpagehandler"
-            | "reporthandler"
-            | "requestpagehandler"
-            | "sendnotificationhandler"
-            | "recallnotificationhandler"
-            | "sessionsettingshandler"
-            | "strmenuhandler"
-            | "filterpagehandler"
-            | "hyperlinkhandler"
-    )
+/// AL record methods that raise a runtime error on failure unless their
+/// Boolean return value is consumed (`if Rec.Get(...) then`, `Found :=
+/// Rec.Get(...)`, `exit(Rec.Insert())`, ...). Lowercased.
+const RISKY_RECORD_METHODS: &[&str] = &["get", "insert", "modify", "delete"];
+
+/// Record methods that change data, for [`unexpected_writes_ir`].
+const RECORD_WRITE_METHODS: &[&str] = &[
+    "insert",
+    "modify",
+    "modifyall",
+    "delete",
+    "deleteall",
+    "rename",
+];
+
+/// A data-changing call found by [`unexpected_writes_ir`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriteCall {
+    /// The method name as written (`Insert`, `Modify`, ...).
+    pub method: String,
+    /// 1-based source line of the call.
+    pub line: u32,
 }
 
-/// Render a procedure/trigger header as raw source text: everything from
-/// `r.origin`'s start up to (but not including) the body's `var` section or
-/// `begin` keyword, whitespace-collapsed to single spaces. Relocated here
-/// from `parser.rs` (T3 Task 13 review fix-wave — mirrors this module's own
-/// earlier relocation of [`is_framework_invocation_attribute`]/
-/// [`routine_complexity_ir`] above): `src/lsp/custom.rs`'s
-/// `event_publishers_in_file` needs this SAME signature-rendering logic (to
-/// stay byte-identical to what `parser.rs`'s own
-/// `ParsedEventPublisher::signature` produces for the same routine), but
-/// `parser.rs` is a documented Task-17 deletion target — sharing one
-/// definition here means neither module drifts, and Task 17 can delete
-/// `parser.rs` without orphaning anything `custom.rs` depends on.
-pub(crate) fn signature_ir(source: &str, r: &RoutineDecl) -> String {
-    let raw = &source[r.origin.byte.clone()];
-    let end = find_body_start(raw).unwrap_or(raw.len());
-    normalize_signature_ws(&raw[..end])
+/// `Insert`/`Modify`/`Delete`-style calls in a routine whose name starts
+/// with one of `read_only_prefixes` (case-insensitive, e.g. `Get`, `Find`,
+/// `Calc`) as a whole word — a name that promises a read. The prefix must
+/// be followed by the end of the name or a character that is not a
+/// lowercase letter, so `GetBalance` matches `Get` but `Calculate` does not
+/// match `Calc`. Matched on the method name alone, so a non-record receiver
+/// with a same-named method is reported too; with `implicit_rec` (a table or
+/// table extension) a bare `Modify()` on the implicit `Rec` counts as well.
+/// Empty when the name matches no prefix. Sorted by line.
+pub fn unexpected_writes_ir(
+    ir: &ir::Ir,
+    r: &RoutineDecl,
+    read_only_prefixes: &[String],
+    implicit_rec: bool,
+) -> Vec<WriteCall> {
+    let name = r.name.trim_matches('"');
+    let reads_only = read_only_prefixes.iter().any(|p| {
+        name.get(..p.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(p))
+            && name[p.len()..]
+                .chars()
+                .next()
+                .is_none_or(|c| !c.is_lowercase())
+    });
+    let Some(body) = r.body.filter(|_| reads_only) else {
+        return Vec::new();
+    };
+    let range = ir.block(body).origin.byte.clone();
+    let mut out: Vec<WriteCall> = ir
+        .iter_exprs()
+        .filter(|e| range.start <= e.origin.byte.start && e.origin.byte.end <= range.end)
+        .filter_map(|e| {
+            let ExprKind::Call { function, .. } = &e.kind else {
+                return None;
+            };
+            let method = match &ir.expr(*function).kind {
+                ExprKind::Member { member, .. } => member.trim_matches('"'),
+                ExprKind::Identifier(name) | ExprKind::QuotedIdentifier(name) if implicit_rec => {
+                    name.trim_matches('"')
+                }
+                _ => return None,
+            };
+            RECORD_WRITE_METHODS
+                .iter()
+                .any(|m| method.eq_ignore_ascii_case(m))
+                .then(|| WriteCall {
+                    method: method.to_string(),
+                    line: e.origin.start.row + 1,
+                })
+        })
+        .collect();
+    out.sort_by_key(|w| w.line);
+    out
 }
 
-/// Find the byte offset (relative to the start of `text`) where a procedure
-/// body begins (the `begin` keyword or `var` section). Returns `None` when no
-/// body marker is present in this slice.
-///
-/// Requires the keyword to be alone at the start of a line (preceded only by
-/// whitespace) so a `var` parameter modifier is never mistaken for the `var`
-/// section, and skips scanning inside string literals so a quoted
-/// identifier/comment containing "begin"/"var" text can't false-positive.
-fn find_body_start(text: &str) -> Option<usize> {
-    let bytes = text.as_bytes();
-    let len = bytes.len();
-    let mut i = 0;
-    let mut in_string = false;
-    let mut string_quote = 0u8;
-    while i < len {
-        let b = bytes[i];
-        if in_string {
-            if b == string_quote {
-                in_string = false;
+/// A statement-position risky record call found by [`unchecked_risky_calls_ir`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UncheckedCall {
+    /// The method name as written (`Get`, `Insert`, ...).
+    pub method: String,
+    /// 1-based source line of the call statement.
+    pub line: u32,
+}
+
+/// Risky record calls (`Get`/`Insert`/`Modify`/`Delete`) whose result the
+/// routine discards — i.e. the call IS the statement, so a failure surfaces as
+/// a runtime error rather than a handled `false`. Any expression position
+/// (an `if` condition, an assignment, an `exit(...)` argument, a call
+/// argument) counts as a Boolean context and is NOT reported. A
+/// `[TryFunction]` routine, a `try` body, and an `asserterror` body are
+/// treated as already guarded. Heuristic by design: a receiver that is not a
+/// record (a codeunit or dictionary with a same-named method) is still
+/// reported, which is why the finding is opt-in.
+pub fn unchecked_risky_calls_ir(ir: &ir::Ir, r: &RoutineDecl) -> Vec<UncheckedCall> {
+    let mut out = Vec::new();
+    if r.attributes.iter().any(|a| a == "tryfunction") {
+        return out;
+    }
+    if let Some(body) = r.body {
+        risky_calls_block(ir, body, &mut out);
+    }
+    out
+}
+
+fn risky_calls_block(ir: &ir::Ir, bid: BlockId, out: &mut Vec<UncheckedCall>) {
+    for item in &ir.block(bid).items {
+        match item {
+            BlockItem::Stmt(sid) => risky_calls_stmt(ir, *sid, out),
+            BlockItem::Preproc(g) => {
+                for b in &g.branches {
+                    risky_calls_block(ir, *b, out);
+                }
             }
-            i += 1;
-            continue;
         }
-        if b == b'\'' || b == b'"' {
-            in_string = true;
-            string_quote = b;
-            i += 1;
-            continue;
+    }
+}
+
+fn risky_calls_stmt(ir: &ir::Ir, sid: ir::StmtId, out: &mut Vec<UncheckedCall>) {
+    let stmt = ir.stmt(sid);
+    match &stmt.kind {
+        StmtKind::Call(e) => {
+            if let ExprKind::Call { function, .. } = &ir.expr(*e).kind
+                && let ExprKind::Member { member, .. } = &ir.expr(*function).kind
+            {
+                let method = member.trim_matches('"');
+                if RISKY_RECORD_METHODS
+                    .iter()
+                    .any(|m| method.eq_ignore_ascii_case(m))
+                {
+                    out.push(UncheckedCall {
+                        method: method.to_string(),
+                        line: stmt.origin.start.row + 1,
+                    });
+                }
+            }
         }
-        // Look at line starts only (`\n` followed by optional whitespace).
-        if b == b'\n' {
-            let mut j = i + 1;
-            while j < len && (bytes[j] == b' ' || bytes[j] == b'\t') {
-                j += 1;
+        StmtKind::If {
+            then_block,
+            else_block,
+            ..
+        } => {
+            risky_calls_block(ir, *then_block, out);
+            if let Some(b) = else_block {
+                risky_calls_block(ir, *b, out);
             }
-            if matches_keyword(bytes, j, b"begin") || matches_keyword(bytes, j, b"var") {
-                return Some(j);
+        }
+        StmtKind::While { body, .. }
+        | StmtKind::Repeat { body, .. }
+        | StmtKind::For { body, .. }
+        | StmtKind::Foreach { body, .. }
+        | StmtKind::With { body, .. } => risky_calls_block(ir, *body, out),
+        StmtKind::Case {
+            branches,
+            else_block,
+            ..
+        } => {
+            for br in branches {
+                risky_calls_block(ir, br.body, out);
+            }
+            if let Some(b) = else_block {
+                risky_calls_block(ir, *b, out);
             }
         }
-        i += 1;
+        StmtKind::Block(b) => risky_calls_block(ir, *b, out),
+        // `try` / `asserterror` bodies are guarded by construction.
+        _ => {}
     }
-    None
 }
 
-fn matches_keyword(bytes: &[u8], at: usize, kw: &[u8]) -> bool {
-    if at + kw.len() > bytes.len() {
+/// Whether a procedure with an anonymous return type (`procedure F(): Integer`)
+/// has a control-flow path that reaches the end of its body without an
+/// `exit(...)`, so the caller gets the type's default value. A path ends the
+/// routine at an `exit`, an `Error(...)` call, an `if` whose both branches
+/// end it, a `case` with an `else` whose every branch ends it, or a
+/// `repeat` whose body ends it. `while`/`for`/`foreach` bodies may not run
+/// at all, so they never count. Named returns (`procedure F() Result:
+/// Integer`) are skipped — assigning `Result` is how they return — as are
+/// triggers and routines that failed to parse. Best-effort: statements the
+/// lowerer does not model are assumed to end the routine, which is why the
+/// finding is opt-in.
+pub fn falls_through_without_exit_ir(ir: &ir::Ir, r: &RoutineDecl) -> bool {
+    if r.kind != ir::RoutineKind::Procedure
+        || r.return_type.is_none()
+        || r.return_name.is_some()
+        || r.parse_incomplete
+    {
         return false;
     }
-    if &bytes[at..at + kw.len()] != kw {
+    match r.body {
+        Some(body) => !block_always_exits(ir, body),
+        None => false,
+    }
+}
+
+fn block_always_exits(ir: &ir::Ir, bid: BlockId) -> bool {
+    for item in &ir.block(bid).items {
+        match item {
+            BlockItem::Stmt(sid) => {
+                if matches!(ir.stmt(*sid).kind, StmtKind::Break | StmtKind::Continue) {
+                    return false;
+                }
+                if stmt_always_exits(ir, *sid) {
+                    return true;
+                }
+            }
+            // Only one branch of a preprocessor group is compiled; give the
+            // benefit of the doubt when any of them ends the routine.
+            BlockItem::Preproc(g) => {
+                if g.branches.iter().any(|b| block_always_exits(ir, *b)) {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+fn stmt_always_exits(ir: &ir::Ir, sid: ir::StmtId) -> bool {
+    match &ir.stmt(sid).kind {
+        StmtKind::Exit(_) | StmtKind::Unknown => true,
+        StmtKind::Call(e) => is_error_call(ir, *e),
+        StmtKind::If {
+            then_block,
+            else_block,
+            ..
+        } => else_block
+            .is_some_and(|b| block_always_exits(ir, *then_block) && block_always_exits(ir, b)),
+        StmtKind::Case {
+            branches,
+            else_block,
+            ..
+        } => else_block.is_some_and(|b| {
+            block_always_exits(ir, b) && branches.iter().all(|br| block_always_exits(ir, br.body))
+        }),
+        StmtKind::Repeat { body, .. } | StmtKind::With { body, .. } | StmtKind::Block(body) => {
+            block_always_exits(ir, *body)
+        }
+        _ => false,
+    }
+}
+
+/// `Error(...)` in statement position — raises, so the routine never returns.
+fn is_error_call(ir: &ir::Ir, eid: ExprId) -> bool {
+    let ExprKind::Call { function, .. } = &ir.expr(eid).kind else {
         return false;
+    };
+    match &ir.expr(*function).kind {
+        ExprKind::Identifier(name) | ExprKind::QuotedIdentifier(name) => {
+            name.trim_matches('"').eq_ignore_ascii_case("error")
+        }
+        _ => false,
     }
-    let next = bytes.get(at + kw.len()).copied().unwrap_or(b' ');
-    !next.is_ascii_alphanumeric() && next != b'_'
 }
 
-/// Collapse runs of whitespace (including newlines) to single spaces, trimmed.
-fn normalize_signature_ws(raw: &str) -> String {
-    let mut out = String::with_capacity(raw.len());
-    let mut prev_space = false;
-    for ch in raw.chars() {
-        if ch.is_whitespace() {
-            if !prev_space && !out.is_empty() {
-                out.push(' ');
+/// An `if ... else if ...` chain found by [`long_if_chains_ir`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IfChain {
+    /// 1-based source line of the chain's first `if`.
+    pub line: u32,
+    /// Number of branches: the `then`, one per `else if`, and a trailing
+    /// plain `else`.
+    pub branches: u32,
+}
+
+/// Every `if` chain in the routine that continues with at least one
+/// `else if`, outermost first in source order. An `else if` is an `else`
+/// whose body is a lone `if` statement; `else begin if ... end` ends the
+/// chain. Chains nested inside a branch are reported on their own.
+pub fn long_if_chains_ir(ir: &ir::Ir, r: &RoutineDecl) -> Vec<IfChain> {
+    let mut out = Vec::new();
+    if let Some(body) = r.body {
+        if_chains_block(ir, body, &mut out);
+    }
+    out
+}
+
+fn if_chains_block(ir: &ir::Ir, bid: BlockId, out: &mut Vec<IfChain>) {
+    for item in &ir.block(bid).items {
+        match item {
+            BlockItem::Stmt(sid) => if_chains_stmt(ir, *sid, out),
+            BlockItem::Preproc(g) => {
+                for b in &g.branches {
+                    if_chains_block(ir, *b, out);
+                }
             }
-            prev_space = true;
-        } else {
-            out.push(ch);
-            prev_space = false;
         }
     }
-    out.trim().to_string()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn if_chains_stmt(ir: &ir::Ir, sid: ir::StmtId, out: &mut Vec<IfChain>) {
+    let stmt = ir.stmt(sid);
+    match &stmt.kind {
+        StmtKind::If {
+            then_block,
+            else_block,
+            ..
+        } => {
+            let slot = out.len();
+            let mut branches = 1;
+            let mut else_ifs = 0;
+            let mut bodies = vec![*then_block];
+            let mut next = *else_block;
+            while let Some(b) = next {
+                branches += 1;
+                match else_if(ir, b) {
+                    Some((then_block, else_block)) => {
+                        else_ifs += 1;
+                        bodies.push(then_block);
+                        next = else_block;
+                    }
+                    None => {
+                        bodies.push(b);
+                        next = None;
+                    }
+                }
+            }
+            for b in bodies {
+                if_chains_block(ir, b, out);
+            }
+            if else_ifs > 0 {
+                out.insert(
+                    slot,
+                    IfChain {
+                        line: stmt.origin.start.row + 1,
+                        branches,
+                    },
+                );
+            }
+        }
+        StmtKind::While { body, .. }
+        | StmtKind::Repeat { body, .. }
+        | StmtKind::For { body, .. }
+        | StmtKind::Foreach { body, .. }
+        | StmtKind::With { body, .. }
+        | StmtKind::AssertError(body)
+        | StmtKind::Block(body) => if_chains_block(ir, *body, out),
+        StmtKind::Case {
+            branches,
+            else_block,
+            ..
+        } => {
+            for br in branches {
+                if_chains_block(ir, br.body, out);
+            }
+            if let Some(b) = else_block {
+                if_chains_block(ir, *b, out);
+            }
+        }
+        StmtKind::Try { body, catch_block } => {
+            if_chains_block(ir, *body, out);
+            if let Some(b) = catch_block {
+                if_chains_block(ir, *b, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The `then`/`else` blocks of an `else` body that is a lone `if`.
+fn else_if(ir: &ir::Ir, bid: BlockId) -> Option<(BlockId, Option<BlockId>)> {
+    let [BlockItem::Stmt(sid)] = ir.block(bid).items.as_slice() else {
+        return None;
+    };
+    match &ir.stmt(*sid).kind {
+        StmtKind::If {
+            then_block,
+            else_block,
+            ..
+        } => Some((*then_block, *else_block)),
+        _ => None,
+    }
+}
+
+/// The line of a direct self-call that every run of the procedure reaches
+/// before any way out — no `exit`/`Error(...)` precedes it and no condition
+/// guards it — so each call recurses again until the stack overflows.
+/// `None` when there is no such call. A self-call is an unqualified call of
+/// the procedure's own name with its own arity. Any `if`/`case`/loop that
+/// can `exit` or raise counts as a base case; a self-call inside a condition
+/// counts as guarded. Best-effort: statements the lowerer does not model and
+/// preprocessor groups end the scan without a finding.
+pub fn unguarded_self_recursion_ir(ir: &ir::Ir, r: &RoutineDecl) -> Option<u32> {
+    if r.kind != ir::RoutineKind::Procedure || r.parse_incomplete {
+        return None;
+    }
+    match recursion_scan_block(ir, r.body?, r) {
+        RecursionScan::Recurses(line) => Some(line),
+        RecursionScan::Continue | RecursionScan::Guarded => None,
+    }
+}
+
+/// Outcome of scanning statements in execution order for
+/// [`unguarded_self_recursion_ir`].
+enum RecursionScan {
+    /// Nothing decisive yet; keep scanning.
+    Continue,
+    /// A way out (or something unmodelled) comes first.
+    Guarded,
+    /// An unconditional self-call on this 1-based line comes first.
+    Recurses(u32),
+}
+
+fn recursion_scan_block(ir: &ir::Ir, bid: BlockId, r: &RoutineDecl) -> RecursionScan {
+    for item in &ir.block(bid).items {
+        let BlockItem::Stmt(sid) = item else {
+            return RecursionScan::Guarded;
+        };
+        match recursion_scan_stmt(ir, *sid, r) {
+            RecursionScan::Continue => {}
+            decided => return decided,
+        }
+    }
+    RecursionScan::Continue
+}
+
+fn recursion_scan_stmt(ir: &ir::Ir, sid: ir::StmtId, r: &RoutineDecl) -> RecursionScan {
+    let stmt = ir.stmt(sid);
+    let recurses = RecursionScan::Recurses(stmt.origin.start.row + 1);
+    // The expression every run evaluates first, and the bodies that may not run.
+    let (head, bodies): (Vec<ExprId>, Vec<BlockId>) = match &stmt.kind {
+        StmtKind::Exit(e) => {
+            return if e.is_some_and(|e| calls_self(ir, e, r)) {
+                recurses
+            } else {
+                RecursionScan::Guarded
+            };
+        }
+        StmtKind::Call(e) if is_error_call(ir, *e) => return RecursionScan::Guarded,
+        StmtKind::Call(e) => (vec![*e], Vec::new()),
+        StmtKind::Assignment { target, value } => (vec![*target, *value], Vec::new()),
+        StmtKind::Block(b) => return recursion_scan_block(ir, *b, r),
+        StmtKind::With { receiver, body } => {
+            if calls_self(ir, *receiver, r) {
+                return recurses;
+            }
+            return recursion_scan_block(ir, *body, r);
+        }
+        StmtKind::Repeat { body, .. } => return recursion_scan_block(ir, *body, r),
+        StmtKind::If {
+            cond,
+            then_block,
+            else_block,
+        } => (
+            vec![*cond],
+            std::iter::once(*then_block).chain(*else_block).collect(),
+        ),
+        StmtKind::Case {
+            scrutinee,
+            branches,
+            else_block,
+        } => (
+            vec![*scrutinee],
+            branches.iter().map(|b| b.body).chain(*else_block).collect(),
+        ),
+        StmtKind::While { cond, body } => (vec![*cond], vec![*body]),
+        StmtKind::For { from, to, body, .. } => (vec![*from, *to], vec![*body]),
+        StmtKind::Foreach { iterable, body, .. } => (vec![*iterable], vec![*body]),
+        StmtKind::Try { .. }
+        | StmtKind::AssertError(_)
+        | StmtKind::Break
+        | StmtKind::Continue
+        | StmtKind::Unknown => return RecursionScan::Guarded,
+    };
+    if head.iter().any(|e| calls_self(ir, *e, r)) {
+        recurses
+    } else if bodies.iter().any(|b| block_may_leave(ir, *b)) {
+        RecursionScan::Guarded
+    } else {
+        RecursionScan::Continue
+    }
+}
+
+/// Whether `eid` contains a direct call of `r` itself (see
+/// [`unguarded_self_recursion_ir`]).
+fn calls_self(ir: &ir::Ir, eid: ExprId, r: &RoutineDecl) -> bool {
+    if let ExprKind::Call { function, args } = &ir.expr(eid).kind
+        && args.len() == r.params.len()
+        && let ExprKind::Identifier(name) | ExprKind::QuotedIdentifier(name) =
+            &ir.expr(*function).kind
+        && name.trim_matches('"').eq_ignore_ascii_case(&r.name)
+    {
+        return true;
+    }
+    let mut found = false;
+    for_each_subexpr(ir, eid, &mut |sub| found = found || calls_self(ir, sub, r));
+    found
+}
+
+/// Whether any statement under `bid` can end the routine: an `exit`, an
+/// `Error(...)` call, or a statement the lowerer does not model.
+fn block_may_leave(ir: &ir::Ir, bid: BlockId) -> bool {
+    ir.block(bid).items.iter().any(|item| match item {
+        BlockItem::Stmt(sid) => stmt_may_leave(ir, *sid),
+        BlockItem::Preproc(g) => g.branches.iter().any(|b| block_may_leave(ir, *b)),
+    })
+}
+
+fn stmt_may_leave(ir: &ir::Ir, sid: ir::StmtId) -> bool {
+    match &ir.stmt(sid).kind {
+        StmtKind::Exit(_) | StmtKind::Unknown => true,
+        StmtKind::Call(e) => is_error_call(ir, *e),
+        StmtKind::If {
+            then_block,
+            else_block,
+            ..
+        } => block_may_leave(ir, *then_block) || else_block.is_some_and(|b| block_may_leave(ir, b)),
+        StmtKind::Case {
+            branches,
+            else_block,
+            ..
+        } => {
+            branches.iter().any(|br| block_may_leave(ir, br.body))
+                || else_block.is_some_and(|b| block_may_leave(ir, b))
+        }
+        StmtKind::While { body, .. }
+        | StmtKind::Repeat { body, .. }
+        | StmtKind::For { body, .. }
+        | StmtKind::Foreach { body, .. }
+        | StmtKind::With { body, .. }
+        | StmtKind::Block(body)
+        | StmtKind::AssertError(body) => block_may_leave(ir, *body),
+        StmtKind::Try { body, catch_block } => {
+            block_may_leave(ir, *body) || catch_block.is_some_and(|b| block_may_leave(ir, b))
+        }
+        StmtKind::Assignment { .. } | StmtKind::Break | StmtKind::Continue => false,
+    }
+}
+
+/// Hash of a routine body's token sequence, for copy-paste detection
+/// ([`duplicate_code_findings`]). Whitespace, comments and keyword/identifier
+/// casing never matter; with `normalize_identifiers` every non-keyword
+/// identifier also collapses to one placeholder, so bodies that differ only
+/// by variable names still match. Returns `None` for a routine without a body
+/// or with fewer than `min_tokens` tokens (trivial bodies such as a lone
+/// `exit(true)` are not worth reporting).
+pub fn body_fingerprint_ir(
+    source: &str,
+    ir: &ir::Ir,
+    r: &RoutineDecl,
+    normalize_identifiers: bool,
+    min_tokens: usize,
+) -> Option<u64> {
+    use std::hash::{Hash, Hasher};
+
+    let body = ir.block(r.body?);
+    let text = source.get(body.origin.byte.clone())?;
+    let tokens = body_tokens(text, normalize_identifiers);
+    if tokens.len() < min_tokens {
+        return None;
+    }
+    let mut h = std::collections::hash_map::DefaultHasher::new();
+    tokens.hash(&mut h);
+    Some(h.finish())
+}
+
+/// Parameters `r` declares but never names in its body — a best-effort scan
+/// of the body's identifier tokens ([`body_tokens`]), so a mention in a
+/// comment does not count as a use. Routines whose signature is dictated
+/// from outside are skipped: triggers, event publishers and subscribers,
+/// framework-invoked handlers ([`is_framework_invocation_attribute`]), and
+/// the public procedures of an object that implements an interface. A
+/// routine without a body has nothing to scan.
+pub fn unused_parameters_ir<'r>(
+    source: &str,
+    ir: &ir::Ir,
+    obj: &ir::ObjectDecl,
+    r: &'r RoutineDecl,
+) -> Vec<&'r ir::Param> {
+    let fixed_signature = r.kind == ir::RoutineKind::Trigger
+        || (r.access_modifier.is_none() && !obj.implements.is_empty())
+        || r.attributes.iter().any(|a| {
+            matches!(
+                a.as_str(),
+                "eventsubscriber" | "integrationevent" | "businessevent" | "internalevent"
+            ) || is_framework_invocation_attribute(a)
+        });
+    if fixed_signature {
+        return Vec::new();
+    }
+    let Some(text) = r
+        .body
+        .and_then(|b| source.get(ir.block(b).origin.byte.clone()))
+    else {
+        return Vec::new();
+    };
+    let used: HashSet<String> = body_tokens(text, false).into_iter().collect();
+    r.params
+        .iter()
+        .filter(|p| !used.contains(&p.name.trim_matches('"').to_lowercase()))
+        .collect()
+}
+
+/// The identifiers (lowercased) `r`'s body names — its [`body_tokens`], so
+/// a mention in a comment does not count. Empty for a routine without a
+/// body.
+pub fn body_identifiers_ir(source: &str, ir: &ir::Ir, r: &RoutineDecl) -> HashSet<String> {
+    r.body
+        .and_then(|b| source.get(ir.block(b).origin.byte.clone()))
+        .map(|text| body_tokens(text, false).into_iter().collect())
+        .unwrap_or_default()
+}
+
+/// One informational `unused_parameter` finding per parameter
+/// [`unused_parameters_ir`] reports, located at the parameter.
+pub fn unused_parameter_findings(
+    metrics: &ProcedureMetrics,
+    source: &str,
+    ir: &ir::Ir,
+    obj: &ir::ObjectDecl,
+    r: &RoutineDecl,
+) -> Vec<Finding> {
+    unused_parameters_ir(source, ir, obj, r)
+        .into_iter()
+        .map(|p| Finding {
+            category: "unused_parameter".to_string(),
+            severity: "info".to_string(),
+            location: format!("{}:{}", metrics.file, p.origin.start.row + 1),
+            procedure: format!("{}.{}", metrics.object_name, metrics.procedure_name),
+            description: format!(
+                "Parameter {} is never used in the body; consider removing it",
+                p.name
+            ),
+        })
+        .collect()
+}
+
+/// An informational `naming_convention` finding when `r`'s name does not
+/// match `pattern` (the configured `namingPattern`). Triggers are named by
+/// the platform and quoted names (`"Post Document"`) are a deliberate
+/// choice, so neither is checked.
+pub fn naming_convention_finding(
+    metrics: &ProcedureMetrics,
+    source: &str,
+    r: &RoutineDecl,
+    pattern: &regex::Regex,
+) -> Option<Finding> {
+    let quoted = source
+        .get(r.name_origin.byte.clone())
+        .is_some_and(|name| name.starts_with('"'));
+    if r.kind != ir::RoutineKind::Procedure || r.name.is_empty() || quoted {
+        return None;
+    }
+    if pattern.is_match(&r.name) {
+        return None;
+    }
+    Some(Finding {
+        category: "naming_convention".to_string(),
+        severity: "info".to_string(),
+        location: format!("{}:{}", metrics.file, metrics.line),
+        procedure: format!("{}.{}", metrics.object_name, metrics.procedure_name),
+        description: format!(
+            "Procedure name {} does not match the naming convention `{}`",
+            r.name,
+            pattern.as_str()
+        ),
+    })
+}
+
+/// An object run whose target is chosen at runtime, found by
+/// [`dynamic_object_runs_ir`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicCall {
+    /// 1-based source line of the call.
+    pub line: u32,
+    /// The called member as written, e.g. `Codeunit.Run`.
+    pub call: String,
+    /// The first argument as written, e.g. `CuId`.
+    pub argument: String,
+    /// The argument's value when it is a variable the routine assigns
+    /// exactly once, from an integer literal or a `<Kind>::X` reference.
+    pub assigned: Option<String>,
+}
+
+/// Object runs (`Codeunit.Run(X)`, `Page.RunModal(X)`, … — the calls
+/// [`object_run_kind`] accepts) in `r`'s body whose first argument is not a
+/// `<Kind>::X` reference. The resolver records these as open-world dynamic
+/// calls with no target; this names them, plus the likely target when the
+/// argument is a once-assigned constant. Sorted by line.
+pub fn dynamic_object_runs_ir(source: &str, ir: &ir::Ir, r: &RoutineDecl) -> Vec<DynamicCall> {
+    let Some(body) = r.body else {
+        return Vec::new();
+    };
+    let range = ir.block(body).origin.byte.clone();
+    let within = |byte: &std::ops::Range<usize>| range.start <= byte.start && byte.end <= range.end;
+    let text = |e: ExprId| source[ir.expr(e).origin.byte.clone()].trim().to_string();
+    let var_name = |e: ExprId| match &ir.expr(e).kind {
+        ExprKind::Identifier(n) | ExprKind::QuotedIdentifier(n) => {
+            Some(n.trim_matches('"').to_lowercase())
+        }
+        _ => None,
+    };
+
+    // Variable -> its constant value, or `None` once it is assigned twice or
+    // from anything else.
+    let mut constants: std::collections::HashMap<String, Option<String>> =
+        std::collections::HashMap::new();
+    for stmt in ir.iter_stmts().filter(|s| within(&s.origin.byte)) {
+        let StmtKind::Assignment { target, value } = &stmt.kind else {
+            continue;
+        };
+        let Some(name) = var_name(*target) else {
+            continue;
+        };
+        let constant = matches!(
+            ir.expr(*value).kind,
+            ExprKind::Literal(ir::Literal::Int(_)) | ExprKind::DatabaseReference(_)
+        )
+        .then(|| text(*value));
+        constants
+            .entry(name)
+            .and_modify(|v| *v = None)
+            .or_insert(constant);
+    }
+
+    let mut out: Vec<DynamicCall> = ir
+        .iter_exprs()
+        .filter(|e| within(&e.origin.byte))
+        .filter_map(|e| {
+            let ExprKind::Call { function, args } = &e.kind else {
+                return None;
+            };
+            let ExprKind::Member { object, member, .. } = &ir.expr(*function).kind else {
+                return None;
+            };
+            let method_lc = member.trim_matches('"').to_lowercase();
+            object_run_kind(ir, source, *object, &method_lc, args)?;
+            let &arg = args.first()?;
+            if matches!(ir.expr(arg).kind, ExprKind::DatabaseReference(_)) {
+                return None;
+            }
+            Some(DynamicCall {
+                line: e.origin.start.row + 1,
+                call: text(*function),
+                argument: text(arg),
+                assigned: var_name(arg).and_then(|n| constants.get(&n).cloned().flatten()),
+            })
+        })
+        .collect();
+    out.sort_by_key(|c| c.line);
+    out
+}
+
+/// One informational `dynamic_call` finding per call
+/// [`dynamic_object_runs_ir`] reports, located at the call.
+pub fn dynamic_call_findings(
+    metrics: &ProcedureMetrics,
+    source: &str,
+    ir: &ir::Ir,
+    r: &RoutineDecl,
+) -> Vec<Finding> {
+    dynamic_object_runs_ir(source, ir, r)
+        .into_iter()
+        .map(|c| Finding {
+            category: "dynamic_call".to_string(),
+            severity: "info".to_string(),
+            location: format!("{}:{}", metrics.file, c.line),
+            procedure: format!("{}.{}", metrics.object_name, metrics.procedure_name),
+            description: match &c.assigned {
+                Some(value) => format!(
+                    "{}({}) runs an object chosen at runtime; {} is only assigned {} here, \
+                     so the target is likely {}",
+                    c.call, c.argument, c.argument, value, value
+                ),
+                None => format!(
+                    "{}({}) runs an object chosen at runtime; the call hierarchy cannot \
+                     follow it",
+                    c.call, c.argument
+                ),
+            },
+        })
+        .collect()
+}
+
+/// AL keywords kept verbatim (lowercased) when identifiers are normalized,
+/// so control-flow shape still distinguishes bodies.
+const AL_KEYWORDS: &[&str] = &[
+    "and", "begin", "case", "div", "do", "downto", "else", "end", "exit", "false", "for",
+    "foreach", "if", "in", "mod", "not", "of", "or", "repeat", "then", "to", "true", "until",
+    "var", "while", "with", "xor",
+];
+
+/// Tokenize AL body text for [`body_fingerprint_ir`]: drops whitespace and
+/// `//` / `/* */` comments, keeps string literals verbatim, lowercases
+/// identifiers (AL is case-insensitive), and keeps multi-character operators
+/// (`:=`, `<>`, `::`, …) as single tokens.
+fn body_tokens(text: &str, normalize_identifiers: bool) -> Vec<String> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b.is_ascii_whitespace() {
+            i += 1;
+        } else if text[i..].starts_with("//") {
+            i = text[i..].find('\n').map_or(bytes.len(), |n| i + n);
+        } else if text[i..].starts_with("/*") {
+            i = text[i + 2..]
+                .find("*/")
+                .map_or(bytes.len(), |n| i + 2 + n + 2);
+        } else if b == b'\'' || b == b'"' {
+            // String literal / quoted identifier; a doubled quote is an escape.
+            let mut j = i + 1;
+            while j < bytes.len() {
+                if bytes[j] == b {
+                    if bytes.get(j + 1) == Some(&b) {
+                        j += 2;
+                        continue;
+                    }
+                    break;
+                }
+                j += 1;
+            }
+            let end = (j + 1).min(bytes.len());
+            if b == b'"' {
+                out.push(ident_token(
+                    &text[i + 1..j.min(bytes.len())],
+                    normalize_identifiers,
+                ));
+            } else {
+                out.push(text[i..end].to_string());
+            }
+            i = end;
+        } else if b.is_ascii_alphabetic() || b == b'_' || !b.is_ascii() {
+            let start = i;
+            while i < bytes.len()
+                && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_' || !bytes[i].is_ascii())
+            {
+                i += 1;
+            }
+            out.push(ident_token(&text[start..i], normalize_identifiers));
+        } else if b.is_ascii_digit() {
+            let start = i;
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'.') {
+                // Stop before a `..` range operator.
+                if bytes[i] == b'.' && bytes.get(i + 1) == Some(&b'.') {
+                    break;
+                }
+                i += 1;
+            }
+            out.push(text[start..i].to_string());
+        } else {
+            let two = text.get(i..i + 2).unwrap_or("");
+            if matches!(
+                two,
+                ":=" | "+=" | "-=" | "*=" | "/=" | "<>" | "<=" | ">=" | ".." | "::"
+            ) {
+                out.push(two.to_string());
+                i += 2;
+            } else {
+                out.push((b as char).to_string());
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+fn ident_token(name: &str, normalize_identifiers: bool) -> String {
+    let lc = name.to_lowercase();
+    if normalize_identifiers && !AL_KEYWORDS.contains(&lc.as_str()) {
+        "<id>".to_string()
+    } else {
+        lc
+    }
+}
+
+/// True for AL attributes whose procedure is invoked by a framework (the test
+/// runner or test framework) rather than by an explicit call, so the procedure
+/// must not be reported as unused. AL attribute names are case-insensitive.
+/// Event publishers/subscribers are handled separately and are not listed here.
+pub(crate) fn is_framework_invocation_attribute(name: &str) -> bool {
+    matches!(
+        name.to_ascii_lowercase().as_str(),
+        "test"
+            | "confirmhandler"
+            | "messagehandler"
+            | "pagehandler"
+            | "modalpagehandler"
+            | "reporthandler"
+            | "requestpagehandler"
+            | "sendnotificationhandler"
+            | "recallnotificationhandler"
+            | "sessionsettingshandler"
+            | "strmenuhandler"
+            | "filterpagehandler"
+            | "hyperlinkhandler"
+    )
+}
+
+/// Render a procedure/trigger header as raw source text: everything from
+/// `r.origin`'s start up to (but not including) the body's `var` section or
+/// `begin` keyword, whitespace-collapsed to single spaces. Relocated here
+/// from `parser.rs` (T3 Task 13 review fix-wave — mirrors this module's own
+/// earlier relocation of [`is_framework_invocation_attribute`]/
+/// [`routine_complexity_ir`] above): `src/lsp/custom.rs`'s
+/// `event_publishers_in_file` needs this SAME signature-rendering logic (to
+/// stay byte-identical to what `parser.rs`'s own
+/// `ParsedEventPublisher::signature` produces for the same routine), but
+/// `parser.rs` is a documented Task-17 deletion target — sharing one
+/// definition here means neither module drifts, and Task 17 can delete
+/// `parser.rs` without orphaning anything `custom.rs` depends on.
+pub(crate) fn signature_ir(source: &str, r: &RoutineDecl) -> String {
+    let raw = &source[r.origin.byte.clone()];
+    let end = find_body_start(raw).unwrap_or(raw.len());
+    normalize_signature_ws(&raw[..end])
+}
+
+/// Find the byte offset (relative to the start of `text`) where a procedure
+/// body begins (the `begin` keyword or `var` section). Returns `None` when no
+/// body marker is present in this slice.
+///
+/// Requires the keyword to be alone at the start of a line (preceded only by
+/// whitespace) so a `var` parameter modifier is never mistaken for the `var`
+/// section, and skips scanning inside string literals so a quoted
+/// identifier/comment containing "begin"/"var" text can't false-positive.
+fn find_body_start(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let len = bytes.len();
+    let mut i = 0;
+    let mut in_string = false;
+    let mut string_quote = 0u8;
+    while i < len {
+        let b = bytes[i];
+        if in_string {
+            if b == string_quote {
+                in_string = false;
+            }
+            i += 1;
+            continue;
+        }
+        if b == b'\'' || b == b'"' {
+            in_string = true;
+            string_quote = b;
+            i += 1;
+            continue;
+        }
+        // Look at line starts only (`\n` followed by optional whitespace).
+        if b == b'\n' {
+            let mut j = i + 1;
+            while j < len && (bytes[j] == b' ' || bytes[j] == b'\t') {
+                j += 1;
+            }
+            if matches_keyword(bytes, j, b"begin") || matches_keyword(bytes, j, b"var") {
+                return Some(j);
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+fn matches_keyword(bytes: &[u8], at: usize, kw: &[u8]) -> bool {
+    if at + kw.len() > bytes.len() {
+        return false;
+    }
+    if &bytes[at..at + kw.len()] != kw {
+        return false;
+    }
+    let next = bytes.get(at + kw.len()).copied().unwrap_or(b' ');
+    !next.is_ascii_alphanumeric() && next != b'_'
+}
+
+/// Collapse runs of whitespace (including newlines) to single spaces, trimmed.
+fn normalize_signature_ws(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    let mut prev_space = false;
+    for ch in raw.chars() {
+        if ch.is_whitespace() {
+            if !prev_space && !out.is_empty() {
+                out.push(' ');
+            }
+            prev_space = true;
+        } else {
+            out.push(ch);
+            prev_space = false;
+        }
+    }
+    out.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl ProcedureMetrics {
+        /// A small, finding-free procedure in codeunit `Test`; tests override
+        /// the fields they exercise with struct-update syntax.
+        fn for_test(procedure_name: &str) -> Self {
+            ProcedureMetrics {
+                object_type: "Codeunit".to_string(),
+                object_name: "Test".to_string(),
+                procedure_name: procedure_name.to_string(),
+                file: "test.al".to_string(),
+                line: 1,
+                complexity: 1,
+                line_count: 1,
+                parameter_count: 0,
+                var_param_count: 0,
+                coupling: 0,
+                ui_reachable: None,
+                quality_score: 10.0,
+            }
+        }
+    }
+
+    /// Cyclomatic complexity of a named routine, via the owned IR (the canonical
+    /// complexity walker — the tree-sitter `calculate_complexity` is retired).
+    fn complexity_of(al_code: &str, proc_name: &str) -> u32 {
+        let f = al_syntax::parse(al_code);
+        for obj in &f.objects {
+            for r in &obj.routines {
+                if r.name == proc_name {
+                    return routine_complexity_ir(&f.ir, r);
+                }
+            }
+        }
+        panic!("procedure {proc_name} not found");
+    }
+
+    #[test]
+    fn test_one_line_and_bodyless_routines_measure_cleanly() {
+        let code = r#"
+codeunit 50000 Test
+{
+    trigger OnRun() begin Foo(); end;
+
+    local procedure Foo() begin end;
+}
+
+interface IShape
+{
+    procedure Area(Scale: Decimal): Decimal;
+}
+"#;
+        let f = al_syntax::parse(code);
+        let run = &f.objects[0].routines[0];
+        assert_eq!(run.origin.start.row, run.origin.end.row, "one-line trigger");
+        assert!(run.body.is_some());
+        assert_eq!(routine_complexity_ir(&f.ir, run), 1);
+        assert_eq!(guard_clause_count_ir(&f.ir, run), 0);
+
+        // An interface method has no body at all: base complexity, real
+        // parameter count, and a declaration range on its own line.
+        let area = &f.objects[1].routines[0];
+        assert!(area.body.is_none());
+        assert_eq!(routine_complexity_ir(&f.ir, area), 1);
+        assert_eq!(area.params.len(), 1);
+        assert_eq!(area.origin.start.row, area.origin.end.row);
+    }
+
+    #[test]
+    fn test_guard_clauses_can_be_discounted_from_complexity() {
+        let code = r#"
+codeunit 50000 Test
+{
+    procedure Guarded(Code: Code[20]; Qty: Decimal)
+    begin
+        if Code = '' then
+            exit;
+        if Qty <= 0 then begin
+            exit;
+        end;
+        if Qty > 10 then
+            Qty := 10
+        else
+            Qty := Qty + 1;
+    end;
+}
+"#;
+        let f = al_syntax::parse(code);
+        let r = &f.objects[0].routines[0];
+        assert_eq!(guard_clause_count_ir(&f.ir, r), 2);
+
+        let mut config = DiagnosticConfig::default();
+        assert_eq!(routine_complexity_for(&f.ir, r, &config), 5);
+        config.discount_guard_clauses = true;
+        assert_eq!(routine_complexity_for(&f.ir, r, &config), 3);
+    }
+
+    #[test]
+    fn test_complexity_calculation_with_actual_al_parsing() {
+        // Test AL code with various control flow constructs
+        // Expected complexity calculation:
+        // Base: 1
+        // if i > 0: +1 (no else)
+        // nested if i > 10: +1, else: +1 (has else branch)
+        // while not done: +1
+        // if (i > 0) and (i < 100): +1 for if, +1 for and
+        // case: 2 case_branch items (+2, case_else_branch is not counted)
+        // Total expected: 1 + 1 + 2 + 1 + 2 + 2 = 9
+        //
+        // Note: case_else_branch is correctly NOT counted per standard cyclomatic complexity
+        // (the else/default path is not a decision point)
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure ComplexProcedure()
+    var
+        i: Integer;
+        done: Boolean;
+    begin
+        if i > 0 then begin
+            if i > 10 then
+                i := 10
+            else
+                i := 5;
+        end;
+
+        while not done do begin
+            if (i > 0) and (i < 100) then
+                i += 1;
+            done := i >= 100;
+        end;
+
+        case i of
+            1: i := 2;
+            2: i := 3;
+            else i := 0;
+        end;
+    end;
+}"#;
+
+        let complexity = complexity_of(al_code, "ComplexProcedure");
+
+        // Expected 9 (1 + if + nested if/else + while + if + `and` + 2 case branches).
+        // The tree-sitter-al grammar fix (owned-IR consumer: named in/is/as expressions,
+        // removing the spurious left/operator/right field bleed) corrected a previous
+        // undercount to 8 — the clean grammar now yields the theoretically correct 9.
+        assert_eq!(complexity, 9, "Expected complexity 9, got {}", complexity);
+    }
+
+    #[test]
+    fn test_complexity_if_else() {
+        // Test if-else counting: if adds +1, else adds +1
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure IfElseProcedure()
+    var
+        i: Integer;
+    begin
+        if i > 0 then
+            i := 1
+        else
+            i := 0;
+    end;
+}"#;
+
+        let complexity = complexity_of(al_code, "IfElseProcedure");
+        // Base: 1, if: +1, else: +1 = 3
+        assert_eq!(
+            complexity, 3,
+            "If-else procedure should have complexity 3, got {}",
+            complexity
+        );
+    }
+
+    #[test]
+    fn test_complexity_simple_procedure() {
+        // A simple procedure with no control flow should have complexity 1
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure SimpleProcedure()
+    begin
+        Message('Hello');
+    end;
+}"#;
+
+        let complexity = complexity_of(al_code, "SimpleProcedure");
+        assert_eq!(complexity, 1, "Simple procedure should have complexity 1");
+    }
+
+    #[test]
+    fn test_complexity_loops() {
+        // Test all loop types: for, foreach, repeat, while
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure LoopProcedure()
+    var
+        i: Integer;
+        items: List of [Integer];
+    begin
+        for i := 1 to 10 do
+            i := i;
+
+        foreach i in items do
+            i := i;
+
+        repeat
+            i += 1;
+        until i > 10;
+
+        while i < 20 do
+            i += 1;
+    end;
+}"#;
+
+        let complexity = complexity_of(al_code, "LoopProcedure");
+        // Base: 1, for: +1, foreach: +1, repeat: +1, while: +1 = 5
+        assert_eq!(
+            complexity, 5,
+            "Loop procedure should have complexity 5, got {}",
+            complexity
+        );
+    }
+
+    #[test]
+    fn test_complexity_logical_operators() {
+        // Test logical operators: and, or (xor is not counted per standard CC)
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure LogicalProcedure()
+    var
+        a: Boolean;
+        b: Boolean;
+        c: Boolean;
+    begin
+        if a and b then
+            c := true;
+
+        if a or b then
+            c := false;
+
+        if a and b and c then
+            c := true;
+    end;
+}"#;
+
+        let complexity = complexity_of(al_code, "LogicalProcedure");
+        // Base: 1
+        // if a and b: +1 (if) +1 (and)
+        // if a or b: +1 (if) +1 (or)
+        // if a and b and c: +1 (if) +2 (two and operators)
+        // Total: 1 + 2 + 2 + 3 = 8
+        assert_eq!(
+            complexity, 8,
+            "Logical procedure should have complexity 8, got {}",
+            complexity
+        );
+    }
+
+    #[test]
+    fn test_quality_score_perfect() {
+        let score = calculate_quality_score(1, 5, 1);
+        assert!((score - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_quality_score_high_complexity() {
+        let score = calculate_quality_score(10, 5, 1);
+        assert!(score < 5.0);
+    }
+
+    #[test]
+    fn test_quality_score_long_method() {
+        let score = calculate_quality_score(1, 50, 1);
+        assert!(score < 5.0);
+    }
+
+    #[test]
+    fn test_quality_score_many_params() {
+        let score = calculate_quality_score(1, 5, 8);
+        assert!(score < 7.0);
+    }
+
+    #[test]
+    fn test_findings_generated_for_high_complexity() {
+        let metrics = ProcedureMetrics {
+            line: 10,
+            complexity: 12,
+            line_count: 10,
+            parameter_count: 2,
+            quality_score: 5.0,
+            ..ProcedureMetrics::for_test("TestProc")
+        };
+        let config = DiagnosticConfig::default();
+        let findings = generate_findings(&metrics, &config);
+        assert!(
+            findings
+                .iter()
+                .any(|f| f.category == "high_complexity" && f.severity == "critical")
+        );
+    }
+
+    #[test]
+    fn test_quality_score_moderate_complexity() {
+        // complexity 3 hits the else-if branch (> 2 but <= 4)
+        let score = calculate_quality_score(3, 5, 1);
+        // penalty = (3-2) * 0.8 = 0.8, so score = 9.2
+        assert!((score - 9.2).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_quality_score_moderate_length() {
+        // line_count 12 hits the else-if branch (> 10 but <= 15)
+        let score = calculate_quality_score(1, 12, 1);
+        // penalty = (12-10) * 0.3 = 0.6, so score = 9.4
+        assert!((score - 9.4).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_quality_score_moderate_params() {
+        // params 3 hits the else-if branch (> 2 but <= 4)
+        let score = calculate_quality_score(1, 5, 3);
+        // penalty = (3-2) * 0.5 = 0.5, so score = 9.5
+        assert!((score - 9.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_quality_score_clamped_to_zero() {
+        // Very bad metrics should clamp to 0
+        let score = calculate_quality_score(50, 200, 20);
+        assert_eq!(score, 0.0);
+    }
+
+    #[test]
+    fn test_findings_complexity_warning() {
+        let config = DiagnosticConfig::default();
+        let metrics = ProcedureMetrics {
+            line: 10,
+            complexity: config.complexity_warning, // at warning threshold
+            line_count: 5,
+            parameter_count: 1,
+            quality_score: 8.0,
+            ..ProcedureMetrics::for_test("TestProc")
+        };
+        let findings = generate_findings(&metrics, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, "high_complexity");
+        assert_eq!(findings[0].severity, "warning");
+    }
+
+    #[test]
+    fn test_workspace_config_raises_the_critical_complexity_threshold() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(".al-call-hierarchy.json"),
+            r#"{ "diagnostics": { "complexity": { "critical": 20 } } }"#,
+        )
+        .unwrap();
+        let metrics = ProcedureMetrics {
+            line: 10,
+            complexity: 12,
+            line_count: 5,
+            parameter_count: 1,
+            quality_score: 2.0,
+            ..ProcedureMetrics::for_test("TestProc")
+        };
+        let default_findings = generate_findings(&metrics, &DiagnosticConfig::default());
+        assert_eq!(default_findings[0].severity, "critical");
+
+        let config = DiagnosticConfig::load(dir.path());
+        assert_eq!(config.complexity_critical, 20);
+        let findings = generate_findings(&metrics, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, "high_complexity");
+        assert_eq!(findings[0].severity, "warning");
+    }
+
+    #[test]
+    fn test_findings_length_critical() {
+        let config = DiagnosticConfig::default();
+        let metrics = ProcedureMetrics {
+            line: 10,
+            line_count: config.length_critical, // at critical threshold
+            parameter_count: 1,
+            quality_score: 5.0,
+            ..ProcedureMetrics::for_test("TestProc")
+        };
+        let findings = generate_findings(&metrics, &config);
+        assert!(
+            findings
+                .iter()
+                .any(|f| f.category == "long_method" && f.severity == "critical")
+        );
+    }
+
+    #[test]
+    fn test_findings_length_warning() {
+        let config = DiagnosticConfig::default();
+        let metrics = ProcedureMetrics {
+            line: 10,
+            line_count: config.length_warning, // at warning threshold
+            parameter_count: 1,
+            quality_score: 7.0,
+            ..ProcedureMetrics::for_test("TestProc")
+        };
+        let findings = generate_findings(&metrics, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, "long_method");
+        assert_eq!(findings[0].severity, "warning");
+    }
+
+    #[test]
+    fn test_findings_params_critical() {
+        let config = DiagnosticConfig::default();
+        let metrics = ProcedureMetrics {
+            line: 10,
+            line_count: 5,
+            parameter_count: config.params_critical, // at critical threshold
+            quality_score: 5.0,
+            ..ProcedureMetrics::for_test("TestProc")
+        };
+        let findings = generate_findings(&metrics, &config);
+        assert!(
+            findings
+                .iter()
+                .any(|f| f.category == "too_many_parameters" && f.severity == "critical")
+        );
+    }
+
+    #[test]
+    fn test_findings_params_warning() {
+        let config = DiagnosticConfig::default();
+        let metrics = ProcedureMetrics {
+            line: 10,
+            line_count: 5,
+            parameter_count: config.params_warning, // at warning threshold
+            quality_score: 7.0,
+            ..ProcedureMetrics::for_test("TestProc")
+        };
+        let findings = generate_findings(&metrics, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, "too_many_parameters");
+        assert_eq!(findings[0].severity, "warning");
+    }
+
+    #[test]
+    fn test_findings_no_issues() {
+        let config = DiagnosticConfig::default();
+        let metrics = ProcedureMetrics {
+            line: 10,
+            line_count: 5,
+            parameter_count: 1,
+            ..ProcedureMetrics::for_test("TestProc")
+        };
+        let findings = generate_findings(&metrics, &config);
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn test_build_summary_with_metrics() {
+        let metrics = vec![
+            ProcedureMetrics {
+                line: 10,
+                complexity: 4,
+                line_count: 20,
+                parameter_count: 2,
+                quality_score: 8.0,
+                ..ProcedureMetrics::for_test("Proc1")
+            },
+            ProcedureMetrics {
+                line: 30,
+                complexity: 6,
+                line_count: 30,
+                parameter_count: 3,
+                quality_score: 6.0,
+                ..ProcedureMetrics::for_test("Proc2")
+            },
+        ];
+        let findings = vec![
+            Finding {
+                category: "high_complexity".to_string(),
+                severity: "critical".to_string(),
+                location: "test.al:30".to_string(),
+                procedure: "Test.Proc2".to_string(),
+                description: "test".to_string(),
+            },
+            Finding {
+                category: "long_method".to_string(),
+                severity: "warning".to_string(),
+                location: "test.al:30".to_string(),
+                procedure: "Test.Proc2".to_string(),
+                description: "test".to_string(),
+            },
+        ];
+        let summary = build_summary(&metrics, &findings);
+        assert_eq!(summary.total_procedures, 2);
+        assert!((summary.avg_complexity - 5.0).abs() < 0.01);
+        assert!((summary.avg_quality_score - 7.0).abs() < 0.01);
+        assert_eq!(summary.critical_findings, 1);
+        assert_eq!(summary.warning_findings, 1);
+    }
+
+    #[test]
+    fn test_build_summary_empty() {
+        let summary = build_summary(&[], &[]);
+        assert_eq!(summary.total_procedures, 0);
+        assert_eq!(summary.avg_complexity, 0.0);
+        assert_eq!(summary.avg_quality_score, 0.0);
+        assert_eq!(summary.critical_findings, 0);
+        assert_eq!(summary.warning_findings, 0);
+    }
+
+    /// Unchecked risky calls of a named routine, via the owned IR.
+    fn unchecked_calls_of(al_code: &str, proc_name: &str) -> Vec<UncheckedCall> {
+        let f = al_syntax::parse(al_code);
+        for obj in &f.objects {
+            for r in &obj.routines {
+                if r.name == proc_name {
+                    return unchecked_risky_calls_ir(&f.ir, r);
+                }
+            }
+        }
+        panic!("procedure {proc_name} not found");
+    }
+
+    #[test]
+    fn test_unchecked_risky_call_statement_vs_if_condition() {
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure Unchecked(No: Code[20])
+    var
+        Customer: Record Customer;
+    begin
+        Customer.Get(No);
+    end;
+
+    procedure Checked(No: Code[20])
+    var
+        Customer: Record Customer;
+    begin
+        if Customer.Get(No) then
+            Customer.Modify();
+    end;
+}"#;
+
+        let unchecked = unchecked_calls_of(al_code, "Unchecked");
+        assert_eq!(
+            unchecked,
+            vec![UncheckedCall {
+                method: "Get".to_string(),
+                line: 7,
+            }]
+        );
+
+        // The `Get` is the `if` condition (a Boolean context); only the bare
+        // `Modify()` statement inside the then-branch is reported.
+        let checked = unchecked_calls_of(al_code, "Checked");
+        assert_eq!(checked.len(), 1);
+        assert_eq!(checked[0].method, "Modify");
+    }
+
+    #[test]
+    fn test_unchecked_risky_call_guarded_contexts() {
+        let al_code = r#"codeunit 50100 "Test"
+{
+    [TryFunction]
+    procedure TryInsert()
+    var
+        Customer: Record Customer;
+    begin
+        Customer.Insert();
+    end;
+
+    procedure Assigned(): Boolean
+    var
+        Customer: Record Customer;
+        Found: Boolean;
+    begin
+        Found := Customer.Get('10000');
+        exit(Customer.Delete());
+    end;
+}"#;
+
+        assert!(unchecked_calls_of(al_code, "TryInsert").is_empty());
+        assert!(unchecked_calls_of(al_code, "Assigned").is_empty());
+    }
+
+    #[test]
+    fn test_unchecked_risky_call_findings_gated_by_config() {
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure Unchecked()
+    var
+        Customer: Record Customer;
+    begin
+        Customer.Get('10000');
+    end;
+}"#;
+        let f = al_syntax::parse(al_code);
+        let r = &f.objects[0].routines[0];
+        let metrics = ProcedureMetrics {
+            line: 3,
+            line_count: 6,
+            ..ProcedureMetrics::for_test("Unchecked")
+        };
+
+        let config = DiagnosticConfig::default();
+        assert!(generate_ir_findings(&metrics, &f.ir, r, &config).is_empty());
+
+        let config = DiagnosticConfig {
+            unchecked_risky_calls: true,
+            ..DiagnosticConfig::default()
+        };
+        let findings = generate_ir_findings(&metrics, &f.ir, r, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, "unchecked_risky_call");
+        assert_eq!(findings[0].location, "test.al:7");
+    }
+
+    #[test]
+    fn test_three_boolean_parameters_are_flagged() {
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure Post(Preview: Boolean; HideDialog: Boolean; var Posted: Boolean; Qty: Decimal)
+    begin
+    end;
+
+    procedure Release(Preview: Boolean; HideDialog: Boolean)
+    begin
+    end;
+}"#;
+        let f = al_syntax::parse(al_code);
+        let post = &f.objects[0].routines[0];
+        let release = &f.objects[0].routines[1];
+        assert_eq!(boolean_param_count_ir(post), 3);
+        assert_eq!(boolean_param_count_ir(release), 2);
+
+        let metrics = |procedure_name: &str, line: u32| ProcedureMetrics {
+            line,
+            line_count: 3,
+            parameter_count: 4,
+            var_param_count: 1,
+            ..ProcedureMetrics::for_test(procedure_name)
+        };
+        let config = DiagnosticConfig::default();
+        let findings = generate_ir_findings(&metrics("Post", 3), &f.ir, post, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, "boolean_parameter_overload");
+        assert_eq!(findings[0].location, "test.al:3");
+        assert!(
+            generate_ir_findings(&metrics("Release", 7), &f.ir, release, &config).is_empty(),
+            "two is the default threshold, not above it"
+        );
+    }
+
+    #[test]
+    fn test_write_in_get_procedure_is_unexpected() {
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure GetBalance(No: Code[20])
+    var
+        Customer: Record Customer;
+    begin
+        if Customer.Get(No) then
+            Customer.Modify();
+    end;
+
+    procedure Create(No: Code[20])
+    var
+        Customer: Record Customer;
+    begin
+        Customer.Insert();
+    end;
+
+    procedure Calculate(No: Code[20])
+    var
+        Customer: Record Customer;
+    begin
+        Customer.Modify();
+    end;
+}"#;
+        let f = al_syntax::parse(al_code);
+        let prefixes = DiagnosticConfig::default().read_only_prefixes;
+        let get = &f.objects[0].routines[0];
+        assert_eq!(
+            unexpected_writes_ir(&f.ir, get, &prefixes, false),
+            [WriteCall {
+                method: "Modify".to_string(),
+                line: 8
+            }]
+        );
+        let create = &f.objects[0].routines[1];
+        assert!(unexpected_writes_ir(&f.ir, create, &prefixes, false).is_empty());
+        assert!(unexpected_writes_ir(&f.ir, get, &[], false).is_empty());
+        let calculate = &f.objects[0].routines[2];
+        assert!(
+            unexpected_writes_ir(&f.ir, calculate, &prefixes, false).is_empty(),
+            "Calc is not a word prefix of Calculate"
+        );
+
+        let metrics = ProcedureMetrics {
+            line: 3,
+            complexity: 2,
+            line_count: 7,
+            parameter_count: 1,
+            ..ProcedureMetrics::for_test("GetBalance")
+        };
+        let findings = generate_ir_findings(&metrics, &f.ir, get, &DiagnosticConfig::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, "unexpected_write");
+        assert_eq!(findings[0].severity, "info");
+        assert_eq!(findings[0].location, "test.al:8");
+    }
+
+    #[test]
+    fn test_bare_write_on_the_implicit_rec_is_unexpected() {
+        let al_code = r#"table 50100 "Test"
+{
+    procedure FindAndTouch()
+    begin
+        if Find() then
+            Modify();
+    end;
+}"#;
+        let f = al_syntax::parse(al_code);
+        let prefixes = DiagnosticConfig::default().read_only_prefixes;
+        let find = &f.objects[0].routines[0];
+        assert_eq!(
+            unexpected_writes_ir(&f.ir, find, &prefixes, true),
+            [WriteCall {
+                method: "Modify".to_string(),
+                line: 6
+            }]
+        );
+        assert!(
+            unexpected_writes_ir(&f.ir, find, &prefixes, false).is_empty(),
+            "outside a table a bare Modify() is not a record call"
+        );
+    }
 
-    /// Cyclomatic complexity of a named routine, via the owned IR (the canonical
-    /// complexity walker — the tree-sitter `calculate_complexity` is retired).
-    fn complexity_of(al_code: &str, proc_name: &str) -> u32 {
+    fn falls_through_of(al_code: &str, proc_name: &str) -> bool {
         let f = al_syntax::parse(al_code);
         for obj in &f.objects {
             for r in &obj.routines {
                 if r.name == proc_name {
-                    return routine_complexity_ir(&f.ir, r);
+                    return falls_through_without_exit_ir(&f.ir, r);
                 }
             }
         }
@@ -492,421 +3120,1013 @@ mod tests {
     }
 
     #[test]
-    fn test_complexity_calculation_with_actual_al_parsing() {
-        // Test AL code with various control flow constructs
-        // Expected complexity calculation:
-        // Base: 1
-        // if i > 0: +1 (no else)
-        // nested if i > 10: +1, else: +1 (has else branch)
-        // while not done: +1
-        // if (i > 0) and (i < 100): +1 for if, +1 for and
-        // case: 2 case_branch items (+2, case_else_branch is not counted)
-        // Total expected: 1 + 1 + 2 + 1 + 2 + 2 = 9
-        //
-        // Note: case_else_branch is correctly NOT counted per standard cyclomatic complexity
-        // (the else/default path is not a decision point)
+    fn test_missing_return_on_if_without_else() {
         let al_code = r#"codeunit 50100 "Test"
 {
-    procedure ComplexProcedure()
-    var
-        i: Integer;
-        done: Boolean;
+    procedure Sign(Value: Integer): Integer
     begin
-        if i > 0 then begin
-            if i > 10 then
-                i := 10
+        if Value > 0 then
+            exit(1);
+    end;
+
+    procedure SignOrZero(Value: Integer): Integer
+    begin
+        if Value > 0 then
+            exit(1)
+        else
+            exit(0);
+    end;
+
+    procedure Trailing(Value: Integer): Integer
+    begin
+        if Value > 0 then
+            exit(1);
+        exit(0);
+    end;
+
+    procedure Raises(Value: Integer): Integer
+    begin
+        case Value of
+            1:
+                exit(10);
             else
-                i := 5;
+                Error('Unsupported value %1', Value);
         end;
+    end;
 
-        while not done do begin
-            if (i > 0) and (i < 100) then
-                i += 1;
-            done := i >= 100;
-        end;
+    procedure Named(Value: Integer) Result: Integer
+    begin
+        if Value > 0 then
+            Result := 1;
+    end;
 
-        case i of
-            1: i := 2;
-            2: i := 3;
-            else i := 0;
-        end;
+    procedure NoReturn(Value: Integer)
+    begin
+        if Value > 0 then
+            exit;
     end;
 }"#;
 
-        let complexity = complexity_of(al_code, "ComplexProcedure");
+        assert!(falls_through_of(al_code, "Sign"));
+        assert!(!falls_through_of(al_code, "SignOrZero"));
+        assert!(!falls_through_of(al_code, "Trailing"));
+        assert!(!falls_through_of(al_code, "Raises"));
+        assert!(!falls_through_of(al_code, "Named"));
+        assert!(!falls_through_of(al_code, "NoReturn"));
+    }
 
-        // Expected 9 (1 + if + nested if/else + while + if + `and` + 2 case branches).
-        // The tree-sitter-al grammar fix (owned-IR consumer: named in/is/as expressions,
-        // removing the spurious left/operator/right field bleed) corrected a previous
-        // undercount to 8 — the clean grammar now yields the theoretically correct 9.
-        assert_eq!(complexity, 9, "Expected complexity 9, got {}", complexity);
+    #[test]
+    fn test_missing_return_findings_gated_by_config() {
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure Sign(Value: Integer): Integer
+    begin
+        if Value > 0 then
+            exit(1);
+    end;
+}"#;
+        let f = al_syntax::parse(al_code);
+        let r = &f.objects[0].routines[0];
+        let metrics = ProcedureMetrics {
+            line: 3,
+            complexity: 2,
+            line_count: 5,
+            parameter_count: 1,
+            ..ProcedureMetrics::for_test("Sign")
+        };
+
+        let config = DiagnosticConfig::default();
+        assert!(generate_ir_findings(&metrics, &f.ir, r, &config).is_empty());
+
+        let config = DiagnosticConfig {
+            missing_return: true,
+            ..DiagnosticConfig::default()
+        };
+        let findings = generate_ir_findings(&metrics, &f.ir, r, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, "missing_return");
+        assert_eq!(findings[0].location, "test.al:3");
+        assert_eq!(findings[0].procedure, "Test.Sign");
+    }
+
+    fn self_recursion_of(al_code: &str, proc_name: &str) -> Option<u32> {
+        let f = al_syntax::parse(al_code);
+        let r = f.objects[0]
+            .routines
+            .iter()
+            .find(|r| r.name == proc_name)
+            .unwrap_or_else(|| panic!("procedure {proc_name} not found"));
+        unguarded_self_recursion_ir(&f.ir, r)
     }
 
     #[test]
-    fn test_complexity_if_else() {
-        // Test if-else counting: if adds +1, else adds +1
+    fn test_unguarded_self_recursion_needs_a_base_case_first() {
         let al_code = r#"codeunit 50100 "Test"
 {
-    procedure IfElseProcedure()
+    procedure Forever(Value: Integer): Integer
     var
-        i: Integer;
+        Next: Integer;
+    begin
+        Next := Value + 1;
+        exit(Forever(Next));
+    end;
+
+    procedure Factorial(Value: Integer): Integer
+    begin
+        if Value <= 1 then
+            exit(1);
+        exit(Value * Factorial(Value - 1));
+    end;
+
+    procedure Countdown(Value: Integer)
+    begin
+        if Value > 0 then
+            Countdown(Value - 1);
+    end;
+
+    procedure Overloaded(Value: Integer)
+    begin
+        Overloaded(Value, 1);
+    end;
+
+    procedure Overloaded(Value: Integer; Step: Integer)
     begin
-        if i > 0 then
-            i := 1
-        else
-            i := 0;
     end;
 }"#;
 
-        let complexity = complexity_of(al_code, "IfElseProcedure");
-        // Base: 1, if: +1, else: +1 = 3
+        assert_eq!(self_recursion_of(al_code, "Forever"), Some(8));
+        assert_eq!(self_recursion_of(al_code, "Factorial"), None);
+        assert_eq!(self_recursion_of(al_code, "Countdown"), None);
         assert_eq!(
-            complexity, 3,
-            "If-else procedure should have complexity 3, got {}",
-            complexity
+            self_recursion_of(al_code, "Overloaded"),
+            None,
+            "a different arity is another overload"
         );
+
+        let f = al_syntax::parse(al_code);
+        let r = &f.objects[0].routines[0];
+        let metrics = ProcedureMetrics {
+            line: 3,
+            line_count: 7,
+            parameter_count: 1,
+            ..ProcedureMetrics::for_test("Forever")
+        };
+        let findings = generate_ir_findings(&metrics, &f.ir, r, &DiagnosticConfig::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, "unbounded_recursion");
+        assert_eq!(findings[0].location, "test.al:8");
+
+        let config = DiagnosticConfig {
+            unbounded_recursion: false,
+            ..DiagnosticConfig::default()
+        };
+        assert!(generate_ir_findings(&metrics, &f.ir, r, &config).is_empty());
     }
 
     #[test]
-    fn test_complexity_simple_procedure() {
-        // A simple procedure with no control flow should have complexity 1
+    fn test_long_if_chain_flags_six_branches() {
         let al_code = r#"codeunit 50100 "Test"
 {
-    procedure SimpleProcedure()
+    procedure Describe(Value: Integer): Text
     begin
-        Message('Hello');
+        if Value = 1 then
+            exit('one')
+        else if Value = 2 then
+            exit('two')
+        else if Value = 3 then
+            exit('three')
+        else if Value = 4 then
+            exit('four')
+        else if Value = 5 then
+            exit('five')
+        else
+            exit('many');
+    end;
+
+    procedure Sign(Value: Integer): Integer
+    begin
+        if Value > 0 then
+            exit(1)
+        else if Value < 0 then
+            exit(-1);
+        exit(0);
     end;
 }"#;
+        let f = al_syntax::parse(al_code);
+        let describe = &f.objects[0].routines[0];
+        assert_eq!(
+            long_if_chains_ir(&f.ir, describe),
+            [IfChain {
+                line: 5,
+                branches: 6
+            }]
+        );
+        let sign = &f.objects[0].routines[1];
+        assert_eq!(
+            long_if_chains_ir(&f.ir, sign),
+            [IfChain {
+                line: 21,
+                branches: 2
+            }]
+        );
 
-        let complexity = complexity_of(al_code, "SimpleProcedure");
-        assert_eq!(complexity, 1, "Simple procedure should have complexity 1");
+        let metrics = |name: &str| ProcedureMetrics {
+            line: 3,
+            complexity: 6,
+            line_count: 15,
+            parameter_count: 1,
+            ..ProcedureMetrics::for_test(name)
+        };
+        let config = DiagnosticConfig::default();
+        let findings = generate_ir_findings(&metrics("Describe"), &f.ir, describe, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, "long_if_chain");
+        assert_eq!(findings[0].location, "test.al:5");
+        assert!(findings[0].description.contains("case"));
+        assert!(generate_ir_findings(&metrics("Sign"), &f.ir, sign, &config).is_empty());
+
+        let config = DiagnosticConfig {
+            long_if_chain_enabled: false,
+            ..DiagnosticConfig::default()
+        };
+        assert!(generate_ir_findings(&metrics("Describe"), &f.ir, describe, &config).is_empty());
     }
 
     #[test]
-    fn test_complexity_loops() {
-        // Test all loop types: for, foreach, repeat, while
+    fn test_unused_parameter_is_flagged_and_used_one_is_not() {
         let al_code = r#"codeunit 50100 "Test"
 {
-    procedure LoopProcedure()
-    var
-        i: Integer;
-        items: List of [Integer];
+    procedure Greet(Name: Text; "Unused Flag": Boolean): Text
     begin
-        for i := 1 to 10 do
-            i := i;
-
-        foreach i in items do
-            i := i;
-
-        repeat
-            i += 1;
-        until i > 10;
+        // "Unused Flag" only appears in this comment
+        exit('Hello ' + NAME);
+    end;
 
-        while i < 20 do
-            i += 1;
+    [EventSubscriber(ObjectType::Codeunit, Codeunit::"Sales-Post", 'OnAfterPost', '', false, false)]
+    local procedure OnAfterPost(DocNo: Code[20])
+    begin
     end;
 }"#;
-
-        let complexity = complexity_of(al_code, "LoopProcedure");
-        // Base: 1, for: +1, foreach: +1, repeat: +1, while: +1 = 5
-        assert_eq!(
-            complexity, 5,
-            "Loop procedure should have complexity 5, got {}",
-            complexity
+        let f = al_syntax::parse(al_code);
+        let obj = &f.objects[0];
+        let greet = &obj.routines[0];
+        let unused: Vec<&str> = unused_parameters_ir(al_code, &f.ir, obj, greet)
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(unused.len(), 1);
+        assert_eq!(unused[0].trim_matches('"'), "Unused Flag");
+        assert!(
+            unused_parameters_ir(al_code, &f.ir, obj, &obj.routines[1]).is_empty(),
+            "an event subscriber's signature is fixed by its publisher"
         );
+
+        let metrics = ProcedureMetrics {
+            line: 3,
+            line_count: 5,
+            parameter_count: 2,
+            ..ProcedureMetrics::for_test("Greet")
+        };
+        let findings = unused_parameter_findings(&metrics, al_code, &f.ir, obj, greet);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, "unused_parameter");
+        assert_eq!(findings[0].severity, "info");
+        assert_eq!(findings[0].location, "test.al:3");
+        assert!(findings[0].description.contains("Unused Flag"));
     }
 
     #[test]
-    fn test_complexity_logical_operators() {
-        // Test logical operators: and, or (xor is not counted per standard CC)
+    fn test_procedure_names_off_the_naming_convention_are_flagged() {
         let al_code = r#"codeunit 50100 "Test"
 {
-    procedure LogicalProcedure()
-    var
-        a: Boolean;
-        b: Boolean;
-        c: Boolean;
+    procedure my_proc()
     begin
-        if a and b then
-            c := true;
+    end;
 
-        if a or b then
-            c := false;
+    procedure PostDocument()
+    begin
+    end;
 
-        if a and b and c then
-            c := true;
+    procedure "post_quoted"()
+    begin
+    end;
+
+    trigger OnRun()
+    begin
     end;
 }"#;
+        let f = al_syntax::parse(al_code);
+        let obj = &f.objects[0];
+        let pattern = regex::Regex::new("^[A-Z][A-Za-z0-9]*$").unwrap();
+        let metrics = |procedure_name: &str, line: u32| ProcedureMetrics {
+            line,
+            line_count: 3,
+            ..ProcedureMetrics::for_test(procedure_name)
+        };
+        let findings: Vec<Finding> = obj
+            .routines
+            .iter()
+            .filter_map(|r| {
+                let m = metrics(&r.name, r.origin.start.row + 1);
+                naming_convention_finding(&m, al_code, r, &pattern)
+            })
+            .collect();
+        assert_eq!(findings.len(), 1, "{findings:?}");
+        assert_eq!(findings[0].category, "naming_convention");
+        assert_eq!(findings[0].severity, "info");
+        assert_eq!(findings[0].procedure, "Test.my_proc");
+        assert_eq!(findings[0].location, "test.al:3");
+    }
 
-        let complexity = complexity_of(al_code, "LogicalProcedure");
-        // Base: 1
-        // if a and b: +1 (if) +1 (and)
-        // if a or b: +1 (if) +1 (or)
-        // if a and b and c: +1 (if) +2 (two and operators)
-        // Total: 1 + 2 + 2 + 3 = 8
+    #[test]
+    fn test_runtime_object_run_is_a_dynamic_call() {
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure Dispatch(Other: Integer)
+    var
+        CuId: Integer;
+    begin
+        CuId := 50100;
+        Codeunit.Run(CuId);
+        Codeunit.Run(Codeunit::"Sales-Post");
+        Codeunit.Run(Other);
+    end;
+}"#;
+        let f = al_syntax::parse(al_code);
+        let r = &f.objects[0].routines[0];
+        let calls = dynamic_object_runs_ir(al_code, &f.ir, r);
         assert_eq!(
-            complexity, 8,
-            "Logical procedure should have complexity 8, got {}",
-            complexity
+            calls,
+            [
+                DynamicCall {
+                    line: 8,
+                    call: "Codeunit.Run".to_string(),
+                    argument: "CuId".to_string(),
+                    assigned: Some("50100".to_string()),
+                },
+                DynamicCall {
+                    line: 10,
+                    call: "Codeunit.Run".to_string(),
+                    argument: "Other".to_string(),
+                    assigned: None,
+                },
+            ],
+            "the Codeunit::\"Sales-Post\" run is static"
         );
+
+        let metrics = ProcedureMetrics {
+            line: 3,
+            line_count: 9,
+            parameter_count: 1,
+            ..ProcedureMetrics::for_test("Dispatch")
+        };
+        let findings = dynamic_call_findings(&metrics, al_code, &f.ir, r);
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].category, "dynamic_call");
+        assert_eq!(findings[0].severity, "info");
+        assert_eq!(findings[0].location, "test.al:8");
+        assert!(findings[0].description.contains("likely 50100"));
     }
 
     #[test]
-    fn test_quality_score_perfect() {
-        let score = calculate_quality_score(1, 5, 1);
-        assert!((score - 10.0).abs() < 0.01);
+    fn test_baseline_suppresses_known_findings_and_keeps_new_ones() {
+        let finding = |category: &str, location: &str, severity: &str| Finding {
+            category: category.to_string(),
+            severity: severity.to_string(),
+            location: location.to_string(),
+            procedure: "Post".to_string(),
+            description: "details may change between runs".to_string(),
+        };
+        let baseline = Baseline::from_json(
+            r#"{
+                "metrics": [],
+                "findings": [{
+                    "category": "high_complexity",
+                    "severity": "critical",
+                    "location": "Sales.al:10",
+                    "procedure": "Post",
+                    "description": "Cyclomatic complexity is 31"
+                }]
+            }"#,
+        )
+        .expect("a JSON report parses as a baseline");
+        let mut result = AnalysisResult {
+            metrics: Vec::new(),
+            findings: vec![
+                finding("high_complexity", "Sales.al:10", "critical"),
+                finding("missing_return", "Sales.al:42", "warning"),
+            ],
+            blast_radius: Vec::new(),
+            churn_risk: Vec::new(),
+            callees_by_object: Vec::new(),
+            summary: build_summary(&[], &[]),
+        };
+
+        result.retain_new(&baseline);
+
+        assert_eq!(result.findings.len(), 1);
+        assert_eq!(result.findings[0].category, "missing_return");
+        assert_eq!(result.summary.critical_findings, 0);
+        assert_eq!(result.summary.warning_findings, 1);
+
+        let bare = Baseline::from_json(
+            r#"[{"category": "missing_return", "severity": "warning",
+                 "location": "Sales.al:42", "procedure": "Post", "description": ""}]"#,
+        )
+        .expect("a bare findings array parses as a baseline");
+        assert!(bare.contains(&finding("missing_return", "Sales.al:42", "warning")));
+        assert!(!bare.contains(&finding("missing_return", "Sales.al:43", "warning")));
+        assert!(
+            Baseline::from_json(r#"{"total_procedures": 3}"#).is_err(),
+            "a summary-only report has no findings to compare against"
+        );
     }
 
     #[test]
-    fn test_quality_score_high_complexity() {
-        let score = calculate_quality_score(10, 5, 1);
-        assert!(score < 5.0);
+    fn test_retain_files_scopes_the_report_to_changed_files() {
+        let metric = |file: &str, name: &str| ProcedureMetrics {
+            object_name: "Sales".to_string(),
+            file: file.to_string(),
+            line: 10,
+            complexity: 12,
+            line_count: 30,
+            parameter_count: 1,
+            quality_score: 5.0,
+            ..ProcedureMetrics::for_test(name)
+        };
+        let finding = |location: &str| Finding {
+            category: "high_complexity".to_string(),
+            severity: "critical".to_string(),
+            location: location.to_string(),
+            procedure: "Sales.Post".to_string(),
+            description: "Cyclomatic complexity is 12".to_string(),
+        };
+        let metrics = vec![
+            metric("src/Sales.al", "Post"),
+            metric("src/Purchase.al", "Receive"),
+        ];
+        let findings = vec![finding("src/Sales.al:10"), finding("src/Purchase.al:10")];
+        let mut result = AnalysisResult {
+            summary: build_summary(&metrics, &findings),
+            metrics,
+            findings,
+            blast_radius: vec![BlastRadius {
+                procedure: "Purchase.Receive".to_string(),
+                location: "src/Purchase.al:10".to_string(),
+                score: 4,
+            }],
+            churn_risk: Vec::new(),
+            callees_by_object: Vec::new(),
+        };
+
+        let changed: HashSet<String> = ["src/Sales.al".to_string()].into();
+        result.retain_files(&changed);
+
+        assert_eq!(result.metrics.len(), 1);
+        assert_eq!(result.metrics[0].procedure_name, "Post");
+        assert_eq!(result.findings.len(), 1);
+        assert_eq!(result.findings[0].location, "src/Sales.al:10");
+        assert!(result.blast_radius.is_empty());
+        assert_eq!(result.summary.total_procedures, 1);
+        assert_eq!(result.summary.critical_findings, 1);
     }
 
     #[test]
-    fn test_quality_score_long_method() {
-        let score = calculate_quality_score(1, 50, 1);
-        assert!(score < 5.0);
+    fn test_rename_files_shortens_paths_after_scoping() {
+        let metric = |file: &str| ProcedureMetrics {
+            object_name: "Sales".to_string(),
+            file: file.to_string(),
+            line: 10,
+            complexity: 12,
+            line_count: 30,
+            parameter_count: 1,
+            quality_score: 5.0,
+            ..ProcedureMetrics::for_test("Post")
+        };
+        let finding = |category: &str, location: &str, description: &str| Finding {
+            category: category.to_string(),
+            severity: "warning".to_string(),
+            location: location.to_string(),
+            procedure: "Sales.Post".to_string(),
+            description: description.to_string(),
+        };
+        let metrics = vec![metric("a/Sales.al"), metric("b/Sales.al")];
+        let findings = vec![
+            finding(
+                "high_complexity",
+                "a/Sales.al:10",
+                "Cyclomatic complexity is 12",
+            ),
+            finding(
+                "duplicate_code",
+                "b/Sales.al:10",
+                "2 procedures have identical bodies: Sales.Post (b/Sales.al:10), Sales.Post \
+                 (c/b/Sales.al:10)",
+            ),
+        ];
+        let mut result = AnalysisResult {
+            summary: build_summary(&metrics, &findings),
+            metrics,
+            findings,
+            blast_radius: Vec::new(),
+            churn_risk: Vec::new(),
+            callees_by_object: Vec::new(),
+        };
+
+        // Scoping by project-relative path keeps the same-named files apart.
+        result.retain_files(&["b/Sales.al".to_string()].into());
+        assert_eq!(result.metrics.len(), 1);
+        assert_eq!(result.findings.len(), 1);
+
+        let display: HashMap<String, String> = [
+            ("a/Sales.al".to_string(), "Sales.al".to_string()),
+            ("b/Sales.al".to_string(), "Sales.al".to_string()),
+        ]
+        .into();
+        result.rename_files(&display);
+        assert_eq!(result.metrics[0].file, "Sales.al");
+        assert_eq!(result.findings[0].location, "Sales.al:10");
+        assert_eq!(
+            result.findings[0].description,
+            "2 procedures have identical bodies: Sales.Post (Sales.al:10), Sales.Post \
+             (c/b/Sales.al:10)",
+            "only whole report paths are rewritten"
+        );
+    }
+
+    #[test]
+    fn test_is_generated_source_checks_header_comments_only() {
+        let marker = "<auto-generated>";
+        assert!(is_generated_source(
+            "// <auto-generated>\n// Do not edit.\ncodeunit 50100 X\n{\n}\n",
+            marker
+        ));
+        assert!(is_generated_source(
+            "\u{feff}/*\n * <Auto-Generated by tool />\n */\ncodeunit 50100 X\n{\n}\n",
+            marker
+        ));
+        assert!(
+            !is_generated_source("// <auto-generated>\ncodeunit 50100 X\n{\n}\n", ""),
+            "an empty marker disables detection"
+        );
+        let late = format!(
+            "{}// <auto-generated>\n",
+            "\n".repeat(GENERATED_HEADER_LINES)
+        );
+        assert!(!is_generated_source(&late, marker), "past the header");
+        assert!(!is_generated_source(
+            "codeunit 50100 \"<auto-generated>\"\n{\n}\n",
+            marker
+        ));
     }
 
     #[test]
-    fn test_quality_score_many_params() {
-        let score = calculate_quality_score(1, 5, 8);
-        assert!(score < 7.0);
+    fn test_var_param_count_counts_only_by_reference_params() {
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure Split(Source: Text; var Head: Text; var Tail: Text; var Count: Integer; Sep: Char)
+    begin
+    end;
+
+    procedure ByValue(A: Integer; B: Integer)
+    begin
+    end;
+}"#;
+        let f = al_syntax::parse(al_code);
+        let routines = &f.objects[0].routines;
+        assert_eq!(var_param_count_ir(&routines[0]), 3);
+        assert_eq!(var_param_count_ir(&routines[1]), 0);
     }
 
     #[test]
-    fn test_findings_generated_for_high_complexity() {
+    fn test_findings_var_params_warning() {
+        let config = DiagnosticConfig::default();
         let metrics = ProcedureMetrics {
-            object_type: "Codeunit".to_string(),
-            object_name: "Test".to_string(),
-            procedure_name: "TestProc".to_string(),
-            file: "test.al".to_string(),
-            line: 10,
-            complexity: 12,
-            line_count: 10,
-            parameter_count: 2,
-            quality_score: 5.0,
+            line: 3,
+            line_count: 3,
+            parameter_count: 3,
+            var_param_count: config.var_params_warning, // at warning threshold
+            quality_score: 9.5,
+            ..ProcedureMetrics::for_test("Split")
         };
-        let config = DiagnosticConfig::default();
         let findings = generate_findings(&metrics, &config);
-        assert!(
-            findings
-                .iter()
-                .any(|f| f.category == "high_complexity" && f.severity == "critical")
-        );
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, "many_var_parameters");
+        assert_eq!(findings[0].severity, "warning");
+
+        let below = ProcedureMetrics {
+            var_param_count: config.var_params_warning - 1,
+            coupling: 0,
+            ..metrics
+        };
+        assert!(generate_findings(&below, &config).is_empty());
     }
 
     #[test]
-    fn test_quality_score_moderate_complexity() {
-        // complexity 3 hits the else-if branch (> 2 but <= 4)
-        let score = calculate_quality_score(3, 5, 1);
-        // penalty = (3-2) * 0.8 = 0.8, so score = 9.2
-        assert!((score - 9.2).abs() < 0.01);
+    fn test_report_path_relative_to_workspace_root() {
+        let root = Path::new("/work/app");
+        let file = root.join("src").join("Codeunits").join("Post.al");
+
+        assert_eq!(report_path(&file, None), "Post.al");
+        assert_eq!(
+            report_path(&file, Some(root)),
+            "src/Codeunits/Post.al",
+            "relative paths must use `/` on every platform"
+        );
+        let outside = Path::new("/elsewhere/Other.al");
+        assert_eq!(
+            report_path(outside, Some(root)),
+            outside.display().to_string()
+        );
     }
 
     #[test]
-    fn test_quality_score_moderate_length() {
-        // line_count 12 hits the else-if branch (> 10 but <= 15)
-        let score = calculate_quality_score(1, 12, 1);
-        // penalty = (12-10) * 0.3 = 0.6, so score = 9.4
-        assert!((score - 9.4).abs() < 0.01);
+    fn test_html_report_has_summary_and_a_row_per_procedure() {
+        let metric = |name: &str, complexity: u32| ProcedureMetrics {
+            object_name: "Sales <Post>".to_string(),
+            file: "post.al".to_string(),
+            line: 3,
+            complexity,
+            line_count: 10,
+            parameter_count: 1,
+            quality_score: 8.0,
+            ..ProcedureMetrics::for_test(name)
+        };
+        let metrics = vec![metric("Post", 12), metric("Check", 2)];
+        let findings = vec![Finding {
+            category: "complexity".to_string(),
+            severity: "warning".to_string(),
+            location: "post.al:3".to_string(),
+            procedure: "Sales <Post>.Post".to_string(),
+            description: "Cyclomatic complexity 12 & rising".to_string(),
+        }];
+        let summary = build_summary(&metrics, &findings);
+        let result = AnalysisResult {
+            metrics,
+            findings,
+            blast_radius: Vec::new(),
+            churn_risk: Vec::new(),
+            callees_by_object: Vec::new(),
+            summary,
+        };
+
+        let html = result.to_html("My \"App\"", false);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Code Quality Analysis: My &quot;App&quot;</title>"));
+        assert!(html.contains(r#"<th>Procedures</th><td class="num">2</td>"#));
+        assert!(html.contains(r#"<th>Average complexity</th><td class="num">7.0</td>"#));
+        assert!(html.contains(r#"<th>Warning findings</th><td class="num">1</td>"#));
+        assert!(html.contains("<td>Sales &lt;Post&gt;.Post</td>"));
+        assert!(html.contains("<td>Sales &lt;Post&gt;.Check</td>"));
+        assert!(html.contains("Cyclomatic complexity 12 &amp; rising"));
+        assert!(!html.contains("<Post>"), "text is escaped");
+        assert!(!html.contains("{{"), "every placeholder is filled");
+
+        let compact = result.to_html("My App", true);
+        assert!(compact.contains(r#"<th>Procedures</th><td class="num">2</td>"#));
+        assert!(!compact.contains("Sales &lt;Post&gt;.Post"));
     }
 
     #[test]
-    fn test_quality_score_moderate_params() {
-        // params 3 hits the else-if branch (> 2 but <= 4)
-        let score = calculate_quality_score(1, 5, 3);
-        // penalty = (3-2) * 0.5 = 0.5, so score = 9.5
-        assert!((score - 9.5).abs() < 0.01);
+    fn test_summary_only_json_omits_metrics_and_findings() {
+        let metrics = vec![ProcedureMetrics {
+            line: 3,
+            line_count: 3,
+            ..ProcedureMetrics::for_test("Simple")
+        }];
+        let findings: Vec<Finding> = Vec::new();
+        let summary = build_summary(&metrics, &findings);
+        let result = AnalysisResult {
+            metrics,
+            findings,
+            blast_radius: Vec::new(),
+            churn_risk: Vec::new(),
+            callees_by_object: Vec::new(),
+            summary,
+        };
+
+        let full: serde_json::Value =
+            serde_json::from_str(&result.to_json(false).unwrap()).unwrap();
+        assert!(full.get("metrics").is_some());
+
+        let compact: serde_json::Value =
+            serde_json::from_str(&result.to_json(true).unwrap()).unwrap();
+        let keys: Vec<&str> = compact
+            .as_object()
+            .expect("summary is a JSON object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(
+            keys.len(),
+            5,
+            "only the summary fields may be present: {keys:?}"
+        );
+        assert!(compact.get("metrics").is_none());
+        assert!(compact.get("findings").is_none());
+        assert_eq!(compact["total_procedures"], 1);
     }
 
     #[test]
-    fn test_quality_score_clamped_to_zero() {
-        // Very bad metrics should clamp to 0
-        let score = calculate_quality_score(50, 200, 20);
-        assert_eq!(score, 0.0);
+    fn test_sort_for_output_is_independent_of_input_order() {
+        let metric = |file: &str, line: u32, name: &str| ProcedureMetrics {
+            file: file.to_string(),
+            line,
+            line_count: 3,
+            ..ProcedureMetrics::for_test(name)
+        };
+        let finding = |location: &str, procedure: &str| Finding {
+            category: "high_complexity".to_string(),
+            severity: "warning".to_string(),
+            location: location.to_string(),
+            procedure: procedure.to_string(),
+            description: String::new(),
+        };
+        let metrics = vec![
+            metric("b.al", 3, "B"),
+            metric("a.al", 10, "A2"),
+            metric("a.al", 9, "A1"),
+        ];
+        let findings = vec![
+            finding("b.al:3", "Test.B"),
+            finding("a.al:10", "Test.A2"),
+            finding("a.al:9", "Test.A1"),
+        ];
+        let run = |metrics: Vec<ProcedureMetrics>, findings: Vec<Finding>| {
+            let summary = build_summary(&metrics, &findings);
+            let mut result = AnalysisResult {
+                metrics,
+                findings,
+                blast_radius: Vec::new(),
+                churn_risk: Vec::new(),
+                callees_by_object: Vec::new(),
+                summary,
+            };
+            result.sort_for_output();
+            result.to_json(false).unwrap()
+        };
+
+        let first = run(metrics.clone(), findings.clone());
+        let second = run(
+            metrics.into_iter().rev().collect(),
+            findings.into_iter().rev().collect(),
+        );
+        assert_eq!(first, second);
+
+        let parsed: serde_json::Value = serde_json::from_str(&first).unwrap();
+        let names: Vec<&str> = parsed["metrics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["procedure_name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["A1", "A2", "B"]);
+        assert_eq!(parsed["findings"][0]["location"], "a.al:9");
     }
 
     #[test]
-    fn test_findings_complexity_warning() {
-        let config = DiagnosticConfig::default();
-        let metrics = ProcedureMetrics {
-            object_type: "Codeunit".to_string(),
-            object_name: "Test".to_string(),
-            procedure_name: "TestProc".to_string(),
-            file: "test.al".to_string(),
-            line: 10,
-            complexity: config.complexity_warning, // at warning threshold
-            line_count: 5,
-            parameter_count: 1,
-            quality_score: 8.0,
-        };
-        let findings = generate_findings(&metrics, &config);
-        assert_eq!(findings.len(), 1);
-        assert_eq!(findings[0].category, "high_complexity");
-        assert_eq!(findings[0].severity, "warning");
+    fn test_blast_radius_counts_every_transitive_caller_once() {
+        // 0 <- 1 <- 3, 0 <- 2 <- {3, 4}; and a cycle 5 <-> 6 called by 7.
+        let callers = vec![
+            vec![1, 2],
+            vec![3],
+            vec![3, 4],
+            vec![],
+            vec![],
+            vec![6, 7],
+            vec![5],
+            vec![],
+        ];
+        assert_eq!(
+            transitive_caller_counts(&callers),
+            vec![4, 1, 2, 0, 0, 2, 2, 0]
+        );
     }
 
     #[test]
-    fn test_findings_length_critical() {
-        let config = DiagnosticConfig::default();
-        let metrics = ProcedureMetrics {
-            object_type: "Codeunit".to_string(),
-            object_name: "Test".to_string(),
-            procedure_name: "TestProc".to_string(),
-            file: "test.al".to_string(),
-            line: 10,
-            complexity: 1,
-            line_count: config.length_critical, // at critical threshold
-            parameter_count: 1,
+    fn test_churn_risk_ranks_heavily_called_code_above_rarely_called() {
+        let metric = |name: &str, line: u32, complexity: u32| ProcedureMetrics {
+            line,
+            complexity,
+            line_count: 10,
             quality_score: 5.0,
+            ..ProcedureMetrics::for_test(name)
         };
-        let findings = generate_findings(&metrics, &config);
-        assert!(
-            findings
-                .iter()
-                .any(|f| f.category == "long_method" && f.severity == "critical")
-        );
+        let metrics = vec![
+            metric("Tangled", 3, 10),
+            metric("Popular", 20, 4),
+            metric("Uncalled", 40, 30),
+        ];
+        let incoming: std::collections::HashMap<String, usize> = [
+            ("test.al:3".to_string(), 1),
+            ("test.al:20".to_string(), 20),
+            ("test.al:40".to_string(), 0),
+        ]
+        .into_iter()
+        .collect();
+
+        let ranked = churn_risk_ranking(&metrics, &incoming);
+        let names: Vec<&str> = ranked.iter().map(|c| c.procedure.as_str()).collect();
+        // 4 * ln(21) ≈ 12.2 outranks 10 * ln(2) ≈ 6.9; no callers, no risk.
+        assert_eq!(names, ["Test.Popular", "Test.Tangled"]);
+        assert!((ranked[0].score - 4.0 * 21f64.ln()).abs() < 1e-9);
+        assert_eq!(ranked[1].incoming_calls, 1);
     }
 
     #[test]
-    fn test_findings_length_warning() {
-        let config = DiagnosticConfig::default();
-        let metrics = ProcedureMetrics {
-            object_type: "Codeunit".to_string(),
-            object_name: "Test".to_string(),
-            procedure_name: "TestProc".to_string(),
-            file: "test.al".to_string(),
-            line: 10,
-            complexity: 1,
-            line_count: config.length_warning, // at warning threshold
-            parameter_count: 1,
-            quality_score: 7.0,
+    fn test_coupling_is_applied_and_flagged_past_the_threshold() {
+        let metric = |procedure_name: &str, line: u32| ProcedureMetrics {
+            object_name: "Hub".to_string(),
+            file: "hub.al".to_string(),
+            line,
+            line_count: 5,
+            ..ProcedureMetrics::for_test(procedure_name)
         };
-        let findings = generate_findings(&metrics, &config);
+        let mut metrics = vec![metric("Coordinate", 3), metric("Helper", 12)];
+        let coupling = std::collections::HashMap::from([("hub.al:3".to_string(), 3)]);
+        apply_coupling(&mut metrics, &coupling);
+        assert_eq!(metrics[0].coupling, 3);
+        assert_eq!(metrics[1].coupling, 0);
+
+        let config = DiagnosticConfig {
+            coupling_warning: 2,
+            ..DiagnosticConfig::default()
+        };
+        let findings = coupling_findings(&metrics, &config);
         assert_eq!(findings.len(), 1);
-        assert_eq!(findings[0].category, "long_method");
-        assert_eq!(findings[0].severity, "warning");
+        assert_eq!(findings[0].category, "high_coupling");
+        assert_eq!(findings[0].location, "hub.al:3");
+        assert!(coupling_findings(&metrics, &DiagnosticConfig::default()).is_empty());
     }
 
     #[test]
-    fn test_findings_params_critical() {
-        let config = DiagnosticConfig::default();
-        let metrics = ProcedureMetrics {
-            object_type: "Codeunit".to_string(),
-            object_name: "Test".to_string(),
-            procedure_name: "TestProc".to_string(),
-            file: "test.al".to_string(),
-            line: 10,
-            complexity: 1,
+    fn test_ui_reachability_is_applied_by_location() {
+        let metric = |line: u32| ProcedureMetrics {
+            object_name: "Hub".to_string(),
+            file: "hub.al".to_string(),
+            line,
             line_count: 5,
-            parameter_count: config.params_critical, // at critical threshold
-            quality_score: 5.0,
+            ..ProcedureMetrics::for_test(&format!("P{line}"))
         };
-        let findings = generate_findings(&metrics, &config);
-        assert!(
-            findings
-                .iter()
-                .any(|f| f.category == "too_many_parameters" && f.severity == "critical")
-        );
+        let mut metrics = vec![metric(3), metric(12), metric(20)];
+        let reachable = std::collections::HashMap::from([
+            ("hub.al:3".to_string(), true),
+            ("hub.al:12".to_string(), false),
+        ]);
+        apply_ui_reachability(&mut metrics, &reachable);
+        let classes: Vec<Option<bool>> = metrics.iter().map(|m| m.ui_reachable).collect();
+        assert_eq!(classes, [Some(true), Some(false), None]);
     }
 
     #[test]
-    fn test_findings_params_warning() {
-        let config = DiagnosticConfig::default();
-        let metrics = ProcedureMetrics {
-            object_type: "Codeunit".to_string(),
-            object_name: "Test".to_string(),
-            procedure_name: "TestProc".to_string(),
-            file: "test.al".to_string(),
-            line: 10,
-            complexity: 1,
-            line_count: 5,
-            parameter_count: config.params_warning, // at warning threshold
-            quality_score: 7.0,
+    fn test_duplicate_bodies_are_grouped() {
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure First(var Customer: Record Customer)
+    begin
+        // Normalize the name before saving.
+        Customer.Name := UpperCase(Customer.Name);
+        if Customer.Name <> '' then
+            Customer.Modify(true);
+    end;
+
+    procedure Second(var Customer: Record Customer)
+    begin
+        customer.name := UPPERCASE(customer.name);
+        if customer.name <> '' then customer.modify(true);
+    end;
+
+    procedure Renamed(var Vendor: Record Vendor)
+    begin
+        Vendor.Name := UpperCase(Vendor.Name);
+        if Vendor.Name <> '' then
+            Vendor.Modify(true);
+    end;
+}"#;
+        let f = al_syntax::parse(al_code);
+        let fingerprints = |normalize: bool| -> Vec<BodyFingerprint> {
+            f.objects[0]
+                .routines
+                .iter()
+                .filter_map(|r| {
+                    Some(BodyFingerprint {
+                        procedure: format!("Test.{}", r.name),
+                        location: format!("test.al:{}", r.origin.start.row + 1),
+                        hash: body_fingerprint_ir(al_code, &f.ir, r, normalize, 5)?,
+                    })
+                })
+                .collect()
         };
-        let findings = generate_findings(&metrics, &config);
+
+        // Comments, whitespace and casing never matter: First == Second.
+        let findings = duplicate_code_findings(&fingerprints(false));
         assert_eq!(findings.len(), 1);
-        assert_eq!(findings[0].category, "too_many_parameters");
-        assert_eq!(findings[0].severity, "warning");
+        assert_eq!(findings[0].category, "duplicate_code");
+        assert_eq!(findings[0].procedure, "Test.First");
+        assert!(findings[0].description.contains("Test.Second (test.al:11)"));
+        assert!(!findings[0].description.contains("Renamed"));
+
+        // The report does not depend on the order files were read in.
+        let mut reversed = fingerprints(false);
+        reversed.reverse();
+        assert_eq!(
+            serde_json::to_string(&duplicate_code_findings(&reversed)).unwrap(),
+            serde_json::to_string(&findings).unwrap()
+        );
+
+        // With identifier normalization the renamed copy joins the group.
+        let findings = duplicate_code_findings(&fingerprints(true));
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].description.starts_with("3 procedures"));
     }
 
     #[test]
-    fn test_findings_no_issues() {
-        let config = DiagnosticConfig::default();
-        let metrics = ProcedureMetrics {
-            object_type: "Codeunit".to_string(),
-            object_name: "Test".to_string(),
-            procedure_name: "TestProc".to_string(),
-            file: "test.al".to_string(),
-            line: 10,
-            complexity: 1,
-            line_count: 5,
-            parameter_count: 1,
-            quality_score: 10.0,
+    fn test_todo_comment_produces_an_info_finding() {
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure Post()
+    begin
+        // TODO: fix this
+        Message('TODO is not a comment');
+    end;
+}"#;
+        let f = al_syntax::parse(al_code);
+        assert!(
+            marker_comment_findings(al_code, &f, "test.al", &DiagnosticConfig::default())
+                .is_empty(),
+            "off by default"
+        );
+        let config = DiagnosticConfig {
+            todo_comments: true,
+            ..DiagnosticConfig::default()
         };
-        let findings = generate_findings(&metrics, &config);
-        assert!(findings.is_empty());
+        let findings = marker_comment_findings(al_code, &f, "test.al", &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, "todo_comment");
+        assert_eq!(findings[0].severity, "info");
+        assert_eq!(findings[0].location, "test.al:5");
+        assert_eq!(findings[0].procedure, "Test.Post");
+        assert_eq!(findings[0].description, "TODO: fix this");
+
+        // Whole words only, block comments line by line, configurable list.
+        let src = "/* first\n   FIXME later */ // TODOs and HACKY\ncodeunit 50100 X\n{\n}\n";
+        let f = al_syntax::parse(src);
+        let found = marker_comments(src, &f, &config);
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].row, found[0].column), (1, 3));
+        assert_eq!(found[0].text, "FIXME later");
+        let notes = DiagnosticConfig {
+            comment_markers: vec!["NOTE".to_string()],
+            ..config.clone()
+        };
+        assert!(marker_comments(src, &f, &notes).is_empty());
     }
 
     #[test]
-    fn test_build_summary_with_metrics() {
-        let metrics = vec![
-            ProcedureMetrics {
-                object_type: "Codeunit".to_string(),
-                object_name: "Test".to_string(),
-                procedure_name: "Proc1".to_string(),
-                file: "test.al".to_string(),
-                line: 10,
-                complexity: 4,
-                line_count: 20,
-                parameter_count: 2,
-                quality_score: 8.0,
-            },
-            ProcedureMetrics {
-                object_type: "Codeunit".to_string(),
-                object_name: "Test".to_string(),
-                procedure_name: "Proc2".to_string(),
-                file: "test.al".to_string(),
-                line: 30,
-                complexity: 6,
-                line_count: 30,
-                parameter_count: 3,
-                quality_score: 6.0,
-            },
-        ];
-        let findings = vec![
-            Finding {
-                category: "high_complexity".to_string(),
-                severity: "critical".to_string(),
-                location: "test.al:30".to_string(),
-                procedure: "Test.Proc2".to_string(),
-                description: "test".to_string(),
-            },
-            Finding {
-                category: "long_method".to_string(),
-                severity: "warning".to_string(),
-                location: "test.al:30".to_string(),
-                procedure: "Test.Proc2".to_string(),
-                description: "test".to_string(),
-            },
-        ];
-        let summary = build_summary(&metrics, &findings);
-        assert_eq!(summary.total_procedures, 2);
-        assert!((summary.avg_complexity - 5.0).abs() < 0.01);
-        assert!((summary.avg_quality_score - 7.0).abs() < 0.01);
-        assert_eq!(summary.critical_findings, 1);
-        assert_eq!(summary.warning_findings, 1);
+    fn test_todo_comments_in_excluded_objects_are_skipped() {
+        let al_code = r#"// TODO: header
+report 50100 "Rep"
+{
+    // TODO: in the report
+}
+
+codeunit 50101 "Cu"
+{
+    // TODO: in the codeunit
+}"#;
+        let f = al_syntax::parse(al_code);
+        let config = DiagnosticConfig {
+            todo_comments: true,
+            excluded_object_types: vec![ir::ObjectKind::Report],
+            ..DiagnosticConfig::default()
+        };
+        let texts = |config: &DiagnosticConfig| -> Vec<String> {
+            marker_comments(al_code, &f, config)
+                .into_iter()
+                .map(|c| c.text)
+                .collect()
+        };
+        assert_eq!(texts(&config), ["TODO: header", "TODO: in the codeunit"]);
+
+        let all = DiagnosticConfig {
+            excluded_object_types: vec![ir::ObjectKind::Report, ir::ObjectKind::Codeunit],
+            ..config
+        };
+        assert!(texts(&all).is_empty(), "nothing left to own the header");
     }
 
     #[test]
-    fn test_build_summary_empty() {
-        let summary = build_summary(&[], &[]);
-        assert_eq!(summary.total_procedures, 0);
-        assert_eq!(summary.avg_complexity, 0.0);
-        assert_eq!(summary.avg_quality_score, 0.0);
-        assert_eq!(summary.critical_findings, 0);
-        assert_eq!(summary.warning_findings, 0);
+    fn test_trivial_bodies_are_not_fingerprinted() {
+        let al_code = r#"codeunit 50100 "Test"
+{
+    procedure A(): Boolean
+    begin
+        exit(true);
+    end;
+}"#;
+        let f = al_syntax::parse(al_code);
+        let r = &f.objects[0].routines[0];
+        assert!(body_fingerprint_ir(al_code, &f.ir, r, false, 30).is_none());
+        assert!(body_fingerprint_ir(al_code, &f.ir, r, false, 1).is_some());
     }
 }
@@ -4,9 +4,9 @@
 //! - NavxManifest.xml: App metadata (ID, name, publisher, version)
 //! - SymbolReference.json: All symbol definitions (codeunits, tables, etc.)
 
+use crate::error::{Error, Result};
 use crate::types::ObjectType;
-use anyhow::{Context, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::io::{Read, Seek, SeekFrom};
 use std::path::Path;
 
@@ -125,6 +125,42 @@ pub struct ParsedAppPackage {
     pub objects: Vec<ExternalObject>,
 }
 
+/// One object of a package's inventory ([`ParsedAppPackage::inventory`]).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryEntry {
+    pub object_type: String,
+    pub id: i64,
+    pub name: String,
+    /// Number of methods the package's symbols declare on the object.
+    pub methods: usize,
+}
+
+impl ParsedAppPackage {
+    /// Every object with its method count, sorted by type, id, then name —
+    /// what `--analyze` reports for a package that ships no source.
+    pub fn inventory(&self) -> Vec<InventoryEntry> {
+        let mut out: Vec<InventoryEntry> = self
+            .objects
+            .iter()
+            .map(|o| InventoryEntry {
+                object_type: o.object_type.to_string(),
+                id: o.id,
+                name: o.name.clone(),
+                methods: o.methods.len(),
+            })
+            .collect();
+        out.sort_by(|a, b| {
+            (a.object_type.as_str(), a.id, a.name.as_str()).cmp(&(
+                b.object_type.as_str(),
+                b.id,
+                b.name.as_str(),
+            ))
+        });
+        out
+    }
+}
+
 /// Intermediate structure for deserializing SymbolReference.json.
 ///
 /// Top-level keys are flat in older BC versions; from BC 24+ the
@@ -175,6 +211,10 @@ struct SymbolObject {
     id: i64,
     #[serde(default)]
     methods: Vec<SymbolMethod>,
+    /// The object an extension (tableextension, pageextension, ...)
+    /// extends, by name. Absent on non-extension objects.
+    #[serde(default)]
+    target_object: Option<String>,
     /// Nested objects inside a namespace block. Top-level SymbolReference
     /// also has Namespaces, but in BC 24+ codeunits/tables/etc. are nested
     /// inside namespace nodes. This field allows walking that tree.
@@ -290,13 +330,17 @@ struct SymbolMethodProperty {
 pub(crate) fn open_app_zip(
     path: &Path,
 ) -> Result<zip::ZipArchive<std::io::BufReader<std::fs::File>>> {
-    let file = std::fs::File::open(path)
-        .with_context(|| format!("Failed to open .app file: {}", path.display()))?;
+    let io = |source| Error::Io {
+        what: path.display().to_string(),
+        source,
+    };
+    let file = std::fs::File::open(path).map_err(io)?;
     let mut reader = std::io::BufReader::new(file);
-    reader
-        .seek(SeekFrom::Start(NAVX_HEADER_SIZE))
-        .context("Failed to skip NAVX header")?;
-    zip::ZipArchive::new(reader).context("Failed to open .app as ZIP archive")
+    reader.seek(SeekFrom::Start(NAVX_HEADER_SIZE)).map_err(io)?;
+    zip::ZipArchive::new(reader).map_err(|source| Error::Zip {
+        what: path.display().to_string(),
+        source,
+    })
 }
 
 /// Extract and parse a .app package file
@@ -307,11 +351,24 @@ pub fn extract_app_package(path: &Path) -> Result<ParsedAppPackage> {
     let metadata = parse_manifest(&mut archive)?;
 
     // Parse SymbolReference.json
-    let objects = parse_symbols(&mut archive)?;
+    let objects = parse_symbols(&mut archive, &|_, _| true)?;
 
     Ok(ParsedAppPackage { metadata, objects })
 }
 
+/// [`extract_app_package`], keeping only the objects `keep` accepts (by
+/// name and id). Rejected objects are dropped as the symbol tree is
+/// drained — their methods and signatures are never built.
+pub fn extract_app_package_filtered(
+    path: &Path,
+    keep: &dyn Fn(&str, i64) -> bool,
+) -> Result<ParsedAppPackage> {
+    let mut archive = open_app_zip(path)?;
+    let metadata = parse_manifest(&mut archive)?;
+    let objects = parse_symbols(&mut archive, keep)?;
+    Ok(ParsedAppPackage { metadata, objects })
+}
+
 /// Manifest-only read (perf safe-wins Task 3): parses NavxManifest.xml
 /// (KB-sized) WITHOUT touching SymbolReference.json (MB-sized) — the cheap
 /// identity probe `load_all_apps` dedups on before paying for full symbol
@@ -324,15 +381,27 @@ pub fn extract_app_metadata(path: &Path) -> Result<AppMetadata> {
 /// Symbols-only read: the expensive half of [`extract_app_package`], for a
 /// caller that already holds the manifest via [`extract_app_metadata`].
 pub fn extract_app_symbols(path: &Path) -> Result<Vec<ExternalObject>> {
+    extract_app_symbols_filtered(path, &|_, _| true)
+}
+
+/// [`extract_app_symbols`], keeping only the objects `keep` accepts — see
+/// [`extract_app_package_filtered`].
+pub fn extract_app_symbols_filtered(
+    path: &Path,
+    keep: &dyn Fn(&str, i64) -> bool,
+) -> Result<Vec<ExternalObject>> {
     let mut archive = open_app_zip(path)?;
-    parse_symbols(&mut archive)
+    parse_symbols(&mut archive, keep)
 }
 
 /// Parse NavxManifest.xml to extract app metadata
 fn parse_manifest<R: Read + Seek>(archive: &mut zip::ZipArchive<R>) -> Result<AppMetadata> {
     let manifest_file = archive
         .by_name("NavxManifest.xml")
-        .context("NavxManifest.xml not found in app package")?;
+        .map_err(|source| Error::Zip {
+            what: "NavxManifest.xml".to_string(),
+            source,
+        })?;
 
     // T2.2: belt-and-suspenders cap — reject a hostile declared size before
     // decompressing, then bound the read itself (a lying central directory).
@@ -340,11 +409,12 @@ fn parse_manifest<R: Read + Seek>(archive: &mut zip::ZipArchive<R>) -> Result<Ap
         manifest_file.size(),
         crate::capped_io::NAVX_MANIFEST_XML_CAP,
     )
-    .context("NavxManifest.xml declared size exceeds cap")?;
+    .map_err(|e| Error::capped("NavxManifest.xml", e))?;
     let bytes =
         crate::capped_io::read_capped(manifest_file, crate::capped_io::NAVX_MANIFEST_XML_CAP)
-            .context("Failed to read NavxManifest.xml")?;
-    let content = std::str::from_utf8(&bytes).context("Invalid UTF-8 in NavxManifest.xml")?;
+            .map_err(|e| Error::capped("NavxManifest.xml", e))?;
+    let content = std::str::from_utf8(&bytes)
+        .map_err(|_| Error::Parse("Invalid UTF-8 in NavxManifest.xml".to_string()))?;
 
     parse_manifest_xml(content)
 }
@@ -355,13 +425,16 @@ fn parse_manifest<R: Read + Seek>(archive: &mut zip::ZipArchive<R>) -> Result<Ap
 /// an inline manifest string, without constructing an in-memory zip.
 fn parse_manifest_xml(content: &str) -> Result<AppMetadata> {
     // Parse XML using roxmltree
-    let doc = roxmltree::Document::parse(content).context("Failed to parse NavxManifest.xml")?;
+    let doc = roxmltree::Document::parse(content).map_err(|source| Error::Xml {
+        what: "NavxManifest.xml".to_string(),
+        source,
+    })?;
 
     // Find the App element
     let app_node = doc
         .descendants()
         .find(|n| n.has_tag_name("App"))
-        .context("App element not found in NavxManifest.xml")?;
+        .ok_or_else(|| Error::Parse("App element not found in NavxManifest.xml".to_string()))?;
 
     let attr = |name: &str| app_node.attribute(name).unwrap_or_default().to_string();
 
@@ -445,11 +518,36 @@ pub(crate) fn parse_first_json_value<T: serde::de::DeserializeOwned>(
     }
 }
 
-/// Parse SymbolReference.json to extract object definitions
-fn parse_symbols<R: Read + Seek>(archive: &mut zip::ZipArchive<R>) -> Result<Vec<ExternalObject>> {
-    let symbols_file = archive
-        .by_name("SymbolReference.json")
-        .context("SymbolReference.json not found in app package")?;
+/// The archive index of the symbol-reference entry: `SymbolReference.json`
+/// exactly when present, otherwise the first entry (in archive order) whose
+/// `\`-normalized, lowercased name ends with `symbolreference.json` or
+/// `symbolreference.json.gz` — compilers differ in the entry's casing and
+/// folder, and may store it gzip-compressed.
+fn symbol_reference_index<R: Read + Seek>(archive: &mut zip::ZipArchive<R>) -> Option<usize> {
+    if let Some(i) = archive.index_for_name("SymbolReference.json") {
+        return Some(i);
+    }
+    (0..archive.len()).find(|&i| {
+        archive.name_for_index(i).is_some_and(|name| {
+            let name = name.replace('\\', "/").to_ascii_lowercase();
+            name.ends_with("symbolreference.json") || name.ends_with("symbolreference.json.gz")
+        })
+    })
+}
+
+/// Read the raw `SymbolReference.json` bytes of an `.app` archive (see
+/// [`symbol_reference_index`] for which entry), gunzipping a gzip-compressed
+/// entry. Both the entry and the inflated content are bounded by
+/// [`crate::capped_io::SYMBOL_REFERENCE_JSON_CAP`].
+pub(crate) fn read_symbol_reference_bytes<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+) -> Result<Vec<u8>> {
+    let index = symbol_reference_index(archive)
+        .ok_or_else(|| Error::Parse("SymbolReference.json not found in app package".to_string()))?;
+    let symbols_file = archive.by_index(index).map_err(|source| Error::Zip {
+        what: "SymbolReference.json".to_string(),
+        source,
+    })?;
 
     // T2.2: belt-and-suspenders cap — reject a hostile declared size before
     // decompressing, then bound the read itself (a lying central directory).
@@ -457,84 +555,164 @@ fn parse_symbols<R: Read + Seek>(archive: &mut zip::ZipArchive<R>) -> Result<Vec
         symbols_file.size(),
         crate::capped_io::SYMBOL_REFERENCE_JSON_CAP,
     )
-    .context("SymbolReference.json declared size exceeds cap")?;
+    .map_err(|e| Error::capped("SymbolReference.json", e))?;
     let content =
         crate::capped_io::read_capped(symbols_file, crate::capped_io::SYMBOL_REFERENCE_JSON_CAP)
-            .context("Failed to read SymbolReference.json")?;
+            .map_err(|e| Error::capped("SymbolReference.json", e))?;
+
+    inflate_symbol_reference(content)
+        .map_err(|e| Error::capped("gzip-compressed SymbolReference.json", e))
+}
+
+/// Gunzip symbol-reference bytes that start with the gzip magic (`1f 8b`),
+/// bounded by [`crate::capped_io::SYMBOL_REFERENCE_JSON_CAP`]; any other
+/// content comes back unchanged. Shared with the engine's fail-closed
+/// reader, [`crate::engine::deps::app_package_zip::extract_symbol_reference_json`].
+pub(crate) fn inflate_symbol_reference(
+    content: Vec<u8>,
+) -> std::result::Result<Vec<u8>, crate::capped_io::CapReadError> {
+    if !content.starts_with(&[0x1f, 0x8b]) {
+        return Ok(content);
+    }
+    let decoder = flate2::read::GzDecoder::new(content.as_slice());
+    crate::capped_io::read_capped(decoder, crate::capped_io::SYMBOL_REFERENCE_JSON_CAP)
+}
+
+/// Parse SymbolReference.json to extract the object definitions `keep`
+/// accepts.
+fn parse_symbols<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    keep: &dyn Fn(&str, i64) -> bool,
+) -> Result<Vec<ExternalObject>> {
+    let content = read_symbol_reference_bytes(archive)?;
 
     // Handle UTF-8 BOM if present
-    let json_str = if content.starts_with(&[0xEF, 0xBB, 0xBF]) {
-        std::str::from_utf8(&content[3..]).context("Invalid UTF-8 in SymbolReference.json")?
-    } else {
-        std::str::from_utf8(&content).context("Invalid UTF-8 in SymbolReference.json")?
-    };
+    let body = content
+        .strip_prefix(&[0xEF, 0xBB, 0xBF])
+        .unwrap_or(&content);
+    let json_str = std::str::from_utf8(body)
+        .map_err(|_| Error::Parse("Invalid UTF-8 in SymbolReference.json".to_string()))?;
 
     // The JSON may have null byte padding after the actual content — see
     // `parse_first_json_value`'s doc.
     let symbols: SymbolReference =
-        parse_first_json_value(json_str).context("Failed to parse SymbolReference.json")?;
+        parse_first_json_value(json_str).map_err(|source| Error::Json {
+            what: "SymbolReference.json".to_string(),
+            source,
+        })?;
 
     let mut objects = Vec::new();
-    collect_objects_top(symbols, &mut objects);
+    collect_objects_top(symbols, keep, &mut objects);
     Ok(objects)
 }
 
 /// Drain top-level SymbolReference into ExternalObject entries, including
 /// any nested Namespaces (BC 24+ stores codeunits inside namespace nodes).
-fn collect_objects_top(symbols: SymbolReference, out: &mut Vec<ExternalObject>) {
-    push_objects(symbols.tables, ObjectType::Table, out);
-    push_objects(symbols.codeunits, ObjectType::Codeunit, out);
-    push_objects(symbols.pages, ObjectType::Page, out);
-    push_objects(symbols.reports, ObjectType::Report, out);
-    push_objects(symbols.queries, ObjectType::Query, out);
-    push_objects(symbols.xml_ports, ObjectType::XmlPort, out);
-    push_objects(symbols.interfaces, ObjectType::Interface, out);
-    push_objects(symbols.enum_types, ObjectType::Enum, out);
-    push_objects(symbols.control_add_ins, ObjectType::ControlAddIn, out);
-    push_objects(symbols.page_extensions, ObjectType::PageExtension, out);
-    push_objects(symbols.table_extensions, ObjectType::TableExtension, out);
-    push_objects(symbols.enum_extension_types, ObjectType::EnumExtension, out);
-    push_objects(symbols.permission_sets, ObjectType::PermissionSet, out);
+fn collect_objects_top(
+    symbols: SymbolReference,
+    keep: &dyn Fn(&str, i64) -> bool,
+    out: &mut Vec<ExternalObject>,
+) {
+    push_objects(symbols.tables, ObjectType::Table, keep, out);
+    push_objects(symbols.codeunits, ObjectType::Codeunit, keep, out);
+    push_objects(symbols.pages, ObjectType::Page, keep, out);
+    push_objects(symbols.reports, ObjectType::Report, keep, out);
+    push_objects(symbols.queries, ObjectType::Query, keep, out);
+    push_objects(symbols.xml_ports, ObjectType::XmlPort, keep, out);
+    push_objects(symbols.interfaces, ObjectType::Interface, keep, out);
+    push_objects(symbols.enum_types, ObjectType::Enum, keep, out);
+    push_objects(symbols.control_add_ins, ObjectType::ControlAddIn, keep, out);
+    push_objects(
+        symbols.page_extensions,
+        ObjectType::PageExtension,
+        keep,
+        out,
+    );
+    push_objects(
+        symbols.table_extensions,
+        ObjectType::TableExtension,
+        keep,
+        out,
+    );
+    push_objects(
+        symbols.enum_extension_types,
+        ObjectType::EnumExtension,
+        keep,
+        out,
+    );
+    push_objects(
+        symbols.permission_sets,
+        ObjectType::PermissionSet,
+        keep,
+        out,
+    );
     push_objects(
         symbols.permission_set_extensions,
         ObjectType::PermissionSetExtension,
+        keep,
         out,
     );
     for ns in symbols.namespaces {
-        collect_objects_ns(ns, out);
+        collect_objects_ns(ns, keep, out);
     }
 }
 
-fn collect_objects_ns(ns: SymbolNamespace, out: &mut Vec<ExternalObject>) {
-    push_objects(ns.tables, ObjectType::Table, out);
-    push_objects(ns.codeunits, ObjectType::Codeunit, out);
-    push_objects(ns.pages, ObjectType::Page, out);
-    push_objects(ns.reports, ObjectType::Report, out);
-    push_objects(ns.queries, ObjectType::Query, out);
-    push_objects(ns.xml_ports, ObjectType::XmlPort, out);
-    push_objects(ns.interfaces, ObjectType::Interface, out);
-    push_objects(ns.enum_types, ObjectType::Enum, out);
-    push_objects(ns.control_add_ins, ObjectType::ControlAddIn, out);
-    push_objects(ns.page_extensions, ObjectType::PageExtension, out);
-    push_objects(ns.table_extensions, ObjectType::TableExtension, out);
-    push_objects(ns.enum_extension_types, ObjectType::EnumExtension, out);
-    push_objects(ns.permission_sets, ObjectType::PermissionSet, out);
+fn collect_objects_ns(
+    ns: SymbolNamespace,
+    keep: &dyn Fn(&str, i64) -> bool,
+    out: &mut Vec<ExternalObject>,
+) {
+    push_objects(ns.tables, ObjectType::Table, keep, out);
+    push_objects(ns.codeunits, ObjectType::Codeunit, keep, out);
+    push_objects(ns.pages, ObjectType::Page, keep, out);
+    push_objects(ns.reports, ObjectType::Report, keep, out);
+    push_objects(ns.queries, ObjectType::Query, keep, out);
+    push_objects(ns.xml_ports, ObjectType::XmlPort, keep, out);
+    push_objects(ns.interfaces, ObjectType::Interface, keep, out);
+    push_objects(ns.enum_types, ObjectType::Enum, keep, out);
+    push_objects(ns.control_add_ins, ObjectType::ControlAddIn, keep, out);
+    push_objects(ns.page_extensions, ObjectType::PageExtension, keep, out);
+    push_objects(ns.table_extensions, ObjectType::TableExtension, keep, out);
+    push_objects(
+        ns.enum_extension_types,
+        ObjectType::EnumExtension,
+        keep,
+        out,
+    );
+    push_objects(ns.permission_sets, ObjectType::PermissionSet, keep, out);
     push_objects(
         ns.permission_set_extensions,
         ObjectType::PermissionSetExtension,
+        keep,
         out,
     );
     for sub in ns.namespaces {
-        collect_objects_ns(sub, out);
+        collect_objects_ns(sub, keep, out);
     }
 }
 
-fn push_objects(objs: Vec<SymbolObject>, object_type: ObjectType, out: &mut Vec<ExternalObject>) {
+/// Drain `objs` into `out`, skipping those `keep` rejects. An extension
+/// object is also kept when `keep` accepts its target's name: its methods
+/// are called through a variable of the target type (`Cust.DepExtProc()`
+/// on a `Record Customer`), so the extension's own name never appears.
+fn push_objects(
+    objs: Vec<SymbolObject>,
+    object_type: ObjectType,
+    keep: &dyn Fn(&str, i64) -> bool,
+    out: &mut Vec<ExternalObject>,
+) {
     for obj in objs {
         // Some object kinds (tables, pages) have inline namespace nodes that
         // contain extension objects. Walk them too so nothing is dropped.
         for ns in obj.namespaces {
-            collect_objects_ns(ns, out);
+            collect_objects_ns(ns, keep, out);
+        }
+        let target_kept = obj
+            .target_object
+            .as_deref()
+            .is_some_and(|target| keep(target, obj.id));
+        if !target_kept && !keep(&obj.name, obj.id) {
+            continue;
         }
 
         let methods = obj
@@ -909,6 +1087,125 @@ mod tests {
         );
     }
 
+    #[test]
+    fn failures_surface_as_matchable_error_kinds() {
+        let dir = tempfile::tempdir().expect("tempdir");
+
+        let missing = extract_app_package(&dir.path().join("missing.app"));
+        assert!(matches!(missing, Err(Error::Io { .. })), "{missing:?}");
+
+        let not_zip = dir.path().join("not-a-zip.app");
+        std::fs::write(&not_zip, vec![0u8; 128]).expect("write junk .app");
+        let not_zip = extract_app_package(&not_zip);
+        assert!(matches!(not_zip, Err(Error::Zip { .. })), "{not_zip:?}");
+
+        let bomb = dir.path().join("bomb.app");
+        std::fs::write(
+            &bomb,
+            build_app_with_oversized_symbol_reference(
+                crate::capped_io::SYMBOL_REFERENCE_JSON_CAP as usize + 1024,
+            ),
+        )
+        .expect("write crafted .app");
+        let bomb = extract_app_package(&bomb);
+        assert!(matches!(bomb, Err(Error::Parse(_))), "{bomb:?}");
+
+        let xml = parse_manifest_xml("<Package><App");
+        assert!(matches!(xml, Err(Error::Xml { .. })), "{xml:?}");
+        let no_app = parse_manifest_xml("<Package/>");
+        assert!(matches!(no_app, Err(Error::Parse(_))), "{no_app:?}");
+    }
+
+    /// Write a `.app` whose symbol table is stored under `entry_name`
+    /// (optionally gzip-compressed), holding one codeunit `Probe CU`.
+    fn write_app_with_symbol_entry(dir: &Path, entry_name: &str, gzip: bool) -> std::path::PathBuf {
+        use std::io::Write as _;
+
+        let symbols = br#"{"Codeunits":[{"Id":50100,"Name":"Probe CU","Methods":[]}]}"#;
+        let payload = if gzip {
+            let mut enc = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            enc.write_all(symbols).unwrap();
+            enc.finish().unwrap()
+        } else {
+            symbols.to_vec()
+        };
+
+        let mut zip_buf = std::io::Cursor::new(Vec::new());
+        {
+            let mut writer = zip::ZipWriter::new(&mut zip_buf);
+            let opts = zip::write::SimpleFileOptions::default();
+            writer.start_file("NavxManifest.xml", opts).unwrap();
+            writer
+                .write_all(
+                    br#"<Package xmlns="http://schemas.microsoft.com/navx/2015/manifest"><App Id="aaaaaaaa-0000-0000-0000-000000000002" Name="CaseApp" Publisher="Test" Version="1.0.0.0" /></Package>"#,
+                )
+                .unwrap();
+            writer.start_file(entry_name, opts).unwrap();
+            writer.write_all(&payload).unwrap();
+            writer.finish().unwrap();
+        }
+        let mut bytes = vec![0u8; NAVX_HEADER_SIZE as usize];
+        bytes.extend_from_slice(&zip_buf.into_inner());
+        let path = dir.join("case.app");
+        std::fs::write(&path, &bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn inventory_lists_every_object_with_its_method_count() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_app_with_symbol_entry(dir.path(), "SymbolReference.json", false);
+        let mut package = extract_app_package(&path).expect("package must load");
+        package.objects.push(ExternalObject {
+            name: "Probe Table".to_string(),
+            object_type: ObjectType::Table,
+            id: 50100,
+            methods: vec![ExternalMethod {
+                name: "Touch".to_string(),
+                kind: ExternalMethodKind::Procedure,
+                signature: "Touch()".to_string(),
+                is_local: false,
+            }],
+        });
+
+        let inventory = package.inventory();
+        assert_eq!(
+            inventory,
+            vec![
+                InventoryEntry {
+                    object_type: "Codeunit".to_string(),
+                    id: 50100,
+                    name: "Probe CU".to_string(),
+                    methods: 0,
+                },
+                InventoryEntry {
+                    object_type: "Table".to_string(),
+                    id: 50100,
+                    name: "Probe Table".to_string(),
+                    methods: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn symbol_reference_entry_is_found_case_insensitively() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_app_with_symbol_entry(dir.path(), "src/symbolreference.JSON", false);
+        let package = extract_app_package(&path).expect("differently-cased entry must load");
+        assert_eq!(package.objects.len(), 1);
+        assert_eq!(package.objects[0].name, "Probe CU");
+    }
+
+    #[test]
+    fn gzip_compressed_symbol_reference_is_inflated() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_app_with_symbol_entry(dir.path(), "SymbolReference.json.gz", true);
+        let package = extract_app_package(&path).expect("gzip entry must load");
+        assert_eq!(package.objects.len(), 1);
+        assert_eq!(package.objects[0].id, 50100);
+    }
+
     #[test]
     fn oversized_navx_manifest_is_rejected_not_panicking() {
         use std::io::Write as _;
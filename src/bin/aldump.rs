@@ -52,7 +52,8 @@ fn usage() -> ExitCode {
          --r3a4-dep-hooks | --r3a5-cross-app-summary | --r4-findings | \
          --r4f-root-classifications | --r4f-return-summaries | --r4f-snapshot | \
          --r4f-digest-effects | --r4f-scoped-guarantees | --program-call-graph-stats | \
-         --graphify-export | --graphify-export-fragments | --integration-points] \
+         --graphify-export [--snippets] [--absolute-paths] | \
+         --graphify-export-fragments [--absolute-paths] | --integration-points] \
          <workspace-or-.app>"
     );
     ExitCode::FAILURE
@@ -67,6 +68,8 @@ fn main() -> ExitCode {
     let mut program_call_graph_stats = false;
     let mut graphify_export = false;
     let mut graphify_export_fragments = false;
+    let mut snippets = false;
+    let mut absolute_paths = false;
     let mut integration_points = false;
     let mut l3_unknown_breakdown = false;
     let mut l3_unknown_breakdown_cross_app = false;
@@ -117,6 +120,14 @@ fn main() -> ExitCode {
             graphify_export = true;
             continue;
         }
+        if arg == "--snippets" {
+            snippets = true;
+            continue;
+        }
+        if arg == "--absolute-paths" {
+            absolute_paths = true;
+            continue;
+        }
         if arg == "--graphify-export-fragments" {
             graphify_export_fragments = true;
             continue;
@@ -262,6 +273,18 @@ fn main() -> ExitCode {
         return usage();
     }
 
+    if snippets && !graphify_export {
+        eprintln!("aldump: error: --snippets only applies to --graphify-export");
+        return usage();
+    }
+    if absolute_paths && !graphify_export && !graphify_export_fragments {
+        eprintln!(
+            "aldump: error: --absolute-paths only applies to --graphify-export / \
+             --graphify-export-fragments"
+        );
+        return usage();
+    }
+
     let Some(workspace_arg) = workspace_arg else {
         return usage();
     };
@@ -1379,8 +1402,18 @@ fn main() -> ExitCode {
         // graphify node-link extraction document (`{ nodes, edges, hyperedges }`),
         // consumed by graphify's `build_from_json` (see `graphify_export.rs` +
         // `U:\Git\graphify\adapter.md`). Fail-closed → snapshot build error.
-        let Some(doc) = al_call_hierarchy::program::graphify_export::export_workspace(&workspace)
-        else {
+        // `--snippets` adds each call site's source line to its edges;
+        // `--absolute-paths` writes workspace `source_file`s as absolute paths
+        // under the workspace root instead of root-relative.
+        use al_call_hierarchy::program::graphify_export::{
+            export_workspace, export_workspace_with_snippets,
+        };
+        let doc = if snippets {
+            export_workspace_with_snippets(&workspace, absolute_paths)
+        } else {
+            export_workspace(&workspace, absolute_paths)
+        };
+        let Some(doc) = doc else {
             eprintln!("aldump: error: graphify export failed (snapshot build error)");
             return ExitCode::FAILURE;
         };
@@ -1401,9 +1434,10 @@ fn main() -> ExitCode {
         // fragments + a content-hash manifest (`{ manifest, fragments, shared }`).
         // Diff the manifest across runs → only re-process the objects whose output
         // changed (see `program::graphify_export::FragmentSet`). Fail-closed.
-        let Some(fs) =
-            al_call_hierarchy::program::graphify_export::export_workspace_fragments(&workspace)
-        else {
+        let Some(fs) = al_call_hierarchy::program::graphify_export::export_workspace_fragments(
+            &workspace,
+            absolute_paths,
+        ) else {
             eprintln!("aldump: error: graphify fragment export failed (snapshot build error)");
             return ExitCode::FAILURE;
         };
@@ -5,10 +5,15 @@
 //! 2. Global config at `~/.al-call-hierarchy/config.json`
 //! 3. Workspace config at `{workspace}/.al-call-hierarchy.json`
 
+use al_syntax::IdentifierFoldExt;
+use al_syntax::ir::ObjectKind;
 use log::{info, warn};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use crate::program::resolve::extract::record_op_names;
+
 /// All configurable diagnostic thresholds (fully resolved, no Options)
 #[derive(Debug, Clone)]
 pub struct DiagnosticConfig {
@@ -21,9 +26,110 @@ pub struct DiagnosticConfig {
     pub params_enabled: bool,
     pub params_warning: u32,
     pub params_critical: u32,
+    pub var_params_enabled: bool,
+    pub var_params_warning: u32,
+    /// Flag procedures with more than this many `Boolean` parameters (see
+    /// [`crate::analysis::boolean_param_count_ir`]).
+    pub boolean_params_enabled: bool,
+    pub boolean_params_warning: u32,
+    /// Flag `if ... else if ...` chains with at least this many branches
+    /// (see [`crate::analysis::long_if_chains_ir`]); a `case` usually reads
+    /// better.
+    pub long_if_chain_enabled: bool,
+    pub long_if_chain_warning: u32,
     pub fan_in_enabled: bool,
     pub fan_in_warning: usize,
+    /// Flag procedures calling into more than this many distinct other
+    /// objects (`--analyze`'s `high_coupling`; see
+    /// [`crate::lsp::snapshot::LspSnapshot::coupling`]).
+    pub coupling_enabled: bool,
+    pub coupling_warning: usize,
     pub unused_procedures: bool,
+    /// Report workspace variables typed as a dependency object that nothing
+    /// in their scope uses (`--analyze`'s informational
+    /// `unused_external_ref` finding; see
+    /// [`crate::lsp::snapshot::LspSnapshot::unused_external_refs`]). Off by
+    /// default.
+    pub unused_external_refs: bool,
+    /// Report groups of procedures with identical bodies (`--analyze`'s
+    /// `duplicate_code` finding). Off by default.
+    pub duplicate_code_enabled: bool,
+    /// Bodies with fewer tokens than this are never reported as duplicates.
+    pub duplicate_code_min_tokens: u32,
+    /// Treat bodies that differ only by identifier names as duplicates.
+    pub duplicate_code_normalize_identifiers: bool,
+    /// Flag statement-position `Get`/`Insert`/`Modify`/`Delete` record calls
+    /// whose Boolean result is discarded (see
+    /// [`crate::analysis::unchecked_risky_calls_ir`]). Opinionated, so off by
+    /// default.
+    pub unchecked_risky_calls: bool,
+    /// Flag return-type procedures with a path that reaches `end` without
+    /// `exit(...)` (see [`crate::analysis::falls_through_without_exit_ir`]).
+    /// Heuristic, so off by default.
+    pub missing_return: bool,
+    /// Flag procedures that call themselves before any `exit`/`Error` or
+    /// condition (see [`crate::analysis::unguarded_self_recursion_ir`]).
+    pub unbounded_recursion: bool,
+    /// Report parameters a procedure never uses (`--analyze`'s
+    /// informational `unused_parameter` finding; see
+    /// [`crate::analysis::unused_parameters_ir`]).
+    pub unused_parameters: bool,
+    /// Report object runs whose target is a runtime value (`--analyze`'s
+    /// informational `dynamic_call` finding; see
+    /// [`crate::analysis::dynamic_object_runs_ir`]).
+    pub dynamic_calls: bool,
+    /// Leave guard clauses (`if ... then exit;` without `else`) out of the
+    /// complexity score (`discountGuardClauses`; see
+    /// [`crate::analysis::guard_clause_count_ir`]).
+    pub discount_guard_clauses: bool,
+    /// LSP only: compute and publish diagnostics just for files the client
+    /// has open (`didOpen`), clearing them on `didClose`, instead of for
+    /// the whole workspace at startup.
+    pub open_files_only: bool,
+    /// LSP only: resolve an unqualified call the resolver left unresolved
+    /// to the one project procedure of that name, when exactly one exists
+    /// (`uniqueNameFallback`). Heuristic, so off by default; such outgoing
+    /// items are tagged `[ByName]`.
+    pub unique_name_fallback: bool,
+    /// Mark call sites the resolver could only narrow to several same-named
+    /// overloads (the `ambiguous-call` diagnostic, `ambiguousCalls`). The
+    /// compiler picks one by argument types this engine does not model, so
+    /// most such calls are fine; off by default.
+    pub ambiguous_calls: bool,
+    /// Leave files that declare themselves generated (see
+    /// [`Self::skips_generated`]) out of analysis and diagnostics
+    /// (`excludeGenerated`, or `--exclude-generated`).
+    pub exclude_generated: bool,
+    /// The header-comment text that marks a file as generated.
+    pub generated_marker: String,
+    /// Object kinds whose routines are left out of analysis and diagnostics
+    /// entirely (`excludeObjectTypes`, or `--exclude-object-type`).
+    pub excluded_object_types: Vec<ObjectKind>,
+    /// Report comments carrying one of [`Self::comment_markers`] as
+    /// informational `todo_comment` findings and `todo-comment` diagnostics
+    /// (`todoComments`). Off by default.
+    pub todo_comments: bool,
+    /// Comment markers reported when [`Self::todo_comments`] is on (see
+    /// [`crate::analysis::marker_comments`]); matched as whole,
+    /// case-sensitive words. Empty disables the check.
+    pub comment_markers: Vec<String>,
+    /// Procedure-name prefixes that promise a read (`readOnlyPrefixes`);
+    /// such a procedure calling `Insert`/`Modify`/`Delete` gets an
+    /// informational `unexpected_write` finding (see
+    /// [`crate::analysis::unexpected_writes_ir`]). Empty disables the check.
+    pub read_only_prefixes: Vec<String>,
+    /// Regex every procedure name must match (`namingPattern`, e.g.
+    /// `^[A-Z][A-Za-z0-9]*$` for PascalCase); a procedure that does not gets
+    /// an informational `naming_convention` finding (see
+    /// [`crate::analysis::naming_convention_finding`]). `None` (the default)
+    /// disables the check.
+    pub naming_pattern: Option<regex::Regex>,
+    /// `source` string stamped on every published diagnostic (editors group
+    /// diagnostics by it).
+    pub source: String,
+    /// Prefix prepended to every diagnostic code (`""` by default, so codes
+    /// stay `unused-procedure`, `high-complexity`, ...).
+    pub code_prefix: String,
 }
 
 impl Default for DiagnosticConfig {
@@ -38,9 +144,39 @@ impl Default for DiagnosticConfig {
             params_enabled: true,
             params_warning: 4,
             params_critical: 7,
+            var_params_enabled: true,
+            var_params_warning: 3,
+            boolean_params_enabled: true,
+            boolean_params_warning: 2,
+            long_if_chain_enabled: true,
+            long_if_chain_warning: 6,
             fan_in_enabled: true,
             fan_in_warning: 20,
+            coupling_enabled: true,
+            coupling_warning: 10,
             unused_procedures: true,
+            unused_external_refs: false,
+            duplicate_code_enabled: false,
+            duplicate_code_min_tokens: 30,
+            duplicate_code_normalize_identifiers: false,
+            unchecked_risky_calls: false,
+            missing_return: false,
+            unbounded_recursion: true,
+            unused_parameters: true,
+            dynamic_calls: true,
+            discount_guard_clauses: false,
+            open_files_only: false,
+            unique_name_fallback: false,
+            ambiguous_calls: false,
+            exclude_generated: false,
+            generated_marker: "<auto-generated>".to_string(),
+            excluded_object_types: Vec::new(),
+            todo_comments: false,
+            comment_markers: ["TODO", "FIXME", "HACK"].map(String::from).to_vec(),
+            read_only_prefixes: ["Get", "Find", "Calc"].map(String::from).to_vec(),
+            naming_pattern: None,
+            source: "al-call-hierarchy".to_string(),
+            code_prefix: String::new(),
         }
     }
 }
@@ -58,8 +194,32 @@ struct DiagnosticsSection {
     complexity: Option<ThresholdPair>,
     parameters: Option<ThresholdPair>,
     line_count: Option<ThresholdPair>,
+    var_parameters: Option<ThresholdSingle>,
+    boolean_parameters: Option<ThresholdSingle>,
+    long_if_chain: Option<ThresholdSingle>,
     fan_in: Option<ThresholdSingle>,
+    coupling: Option<ThresholdSingle>,
     unused_procedures: Option<bool>,
+    unused_external_refs: Option<bool>,
+    duplicate_code: Option<DuplicateCodeSection>,
+    unchecked_risky_calls: Option<bool>,
+    missing_return: Option<bool>,
+    unbounded_recursion: Option<bool>,
+    unused_parameters: Option<bool>,
+    dynamic_calls: Option<bool>,
+    discount_guard_clauses: Option<bool>,
+    open_files_only: Option<bool>,
+    unique_name_fallback: Option<bool>,
+    ambiguous_calls: Option<bool>,
+    exclude_generated: Option<bool>,
+    generated_marker: Option<String>,
+    exclude_object_types: Option<Vec<String>>,
+    todo_comments: Option<bool>,
+    comment_markers: Option<Vec<String>>,
+    read_only_prefixes: Option<Vec<String>>,
+    naming_pattern: Option<String>,
+    source: Option<String>,
+    code_prefix: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -75,6 +235,14 @@ struct ThresholdSingle {
     warning: Option<u32>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DuplicateCodeSection {
+    enabled: Option<bool>,
+    min_tokens: Option<u32>,
+    normalize_identifiers: Option<bool>,
+}
+
 /// Returns the global config path: `~/.al-call-hierarchy/config.json`
 fn global_config_path() -> Option<PathBuf> {
     dirs::home_dir().map(|home| home.join(".al-call-hierarchy").join("config.json"))
@@ -111,8 +279,37 @@ fn merge_sections(base: DiagnosticsSection, overlay: DiagnosticsSection) -> Diag
         complexity: merge_threshold_pair(base.complexity, overlay.complexity),
         parameters: merge_threshold_pair(base.parameters, overlay.parameters),
         line_count: merge_threshold_pair(base.line_count, overlay.line_count),
+        var_parameters: merge_threshold_single(base.var_parameters, overlay.var_parameters),
+        boolean_parameters: merge_threshold_single(
+            base.boolean_parameters,
+            overlay.boolean_parameters,
+        ),
+        long_if_chain: merge_threshold_single(base.long_if_chain, overlay.long_if_chain),
         fan_in: merge_threshold_single(base.fan_in, overlay.fan_in),
+        coupling: merge_threshold_single(base.coupling, overlay.coupling),
         unused_procedures: overlay.unused_procedures.or(base.unused_procedures),
+        unused_external_refs: overlay.unused_external_refs.or(base.unused_external_refs),
+        duplicate_code: merge_duplicate_code(base.duplicate_code, overlay.duplicate_code),
+        unchecked_risky_calls: overlay.unchecked_risky_calls.or(base.unchecked_risky_calls),
+        missing_return: overlay.missing_return.or(base.missing_return),
+        unbounded_recursion: overlay.unbounded_recursion.or(base.unbounded_recursion),
+        unused_parameters: overlay.unused_parameters.or(base.unused_parameters),
+        dynamic_calls: overlay.dynamic_calls.or(base.dynamic_calls),
+        discount_guard_clauses: overlay
+            .discount_guard_clauses
+            .or(base.discount_guard_clauses),
+        open_files_only: overlay.open_files_only.or(base.open_files_only),
+        unique_name_fallback: overlay.unique_name_fallback.or(base.unique_name_fallback),
+        ambiguous_calls: overlay.ambiguous_calls.or(base.ambiguous_calls),
+        exclude_generated: overlay.exclude_generated.or(base.exclude_generated),
+        generated_marker: overlay.generated_marker.or(base.generated_marker),
+        exclude_object_types: overlay.exclude_object_types.or(base.exclude_object_types),
+        todo_comments: overlay.todo_comments.or(base.todo_comments),
+        comment_markers: overlay.comment_markers.or(base.comment_markers),
+        read_only_prefixes: overlay.read_only_prefixes.or(base.read_only_prefixes),
+        naming_pattern: overlay.naming_pattern.or(base.naming_pattern),
+        source: overlay.source.or(base.source),
+        code_prefix: overlay.code_prefix.or(base.code_prefix),
     }
 }
 
@@ -147,6 +344,22 @@ fn merge_threshold_single(
     }
 }
 
+fn merge_duplicate_code(
+    base: Option<DuplicateCodeSection>,
+    overlay: Option<DuplicateCodeSection>,
+) -> Option<DuplicateCodeSection> {
+    match (base, overlay) {
+        (None, None) => None,
+        (Some(b), None) => Some(b),
+        (None, Some(o)) => Some(o),
+        (Some(b), Some(o)) => Some(DuplicateCodeSection {
+            enabled: o.enabled.or(b.enabled),
+            min_tokens: o.min_tokens.or(b.min_tokens),
+            normalize_identifiers: o.normalize_identifiers.or(b.normalize_identifiers),
+        }),
+    }
+}
+
 /// Apply defaults to a merged DiagnosticsSection, producing the final config.
 fn apply_defaults(section: DiagnosticsSection) -> DiagnosticConfig {
     let defaults = DiagnosticConfig::default();
@@ -197,6 +410,36 @@ fn apply_defaults(section: DiagnosticsSection) -> DiagnosticConfig {
             .as_ref()
             .and_then(|c| c.critical)
             .unwrap_or(defaults.params_critical),
+        var_params_enabled: section
+            .var_parameters
+            .as_ref()
+            .and_then(|c| c.enabled)
+            .unwrap_or(defaults.var_params_enabled),
+        var_params_warning: section
+            .var_parameters
+            .as_ref()
+            .and_then(|c| c.warning)
+            .unwrap_or(defaults.var_params_warning),
+        boolean_params_enabled: section
+            .boolean_parameters
+            .as_ref()
+            .and_then(|c| c.enabled)
+            .unwrap_or(defaults.boolean_params_enabled),
+        boolean_params_warning: section
+            .boolean_parameters
+            .as_ref()
+            .and_then(|c| c.warning)
+            .unwrap_or(defaults.boolean_params_warning),
+        long_if_chain_enabled: section
+            .long_if_chain
+            .as_ref()
+            .and_then(|c| c.enabled)
+            .unwrap_or(defaults.long_if_chain_enabled),
+        long_if_chain_warning: section
+            .long_if_chain
+            .as_ref()
+            .and_then(|c| c.warning)
+            .unwrap_or(defaults.long_if_chain_warning),
         fan_in_enabled: section
             .fan_in
             .as_ref()
@@ -208,26 +451,159 @@ fn apply_defaults(section: DiagnosticsSection) -> DiagnosticConfig {
             .and_then(|c| c.warning)
             .map(|v| v as usize)
             .unwrap_or(defaults.fan_in_warning),
+        coupling_enabled: section
+            .coupling
+            .as_ref()
+            .and_then(|c| c.enabled)
+            .unwrap_or(defaults.coupling_enabled),
+        coupling_warning: section
+            .coupling
+            .as_ref()
+            .and_then(|c| c.warning)
+            .map(|v| v as usize)
+            .unwrap_or(defaults.coupling_warning),
         unused_procedures: section
             .unused_procedures
             .unwrap_or(defaults.unused_procedures),
+        unused_external_refs: section
+            .unused_external_refs
+            .unwrap_or(defaults.unused_external_refs),
+        duplicate_code_enabled: section
+            .duplicate_code
+            .as_ref()
+            .and_then(|c| c.enabled)
+            .unwrap_or(defaults.duplicate_code_enabled),
+        duplicate_code_min_tokens: section
+            .duplicate_code
+            .as_ref()
+            .and_then(|c| c.min_tokens)
+            .unwrap_or(defaults.duplicate_code_min_tokens),
+        duplicate_code_normalize_identifiers: section
+            .duplicate_code
+            .as_ref()
+            .and_then(|c| c.normalize_identifiers)
+            .unwrap_or(defaults.duplicate_code_normalize_identifiers),
+        unchecked_risky_calls: section
+            .unchecked_risky_calls
+            .unwrap_or(defaults.unchecked_risky_calls),
+        missing_return: section.missing_return.unwrap_or(defaults.missing_return),
+        unbounded_recursion: section
+            .unbounded_recursion
+            .unwrap_or(defaults.unbounded_recursion),
+        unused_parameters: section
+            .unused_parameters
+            .unwrap_or(defaults.unused_parameters),
+        dynamic_calls: section.dynamic_calls.unwrap_or(defaults.dynamic_calls),
+        discount_guard_clauses: section
+            .discount_guard_clauses
+            .unwrap_or(defaults.discount_guard_clauses),
+        open_files_only: section.open_files_only.unwrap_or(defaults.open_files_only),
+        unique_name_fallback: section
+            .unique_name_fallback
+            .unwrap_or(defaults.unique_name_fallback),
+        ambiguous_calls: section.ambiguous_calls.unwrap_or(defaults.ambiguous_calls),
+        exclude_generated: section
+            .exclude_generated
+            .unwrap_or(defaults.exclude_generated),
+        generated_marker: section
+            .generated_marker
+            .unwrap_or(defaults.generated_marker),
+        excluded_object_types: match section.exclude_object_types {
+            Some(names) => names
+                .iter()
+                .filter_map(|name| {
+                    let kind = parse_object_type(name);
+                    if kind.is_none() {
+                        warn!("Ignoring unknown object type in excludeObjectTypes: {name}");
+                    }
+                    kind
+                })
+                .collect(),
+            None => defaults.excluded_object_types,
+        },
+        todo_comments: section.todo_comments.unwrap_or(defaults.todo_comments),
+        comment_markers: section.comment_markers.unwrap_or(defaults.comment_markers),
+        read_only_prefixes: section
+            .read_only_prefixes
+            .unwrap_or(defaults.read_only_prefixes),
+        naming_pattern: match section.naming_pattern.as_deref().map(str::trim) {
+            Some("") => None,
+            Some(pattern) => match regex::Regex::new(pattern) {
+                Ok(re) => Some(re),
+                Err(e) => {
+                    warn!("Ignoring invalid namingPattern {pattern:?}: {e}");
+                    None
+                }
+            },
+            None => defaults.naming_pattern,
+        },
+        source: section.source.unwrap_or(defaults.source),
+        code_prefix: section.code_prefix.unwrap_or(defaults.code_prefix),
     }
 }
 
+/// An AL object type keyword (`report`, `XmlPort`, `tableextension`, ...;
+/// case-insensitive) as an [`ObjectKind`]. `None` for anything else.
+pub fn parse_object_type(name: &str) -> Option<ObjectKind> {
+    Some(match name.trim().to_ascii_lowercase().as_str() {
+        "codeunit" => ObjectKind::Codeunit,
+        "table" => ObjectKind::Table,
+        "tableextension" => ObjectKind::TableExtension,
+        "page" => ObjectKind::Page,
+        "pageextension" => ObjectKind::PageExtension,
+        "report" => ObjectKind::Report,
+        "reportextension" => ObjectKind::ReportExtension,
+        "query" => ObjectKind::Query,
+        "xmlport" => ObjectKind::XmlPort,
+        "enum" => ObjectKind::Enum,
+        "enumextension" => ObjectKind::EnumExtension,
+        "interface" => ObjectKind::Interface,
+        "controladdin" => ObjectKind::ControlAddIn,
+        "entitlement" => ObjectKind::Entitlement,
+        "permissionset" => ObjectKind::PermissionSet,
+        "permissionsetextension" => ObjectKind::PermissionSetExtension,
+        "profile" => ObjectKind::Profile,
+        _ => return None,
+    })
+}
+
 impl DiagnosticConfig {
+    /// Whether routines of `kind` are excluded from analysis and diagnostics.
+    pub fn excludes(&self, kind: ObjectKind) -> bool {
+        self.excluded_object_types.contains(&kind)
+    }
+
+    /// Whether the file with this `source` is left out as generated:
+    /// `exclude_generated` is on and its header carries `generated_marker`
+    /// (see [`crate::analysis::is_generated_source`]).
+    pub fn skips_generated(&self, source: &str) -> bool {
+        self.exclude_generated
+            && crate::analysis::is_generated_source(source, &self.generated_marker)
+    }
+
     /// Load config by merging: defaults → global → workspace.
     pub fn load(workspace_root: &Path) -> Self {
-        // Phase 1: Load both config files
-        let global = global_config_path().and_then(|p| load_file(&p));
-        let workspace = load_file(&workspace_root.join(".al-call-hierarchy.json"));
-
-        // Phase 2: Merge sections (global as base, workspace as overlay)
-        let merged = match (global, workspace) {
-            (None, None) => DiagnosticsSection::default(),
-            (Some(g), None) => g.diagnostics,
-            (None, Some(w)) => w.diagnostics,
-            (Some(g), Some(w)) => merge_sections(g.diagnostics, w.diagnostics),
-        };
+        Self::load_with_file(workspace_root, None)
+    }
+
+    /// Like [`Self::load`], with an explicit config file (`--config <path>`)
+    /// layered over the workspace file: defaults → global → workspace →
+    /// `extra`.
+    pub fn load_with_file(workspace_root: &Path, extra: Option<&Path>) -> Self {
+        // Phase 1: Load every config file that exists
+        let files = [
+            global_config_path().and_then(|p| load_file(&p)),
+            load_file(&workspace_root.join(".al-call-hierarchy.json")),
+            extra.and_then(load_file),
+        ];
+
+        // Phase 2: Merge sections (earlier files as base, later as overlay)
+        let merged = files
+            .into_iter()
+            .flatten()
+            .map(|f| f.diagnostics)
+            .reduce(merge_sections)
+            .unwrap_or_default();
 
         // Phase 3: Apply defaults
         apply_defaults(merged)
@@ -296,6 +672,219 @@ impl TelemetryFileConfig {
     }
 }
 
+/// Watcher section of the config files (`"watcher": { "pollIntervalMs": 500,
+/// "debounceMs": 250 }`). An absent or zero interval keeps the OS-native
+/// event backend; an absent debounce keeps the default window.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WatcherFileConfig {
+    pub poll_interval_ms: Option<u64>,
+    pub debounce_ms: Option<u64>,
+}
+
+impl WatcherFileConfig {
+    /// Load from a config file path. Returns an empty config if missing/invalid.
+    pub fn load_at(path: &Path) -> Self {
+        #[derive(Deserialize, Default)]
+        struct Wrapper {
+            #[serde(default)]
+            watcher: WatcherFileConfig,
+        }
+
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return Self::default();
+        };
+        serde_json::from_str::<Wrapper>(&contents)
+            .map(|w| w.watcher)
+            .unwrap_or_default()
+    }
+
+    /// Merge global + workspace files. Workspace overlays global per-field.
+    pub fn load_merged(workspace_root: &Path) -> Self {
+        let global = global_config_path()
+            .map(|p| Self::load_at(&p))
+            .unwrap_or_default();
+        let workspace = Self::load_at(&workspace_root.join(".al-call-hierarchy.json"));
+        Self {
+            poll_interval_ms: workspace.poll_interval_ms.or(global.poll_interval_ms),
+            debounce_ms: workspace.debounce_ms.or(global.debounce_ms),
+        }
+    }
+}
+
+/// Object-name resolution settings (`resolution` section of the config
+/// file).
+///
+/// `fuzzyObjectNames` lets a qualified call whose object name has no exact
+/// match fall back to the unique object whose normalized name (case and
+/// punctuation ignored) matches. Off unless a config file enables it.
+///
+/// `triggerOps` maps further record methods to the trigger they run, on top
+/// of the built-in `Insert`/`Modify`/`Delete`/`Rename`/`Validate` mapping:
+/// `{ "CalcFields": "OnValidate" }` links `Rec.CalcFields(Amount)` to the
+/// `Amount` field's `OnValidate`.
+///
+/// `lazyDependencies` loads only the `.alpackages` objects the workspace
+/// source refers to, by name or number (see
+/// [`crate::dependencies::ReferencedObjects`]). Off by default.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ResolutionFileConfig {
+    pub fuzzy_object_names: Option<bool>,
+    pub trigger_ops: HashMap<String, String>,
+    pub lazy_dependencies: Option<bool>,
+}
+
+impl ResolutionFileConfig {
+    /// Load from a config file path. Returns an empty config if missing/invalid.
+    pub fn load_at(path: &Path) -> Self {
+        #[derive(Deserialize, Default)]
+        struct Wrapper {
+            #[serde(default)]
+            resolution: ResolutionFileConfig,
+        }
+
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return Self::default();
+        };
+        serde_json::from_str::<Wrapper>(&contents)
+            .map(|w| w.resolution)
+            .unwrap_or_default()
+    }
+
+    /// Merge global + workspace files. Workspace overlays global per-field.
+    pub fn load_merged(workspace_root: &Path) -> Self {
+        let global = global_config_path()
+            .map(|p| Self::load_at(&p))
+            .unwrap_or_default();
+        let workspace = Self::load_at(&workspace_root.join(".al-call-hierarchy.json"));
+        let mut trigger_ops = global.trigger_ops;
+        trigger_ops.extend(workspace.trigger_ops);
+        Self {
+            fuzzy_object_names: workspace.fuzzy_object_names.or(global.fuzzy_object_names),
+            trigger_ops,
+            lazy_dependencies: workspace.lazy_dependencies.or(global.lazy_dependencies),
+        }
+    }
+
+    /// Whether dependency packages are filtered to referenced objects.
+    pub fn lazy_dependencies(&self) -> bool {
+        self.lazy_dependencies.unwrap_or(false)
+    }
+
+    /// Whether the normalized object-name fallback is enabled.
+    pub fn fuzzy_object_names(&self) -> bool {
+        self.fuzzy_object_names.unwrap_or(false)
+    }
+
+    /// The configured `triggerOps`, with method and trigger names
+    /// case-folded. A method that is not a record operation
+    /// ([`record_op_names`]) could never match a call, so it is dropped with
+    /// a warning.
+    pub fn trigger_ops(&self) -> HashMap<String, String> {
+        self.trigger_ops
+            .iter()
+            .filter_map(|(op, trigger)| {
+                let op_lc = op.fold_identifier();
+                if record_op_names().contains(&op_lc.as_str()) {
+                    Some((op_lc, trigger.fold_identifier()))
+                } else {
+                    warn!("Ignoring triggerOps entry {op:?}: not a record method");
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// `workspace/symbol` settings (`workspaceSymbol` section of the config
+/// files).
+///
+/// `maxResults` caps how many symbols one query returns; the best-ranked
+/// matches are kept (see [`crate::lsp::symbols::workspace_symbols`]).
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WorkspaceSymbolFileConfig {
+    pub max_results: Option<usize>,
+}
+
+impl WorkspaceSymbolFileConfig {
+    /// Result cap used when no config file sets `maxResults`.
+    pub const DEFAULT_MAX_RESULTS: usize = 100;
+
+    /// Load from a config file path. Returns an empty config if missing/invalid.
+    pub fn load_at(path: &Path) -> Self {
+        #[derive(Deserialize, Default)]
+        #[serde(rename_all = "camelCase")]
+        struct Wrapper {
+            #[serde(default)]
+            workspace_symbol: WorkspaceSymbolFileConfig,
+        }
+
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return Self::default();
+        };
+        serde_json::from_str::<Wrapper>(&contents)
+            .map(|w| w.workspace_symbol)
+            .unwrap_or_default()
+    }
+
+    /// Merge global + workspace files. Workspace overlays global per-field.
+    pub fn load_merged(workspace_root: &Path) -> Self {
+        let global = global_config_path()
+            .map(|p| Self::load_at(&p))
+            .unwrap_or_default();
+        let workspace = Self::load_at(&workspace_root.join(".al-call-hierarchy.json"));
+        Self {
+            max_results: workspace.max_results.or(global.max_results),
+        }
+    }
+
+    /// The effective result cap (at least 1).
+    pub fn max_results(&self) -> usize {
+        self.max_results.unwrap_or(Self::DEFAULT_MAX_RESULTS).max(1)
+    }
+}
+
+/// CLI output settings (`cli` section of a `--config <path>` file). Each
+/// field is the file-side default for the flag of the same name; a flag
+/// given on the command line wins. Thresholds and exclusions live in the
+/// file's `diagnostics` section instead. Any other `cli` key is rejected,
+/// so a setting the tool does not have (ignore globs, extra extensions,
+/// base stubs, dependency skipping) fails loudly rather than being ignored.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct CliFileConfig {
+    /// `text`, `json`, `csv` or `html` (as accepted by `--format`).
+    pub format: Option<String>,
+    pub relative_paths: Option<bool>,
+    pub summary_only: Option<bool>,
+}
+
+impl CliFileConfig {
+    /// Load an explicit `--config` file's `cli` section. Unlike the
+    /// auto-discovered global and workspace files, which fall back to
+    /// defaults when unreadable or invalid, a file the user named must read
+    /// and parse — its `diagnostics` section included, which
+    /// [`DiagnosticConfig::load_with_file`] would otherwise skip with only a
+    /// warning. `Err` carries the read or parse error.
+    pub fn load_at(path: &Path) -> std::result::Result<Self, String> {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            #[serde(default)]
+            cli: CliFileConfig,
+            #[serde(default)]
+            #[allow(dead_code)] // parsed only to validate it
+            diagnostics: DiagnosticsSection,
+        }
+
+        let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        serde_json::from_str::<Wrapper>(&contents)
+            .map(|w| w.cli)
+            .map_err(|e| e.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,9 +898,37 @@ mod tests {
         assert_eq!(config.complexity_critical, 10);
         assert_eq!(config.params_warning, 4);
         assert_eq!(config.params_critical, 7);
+        assert_eq!(config.var_params_warning, 3);
+        assert!(config.boolean_params_enabled);
+        assert_eq!(config.boolean_params_warning, 2);
+        assert!(config.long_if_chain_enabled);
+        assert_eq!(config.long_if_chain_warning, 6);
         assert_eq!(config.length_critical, 50);
         assert_eq!(config.fan_in_warning, 20);
+        assert!(config.coupling_enabled);
+        assert_eq!(config.coupling_warning, 10);
         assert!(config.unused_procedures);
+        assert!(!config.unused_external_refs);
+        assert!(!config.unchecked_risky_calls);
+        assert!(!config.missing_return);
+        assert!(config.unbounded_recursion);
+        assert!(config.unused_parameters);
+        assert!(config.dynamic_calls);
+        assert!(!config.discount_guard_clauses);
+        assert!(!config.open_files_only);
+        assert!(!config.unique_name_fallback);
+        assert!(!config.ambiguous_calls);
+        assert!(!config.exclude_generated);
+        assert_eq!(config.generated_marker, "<auto-generated>");
+        assert!(config.excluded_object_types.is_empty());
+        assert!(!config.todo_comments);
+        assert!(!config.duplicate_code_enabled);
+        assert_eq!(config.duplicate_code_min_tokens, 30);
+        assert_eq!(config.comment_markers, vec!["TODO", "FIXME", "HACK"]);
+        assert_eq!(config.read_only_prefixes, vec!["Get", "Find", "Calc"]);
+        assert!(config.naming_pattern.is_none());
+        assert_eq!(config.source, "al-call-hierarchy");
+        assert_eq!(config.code_prefix, "");
     }
 
     #[test]
@@ -345,8 +962,32 @@ mod tests {
                     "complexity": { "warning": 8, "critical": 15 },
                     "parameters": { "warning": 5, "critical": 10 },
                     "lineCount": { "warning": 30, "critical": 80 },
+                    "varParameters": { "warning": 5 },
+                    "booleanParameters": { "warning": 3 },
+                    "longIfChain": { "warning": 4 },
                     "fanIn": { "warning": 30 },
-                    "unusedProcedures": false
+                    "coupling": { "warning": 15 },
+                    "unusedProcedures": false,
+                    "unusedExternalRefs": true,
+                    "duplicateCode": { "minTokens": 50, "normalizeIdentifiers": true },
+                    "uncheckedRiskyCalls": true,
+                    "missingReturn": true,
+                    "unboundedRecursion": false,
+                    "unusedParameters": false,
+                    "dynamicCalls": false,
+                    "discountGuardClauses": true,
+                    "openFilesOnly": true,
+                    "uniqueNameFallback": true,
+                    "ambiguousCalls": true,
+                    "excludeGenerated": true,
+                    "generatedMarker": "@generated",
+                    "excludeObjectTypes": ["Report", "xmlport", "bogus"],
+                    "todoComments": true,
+                    "commentMarkers": ["TODO", "XXX"],
+                    "readOnlyPrefixes": ["Lookup"],
+                    "namingPattern": "^[A-Z][A-Za-z0-9]*$",
+                    "source": "ach",
+                    "codePrefix": "ACH-"
                 }
             }"#,
         )
@@ -358,8 +999,40 @@ mod tests {
         assert_eq!(config.params_critical, 10);
         assert_eq!(config.length_warning, 30);
         assert_eq!(config.length_critical, 80);
+        assert_eq!(config.var_params_warning, 5);
+        assert_eq!(config.boolean_params_warning, 3);
+        assert_eq!(config.long_if_chain_warning, 4);
         assert_eq!(config.fan_in_warning, 30);
+        assert_eq!(config.coupling_warning, 15);
         assert!(!config.unused_procedures);
+        assert!(config.unused_external_refs);
+        assert!(config.unchecked_risky_calls);
+        assert!(config.missing_return);
+        assert!(!config.unbounded_recursion);
+        assert!(!config.unused_parameters);
+        assert!(!config.dynamic_calls);
+        assert!(config.discount_guard_clauses);
+        assert!(config.open_files_only);
+        assert!(config.unique_name_fallback);
+        assert!(config.ambiguous_calls);
+        assert!(config.exclude_generated);
+        assert_eq!(config.generated_marker, "@generated");
+        assert_eq!(
+            config.excluded_object_types,
+            vec![ObjectKind::Report, ObjectKind::XmlPort]
+        );
+        assert!(!config.duplicate_code_enabled); // default preserved
+        assert_eq!(config.duplicate_code_min_tokens, 50);
+        assert!(config.duplicate_code_normalize_identifiers);
+        assert!(config.todo_comments);
+        assert_eq!(config.comment_markers, vec!["TODO", "XXX"]);
+        assert_eq!(config.read_only_prefixes, vec!["Lookup"]);
+        assert_eq!(
+            config.naming_pattern.as_ref().map(regex::Regex::as_str),
+            Some("^[A-Z][A-Za-z0-9]*$")
+        );
+        assert_eq!(config.source, "ach");
+        assert_eq!(config.code_prefix, "ACH-");
     }
 
     #[test]
@@ -411,8 +1084,32 @@ mod tests {
                 critical: None,
             }),
             line_count: None,
+            var_parameters: None,
+            boolean_parameters: None,
+            long_if_chain: None,
             fan_in: None,
+            coupling: None,
             unused_procedures: Some(false),
+            unused_external_refs: None,
+            duplicate_code: None,
+            unchecked_risky_calls: None,
+            missing_return: None,
+            unbounded_recursion: None,
+            unused_parameters: None,
+            dynamic_calls: None,
+            discount_guard_clauses: None,
+            open_files_only: None,
+            unique_name_fallback: None,
+            ambiguous_calls: None,
+            exclude_generated: None,
+            generated_marker: None,
+            exclude_object_types: None,
+            todo_comments: None,
+            comment_markers: Some(vec!["TODO".to_string()]),
+            read_only_prefixes: None,
+            naming_pattern: None,
+            source: Some("global".to_string()),
+            code_prefix: Some("ACH".to_string()),
         };
         let workspace = DiagnosticsSection {
             complexity: Some(ThresholdPair {
@@ -422,8 +1119,32 @@ mod tests {
             }),
             parameters: None,
             line_count: None,
+            var_parameters: None,
+            boolean_parameters: None,
+            long_if_chain: None,
             fan_in: None,
+            coupling: None,
             unused_procedures: Some(true),
+            unused_external_refs: None,
+            duplicate_code: None,
+            unchecked_risky_calls: Some(true),
+            missing_return: None,
+            unbounded_recursion: None,
+            unused_parameters: None,
+            dynamic_calls: None,
+            discount_guard_clauses: None,
+            open_files_only: None,
+            unique_name_fallback: None,
+            ambiguous_calls: None,
+            exclude_generated: None,
+            generated_marker: None,
+            exclude_object_types: None,
+            todo_comments: None,
+            comment_markers: None,
+            read_only_prefixes: None,
+            naming_pattern: None,
+            source: Some("workspace".to_string()),
+            code_prefix: None,
         };
 
         let merged = merge_sections(global, workspace);
@@ -444,6 +1165,13 @@ mod tests {
         // lineCount: all defaults
         assert_eq!(config.length_warning, 20);
         assert_eq!(config.length_critical, 50);
+        // uncheckedRiskyCalls: workspace opt-in over an unset global
+        assert!(config.unchecked_risky_calls);
+        // commentMarkers: global list kept (workspace didn't set it)
+        assert_eq!(config.comment_markers, vec!["TODO".to_string()]);
+        // source: workspace overrides; codePrefix: global kept
+        assert_eq!(config.source, "workspace");
+        assert_eq!(config.code_prefix, "ACH");
     }
 
     #[test]
@@ -501,4 +1229,110 @@ mod tests {
         assert!(tcfg.enabled.is_none());
         assert!(tcfg.connection_string.is_none());
     }
+
+    #[test]
+    fn test_load_resolution_section() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(".al-call-hierarchy.json");
+        fs::write(&path, r#"{ "resolution": { "fuzzyObjectNames": true } }"#).unwrap();
+        assert!(ResolutionFileConfig::load_at(&path).fuzzy_object_names());
+
+        fs::write(&path, r#"{ "diagnostics": {} }"#).unwrap();
+        assert!(!ResolutionFileConfig::load_at(&path).fuzzy_object_names());
+
+        fs::write(
+            &path,
+            r#"{ "resolution": { "triggerOps": { "CalcFields": "OnValidate" } } }"#,
+        )
+        .unwrap();
+        let ops = ResolutionFileConfig::load_at(&path).trigger_ops();
+        assert_eq!(
+            ops.get("calcfields").map(String::as_str),
+            Some("onvalidate")
+        );
+
+        fs::write(
+            &path,
+            r#"{ "resolution": { "triggerOps": { "CalcFeilds": "OnValidate", "TestField": "OnValidate" } } }"#,
+        )
+        .unwrap();
+        let ops = ResolutionFileConfig::load_at(&path).trigger_ops();
+        assert_eq!(
+            ops.keys().map(String::as_str).collect::<Vec<_>>(),
+            ["testfield"],
+            "a misspelled record method is dropped"
+        );
+    }
+
+    #[test]
+    fn test_load_workspace_symbol_section() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(".al-call-hierarchy.json");
+        fs::write(&path, r#"{ "workspaceSymbol": { "maxResults": 25 } }"#).unwrap();
+        assert_eq!(WorkspaceSymbolFileConfig::load_at(&path).max_results(), 25);
+
+        fs::write(&path, r#"{ "workspaceSymbol": { "maxResults": 0 } }"#).unwrap();
+        assert_eq!(WorkspaceSymbolFileConfig::load_at(&path).max_results(), 1);
+
+        fs::write(&path, r#"{ "diagnostics": {} }"#).unwrap();
+        assert_eq!(
+            WorkspaceSymbolFileConfig::load_at(&path).max_results(),
+            WorkspaceSymbolFileConfig::DEFAULT_MAX_RESULTS
+        );
+    }
+
+    #[test]
+    fn test_explicit_config_file_overlays_workspace() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join(".al-call-hierarchy.json"),
+            r#"{ "diagnostics": { "complexity": { "warning": 8, "critical": 12 } } }"#,
+        )
+        .unwrap();
+        let extra = dir.path().join("ci.json");
+        fs::write(
+            &extra,
+            r#"{
+                "cli": { "format": "json", "summaryOnly": true },
+                "diagnostics": { "complexity": { "warning": 3 } }
+            }"#,
+        )
+        .unwrap();
+
+        let config = DiagnosticConfig::load_with_file(dir.path(), Some(&extra));
+        assert_eq!(config.complexity_warning, 3);
+        assert_eq!(config.complexity_critical, 12); // workspace value preserved
+
+        let cli = CliFileConfig::load_at(&extra).expect("valid file");
+        assert_eq!(cli.format.as_deref(), Some("json"));
+        assert_eq!(cli.summary_only, Some(true));
+        assert_eq!(cli.relative_paths, None);
+    }
+
+    #[test]
+    fn test_explicit_config_file_must_parse() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("ci.json");
+
+        fs::write(&path, r#"{ "cli": { "format": "json" "#).unwrap();
+        assert!(CliFileConfig::load_at(&path).is_err(), "truncated JSON");
+
+        fs::write(
+            &path,
+            r#"{ "diagnostics": { "complexity": { "warning": "high" } } }"#,
+        )
+        .unwrap();
+        assert!(
+            CliFileConfig::load_at(&path).is_err(),
+            "a mistyped diagnostics value is an error too"
+        );
+
+        fs::write(&path, r#"{ "cli": { "ignoreGlobs": ["*.Test.al"] } }"#).unwrap();
+        assert!(
+            CliFileConfig::load_at(&path).is_err(),
+            "an unsupported cli key is an error, not silently ignored"
+        );
+
+        assert!(CliFileConfig::load_at(&dir.path().join("missing.json")).is_err());
+    }
 }
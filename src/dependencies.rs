@@ -3,8 +3,8 @@
 //! Parses app.json to discover dependencies and locates matching .app files
 //! in the .alpackages folder.
 
-use crate::app_package::{ParsedAppPackage, extract_app_package};
-use anyhow::{Context, Result};
+use crate::app_package::{ParsedAppPackage, extract_app_package_filtered};
+use anyhow::Result;
 use log::{debug, info, warn};
 use serde::Deserialize;
 use std::path::{Path, PathBuf};
@@ -176,12 +176,18 @@ fn group_discovered_by_guid(deps: Vec<DiscoveredApp>) -> Vec<Vec<DiscoveredApp>>
 }
 
 /// Parse app.json to extract dependencies
-pub fn parse_app_json(path: &Path) -> Result<Vec<AppDependency>> {
-    let content = std::fs::read_to_string(path)
-        .with_context(|| format!("Failed to read {}", path.display()))?;
+pub fn parse_app_json(path: &Path) -> crate::error::Result<Vec<AppDependency>> {
+    use crate::error::Error;
 
-    let app_json: AppJson = serde_json::from_str(&content)
-        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    let content = std::fs::read_to_string(path).map_err(|source| Error::Io {
+        what: path.display().to_string(),
+        source,
+    })?;
+
+    let app_json: AppJson = serde_json::from_str(&content).map_err(|source| Error::Json {
+        what: path.display().to_string(),
+        source,
+    })?;
 
     Ok(app_json.dependencies)
 }
@@ -365,8 +371,23 @@ fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
     std::cmp::Ordering::Equal
 }
 
+/// Check that a version string is numeric-dotted (`1`, `1.2`, ... up to
+/// `1.2.3.4`): every part non-empty and all ASCII digits.
+fn is_numeric_dotted_version(version: &str) -> bool {
+    let parts: Vec<&str> = version.split('.').collect();
+    (1..=4).contains(&parts.len())
+        && parts
+            .iter()
+            .all(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+}
+
 /// Find a matching .app file for a dependency
 /// Returns the path to the best matching .app file (highest compatible version)
+///
+/// A file whose name after the `Publisher_Name_` prefix is not a
+/// numeric-dotted version (no version at all, or a different app whose name
+/// merely extends this one's — `Publisher_Name_Extra_1.0.0.0.app`) is
+/// skipped with a warning rather than mis-parsed as a version.
 pub fn find_matching_app(alpackages: &Path, dep: &AppDependency) -> Option<PathBuf> {
     let entries = match std::fs::read_dir(alpackages) {
         Ok(e) => e,
@@ -388,9 +409,18 @@ pub fn find_matching_app(alpackages: &Path, dep: &AppDependency) -> Option<PathB
             // Extract version from filename
             // Format: Publisher_Name_Version.app
             let version_part = &filename[expected_prefix.len()..];
-            if let Some(version) = version_part.strip_suffix(".app")
-                && is_version_compatible(&dep.version, version)
-            {
+            let Some(version) = version_part.strip_suffix(".app") else {
+                continue;
+            };
+            if !is_numeric_dotted_version(version) {
+                warn!(
+                    "find_matching_app: skipping {} — no numeric version after '{}'",
+                    path.display(),
+                    expected_prefix
+                );
+                continue;
+            }
+            if is_version_compatible(&dep.version, version) {
                 candidates.push((path.clone(), version.to_string()));
             }
         }
@@ -422,6 +452,17 @@ pub fn find_matching_app(alpackages: &Path, dep: &AppDependency) -> Option<PathB
 /// can ignore it, but it is never silently discarded here.
 pub fn load_all_apps(
     project_root: &Path,
+) -> Result<(Vec<ResolvedDependency>, Vec<DroppedDuplicateDependency>)> {
+    load_all_apps_filtered(project_root, &|_, _| true)
+}
+
+/// [`load_all_apps`], keeping only the package objects `keep` accepts (by
+/// name and id). The filter runs while each package's symbols are drained
+/// (see [`crate::app_package::extract_app_symbols_filtered`]), so a
+/// rejected object never has its methods built; GUID dedup is unaffected.
+pub fn load_all_apps_filtered(
+    project_root: &Path,
+    keep: &dyn Fn(&str, i64) -> bool,
 ) -> Result<(Vec<ResolvedDependency>, Vec<DroppedDuplicateDependency>)> {
     let folders = find_all_alpackages_folders(project_root);
     if folders.is_empty() {
@@ -507,7 +548,7 @@ pub fn load_all_apps(
     for group in groups {
         let mut winner_idx: Option<usize> = None;
         for (i, candidate) in group.iter().enumerate() {
-            match crate::app_package::extract_app_symbols(&candidate.app_path) {
+            match crate::app_package::extract_app_symbols_filtered(&candidate.app_path, keep) {
                 Ok(objects) => {
                     debug!(
                         "load_all_apps: loaded {} v{} ({} objects)",
@@ -598,6 +639,15 @@ pub fn load_all_apps(
 /// Returns a list of resolved dependencies with their parsed packages.
 /// Dependencies that cannot be resolved are logged as warnings and skipped.
 pub fn resolve_all(project_root: &Path) -> Result<Vec<ResolvedDependency>> {
+    resolve_all_filtered(project_root, &|_, _| true)
+}
+
+/// [`resolve_all`], keeping only the package objects `keep` accepts (by
+/// name and id) — see [`crate::app_package::extract_app_package_filtered`].
+fn resolve_all_filtered(
+    project_root: &Path,
+    keep: &dyn Fn(&str, i64) -> bool,
+) -> Result<Vec<ResolvedDependency>> {
     let app_json_path = project_root.join("app.json");
     if !app_json_path.exists() {
         debug!("No app.json found at {}", project_root.display());
@@ -636,7 +686,7 @@ pub fn resolve_all(project_root: &Path) -> Result<Vec<ResolvedDependency>> {
                     app_path.display()
                 );
 
-                match extract_app_package(&app_path) {
+                match extract_app_package_filtered(&app_path, keep) {
                     Ok(package) => {
                         info!(
                             "Loaded {} v{} ({} objects)",
@@ -682,6 +732,129 @@ pub fn resolve_all(project_root: &Path) -> Result<Vec<ResolvedDependency>> {
     Ok(resolved)
 }
 
+/// [`resolve_all`], keeping only the package objects the project refers to.
+///
+/// [`ReferencedObjects::scan`] first collects every name and object number
+/// the project's `.al` source mentions; each package is then drained
+/// through that filter, so unreferenced objects are dropped before their
+/// methods are built. A dependency set as large as the Base Application
+/// then stays resident in proportion to what the project uses, not to the
+/// size of the packages.
+pub fn index_dependencies_lazy(project_root: &Path) -> Result<Vec<ResolvedDependency>> {
+    let referenced = ReferencedObjects::scan(project_root);
+    let resolved = resolve_all_filtered(project_root, &|name, id| referenced.contains(name, id))?;
+    let kept: usize = resolved.iter().map(|rd| rd.package.objects.len()).sum();
+    info!(
+        "Indexed {kept} external objects referenced by {}",
+        project_root.display()
+    );
+    Ok(resolved)
+}
+
+/// The object names and numbers a project's `.al` source refers to — the
+/// filter behind [`index_dependencies_lazy`] and the `lazyDependencies`
+/// resolution setting.
+///
+/// Matching is lexical and case-insensitive, so it errs on the side of
+/// keeping: a name counts if it appears anywhere in the source, comments
+/// and strings included. Numbers only count where AL accepts an object
+/// number — after `::` (`Codeunit::80`), after an object-type keyword
+/// (`Record 18`), or as the first argument of `Run`/`RunModal`
+/// (`Page.RunModal(21)`) — so ordinary integer literals don't pull in
+/// low-numbered objects.
+#[derive(Debug, Default, Clone)]
+pub struct ReferencedObjects {
+    names: std::collections::HashSet<String>,
+    numbers: std::collections::HashSet<i64>,
+}
+
+impl ReferencedObjects {
+    /// Scan the `.al` files under `project_root`, skipping `.alpackages`.
+    pub fn scan(project_root: &Path) -> Self {
+        let mut refs = Self::default();
+        for entry in walkdir::WalkDir::new(project_root)
+            .into_iter()
+            .filter_entry(|e| e.file_name() != ".alpackages")
+            .filter_map(|e| e.ok())
+        {
+            let path = entry.path();
+            if !path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("al"))
+            {
+                continue;
+            }
+            if let Ok(text) = std::fs::read_to_string(path) {
+                refs.scan_source(&text);
+            }
+        }
+        refs
+    }
+
+    /// Whether an object named `name` with number `id` is referenced.
+    pub fn contains(&self, name: &str, id: i64) -> bool {
+        self.numbers.contains(&id) || self.names.contains(&name.to_lowercase())
+    }
+
+    fn scan_source(&mut self, text: &str) {
+        /// What the last significant token makes of a following number.
+        #[derive(PartialEq)]
+        enum Prev {
+            Other,
+            /// `::` or an object-type keyword: a number here is an object.
+            ObjectSlot,
+            /// `Run`/`RunModal`: an object number may follow its `(`.
+            RunWord,
+        }
+        let mut prev = Prev::Other;
+        let mut rest = text;
+        while let Some(c) = rest.chars().next() {
+            if let Some(quoted) = rest.strip_prefix('"') {
+                let end = quoted.find('"').unwrap_or(quoted.len());
+                self.names.insert(quoted[..end].to_lowercase());
+                rest = quoted.get(end + 1..).unwrap_or("");
+                prev = Prev::Other;
+            } else if c.is_alphabetic() || c == '_' {
+                let end = rest
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                let word = rest[..end].to_lowercase();
+                prev = match word.as_str() {
+                    "record" | "table" | "codeunit" | "page" | "report" | "query" | "xmlport"
+                    | "enum" | "interface" | "database" => Prev::ObjectSlot,
+                    "run" | "runmodal" => Prev::RunWord,
+                    _ => Prev::Other,
+                };
+                self.names.insert(word);
+                rest = &rest[end..];
+            } else if c.is_ascii_digit() {
+                let end = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                if prev == Prev::ObjectSlot
+                    && let Ok(n) = rest[..end].parse()
+                {
+                    self.numbers.insert(n);
+                }
+                rest = &rest[end..];
+                prev = Prev::Other;
+            } else if let Some(after) = rest.strip_prefix("::") {
+                rest = after;
+                prev = Prev::ObjectSlot;
+            } else {
+                if !c.is_whitespace() {
+                    prev = if c == '(' && prev == Prev::RunWord {
+                        Prev::ObjectSlot
+                    } else {
+                        Prev::Other
+                    };
+                }
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1171,6 +1344,123 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lazy_index_keeps_only_referenced_external_objects() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let alpackages = dir.path().join(".alpackages");
+        std::fs::create_dir_all(&alpackages).unwrap();
+        write_app_with_symbols(
+            &alpackages,
+            "Pub_DupApp_25.0.0.0.app",
+            "ffffffff-5555-5555-5555-555555555555",
+            "25.0.0.0",
+            r#"{"Codeunits":[
+                {"Id":50100,"Name":"Sales-Post","Methods":[{"Name":"Run","Id":1}]},
+                {"Id":50101,"Name":"Purch-Post","Methods":[{"Name":"Run","Id":1}]},
+                {"Id":50102,"Name":"Helper","Methods":[{"Name":"DoIt","Id":1}]},
+                {"Id":80,"Name":"Gen. Jnl.-Post","Methods":[{"Name":"Run","Id":1}]},
+                {"Id":90,"Name":"Purch.-Post (Yes/No)","Methods":[{"Name":"Run","Id":1}]}
+            ],"Tables":[
+                {"Id":18,"Name":"Customer"},
+                {"Id":27,"Name":"Item"}
+            ]}"#,
+        );
+        std::fs::write(
+            dir.path().join("app.json"),
+            r#"{"dependencies": [{"name": "DupApp", "publisher": "Pub", "version": "25.0.0.0"}]}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("Poster.al"),
+            "codeunit 50200 Poster\n{\n    procedure Post()\n    var\n        SalesPost: Codeunit \"SALES-POST\";\n        Cust: Record 18;\n        i: Integer;\n    begin\n        SalesPost.Run();\n        HELPER.DoIt();\n        Codeunit.Run(Codeunit::80);\n        i := 27 + 90;\n    end;\n}\n",
+        )
+        .unwrap();
+
+        assert_eq!(resolve_all(dir.path()).unwrap()[0].package.objects.len(), 7);
+        let lazy = index_dependencies_lazy(dir.path()).unwrap();
+        assert_eq!(lazy.len(), 1);
+        let mut names: Vec<&str> = lazy[0]
+            .package
+            .objects
+            .iter()
+            .map(|o| o.name.as_str())
+            .collect();
+        names.sort_unstable();
+        assert_eq!(
+            names,
+            ["Customer", "Gen. Jnl.-Post", "Helper", "Sales-Post"],
+            "Purch-Post is never named, and 27/90 are plain integers, not object numbers"
+        );
+    }
+
+    #[test]
+    fn lazy_index_keeps_extensions_of_referenced_objects() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let alpackages = dir.path().join(".alpackages");
+        std::fs::create_dir_all(&alpackages).unwrap();
+        write_app_with_symbols(
+            &alpackages,
+            "Pub_DupApp_25.0.0.0.app",
+            "ffffffff-6666-6666-6666-666666666666",
+            "25.0.0.0",
+            r#"{"Tables":[
+                {"Id":18,"Name":"Customer"},
+                {"Id":27,"Name":"Item"}
+            ],"TableExtensions":[
+                {"Id":50110,"Name":"Dep Cust Ext","TargetObject":"Customer",
+                 "Methods":[{"Name":"DepExtProc","Id":1}]},
+                {"Id":50111,"Name":"Dep Item Ext","TargetObject":"Item",
+                 "Methods":[{"Name":"DepItemProc","Id":1}]}
+            ]}"#,
+        );
+        std::fs::write(
+            dir.path().join("app.json"),
+            r#"{"dependencies": [{"name": "DupApp", "publisher": "Pub", "version": "25.0.0.0"}]}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("Caller.al"),
+            "codeunit 50200 Caller\n{\n    procedure Go()\n    var\n        Cust: Record Customer;\n    begin\n        Cust.DepExtProc();\n    end;\n}\n",
+        )
+        .unwrap();
+
+        let lazy = index_dependencies_lazy(dir.path()).unwrap();
+        let mut names: Vec<&str> = lazy[0]
+            .package
+            .objects
+            .iter()
+            .map(|o| o.name.as_str())
+            .collect();
+        names.sort_unstable();
+        assert_eq!(
+            names,
+            ["Customer", "Dep Cust Ext"],
+            "the Customer extension rides along with Customer; Item is never named"
+        );
+        let ext = lazy[0]
+            .package
+            .objects
+            .iter()
+            .find(|o| o.name == "Dep Cust Ext")
+            .unwrap();
+        assert_eq!(ext.methods[0].name, "DepExtProc");
+    }
+
+    #[test]
+    fn referenced_objects_count_numbers_only_in_object_positions() {
+        let mut refs = ReferencedObjects::default();
+        refs.scan_source(
+            "Codeunit.Run(Codeunit::80); Page.RunModal( 21, Rec); X: Record 18; i := 36 + 37;",
+        );
+        for id in [80, 21, 18] {
+            assert!(refs.contains("unnamed", id), "{id} is an object number");
+        }
+        for id in [36, 37] {
+            assert!(!refs.contains("unnamed", id), "{id} is a plain integer");
+        }
+        assert!(refs.contains("RECORD", 0), "names match case-insensitively");
+    }
+
     #[test]
     fn test_resolve_real_project() {
         let test_path = Path::new("u:/Git/DO/Cloud");
@@ -1201,6 +1491,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_app_json_reports_missing_and_malformed_files_distinctly() {
+        use crate::error::Error;
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("app.json");
+        assert!(matches!(parse_app_json(&path), Err(Error::Io { .. })));
+
+        std::fs::write(&path, "{ not json").expect("write app.json");
+        assert!(matches!(parse_app_json(&path), Err(Error::Json { .. })));
+    }
+
     #[test]
     fn test_parse_app_json_real_project() {
         let app_json = Path::new("U:/Git/DO.Support-wi-75148/DocumentOutput/Cloud/app.json");
@@ -1263,6 +1565,44 @@ mod tests {
         println!("Found: {}", path.display());
     }
 
+    #[test]
+    fn test_find_matching_app_skips_malformed_filenames() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        for name in [
+            "Pub_My App_2.0.0.0.app",
+            "Pub_My App_.app",
+            "Pub_My App_latest.app",
+            "Pub_My App_Extra_9.0.0.0.app",
+            "Pub_My App_3.0.0.0.txt",
+        ] {
+            std::fs::write(dir.path().join(name), b"").unwrap();
+        }
+
+        let dep = AppDependency {
+            app_id: String::new(),
+            name: "My App".to_string(),
+            publisher: "Pub".to_string(),
+            version: "1.0.0.0".to_string(),
+        };
+
+        let result = find_matching_app(dir.path(), &dep);
+        assert_eq!(
+            result.as_deref(),
+            Some(dir.path().join("Pub_My App_2.0.0.0.app").as_path()),
+            "only the well-formed filename is a candidate"
+        );
+    }
+
+    #[test]
+    fn test_is_numeric_dotted_version() {
+        assert!(is_numeric_dotted_version("26.0.0.0"));
+        assert!(is_numeric_dotted_version("1"));
+        assert!(!is_numeric_dotted_version(""));
+        assert!(!is_numeric_dotted_version("1..0"));
+        assert!(!is_numeric_dotted_version("Extra_9.0.0.0"));
+        assert!(!is_numeric_dotted_version("1.0.0.0.0"));
+    }
+
     #[test]
     fn test_find_matching_app_not_found() {
         let alpackages = Path::new("U:/Git/DO.Support-wi-75148/DocumentOutput/Cloud/.alpackages");
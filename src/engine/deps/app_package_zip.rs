@@ -96,14 +96,18 @@ fn decode_text(bytes: &[u8]) -> String {
 }
 
 /// Extract the `SymbolReference.json` text from raw `.app` bytes. Returns `None`
-/// if absent. Never panics. Mirrors al-sem `extractSymbolReferenceJson`.
+/// if absent. Never panics. Mirrors al-sem `extractSymbolReferenceJson`, plus
+/// the gzip handling of [`crate::app_package::read_symbol_reference_bytes`]: a
+/// `symbolreference.json.gz` entry is used when no plain one exists, and
+/// gzip-compressed content is inflated (a corrupt or oversized stream yields
+/// `None`).
 pub fn extract_symbol_reference_json(app_bytes: &[u8]) -> Option<String> {
-    extract_entry_bytes(
-        app_bytes,
-        "symbolreference.json",
-        crate::capped_io::SYMBOL_REFERENCE_JSON_CAP,
-    )
-    .map(|b| decode_text(&b))
+    let cap = crate::capped_io::SYMBOL_REFERENCE_JSON_CAP;
+    let bytes = extract_entry_bytes(app_bytes, "symbolreference.json", cap)
+        .or_else(|| extract_entry_bytes(app_bytes, "symbolreference.json.gz", cap))?;
+    crate::app_package::inflate_symbol_reference(bytes)
+        .ok()
+        .map(|b| decode_text(&b))
 }
 
 /// Extract the `NavxManifest.xml` text from raw `.app` bytes. Returns `None` if
@@ -233,4 +237,29 @@ mod tests {
         }
         buf.into_inner()
     }
+
+    fn gzip(content: &[u8]) -> Vec<u8> {
+        use std::io::Write as _;
+        let mut enc = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        enc.write_all(content).unwrap();
+        enc.finish().unwrap()
+    }
+
+    /// A gzip-compressed entry — under the plain name or a `.json.gz` one —
+    /// is inflated, as in `app_package::read_symbol_reference_bytes`.
+    #[test]
+    fn gzip_compressed_symbol_reference_is_inflated() {
+        let payload = gzip(b"{\"ok\":true}");
+        for name in ["SymbolReference.json", "src/SymbolReference.json.gz"] {
+            let bytes = build_zip_with_entry_with_content(name, &payload);
+            assert_eq!(
+                extract_symbol_reference_json(&bytes).as_deref(),
+                Some("{\"ok\":true}"),
+                "{name}"
+            );
+        }
+
+        let corrupt = build_zip_with_entry_with_content("SymbolReference.json", &[0x1f, 0x8b, 0]);
+        assert!(extract_symbol_reference_json(&corrupt).is_none());
+    }
 }
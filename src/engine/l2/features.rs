@@ -252,7 +252,7 @@ pub struct PRecordOperation {
     #[serde(skip)]
     pub in_until_condition: bool,
     /// Detector-audit d29 FP-1: the literal `RunTrigger` argument of a mutating
-    /// op — `Modify([RunTrigger])` / `Delete([RunTrigger])` /
+    /// op — `Insert([RunTrigger])` / `Modify([RunTrigger])` / `Delete([RunTrigger])` /
     /// `DeleteAll([RunTrigger])` (arg 0) and `ModifyAll(Field, Value[, RunTrigger])`
     /// (arg 2). `Some(false)` ⇒ the canonical RunTrigger=false pattern that
     /// SUPPRESSES trigger re-firing (so no recursive-event loop); `Some(true)` ⇒
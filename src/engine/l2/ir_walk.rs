@@ -858,12 +858,13 @@ impl<'a> SpineCtx<'a> {
                         loop_stack: loop_stack.clone(),
                         source_anchor: anchor.clone(),
                         in_until_condition: self.in_until,
-                        // RunTrigger literal arg of a mutating op (Modify/Delete/
-                        // DeleteAll → arg 0; ModifyAll → arg 2) — Some(bool) iff that
-                        // arg is a boolean literal. L5/d29 input (serde-skipped).
+                        // RunTrigger literal arg of a mutating op (Insert/Modify/
+                        // Delete/DeleteAll → arg 0; ModifyAll → arg 2) — Some(bool)
+                        // iff that arg is a boolean literal. L5/d29 + implicit-trigger
+                        // edge input (serde-skipped).
                         run_trigger: {
                             let idx = match *op_type {
-                                "Modify" | "Delete" | "DeleteAll" => Some(0),
+                                "Insert" | "Modify" | "Delete" | "DeleteAll" => Some(0),
                                 "ModifyAll" => Some(2),
                                 _ => None,
                             };
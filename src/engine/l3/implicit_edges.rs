@@ -14,9 +14,9 @@
 //!    arg to `RunTrigger::Guarded` (NOT `False`) — `Guarded`/`True` both keep the
 //!    edge — so we gate on `op.run_trigger == Some(false)` and treat `None`
 //!    (absent) exactly as the oracle's `Guarded`: edge kept. NOTE the L2 walk only
-//!    captures `run_trigger` for `Modify`/`Delete`/`DeleteAll`/`ModifyAll`
-//!    (`ir_walk.rs`); `Insert`/`Validate` never carry it (always `None` → kept),
-//!    so this gate bites only on `Modify(false)`/`Delete(false)` in real source.
+//!    captures `run_trigger` for `Insert`/`Modify`/`Delete`/`DeleteAll`/`ModifyAll`
+//!    (`ir_walk.rs`); `Validate` never carries it (always `None` → kept), so this
+//!    gate bites on `Insert(false)`/`Modify(false)`/`Delete(false)` in real source.
 //! 2. **Field-specific OnValidate targeting** (`applicability.rs:184-195`): a
 //!    `Validate(field)` edges to that field's OWN `OnValidate`, never an arbitrary
 //!    per-table one. `Insert`/`Modify`/`Delete` still edge to the table's
@@ -0,0 +1,84 @@
+//! Typed errors for the library's package and manifest entry points
+//! ([`crate::app_package::extract_app_package`] and its metadata/symbols
+//! halves, [`crate::dependencies::parse_app_json`]), so a consumer can match
+//! on the kind of failure instead of string-sniffing an `anyhow` chain.
+//!
+//! Hand-rolled like [`crate::capped_io::CapReadError`]: every variant
+//! implements `std::error::Error`, so `?` still composes into the
+//! `anyhow::Result`s the binary and the engine use internally.
+
+use std::fmt;
+
+use crate::capped_io::CapReadError;
+
+/// Why reading an `.app` package or an `app.json` failed. `what` names the
+/// surface involved (a file path or an archive entry such as
+/// `NavxManifest.xml`) and is always part of the message.
+#[derive(Debug)]
+pub enum Error {
+    /// Opening, seeking or reading a file or archive entry failed.
+    Io {
+        what: String,
+        source: std::io::Error,
+    },
+    /// The `.app` is not a readable ZIP archive, or an entry could not be
+    /// opened within it.
+    Zip {
+        what: String,
+        source: zip::result::ZipError,
+    },
+    /// `NavxManifest.xml` is not well-formed XML.
+    Xml {
+        what: String,
+        source: roxmltree::Error,
+    },
+    /// `SymbolReference.json` or `app.json` is not valid JSON for its shape.
+    Json {
+        what: String,
+        source: serde_json::Error,
+    },
+    /// Well-formed input that is still unusable: a missing entry or
+    /// element, invalid UTF-8, or an entry over its size cap.
+    Parse(String),
+}
+
+/// `Result` with this module's [`Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    /// Map a capped read of `what` — an over-cap stream is a [`Self::Parse`]
+    /// failure (the content is unusable), anything else an [`Self::Io`] one.
+    pub(crate) fn capped(what: &str, e: CapReadError) -> Self {
+        match e {
+            CapReadError::CapExceeded { .. } => Error::Parse(format!("{what}: {e}")),
+            CapReadError::Io(source) => Error::Io {
+                what: what.to_string(),
+                source,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { what, source } => write!(f, "Failed to read {what}: {source}"),
+            Error::Zip { what, source } => write!(f, "Failed to open {what}: {source}"),
+            Error::Xml { what, source } => write!(f, "Failed to parse {what}: {source}"),
+            Error::Json { what, source } => write!(f, "Failed to parse {what}: {source}"),
+            Error::Parse(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Zip { source, .. } => Some(source),
+            Error::Xml { source, .. } => Some(source),
+            Error::Json { source, .. } => Some(source),
+            Error::Parse(_) => None,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Changed-file discovery for `--analyze --since-git-ref` (the `git`
+//! feature). Shells out to the `git` binary on `PATH`; no git library is
+//! linked.
+
+use anyhow::{Context, Result, bail};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The `.al` files under `project` that differ between `git_ref` and the
+/// working tree (`git diff --name-only --relative -z <ref>`), as absolute
+/// paths. `-z` keeps non-ASCII names verbatim instead of C-quoted
+/// (`core.quotePath`). Committed and uncommitted changes to tracked files both count;
+/// untracked files do not. Deleted files are listed too — they simply match
+/// no procedure.
+pub fn changed_al_files(project: &Path, git_ref: &str) -> Result<Vec<PathBuf>> {
+    if git_ref.starts_with('-') {
+        bail!("--since-git-ref: `{git_ref}` is not a git ref");
+    }
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project)
+        .args(["diff", "--name-only", "--relative", "-z", git_ref, "--"])
+        .output()
+        .context("--since-git-ref: cannot run git")?;
+    if !output.status.success() {
+        bail!(
+            "--since-git-ref: git diff against `{git_ref}` failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter(|line| {
+            Path::new(line)
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("al"))
+        })
+        .map(|line| project.join(line))
+        .collect())
+}
@@ -27,6 +27,8 @@ pub mod capped_io;
 pub mod config;
 pub mod dependencies;
 pub mod engine;
+/// Typed errors for the package/manifest entry points — see the module doc.
+pub mod error;
 /// Tree-sitter AL language bindings. Exposed from the library so additive
 /// binaries (e.g. the R0 `aldump`) can parse without duplicating the `extern`
 /// declaration. `main.rs` keeps its own `mod language;` for the LSP binary;
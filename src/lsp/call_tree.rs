@@ -0,0 +1,326 @@
+//! A bounded, whole-tree view of the call hierarchy for one routine on the
+//! engine-backed `LspSnapshot` — the nested form of what repeated
+//! `callHierarchy/incomingCalls`/`outgoingCalls` requests would walk, for
+//! scripts that want the tree in one go (the CLI's `--hierarchy`).
+//!
+//! Neighbours come from the same sources as [`crate::lsp::handlers`]:
+//! incoming from [`LspSnapshot::incoming`], outgoing from the routine's own
+//! `edges_by_file` bucket plus `event_edges` (a publisher's subscribers are
+//! its outgoing calls). Only `RouteTarget::Routine` targets with a live decl
+//! become nodes; ABI-boundary and builtin targets have no routine to expand.
+//! Each node's children are distinct and sorted by id, so the tree is
+//! deterministic.
+//!
+//! # Wire shape
+//!
+//! ```jsonc
+//! {
+//!   "name": string,          // "Object.Routine"
+//!   "objectType": string,    // AL spelling, e.g. "Codeunit"
+//!   "uri": string, "line": number,  // declaring file, 1-based line
+//!   "stableId": string,      // RoutineNodeId::stable_id
+//!   "recursive": true,       // only present when this routine is already
+//!                            // an ancestor; its children are then omitted
+//!   "repeated": true,        // only present when this routine was already
+//!                            // expanded elsewhere in the tree; its children
+//!                            // are then omitted
+//!   "children": [ /* same shape */ ]
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use al_syntax::IdentifierFoldExt;
+use serde::Serialize;
+
+use crate::lsp::handlers::{decl_uri, object_name_for};
+use crate::lsp::snapshot::{DeclEntry, LspSnapshot};
+use crate::program::RoutineNodeId;
+use crate::program::resolve::edge::RouteTarget;
+
+/// Which way [`get_call_tree`] walks from the root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallDirection {
+    /// Callers, then their callers.
+    Incoming,
+    /// Callees, then their callees.
+    Outgoing,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallTreeNode {
+    pub name: String,
+    pub object_type: String,
+    pub uri: String,
+    pub line: u32,
+    pub stable_id: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub recursive: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub repeated: bool,
+    pub children: Vec<CallTreeNode>,
+}
+
+/// Workspace declarations named `Object.Routine` (both parts
+/// case-insensitive, optionally double-quoted), in
+/// [`LspSnapshot::decls_by_file`] path and position order. Several entries
+/// mean overloads.
+#[must_use]
+pub fn find_routines<'s>(snap: &'s LspSnapshot, qualified: &str) -> Vec<&'s DeclEntry> {
+    let Some((object, routine)) = qualified.rsplit_once('.') else {
+        return Vec::new();
+    };
+    let (object, routine) = (
+        object.trim().trim_matches('"'),
+        routine.trim().trim_matches('"'),
+    );
+    let mut paths: Vec<&String> = snap.decls_by_file.keys().collect();
+    paths.sort();
+    paths
+        .into_iter()
+        .flat_map(|path| snap.decls_by_file[path].iter())
+        .filter(|d| {
+            d.name.eq_fold_identifier(routine)
+                && object_name_for(&snap.graph, &d.id.object)
+                    .is_some_and(|name| name.eq_fold_identifier(object))
+        })
+        .collect()
+}
+
+/// The call tree rooted at `root`, `depth` levels deep (`Some(0)` is the
+/// root alone, `None` walks until every branch ends). A routine that is
+/// already on the path from the root is emitted once more with `recursive`
+/// set and no children, so cycles terminate at any depth. A routine reached
+/// along a second path (a diamond) is expanded only the first time; later
+/// occurrences are emitted with `repeated` set and no children, so shared
+/// callees don't multiply the tree. An earlier expansion only counts when it
+/// went at least as deep as the new one would.
+/// `None` when `root` has no live decl.
+#[must_use]
+pub fn get_call_tree(
+    snap: &LspSnapshot,
+    root: &RoutineNodeId,
+    direction: CallDirection,
+    depth: Option<usize>,
+) -> Option<CallTreeNode> {
+    let mut path = HashSet::new();
+    let mut expanded = HashMap::new();
+    tree_node(snap, root, direction, depth, &mut path, &mut expanded)
+}
+
+fn tree_node(
+    snap: &LspSnapshot,
+    id: &RoutineNodeId,
+    direction: CallDirection,
+    depth: Option<usize>,
+    path: &mut HashSet<RoutineNodeId>,
+    expanded: &mut HashMap<RoutineNodeId, Option<usize>>,
+) -> Option<CallTreeNode> {
+    let (decl, _) = snap.decl_and_text(id)?;
+    let object_name = object_name_for(&snap.graph, &id.object).unwrap_or("Unknown");
+    let mut node = CallTreeNode {
+        name: format!("{object_name}.{}", decl.name),
+        // `ObjectKind`'s variant names are the AL spellings.
+        object_type: format!("{:?}", id.object.kind),
+        uri: decl_uri(snap, decl).as_str().to_string(),
+        line: decl.origin.start.row + 1,
+        stable_id: id.stable_id(),
+        recursive: path.contains(id),
+        repeated: false,
+        children: Vec::new(),
+    };
+    if node.recursive || depth == Some(0) {
+        return Some(node);
+    }
+    if let Some(&earlier) = expanded.get(id) {
+        // `None` (unbounded) covers every depth; otherwise the earlier
+        // expansion must have had at least as many levels left.
+        node.repeated = match (earlier, depth) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(earlier), Some(depth)) => earlier >= depth,
+        };
+        if node.repeated {
+            return Some(node);
+        }
+    }
+    path.insert(id.clone());
+    let child_depth = depth.map(|d| d - 1);
+    node.children = neighbours(snap, id, direction)
+        .iter()
+        .filter_map(|next| tree_node(snap, next, direction, child_depth, path, expanded))
+        .collect();
+    path.remove(id);
+    // A leaf costs nothing to repeat, so only routines with children are
+    // collapsed on later visits.
+    if !node.children.is_empty() {
+        expanded.insert(id.clone(), depth);
+    }
+    Some(node)
+}
+
+/// `id`'s distinct callers or callees, sorted.
+fn neighbours(
+    snap: &LspSnapshot,
+    id: &RoutineNodeId,
+    direction: CallDirection,
+) -> Vec<RoutineNodeId> {
+    let mut out: Vec<RoutineNodeId> = match direction {
+        CallDirection::Incoming => snap
+            .incoming
+            .get(id)
+            .into_iter()
+            .flatten()
+            .map(|r| snap.edge(r).edge.from.clone())
+            .collect(),
+        CallDirection::Outgoing => {
+            let own_file = snap
+                .decl_by_id
+                .get(id)
+                .and_then(|d| snap.edges_by_file.get(&d.virtual_path));
+            own_file
+                .into_iter()
+                .flat_map(|edges| edges.iter())
+                .chain(snap.event_edges.iter())
+                .filter(|ce| &ce.edge.from == id)
+                .flat_map(|ce| ce.edge.routes.iter())
+                .filter_map(|route| match &route.target {
+                    RouteTarget::Routine(target) => Some(target.clone()),
+                    _ => None,
+                })
+                .collect()
+        }
+    };
+    out.sort();
+    out.dedup();
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_fixture(dir: &std::path::Path) {
+        std::fs::write(
+            dir.join("app.json"),
+            r#"{
+    "id": "77777777-0000-0000-0000-000000002222",
+    "name": "Call Tree Fixture",
+    "publisher": "probe",
+    "version": "1.0.0.0"
+}"#,
+        )
+        .expect("write app.json");
+        std::fs::write(
+            dir.join("Poster.al"),
+            "codeunit 50100 \"Poster\"\n{\n    procedure Post()\n    begin\n        Check();\n        Release();\n    end;\n\n    procedure Check()\n    begin\n        Release();\n    end;\n\n    procedure Release()\n    begin\n        Post();\n    end;\n}\n",
+        )
+        .expect("write Poster.al");
+    }
+
+    fn names(node: &CallTreeNode) -> Vec<&str> {
+        node.children.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    #[test]
+    fn call_tree_is_bounded_by_depth_and_marks_recursion() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_fixture(dir.path());
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let roots = find_routines(&snap, "\"poster\".POST");
+        assert_eq!(roots.len(), 1, "lookup is case-insensitive and unquotes");
+        let post = &roots[0].id;
+
+        let flat = get_call_tree(&snap, post, CallDirection::Outgoing, Some(0)).expect("root");
+        assert_eq!(flat.name, "Poster.Post");
+        assert_eq!(flat.object_type, "Codeunit");
+        assert_eq!(flat.line, 3);
+        assert!(flat.children.is_empty(), "depth 0 is the root alone");
+
+        let tree = get_call_tree(&snap, post, CallDirection::Outgoing, Some(3)).expect("root");
+        let mut callees = names(&tree);
+        callees.sort_unstable();
+        assert_eq!(callees, ["Poster.Check", "Poster.Release"]);
+        let release = tree
+            .children
+            .iter()
+            .find(|c| c.name == "Poster.Release")
+            .expect("Release child");
+        assert_eq!(names(release), ["Poster.Post"]);
+        assert!(release.children[0].recursive, "Post is already an ancestor");
+        assert!(release.children[0].children.is_empty());
+
+        let json = serde_json::to_value(&tree).expect("serialize");
+        assert_eq!(json["name"], "Poster.Post");
+        assert_eq!(json["stableId"], post.stable_id());
+        assert!(json.get("recursive").is_none(), "false is omitted");
+
+        let callers = get_call_tree(&snap, post, CallDirection::Incoming, Some(1)).expect("root");
+        assert_eq!(names(&callers), ["Poster.Release"]);
+        assert!(
+            callers.children[0].children.is_empty(),
+            "depth 1 stops there"
+        );
+
+        assert!(find_routines(&snap, "Poster.Missing").is_empty());
+        assert!(find_routines(&snap, "NoDot").is_empty());
+    }
+
+    /// Unbounded walks over a chain `A → B → C`, a diamond `Top → Left/Right
+    /// → Bottom → C` and a self-recursive `Again`.
+    #[test]
+    fn unbounded_call_tree_follows_chains_and_diamonds_and_stops_at_recursion() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_fixture(dir.path());
+        std::fs::write(
+            dir.path().join("Shapes.al"),
+            "codeunit 50101 Shapes\n{\n    procedure A()\n    begin\n        B();\n    end;\n\n    procedure B()\n    begin\n        C();\n    end;\n\n    procedure C()\n    begin\n    end;\n\n    procedure Top()\n    begin\n        Left();\n        Right();\n    end;\n\n    procedure Left()\n    begin\n        Bottom();\n    end;\n\n    procedure Right()\n    begin\n        Bottom();\n    end;\n\n    procedure Bottom()\n    begin\n        C();\n    end;\n\n    procedure Again()\n    begin\n        Again();\n    end;\n}\n",
+        )
+        .expect("write Shapes.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+        let tree = |name: &str| {
+            let id = &find_routines(&snap, name)[0].id;
+            get_call_tree(&snap, id, CallDirection::Outgoing, None).expect("root")
+        };
+
+        let a = tree("Shapes.A");
+        assert_eq!(names(&a), ["Shapes.B"]);
+        assert_eq!(names(&a.children[0]), ["Shapes.C"]);
+        assert!(a.children[0].children[0].children.is_empty(), "C is a leaf");
+
+        let top = tree("Shapes.Top");
+        let mut sides = names(&top);
+        sides.sort_unstable();
+        assert_eq!(sides, ["Shapes.Left", "Shapes.Right"]);
+        for side in &top.children {
+            assert_eq!(names(side), ["Shapes.Bottom"], "{}", side.name);
+            assert!(!side.children[0].recursive, "a diamond is not a cycle");
+        }
+        let (first, second) = (&top.children[0], &top.children[1]);
+        assert!(!first.children[0].repeated);
+        assert_eq!(names(&first.children[0]), ["Shapes.C"]);
+        assert!(second.children[0].repeated, "Bottom was already expanded");
+        assert!(second.children[0].children.is_empty());
+        let json = serde_json::to_value(&top).expect("serialize");
+        assert!(json["children"][0]["children"][0].get("repeated").is_none());
+        assert_eq!(json["children"][1]["children"][0]["repeated"], true);
+
+        let again = tree("Shapes.Again");
+        assert!(!again.recursive);
+        assert_eq!(names(&again), ["Shapes.Again"]);
+        assert!(again.children[0].recursive, "self-call is marked recursive");
+        assert!(again.children[0].children.is_empty());
+
+        let bottom = &find_routines(&snap, "Shapes.Bottom")[0].id;
+        let callers = get_call_tree(&snap, bottom, CallDirection::Incoming, None).expect("root");
+        let mut callers_names = names(&callers);
+        callers_names.sort_unstable();
+        assert_eq!(callers_names, ["Shapes.Left", "Shapes.Right"]);
+        assert!(
+            callers.children.iter().all(|c| names(c) == ["Shapes.Top"]),
+            "{callers:?}"
+        );
+    }
+}
@@ -168,6 +168,7 @@ use crate::app_package::{ExternalMethodKind, ExternalObject};
 use crate::lsp::encoding::PositionEncoding;
 use crate::lsp::handlers::{origin_to_range, resolve_virtual_path};
 use crate::lsp::snapshot::LspSnapshot;
+use crate::lsp::updater::SharedSnapshot;
 use crate::program::resolve::event::{PublisherKind, is_event_publisher};
 use crate::protocol::uri_to_path;
 use crate::snapshot::{AppSetSnapshot, AppUnit};
@@ -849,6 +850,121 @@ fn urldecode(s: &str) -> String {
     String::from_utf8(out).unwrap_or_else(|_| s.to_string())
 }
 
+// ---------------------------------------------------------------------------
+// health
+// ---------------------------------------------------------------------------
+
+/// Overall readiness reported by `al-call-hierarchy/health`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HealthState {
+    /// Every root with a workspace snapshot has published its latest changes.
+    Ready,
+    /// The roots' first snapshots are still building, or at least one root's
+    /// updater is applying changes not yet published.
+    Indexing,
+    /// No configured root produced a workspace snapshot.
+    NoWorkspace,
+}
+
+/// Response of `al-call-hierarchy/health`, summed over every root that built
+/// a snapshot: `{ "status", "definitions", "calls", "watchedRoots",
+/// "lastReindexMs", "watcher" }`. `calls` counts resolved call sites
+/// (workspace `Call`/`Run`/`ImplicitTrigger` edges plus event-flow edges);
+/// `lastReindexMs` is the Unix-epoch milliseconds of the most recent publish
+/// across roots (the initial build counts), `null` with no workspace;
+/// `watcher` sums every root's [`WatcherCounters`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthStatus {
+    pub status: HealthState,
+    pub definitions: usize,
+    pub calls: usize,
+    pub watched_roots: usize,
+    pub last_reindex_ms: Option<u64>,
+    pub watcher: WatcherCounters,
+}
+
+/// A point-in-time copy of a root's watcher counters (the binary's
+/// `WatcherStats`): `{ "eventsReceived",
+/// "changesEmitted", "reindexes" }` on the wire.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatcherCounters {
+    pub events_received: u64,
+    pub changes_emitted: u64,
+    pub reindexes: u64,
+}
+
+impl std::iter::Sum for WatcherCounters {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, c| Self {
+            events_received: acc.events_received + c.events_received,
+            changes_emitted: acc.changes_emitted + c.changes_emitted,
+            reindexes: acc.reindexes + c.reindexes,
+        })
+    }
+}
+
+/// Params of `al-call-hierarchy/reindex` (optional). By default every root
+/// queues a full rebuild from disk; `reconcile: true` applies only the `.al`
+/// files added or removed on disk since the last index (see
+/// [`crate::lsp::updater::Updater::reconcile`]), for a watcher that missed
+/// events.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ReindexParams {
+    pub reconcile: bool,
+}
+
+/// The `al-call-hierarchy/health` response while the configured roots' first
+/// snapshots are still building: `indexing`, with nothing counted or
+/// published yet.
+pub fn initial_build_health() -> HealthStatus {
+    HealthStatus {
+        status: HealthState::Indexing,
+        definitions: 0,
+        calls: 0,
+        watched_roots: 0,
+        last_reindex_ms: None,
+        watcher: WatcherCounters::default(),
+    }
+}
+
+/// Build the `al-call-hierarchy/health` response from each configured root's
+/// published snapshot (`None` for a root without a valid workspace) and the
+/// roots' summed watcher counters.
+pub fn health_status<'a>(
+    roots: impl IntoIterator<Item = Option<&'a SharedSnapshot>>,
+    watched_roots: usize,
+    watcher: WatcherCounters,
+) -> HealthStatus {
+    let mut status = HealthState::NoWorkspace;
+    let mut definitions = 0;
+    let mut calls = 0;
+    let mut last_reindex_ms: Option<u64> = None;
+    for shared in roots.into_iter().flatten() {
+        if shared.is_indexing() {
+            status = HealthState::Indexing;
+        } else if status == HealthState::NoWorkspace {
+            status = HealthState::Ready;
+        }
+        let snap = shared.get();
+        definitions += snap.decls_by_file.values().map(|v| v.len()).sum::<usize>();
+        calls += snap.edges_by_file.values().map(|v| v.len()).sum::<usize>();
+        calls += snap.event_edges.len();
+        last_reindex_ms = last_reindex_ms.max(Some(shared.published_at_ms()));
+    }
+    HealthStatus {
+        status,
+        definitions,
+        calls,
+        watched_roots,
+        last_reindex_ms,
+        watcher,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
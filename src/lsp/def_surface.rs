@@ -109,7 +109,7 @@
 //! `ObjectNode`/`RoutineNode` data — the LSP SURFACE's DISPLAY code, added
 //! by later T3 tasks after this audit was written:
 //! `src/lsp/handlers.rs`'s `symbol_kind_for` reads `RoutineNode::is_trigger`
-//! to classify a `CallHierarchyItem`'s `SymbolKind` (FUNCTION vs. EVENT), and
+//! to classify a `CallHierarchyItem`'s `SymbolKind` (FUNCTION/METHOD vs. EVENT), and
 //! `object_name_for` reads the graph's RAW-cased `ObjectNode::name` for a
 //! `CallHierarchyItem`'s `detail` text. Since rung 1 NEVER rebuilds `graph`
 //! (it reuses `cur.graph` unchanged — see `src/lsp/updater.rs`'s module
@@ -66,15 +66,23 @@
 use std::collections::{BTreeSet, HashMap};
 
 use al_syntax::ir::{ObjectKind, RoutineKind};
-use lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString};
+use lsp_types::{
+    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, DiagnosticTag, Location,
+    NumberOrString, Position, Range,
+};
+use serde::Serialize;
 
 use crate::config::DiagnosticConfig;
 use crate::lsp::encoding::{LineTable, PositionEncoding};
-use crate::lsp::handlers::{object_name_for, origin_to_range};
+use crate::lsp::handlers::{
+    ItemData, canonical_span_to_range, incoming, object_name_for, origin_to_range,
+};
 use crate::lsp::lens::{effective_incoming_count, find_routine_by_origin, parameter_count_of};
 use crate::lsp::snapshot::{DeclEntry, LspSnapshot};
 use crate::lsp::updater::Rung1Delta;
+use crate::program::resolve::edge::{ObligationOutcome, RouteTarget, classify_obligation};
 use crate::program::resolve::event::{PublisherKind, is_event_publisher};
+use crate::program::resolve::full::ClassifiedEdge;
 use crate::protocol::path_to_uri;
 
 /// Full recompute over the snapshot: every workspace file gets an entry
@@ -221,6 +229,9 @@ fn compute_file(
     let Some(entry) = snap.parsed.get(virtual_path) else {
         return;
     };
+    if cfg.skips_generated(&entry.text) {
+        return;
+    }
     let uri = workspace_uri(snap, virtual_path);
     // Snapshot-scoped cache (`ParsedFileEntry::line_table`,
     // `docs/OUTSTANDING.md`'s "Snapshot-scoped LineTable cache" item): this
@@ -232,6 +243,9 @@ fn compute_file(
     let table = entry.line_table();
 
     for decl in decls.iter() {
+        if cfg.excludes(decl.id.object.kind) {
+            continue;
+        }
         let Some(routine) = find_routine_by_origin(&entry.file, decl.origin.byte.start) else {
             continue;
         };
@@ -251,10 +265,10 @@ fn compute_file(
         if cfg.unused_procedures && is_unused_procedure(decl, routine, incoming_count) {
             out.entry(uri.clone())
                 .or_default()
-                .push(unused_procedure_diagnostic(snap, decl, table, enc));
+                .push(unused_procedure_diagnostic(snap, decl, table, enc, cfg));
         }
 
-        let complexity = crate::analysis::routine_complexity_ir(&entry.file.ir, routine);
+        let complexity = crate::analysis::routine_complexity_for(&entry.file.ir, routine, cfg);
         let parameter_count = parameter_count_of(routine);
         let line_count = decl.origin.end.row.saturating_sub(decl.origin.start.row) + 1;
 
@@ -271,6 +285,25 @@ fn compute_file(
             cfg,
         );
     }
+
+    for c in crate::analysis::marker_comments(&entry.text, &entry.file, cfg) {
+        out.entry(uri.clone())
+            .or_default()
+            .push(todo_comment_diagnostic(&c, table, enc, cfg));
+    }
+
+    if cfg.ambiguous_calls
+        && let Some(edges) = snap.edges_by_file.get(virtual_path)
+    {
+        for ce in edges.iter() {
+            if cfg.excludes(ce.edge.from.object.kind) {
+                continue;
+            }
+            if let Some(d) = ambiguous_call_diagnostic(snap, ce, table, enc, cfg) {
+                out.entry(uri.clone()).or_default().push(d);
+            }
+        }
+    }
 }
 
 fn workspace_uri(snap: &LspSnapshot, virtual_path: &str) -> String {
@@ -346,6 +379,74 @@ fn is_unused_procedure(
     incoming_count == 0
 }
 
+/// One diagnostic rule this module can publish, as listed by the CLI's
+/// `--list-rules` and the `al-call-hierarchy/diagnosticRules` request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticRule {
+    /// The `Diagnostic.code`, before any configured `codePrefix`.
+    pub code: &'static str,
+    pub description: &'static str,
+    /// `"hint"`, `"information"` or `"warning"`; for a rule with two
+    /// thresholds, the severity at the warning one.
+    pub default_severity: &'static str,
+    /// The severity at the critical threshold, for a rule that has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub critical_severity: Option<&'static str>,
+}
+
+/// Every rule [`compute_all`] can emit, in the order of the LSP.md table.
+pub const DIAGNOSTIC_RULES: &[DiagnosticRule] = &[
+    DiagnosticRule {
+        code: "unused-procedure",
+        description: "Procedure has no callers (tagged Unnecessary)",
+        default_severity: "hint",
+        critical_severity: None,
+    },
+    DiagnosticRule {
+        code: "high-complexity",
+        description: "Cyclomatic complexity reaches the warning or critical threshold",
+        default_severity: "information",
+        critical_severity: Some("warning"),
+    },
+    DiagnosticRule {
+        code: "too-many-parameters",
+        description: "Parameter count reaches the warning or critical threshold",
+        default_severity: "information",
+        critical_severity: Some("warning"),
+    },
+    DiagnosticRule {
+        code: "high-fan-in",
+        description: "Procedure has more callers than the fan-in threshold",
+        default_severity: "information",
+        critical_severity: None,
+    },
+    DiagnosticRule {
+        code: "long-method",
+        description: "Procedure spans more lines than the length threshold",
+        default_severity: "information",
+        critical_severity: None,
+    },
+    DiagnosticRule {
+        code: "todo-comment",
+        description: "Comment carries a configured marker such as TODO or FIXME",
+        default_severity: "information",
+        critical_severity: None,
+    },
+    DiagnosticRule {
+        code: "ambiguous-call",
+        description: "Call resolves to several same-named candidates",
+        default_severity: "information",
+        critical_severity: None,
+    },
+];
+
+/// A diagnostic code with the configured [`DiagnosticConfig::code_prefix`]
+/// prepended (a no-op under the default empty prefix).
+fn diagnostic_code(cfg: &DiagnosticConfig, code: &str) -> NumberOrString {
+    NumberOrString::String(format!("{}{code}", cfg.code_prefix))
+}
+
 /// Byte-for-byte legacy message/code/severity/tags
 /// (`src/handlers.rs:600-635`, `get_unused_procedure_diagnostics`).
 fn unused_procedure_diagnostic(
@@ -353,13 +454,14 @@ fn unused_procedure_diagnostic(
     decl: &DeclEntry,
     table: &LineTable,
     enc: PositionEncoding,
+    cfg: &DiagnosticConfig,
 ) -> Diagnostic {
     let object_name = object_name_for(&snap.graph, &decl.id.object).unwrap_or("Unknown");
     Diagnostic {
         range: origin_to_range(&decl.origin, table, enc),
         severity: Some(DiagnosticSeverity::HINT),
-        code: Some(NumberOrString::String("unused-procedure".to_string())),
-        source: Some("al-call-hierarchy".to_string()),
+        code: Some(diagnostic_code(cfg, "unused-procedure")),
+        source: Some(cfg.source.clone()),
         message: format!("Procedure '{object_name}.{}' is never called", decl.name),
         related_information: None,
         tags: Some(vec![DiagnosticTag::UNNECESSARY]),
@@ -393,8 +495,8 @@ fn push_quality_diagnostics(
     let plain = |code: &str, message: String, severity: DiagnosticSeverity| Diagnostic {
         range,
         severity: Some(severity),
-        code: Some(NumberOrString::String(code.to_string())),
-        source: Some("al-call-hierarchy".to_string()),
+        code: Some(diagnostic_code(cfg, code)),
+        source: Some(cfg.source.clone()),
         message,
         related_information: None,
         tags: None,
@@ -443,13 +545,16 @@ fn push_quality_diagnostics(
     }
 
     if cfg.fan_in_enabled && incoming_count > cfg.fan_in_warning {
-        out.push(plain(
-            "high-fan-in",
-            format!(
-                "Procedure '{object_name}.{proc}' has {incoming_count} callers - consider if it's doing too much"
-            ),
-            DiagnosticSeverity::INFORMATION,
-        ));
+        out.push(Diagnostic {
+            related_information: caller_related_information(snap, decl, enc),
+            ..plain(
+                "high-fan-in",
+                format!(
+                    "Procedure '{object_name}.{proc}' has {incoming_count} callers - consider if it's doing too much"
+                ),
+                DiagnosticSeverity::INFORMATION,
+            )
+        });
     }
 
     if cfg.length_enabled && line_count > cfg.length_critical {
@@ -463,6 +568,125 @@ fn push_quality_diagnostics(
     }
 }
 
+/// How many callers a high-fan-in diagnostic links to.
+const FAN_IN_RELATED_CALLERS: usize = 5;
+
+/// One related location per caller of `decl` — its first call site — for
+/// the first [`FAN_IN_RELATED_CALLERS`] callers in [`incoming`]'s order, so
+/// the editor can jump from the diagnostic to them. `None` when no caller
+/// has a position.
+fn caller_related_information(
+    snap: &LspSnapshot,
+    decl: &DeclEntry,
+    enc: PositionEncoding,
+) -> Option<Vec<DiagnosticRelatedInformation>> {
+    let related: Vec<DiagnosticRelatedInformation> =
+        incoming(snap, enc, &ItemData::new(decl.id.clone()))
+            .into_iter()
+            .filter_map(|call| {
+                let range = *call.from_ranges.first()?;
+                let caller = call.from.detail.unwrap_or(call.from.name);
+                Some(DiagnosticRelatedInformation {
+                    location: Location {
+                        uri: call.from.uri,
+                        range,
+                    },
+                    message: format!("Called from {caller}"),
+                })
+            })
+            .take(FAN_IN_RELATED_CALLERS)
+            .collect();
+    (!related.is_empty()).then_some(related)
+}
+
+/// An `INFORMATION` diagnostic for a configured comment marker (`TODO`,
+/// `FIXME`, ...), spanning the marker to the end of its comment line.
+fn todo_comment_diagnostic(
+    c: &crate::analysis::MarkerComment,
+    table: &LineTable,
+    enc: PositionEncoding,
+    cfg: &DiagnosticConfig,
+) -> Diagnostic {
+    Diagnostic {
+        range: Range {
+            start: Position {
+                line: c.row,
+                character: table.col_out(c.row, c.column, enc),
+            },
+            end: Position {
+                line: c.row,
+                character: table.col_out(c.row, c.end_column, enc),
+            },
+        },
+        severity: Some(DiagnosticSeverity::INFORMATION),
+        code: Some(diagnostic_code(cfg, "todo-comment")),
+        source: Some(cfg.source.clone()),
+        message: c.text.clone(),
+        related_information: None,
+        tags: None,
+        code_description: None,
+        data: None,
+    }
+}
+
+/// An `INFORMATION` diagnostic at a call site the resolver could only narrow
+/// to several same-named candidates ([`ObligationOutcome::AmbiguousResolved`]
+/// — exactly one runs, chosen by argument types this engine cannot decide),
+/// listing each candidate so the call can be qualified or its arguments made
+/// distinct. `None` for every other outcome. Only emitted under
+/// [`DiagnosticConfig::ambiguous_calls`]: the compiler settles most of these
+/// by argument type, so the hint is opt-in.
+fn ambiguous_call_diagnostic(
+    snap: &LspSnapshot,
+    ce: &ClassifiedEdge,
+    table: &LineTable,
+    enc: PositionEncoding,
+    cfg: &DiagnosticConfig,
+) -> Option<Diagnostic> {
+    if classify_obligation(&ce.edge) != ObligationOutcome::AmbiguousResolved {
+        return None;
+    }
+    let mut callee = None;
+    let candidates: Vec<String> = ce
+        .edge
+        .routes
+        .iter()
+        .filter_map(|route| match &route.target {
+            RouteTarget::Routine(rid) => {
+                let (decl, _) = snap.decl_and_line_table(rid)?;
+                let object_name = object_name_for(&snap.graph, &rid.object).unwrap_or("Unknown");
+                callee.get_or_insert_with(|| decl.name.to_string());
+                Some(format!(
+                    "{object_name}.{} ({}:{})",
+                    decl.name,
+                    decl.virtual_path,
+                    decl.name_origin.start.row + 1
+                ))
+            }
+            _ => None,
+        })
+        .collect();
+    if candidates.len() < 2 {
+        return None;
+    }
+    Some(Diagnostic {
+        range: canonical_span_to_range(&ce.edge.site.span, table, enc),
+        severity: Some(DiagnosticSeverity::INFORMATION),
+        code: Some(diagnostic_code(cfg, "ambiguous-call")),
+        source: Some(cfg.source.clone()),
+        message: format!(
+            "Call to '{}' is ambiguous between {} candidates: {}",
+            callee.unwrap_or_default(),
+            candidates.len(),
+            candidates.join(", ")
+        ),
+        related_information: None,
+        tags: None,
+        code_description: None,
+        data: None,
+    })
+}
+
 // ---------------------------------------------------------------------------
 // DiagnosticsState — the diff half of recompute-diff-publish-clear
 // ---------------------------------------------------------------------------
@@ -586,6 +810,7 @@ impl DiagnosticsState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use al_syntax::ir::ObjectKind;
     use lsp_types::Range;
 
     fn write_app(dir: &std::path::Path, id: &str, name: &str) {
@@ -1089,6 +1314,59 @@ mod tests {
         assert!(fan_in.unwrap().message.contains("2 callers"));
     }
 
+    #[test]
+    fn high_fan_in_diagnostic_links_to_its_callers() {
+        let dir = tempfile::tempdir().unwrap();
+        write_app(dir.path(), "10000000-0000-0000-0000-000000000018", "F");
+        std::fs::write(
+            dir.path().join("Cu.al"),
+            r#"codeunit 50100 "Cu"
+{
+    procedure Callee()
+    begin
+    end;
+
+    procedure Caller1()
+    begin
+        Callee();
+    end;
+
+    procedure Caller2()
+    begin
+        Callee();
+    end;
+}
+"#,
+        )
+        .unwrap();
+        let snap = build(dir.path());
+        let cfg = DiagnosticConfig {
+            fan_in_warning: 1,
+            ..DiagnosticConfig::default()
+        };
+
+        let diags = diagnostics_for(&snap, &cfg, "Cu.al");
+        let fan_in = diags
+            .iter()
+            .find(|d| d.code == Some(NumberOrString::String("high-fan-in".to_string())))
+            .expect("high-fan-in diagnostic");
+        let related = fan_in
+            .related_information
+            .as_ref()
+            .expect("callers are linked");
+        let lines: Vec<u32> = related
+            .iter()
+            .map(|r| r.location.range.start.line)
+            .collect();
+        assert_eq!(lines, [8, 13], "each caller's `Callee();` line");
+        assert!(related[0].message.contains("Cu.Caller1"), "{related:#?}");
+        assert!(
+            related
+                .iter()
+                .all(|r| r.location.uri.as_str().ends_with("Cu.al"))
+        );
+    }
+
     // ── compute_all includes now-empty URIs (every parsed file) ────────────
 
     #[test]
@@ -1127,6 +1405,243 @@ mod tests {
         assert!(all[&uri].is_empty());
     }
 
+    #[test]
+    fn todo_comment_is_an_information_diagnostic() {
+        let dir = tempfile::tempdir().unwrap();
+        write_app(dir.path(), "10000000-0000-0000-0000-000000000011", "Todo");
+        std::fs::write(
+            dir.path().join("Todo.al"),
+            r#"codeunit 50100 "Todo"
+{
+    trigger OnRun()
+    begin
+        // TODO: fix this
+    end;
+}
+"#,
+        )
+        .unwrap();
+        let snap = build(dir.path());
+
+        assert!(
+            !codes_of(&diagnostics_for(&snap, &DiagnosticConfig::default(), "Todo.al"))
+                .contains(&"todo-comment".to_string()),
+            "todo-comment is off by default"
+        );
+
+        let on = DiagnosticConfig {
+            todo_comments: true,
+            ..DiagnosticConfig::default()
+        };
+        let diags = diagnostics_for(&snap, &on, "Todo.al");
+        let todo = diags
+            .iter()
+            .find(|d| d.code == Some(NumberOrString::String("todo-comment".into())))
+            .expect("a todo-comment diagnostic");
+        assert_eq!(todo.severity, Some(DiagnosticSeverity::INFORMATION));
+        assert_eq!(todo.message, "TODO: fix this");
+        assert_eq!((todo.range.start.line, todo.range.start.character), (4, 11));
+
+        let off = DiagnosticConfig {
+            comment_markers: vec![],
+            ..on
+        };
+        assert!(
+            !codes_of(&diagnostics_for(&snap, &off, "Todo.al"))
+                .contains(&"todo-comment".to_string())
+        );
+    }
+
+    // ── ambiguous unqualified call ─────────────────────────────────────────
+
+    #[test]
+    fn ambiguous_unqualified_call_lists_its_candidates_when_enabled() {
+        let dir = tempfile::tempdir().unwrap();
+        write_app(dir.path(), "10000000-0000-0000-0000-000000000012", "Amb");
+        std::fs::write(
+            dir.path().join("Amb.al"),
+            r#"codeunit 50100 "Amb"
+{
+    procedure Run(A: Integer; B: Text)
+    begin
+        I(A, B);
+    end;
+
+    procedure I(A: Integer; B: Text)
+    begin
+    end;
+
+    procedure I(A: Integer; B: Code[20])
+    begin
+    end;
+}
+"#,
+        )
+        .unwrap();
+        let snap = build(dir.path());
+
+        assert!(
+            !codes_of(&diagnostics_for(
+                &snap,
+                &DiagnosticConfig::default(),
+                "Amb.al"
+            ))
+            .contains(&"ambiguous-call".to_string()),
+            "ambiguous-call is off by default"
+        );
+
+        let cfg = DiagnosticConfig {
+            ambiguous_calls: true,
+            ..DiagnosticConfig::default()
+        };
+        let diags = diagnostics_for(&snap, &cfg, "Amb.al");
+        let amb = diags
+            .iter()
+            .find(|d| d.code == Some(NumberOrString::String("ambiguous-call".into())))
+            .expect("an ambiguous-call diagnostic");
+        assert_eq!(amb.severity, Some(DiagnosticSeverity::INFORMATION));
+        assert_eq!(amb.range.start.line, 4);
+        assert!(
+            amb.message
+                .starts_with("Call to 'I' is ambiguous between 2 candidates: "),
+            "got {:?}",
+            amb.message
+        );
+        assert!(
+            amb.message.contains("Amb.I (Amb.al:8)"),
+            "got {:?}",
+            amb.message
+        );
+        assert!(
+            amb.message.contains("Amb.I (Amb.al:12)"),
+            "got {:?}",
+            amb.message
+        );
+    }
+
+    // ── excluded object types ───────────────────────────────────────────────
+
+    #[test]
+    fn excluded_object_types_drop_report_procedure_diagnostics() {
+        let dir = tempfile::tempdir().unwrap();
+        write_app(dir.path(), "10000000-0000-0000-0000-000000002206", "Ex");
+        std::fs::write(
+            dir.path().join("Rep.al"),
+            r#"report 50100 "Rep"
+{
+    procedure Wide(A: Integer; B: Integer; C: Integer)
+    begin
+    end;
+}
+"#,
+        )
+        .unwrap();
+        let snap = build(dir.path());
+        let cfg = DiagnosticConfig {
+            params_warning: 2,
+            ..DiagnosticConfig::default()
+        };
+        let codes = codes_of(&diagnostics_for(&snap, &cfg, "Rep.al"));
+        assert!(
+            codes.contains(&"too-many-parameters".to_string()),
+            "{codes:?}"
+        );
+
+        let cfg = DiagnosticConfig {
+            excluded_object_types: vec![ObjectKind::Report],
+            ..cfg
+        };
+        let diags = diagnostics_for(&snap, &cfg, "Rep.al");
+        assert!(diags.is_empty(), "{diags:#?}");
+    }
+
+    #[test]
+    fn generated_files_are_excluded_when_configured() {
+        let dir = tempfile::tempdir().unwrap();
+        write_app(dir.path(), "10000000-0000-0000-0000-000000002216", "Gen");
+        let body = r#"codeunit 50100 "Gen"
+{
+    procedure Orphan(A: Integer; B: Integer; C: Integer)
+    begin
+    end;
+}
+"#;
+        std::fs::write(
+            dir.path().join("Gen.al"),
+            format!("// <auto-generated>\n// Changes will be overwritten.\n{body}"),
+        )
+        .unwrap();
+        let snap = build(dir.path());
+        let cfg = DiagnosticConfig {
+            params_warning: 2,
+            ..DiagnosticConfig::default()
+        };
+        let codes = codes_of(&diagnostics_for(&snap, &cfg, "Gen.al"));
+        assert!(
+            codes.contains(&"too-many-parameters".to_string()),
+            "kept while exclusion is off: {codes:?}"
+        );
+
+        let cfg = DiagnosticConfig {
+            exclude_generated: true,
+            ..cfg
+        };
+        let diags = diagnostics_for(&snap, &cfg, "Gen.al");
+        assert!(diags.is_empty(), "{diags:#?}");
+    }
+
+    // ── configured diagnostic source / code prefix ─────────────────────────
+
+    #[test]
+    fn diagnostics_use_the_configured_source_and_code_prefix() {
+        let dir = tempfile::tempdir().unwrap();
+        write_app(dir.path(), "10000000-0000-0000-0000-000000000010", "Src");
+        std::fs::write(
+            dir.path().join("Cu.al"),
+            r#"codeunit 50100 "Cu"
+{
+    procedure Orphan(A: Integer; B: Integer)
+    begin
+    end;
+}
+"#,
+        )
+        .unwrap();
+        let snap = build(dir.path());
+
+        let defaults = diagnostics_for(&snap, &DiagnosticConfig::default(), "Cu.al");
+        assert!(!defaults.is_empty());
+        assert!(
+            defaults
+                .iter()
+                .all(|d| d.source.as_deref() == Some("al-call-hierarchy"))
+        );
+        assert!(codes_of(&defaults).contains(&"unused-procedure".to_string()));
+
+        let cfg = DiagnosticConfig {
+            source: "al-quality".to_string(),
+            code_prefix: "ACH-".to_string(),
+            params_warning: 2,
+            ..DiagnosticConfig::default()
+        };
+        let diags = diagnostics_for(&snap, &cfg, "Cu.al");
+        assert!(
+            diags
+                .iter()
+                .all(|d| d.source.as_deref() == Some("al-quality")),
+            "{diags:#?}"
+        );
+        let codes = codes_of(&diags);
+        assert!(
+            codes.contains(&"ACH-unused-procedure".to_string()),
+            "{codes:?}"
+        );
+        assert!(
+            codes.contains(&"ACH-too-many-parameters".to_string()),
+            "{codes:?}"
+        );
+    }
+
     // ── DiagnosticsState::diff ──────────────────────────────────────────────
 
     #[test]
@@ -1591,4 +2106,37 @@ mod tests {
         eprintln!("rung-scoped diagnostics (rung1_cover + compute_for_files) : {diag_med:?}");
         eprintln!("end-to-end rung-1 save (apply + diagnostics)              : {e2e_med:?}");
     }
+
+    #[test]
+    fn diagnostic_rules_list_every_code_once() {
+        let codes: Vec<&str> = DIAGNOSTIC_RULES.iter().map(|r| r.code).collect();
+        assert_eq!(
+            codes,
+            [
+                "unused-procedure",
+                "high-complexity",
+                "too-many-parameters",
+                "high-fan-in",
+                "long-method",
+                "todo-comment",
+                "ambiguous-call",
+            ]
+        );
+        for rule in DIAGNOSTIC_RULES {
+            assert!(!rule.description.is_empty(), "{}", rule.code);
+            for severity in std::iter::once(rule.default_severity).chain(rule.critical_severity) {
+                assert!(
+                    ["hint", "information", "warning"].contains(&severity),
+                    "{}: {severity}",
+                    rule.code
+                );
+            }
+        }
+
+        let json = serde_json::to_value(DIAGNOSTIC_RULES).expect("serialize");
+        assert_eq!(json[0]["code"], "unused-procedure");
+        assert_eq!(json[0]["defaultSeverity"], "hint");
+        assert!(json[0].get("criticalSeverity").is_none());
+        assert_eq!(json[1]["criticalSeverity"], "warning");
+    }
 }
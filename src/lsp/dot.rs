@@ -0,0 +1,292 @@
+//! Graphviz DOT export of the resolved call graph on the engine-backed
+//! `LspSnapshot` (the CLI's `--export-graph`), for visualizing coupling with
+//! `dot -Tsvg`.
+//!
+//! Edges are the ones [`crate::lsp::call_tree`] walks outgoing: every
+//! workspace `edges_by_file` bucket plus `event_edges`, one DOT edge per
+//! distinct caller/callee pair. `RouteTarget::Routine` and
+//! `RouteTarget::AbiSymbol` targets become nodes; builtin and unresolved
+//! routes have no routine to draw. Routines without a workspace declaration
+//! (dependency source, symbol-only packages) are drawn dashed and grey, and
+//! `EventFlow` edges (publisher to subscriber) dotted.
+//!
+//! Node ids are always quoted: [`RoutineNodeId::stable_id`] for routines, an
+//! `abi:`-prefixed rendering of the [`AbiRoutineKey`] for ABI symbols. Labels
+//! are `Object.Routine`. Nodes and edges are emitted sorted, so the output is
+//! stable across runs and diffable.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use al_syntax::IdentifierFoldExt;
+
+use crate::lsp::handlers::object_name_for;
+use crate::lsp::snapshot::LspSnapshot;
+use crate::program::RoutineNodeId;
+use crate::program::resolve::edge::{AbiRoutineKey, EdgeKind, RouteTarget};
+
+/// Attributes of a node with no workspace declaration.
+const EXTERNAL_STYLE: &str = "style=dashed, color=grey, fontcolor=grey";
+
+/// Which part of the call graph [`call_graph_dot`] exports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphScope {
+    /// Every edge.
+    Whole,
+    /// Edges with the caller or the callee in the object of this name
+    /// (case-insensitive, optionally double-quoted).
+    Object(String),
+    /// Edges whose caller is reachable, through outgoing calls, from one of
+    /// these routines (the routines themselves included).
+    Subtree(Vec<RoutineNodeId>),
+}
+
+/// A callee: a routine in the program graph or a bodyless ABI symbol.
+#[derive(Clone, Copy)]
+enum Callee<'s> {
+    Routine(&'s RoutineNodeId),
+    Abi(&'s AbiRoutineKey),
+}
+
+struct GraphEdge<'s> {
+    from: &'s RoutineNodeId,
+    to: Callee<'s>,
+    event: bool,
+}
+
+/// The `scope` part of `snap`'s call graph as a DOT `digraph`.
+#[must_use]
+pub fn call_graph_dot(snap: &LspSnapshot, scope: &GraphScope) -> String {
+    let mut edges = graph_edges(snap);
+    let mut nodes: BTreeMap<String, String> = BTreeMap::new();
+    match scope {
+        GraphScope::Whole => {}
+        GraphScope::Object(name) => {
+            let name = name.trim().trim_matches('"');
+            edges.retain(|e| {
+                in_object(snap, e.from, name)
+                    || match e.to {
+                        Callee::Routine(id) => in_object(snap, id, name),
+                        Callee::Abi(key) => key.object_name_lc.eq_fold_identifier(name),
+                    }
+            });
+        }
+        GraphScope::Subtree(roots) => {
+            let reachable = reachable_from(roots, &edges);
+            edges.retain(|e| reachable.contains(e.from));
+            // A root that calls nothing still shows up.
+            for root in roots {
+                let (id, attrs) = node(snap, Callee::Routine(root));
+                nodes.insert(id, attrs);
+            }
+        }
+    }
+
+    let mut lines: BTreeSet<(String, String, bool)> = BTreeSet::new();
+    for e in &edges {
+        let (from, from_attrs) = node(snap, Callee::Routine(e.from));
+        let (to, to_attrs) = node(snap, e.to);
+        nodes.insert(from.clone(), from_attrs);
+        nodes.insert(to.clone(), to_attrs);
+        lines.insert((from, to, e.event));
+    }
+
+    let mut out = String::from("digraph calls {\n    node [shape=box];\n");
+    for (id, attrs) in &nodes {
+        out.push_str(&format!("    {id} [{attrs}];\n"));
+    }
+    for (from, to, event) in &lines {
+        let style = if *event { " [style=dotted]" } else { "" };
+        out.push_str(&format!("    {from} -> {to}{style};\n"));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Every drawable caller/callee pair, one per route.
+fn graph_edges(snap: &LspSnapshot) -> Vec<GraphEdge<'_>> {
+    snap.edges_by_file
+        .values()
+        .flat_map(|edges| edges.iter())
+        .chain(snap.event_edges.iter())
+        .flat_map(|ce| {
+            let event = ce.edge.kind == EdgeKind::EventFlow;
+            ce.edge.routes.iter().filter_map(move |route| {
+                let to = match &route.target {
+                    RouteTarget::Routine(target) => Callee::Routine(target),
+                    RouteTarget::AbiSymbol { key } => Callee::Abi(key),
+                    _ => return None,
+                };
+                Some(GraphEdge {
+                    from: &ce.edge.from,
+                    to,
+                    event,
+                })
+            })
+        })
+        .collect()
+}
+
+/// The routines reachable from `roots` along `edges`, `roots` included.
+fn reachable_from<'s>(
+    roots: &'s [RoutineNodeId],
+    edges: &[GraphEdge<'s>],
+) -> HashSet<&'s RoutineNodeId> {
+    let mut seen: HashSet<&RoutineNodeId> = roots.iter().collect();
+    let mut queue: Vec<&RoutineNodeId> = roots.iter().collect();
+    while let Some(id) = queue.pop() {
+        for e in edges.iter().filter(|e| e.from == id) {
+            if let Callee::Routine(next) = e.to
+                && seen.insert(next)
+            {
+                queue.push(next);
+            }
+        }
+    }
+    seen
+}
+
+fn in_object(snap: &LspSnapshot, id: &RoutineNodeId, name: &str) -> bool {
+    object_name_for(&snap.graph, &id.object).is_some_and(|object| object.eq_fold_identifier(name))
+}
+
+/// A node's quoted id and its attribute list.
+fn node(snap: &LspSnapshot, callee: Callee<'_>) -> (String, String) {
+    match callee {
+        Callee::Routine(id) => {
+            let object = object_name_for(&snap.graph, &id.object).unwrap_or("Unknown");
+            let label = quote(&format!("{object}.{}", routine_name(snap, id)));
+            let attrs = if snap.decl_by_id.contains_key(id) {
+                format!("label={label}")
+            } else {
+                format!("label={label}, {EXTERNAL_STYLE}")
+            };
+            (quote(&id.stable_id()), attrs)
+        }
+        Callee::Abi(key) => {
+            // Same object display as the call hierarchy's ABI items.
+            let object = if key.object_number != 0 {
+                format!("{} {}", key.object_type, key.object_number)
+            } else {
+                format!("{} {}", key.object_type, key.object_name_lc)
+            };
+            let id = format!(
+                "abi:{}:{}:{}:{}:{}:{:x}",
+                key.object_type,
+                key.object_number,
+                key.object_name_lc,
+                key.routine_name_lc,
+                key.params_count,
+                key.param_type_fp
+            );
+            let label = quote(&format!("{object}.{}", key.routine_name_lc));
+            (quote(&id), format!("label={label}, {EXTERNAL_STYLE}"))
+        }
+    }
+}
+
+/// `id`'s raw-cased name: from its declaration, else from the program graph
+/// (symbol-only routines), else the lowercased identity.
+fn routine_name(snap: &LspSnapshot, id: &RoutineNodeId) -> String {
+    if let Some((decl, _)) = snap.decl_and_text(id) {
+        return decl.name.to_string();
+    }
+    snap.graph
+        .routines
+        .binary_search_by(|probe| probe.id.cmp(id))
+        .map(|i| snap.graph.routines[i].name.clone())
+        .unwrap_or_else(|_| id.name_lc.clone())
+}
+
+/// `s` as a DOT quoted string.
+fn quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' | '\\' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out.push_str("\\n"),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsp::call_tree::find_routines;
+
+    fn write_fixture(dir: &std::path::Path) {
+        std::fs::write(
+            dir.join("app.json"),
+            r#"{
+    "id": "77777777-0000-0000-0000-000000002256",
+    "name": "Dot Fixture",
+    "publisher": "probe",
+    "version": "1.0.0.0"
+}"#,
+        )
+        .expect("write app.json");
+        std::fs::write(
+            dir.join("Sales Post.al"),
+            "codeunit 50100 \"Sales-Post Mgt\"\n{\n    procedure Post()\n    begin\n        Check();\n    end;\n\n    procedure Check()\n    begin\n    end;\n}\n",
+        )
+        .expect("write Sales Post.al");
+        std::fs::write(
+            dir.join("Other.al"),
+            "codeunit 50101 Other\n{\n    procedure Run()\n    begin\n    end;\n}\n",
+        )
+        .expect("write Other.al");
+    }
+
+    #[test]
+    fn dot_export_has_one_quoted_edge_per_call() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_fixture(dir.path());
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+        let id_of = |name: &str| find_routines(&snap, name)[0].id.clone();
+        let (post, check) = (
+            id_of("\"Sales-Post Mgt\".Post"),
+            id_of("\"Sales-Post Mgt\".Check"),
+        );
+
+        let dot = call_graph_dot(&snap, &GraphScope::Whole);
+        assert!(dot.starts_with("digraph calls {\n"), "{dot}");
+        assert!(dot.ends_with("}\n"), "{dot}");
+        let edge = format!(
+            "    \"{}\" -> \"{}\";\n",
+            post.stable_id(),
+            check.stable_id()
+        );
+        assert!(dot.contains(&edge), "missing {edge:?} in:\n{dot}");
+        assert_eq!(dot.matches(" -> ").count(), 1, "{dot}");
+        assert!(
+            dot.contains(&format!(
+                "    \"{}\" [label=\"Sales-Post Mgt.Post\"];\n",
+                post.stable_id()
+            )),
+            "{dot}"
+        );
+
+        let object = call_graph_dot(&snap, &GraphScope::Object("sales-post mgt".into()));
+        assert!(object.contains(&edge), "{object}");
+        let other = call_graph_dot(&snap, &GraphScope::Object("Other".into()));
+        assert!(!other.contains(" -> "), "{other}");
+
+        let subtree = call_graph_dot(&snap, &GraphScope::Subtree(vec![check.clone()]));
+        assert!(!subtree.contains(" -> "), "Check calls nothing: {subtree}");
+        assert!(subtree.contains(&check.stable_id()), "the root is drawn");
+        let subtree = call_graph_dot(&snap, &GraphScope::Subtree(vec![post]));
+        assert!(subtree.contains(&edge), "{subtree}");
+    }
+
+    #[test]
+    fn dot_quoting_escapes_quotes_and_backslashes() {
+        assert_eq!(quote("Sales-Post Mgt.Post"), "\"Sales-Post Mgt.Post\"");
+        assert_eq!(quote(r#"a"b\c"#), r#""a\"b\\c""#);
+    }
+}
@@ -139,6 +139,16 @@ impl LineTable {
         }
         bytes
     }
+
+    /// Inbound LSP position (`line`, column in `enc`) -> byte offset into
+    /// the whole text. A line past the last one clamps to the end of the
+    /// text, a column past its line's end to that line's end.
+    pub fn offset_in(&self, line: u32, enc_col: u32, enc: PositionEncoding) -> usize {
+        match self.lines.get(line as usize) {
+            Some(r) => r.start + self.col_in(line, enc_col, enc) as usize,
+            None => self.text.len(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -164,6 +174,16 @@ mod tests {
         assert_eq!(t.col_in(0, 5, PositionEncoding::Utf8), 5);
     }
 
+    #[test]
+    fn offset_in_converts_positions_to_whole_text_byte_offsets() {
+        let t = LineTable::new("æøå x\r\n🚀 y\n");
+        assert_eq!(t.offset_in(0, 3, PositionEncoding::Utf16), 6);
+        assert_eq!(t.offset_in(1, 2, PositionEncoding::Utf16), 14); // after `\r\n` + 🚀
+        assert_eq!(t.offset_in(1, 4, PositionEncoding::Utf8), 14);
+        assert_eq!(t.offset_in(0, 99, PositionEncoding::Utf16), 8); // line end, before `\r`
+        assert_eq!(t.offset_in(9, 0, PositionEncoding::Utf16), 17); // past the last line
+    }
+
     #[test]
     fn out_of_range_column_clamps_to_line_end_never_panics() {
         let t = LineTable::new("abc\n");
@@ -30,19 +30,22 @@
 
 use std::collections::HashMap;
 
+use al_syntax::SourceEdit;
 use lsp_types::{
     CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, Position, Range,
-    SymbolKind, Uri,
+    SymbolKind, SymbolTag, TextDocumentContentChangeEvent, Uri,
 };
 use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};
 use serde::{Deserialize, Serialize};
 
 use crate::lsp::encoding::{LineTable, PositionEncoding};
-use crate::lsp::snapshot::{DeclView, LspSnapshot};
-use crate::program::resolve::edge::{AbiRoutineKey, EdgeKind, Route, RouteTarget};
+use crate::lsp::snapshot::{DeclView, DefinitionKind, LspSnapshot, param_list};
+use crate::program::resolve::edge::{AbiRoutineKey, CanonicalSpan, EdgeKind, Route, RouteTarget};
 use crate::program::resolve::full::ClassifiedEdge;
-use crate::program::{AppRef, ObjectNodeId, ProgramGraph, RoutineNodeId};
+use crate::program::resolve::resolver::abi_routine_key;
+use crate::program::{Access, AppRef, ObjectNodeId, ProgramGraph, RoutineNodeId};
 use crate::protocol::{path_to_uri, uri_to_path};
+use crate::snapshot::TrustTier;
 
 /// `item.data` payload — a serde round-trip of the content-addressed id.
 /// Additive `Serialize`/`Deserialize` derives on `RoutineNodeId` and its
@@ -50,9 +53,22 @@ use crate::protocol::{path_to_uri, uri_to_path};
 /// behavior change to any resolution path (see that file's own doc on the
 /// serde "remote" mirror it uses for the foreign `al_syntax::ir::ObjectKind`
 /// type).
+///
+/// `stableId` ([`RoutineNodeId::stable_id`]) is for clients only — a
+/// bookmark that survives reindexes and line moves. It is never read back:
+/// the follow-up requests resolve `node`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ItemData {
     pub node: RoutineNodeId,
+    #[serde(rename = "stableId", default)]
+    pub stable_id: String,
+}
+
+impl ItemData {
+    pub fn new(node: RoutineNodeId) -> Self {
+        let stable_id = node.stable_id();
+        Self { node, stable_id }
+    }
 }
 
 /// Path-segment encode set for the synthetic dependency-source/ABI-symbol
@@ -152,7 +168,11 @@ pub(crate) fn resolve_virtual_path(snap: &LspSnapshot, uri: &str) -> Option<Stri
 ///
 /// A stale `data.node` (not in `snap.incoming` at all, OR present but its
 /// grouped caller's own decl has since vanished) degrades to an empty
-/// result for that entry — fail-closed, never a guess or a panic.
+/// result for that entry — fail-closed, never a guess or a panic. The one
+/// decl-less caller kept is a SymbolOnly dependency event publisher (no
+/// source to position it in): a subscriber to it is reachable only through
+/// that event, so it surfaces as an ABI-boundary item (see
+/// [`symbol_only_publisher_item`]).
 #[must_use]
 pub fn incoming(
     snap: &LspSnapshot,
@@ -184,9 +204,19 @@ pub fn incoming(
     for caller_id in callers {
         let (has_event_flow, edges) = &groups[&caller_id];
         let Some((decl, dline_table)) = snap.decl_and_line_table(&caller_id) else {
-            // The caller's own decl vanished from the current snapshot —
-            // fail closed by dropping this group rather than guessing at a
-            // position for an item we can no longer locate.
+            // A publisher declared only in a dependency's symbols has no
+            // decl to position, but its EventFlow edge is still the real
+            // (often the ONLY) invocation path into this subscriber — surface
+            // it as an ABI-boundary item rather than an empty hierarchy.
+            if *has_event_flow && let Some(item) = symbol_only_publisher_item(snap, &caller_id) {
+                out.push(CallHierarchyIncomingCall {
+                    from: item,
+                    from_ranges: vec![ZERO_RANGE],
+                });
+            }
+            // Otherwise the caller's own decl vanished from the current
+            // snapshot — fail closed by dropping this group rather than
+            // guessing at a position for an item we can no longer locate.
             continue;
         };
         // Snapshot-scoped cache (`docs/OUTSTANDING.md`'s "Snapshot-scoped
@@ -292,6 +322,110 @@ pub fn outgoing(
     out
 }
 
+/// Opt-in heuristic companion to [`outgoing`] (`uniqueNameFallback`): for
+/// each of `data.node`'s call sites the resolver left wholly `Unresolved`
+/// whose callee is a bare identifier, an item for the ONE workspace
+/// procedure of that name in another object — none when zero or several
+/// declare it. The resolver itself never guesses; these items carry a
+/// `[ByName]` detail tag so a client can tell them apart.
+pub fn unique_name_calls(
+    snap: &LspSnapshot,
+    enc: PositionEncoding,
+    data: &ItemData,
+) -> Vec<CallHierarchyOutgoingCall> {
+    let Some(caller_decl) = snap.decl_by_id.get(&data.node) else {
+        return Vec::new();
+    };
+    let Some(caller_entry) = snap.parsed.get(&caller_decl.virtual_path) else {
+        return Vec::new();
+    };
+    let Some(edges) = snap.edges_by_file.get(&caller_decl.virtual_path) else {
+        return Vec::new();
+    };
+    let caller_table = caller_entry.line_table();
+
+    let mut index: Option<HashMap<String, Vec<&RoutineNodeId>>> = None;
+    let mut out = Vec::new();
+    for ce in edges.iter().filter(|ce| {
+        ce.edge.from == data.node
+            && ce.edge.kind == EdgeKind::Call
+            && ce
+                .edge
+                .routes
+                .iter()
+                .all(|r| r.target == RouteTarget::Unresolved)
+    }) {
+        let Some(name) = bare_callee_name(&caller_entry.text, &ce.edge.site.span) else {
+            continue;
+        };
+        let index = index.get_or_insert_with(|| procedure_name_index(snap));
+        let Some([target]) = index.get(&name).map(Vec::as_slice) else {
+            continue;
+        };
+        if target.object == data.node.object {
+            continue;
+        }
+        let Some((decl, dline_table)) = snap.decl_and_line_table(target) else {
+            continue;
+        };
+        out.push(CallHierarchyOutgoingCall {
+            to: build_item(
+                snap,
+                enc,
+                decl,
+                dline_table.table(),
+                decl_uri(snap, decl),
+                Some("[ByName]"),
+            ),
+            from_ranges: vec![canonical_span_to_range(
+                &ce.edge.site.span,
+                caller_table,
+                enc,
+            )],
+        });
+    }
+    out
+}
+
+/// Folded procedure name -> every workspace procedure declaring it, across
+/// all objects (overloads count separately, so an overloaded name is never
+/// unique).
+fn procedure_name_index(snap: &LspSnapshot) -> HashMap<String, Vec<&RoutineNodeId>> {
+    let mut index: HashMap<String, Vec<&RoutineNodeId>> = HashMap::new();
+    for decl in snap.decls_by_file.values().flat_map(|decls| decls.iter()) {
+        if snap.definition_kind(&decl.id) == DefinitionKind::Procedure {
+            index
+                .entry(decl.id.name_lc.clone())
+                .or_default()
+                .push(&decl.id);
+        }
+    }
+    index
+}
+
+/// The folded callee name when the call at `span` starts with a bare
+/// (plain or quoted) identifier rather than a `Receiver.Member` or
+/// `Enum::Value` form.
+fn bare_callee_name(text: &str, span: &CanonicalSpan) -> Option<String> {
+    let line = text.lines().nth(span.start.line as usize)?;
+    let rest = line.get(span.start.col as usize..)?;
+    let (name, after) = match rest.strip_prefix('"') {
+        Some(quoted) => {
+            let end = quoted.find('"')?;
+            (&quoted[..end], &quoted[end + 1..])
+        }
+        None => {
+            let end = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            rest.split_at(end)
+        }
+    };
+    let after = after.trim_start();
+    (!name.is_empty() && !after.starts_with(['.', ':']))
+        .then(|| al_syntax::casing::fold_identifier(name))
+}
+
 /// Emit one `CallHierarchyOutgoingCall` per route in `routes` that resolves
 /// to a real or ABI-boundary target, sharing the same `from_ranges` (they
 /// are all candidates for the SAME call/event site).
@@ -369,6 +503,29 @@ fn abi_symbol_item(snap: &LspSnapshot, key: &AbiRoutineKey) -> CallHierarchyItem
     }
 }
 
+/// [`abi_symbol_item`] for a SymbolOnly event publisher reaching a subscriber
+/// through an `EventFlow` edge (`incoming`'s only decl-less caller shape),
+/// carrying the publisher's raw-cased name and the `[EventPublisher]` tag a
+/// source publisher's item gets. `None` when `id` is not a SymbolOnly graph
+/// routine — a source-tier caller without a decl is stale, not external.
+fn symbol_only_publisher_item(snap: &LspSnapshot, id: &RoutineNodeId) -> Option<CallHierarchyItem> {
+    let node = snap
+        .graph
+        .routines
+        .binary_search_by(|probe| probe.id.cmp(id))
+        .ok()
+        .map(|i| &snap.graph.routines[i])?;
+    if node.tier != TrustTier::SymbolOnly {
+        return None;
+    }
+    let mut item = abi_symbol_item(snap, &abi_routine_key(id, &snap.graph));
+    item.name = node.name.clone();
+    if let Some(detail) = item.detail.as_mut() {
+        detail.push_str(" [EventPublisher]");
+    }
+    Some(item)
+}
+
 // ---------------------------------------------------------------------------
 // Shared item construction
 // ---------------------------------------------------------------------------
@@ -387,6 +544,16 @@ fn build_item(
 ) -> CallHierarchyItem {
     let object_name = object_name_for(&snap.graph, &decl.id.object).unwrap_or("Unknown");
     let mut detail = format!("{object_name}.{}", decl.name);
+    if let Some(params) = decl.params {
+        detail.push('(');
+        detail.push_str(&param_list(params));
+        detail.push(')');
+    }
+    for attr in decl.attributes {
+        detail.push_str(" [");
+        detail.push_str(attr);
+        detail.push(']');
+    }
     if let Some(t) = tag {
         detail.push(' ');
         detail.push_str(t);
@@ -395,19 +562,14 @@ fn build_item(
     CallHierarchyItem {
         name: decl.name.to_string(),
         kind: symbol_kind_for(snap, decl.id),
-        tags: None,
+        tags: symbol_tags_for(decl.attributes),
         detail: Some(detail),
         uri,
         range: origin_to_range(decl.origin, table, enc),
         selection_range: origin_to_range(decl.name_origin, table, enc),
-        data: Some(
-            serde_json::to_value(ItemData {
-                node: decl.id.clone(),
-            })
-            .expect(
-                "ItemData must serialize — RoutineNodeId's component types all derive Serialize",
-            ),
-        ),
+        data: Some(serde_json::to_value(ItemData::new(decl.id.clone())).expect(
+            "ItemData must serialize — RoutineNodeId's component types all derive Serialize",
+        )),
     }
 }
 
@@ -416,7 +578,7 @@ fn build_item(
 /// decl (no real on-disk `.al` file exists for embedded-source dependency
 /// text — see `src/snapshot/embedded.rs`'s doc: it is extracted straight
 /// from the `.app` zip into memory, never materialized to disk).
-fn decl_uri(snap: &LspSnapshot, decl: DeclView<'_>) -> Uri {
+pub(crate) fn decl_uri(snap: &LspSnapshot, decl: DeclView<'_>) -> Uri {
     if is_dep_app(snap, decl.id.object.app) {
         dep_source_uri(snap, decl.id.object.app, decl.virtual_path)
     } else {
@@ -520,11 +682,14 @@ pub(crate) fn object_name_for<'g>(
         .map(|i| graph.objects[i].name.as_str())
 }
 
-/// Best-effort `SymbolKind` classification (`FUNCTION` vs. `EVENT`) via a
-/// `graph.routines` lookup — not required for the audit's live-span
-/// guarantees, but cheap and mirrors legacy's `DefinitionKind::Trigger`/
-/// `EventSubscriber` → `SymbolKind::EVENT` mapping reasonably closely.
-fn symbol_kind_for(snap: &LspSnapshot, id: &RoutineNodeId) -> SymbolKind {
+/// Best-effort `SymbolKind` classification via a `graph.routines` lookup —
+/// not required for the audit's live-span guarantees, but cheap and mirrors
+/// legacy's `DefinitionKind::Trigger`/`EventSubscriber` → `SymbolKind::EVENT`
+/// mapping reasonably closely. A codeunit's `OnRun` trigger is the
+/// `CONSTRUCTOR` (it runs when the object itself is run); a `local`
+/// procedure is a `FUNCTION`, any other procedure a `METHOD` callable on
+/// the object. An id missing from the graph stays `FUNCTION`.
+pub(crate) fn symbol_kind_for(snap: &LspSnapshot, id: &RoutineNodeId) -> SymbolKind {
     let node = snap
         .graph
         .routines
@@ -532,11 +697,28 @@ fn symbol_kind_for(snap: &LspSnapshot, id: &RoutineNodeId) -> SymbolKind {
         .ok()
         .map(|i| &snap.graph.routines[i]);
     match node {
+        Some(n)
+            if n.is_trigger
+                && id.object.kind == al_syntax::ir::ObjectKind::Codeunit
+                && id.name_lc == "onrun" =>
+        {
+            SymbolKind::CONSTRUCTOR
+        }
         Some(n) if n.is_trigger || !n.event_subscribers.is_empty() => SymbolKind::EVENT,
-        _ => SymbolKind::FUNCTION,
+        Some(n) if n.access == Access::Local => SymbolKind::FUNCTION,
+        Some(_) => SymbolKind::METHOD,
+        None => SymbolKind::FUNCTION,
     }
 }
 
+/// `[DEPRECATED]` for a routine carrying an `[Obsolete]` attribute.
+pub(crate) fn symbol_tags_for(attributes: &[String]) -> Option<Vec<SymbolTag>> {
+    attributes
+        .iter()
+        .any(|a| a.eq_ignore_ascii_case("Obsolete"))
+        .then(|| vec![SymbolTag::DEPRECATED])
+}
+
 // ---------------------------------------------------------------------------
 // Position-encoding conversion — the ONE place a byte-native span crosses
 // into an LSP `Range` (see this module's own doc).
@@ -559,7 +741,7 @@ pub(crate) fn origin_to_range(
     }
 }
 
-fn canonical_span_to_range(
+pub(crate) fn canonical_span_to_range(
     span: &crate::program::resolve::edge::CanonicalSpan,
     table: &LineTable,
     enc: PositionEncoding,
@@ -576,6 +758,31 @@ fn canonical_span_to_range(
     }
 }
 
+/// The inbound direction: one `textDocument/didChange` content change as a
+/// byte-range [`SourceEdit`] against `text`, the document as it stands
+/// before the change (feed successive changes one at a time, each against
+/// the text the previous one produced). A change without a range replaces
+/// the whole document.
+pub fn content_change_edit(
+    text: &str,
+    change: &TextDocumentContentChangeEvent,
+    enc: PositionEncoding,
+) -> SourceEdit {
+    let Some(range) = change.range else {
+        return SourceEdit {
+            range: 0..text.len(),
+            text: change.text.clone(),
+        };
+    };
+    let table = LineTable::new(text);
+    let start = table.offset_in(range.start.line, range.start.character, enc);
+    let end = table.offset_in(range.end.line, range.end.character, enc);
+    SourceEdit {
+        range: start..end.max(start),
+        text: change.text.clone(),
+    }
+}
+
 fn range_sort_key(r: &Range) -> (u32, u32, u32, u32) {
     (r.start.line, r.start.character, r.end.line, r.end.character)
 }
@@ -683,13 +890,107 @@ mod tests {
     }
 
     fn item_data_of(decl: &DeclEntry) -> ItemData {
-        ItemData {
-            node: decl.id.clone(),
-        }
+        ItemData::new(decl.id.clone())
     }
 
     // ── prepare: name hit / body-fallback hit / none ───────────────────────
 
+    #[test]
+    fn prepare_detail_lists_the_procedure_attributes() {
+        let dir = fixture_dir();
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+        let uri = uri_string(dir.path(), "Gamma.al");
+
+        let handler = snap.decls_by_file["Gamma.al"]
+            .iter()
+            .find(|d| d.name == "HandleAfterProcess")
+            .expect("Gamma.HandleAfterProcess decl");
+        let items = prepare(
+            &snap,
+            PositionEncoding::Utf8,
+            &uri,
+            handler.name_origin.start.row,
+            handler.name_origin.start.column,
+        )
+        .expect("name-position hit");
+        let detail = items[0].detail.as_deref().unwrap_or("");
+        assert!(
+            detail.ends_with("HandleAfterProcess() [EventSubscriber]"),
+            "{detail:?}"
+        );
+
+        let standalone = snap.decls_by_file["Gamma.al"]
+            .iter()
+            .find(|d| d.name == "Standalone")
+            .expect("Gamma.Standalone decl");
+        let items = prepare(
+            &snap,
+            PositionEncoding::Utf8,
+            &uri,
+            standalone.name_origin.start.row,
+            standalone.name_origin.start.column,
+        )
+        .expect("name-position hit");
+        let detail = items[0].detail.as_deref().unwrap_or("");
+        assert!(!detail.contains('['), "{detail:?}");
+    }
+
+    #[test]
+    fn prepare_tags_obsolete_procedures_and_refines_the_symbol_kind() {
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("Legacy.al"),
+            r#"codeunit 50103 "Legacy"
+{
+    trigger OnRun()
+    begin
+    end;
+
+    [Obsolete('Use NewWay instead', '25.0')]
+    procedure OldWay()
+    begin
+    end;
+
+    procedure NewWay()
+    begin
+    end;
+
+    local procedure Helper()
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write Legacy.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+        let uri = uri_string(dir.path(), "Legacy.al");
+
+        let item_of = |name: &str| {
+            let decl = snap.decls_by_file["Legacy.al"]
+                .iter()
+                .find(|d| d.name == name)
+                .unwrap_or_else(|| panic!("Legacy.{name} decl"));
+            prepare(
+                &snap,
+                PositionEncoding::Utf8,
+                &uri,
+                decl.name_origin.start.row,
+                decl.name_origin.start.column,
+            )
+            .expect("name-position hit")
+            .remove(0)
+        };
+
+        let old_way = item_of("OldWay");
+        assert_eq!(old_way.tags, Some(vec![SymbolTag::DEPRECATED]));
+        assert_eq!(old_way.kind, SymbolKind::METHOD);
+        let new_way = item_of("NewWay");
+        assert_eq!(new_way.tags, None);
+        assert_eq!(new_way.kind, SymbolKind::METHOD);
+        assert_eq!(item_of("Helper").kind, SymbolKind::FUNCTION);
+        assert_eq!(item_of("OnRun").kind, SymbolKind::CONSTRUCTOR);
+    }
+
     #[test]
     fn prepare_hits_name_then_falls_back_to_body_then_returns_none() {
         let dir = fixture_dir();
@@ -837,6 +1138,316 @@ mod tests {
         assert_eq!(dowork_call.from_ranges[0].start.line, expected_line);
     }
 
+    #[test]
+    fn incoming_merges_repeated_calls_from_one_caller_into_one_item() {
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("Delta.al"),
+            r#"codeunit 50103 "Delta"
+{
+    procedure Twice()
+    var
+        Beta: Codeunit "Beta";
+    begin
+        Beta.Process();
+        Beta.Process();
+    end;
+}
+"#,
+        )
+        .expect("write Delta.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let process_decl = snap.decls_by_file["Beta.al"]
+            .iter()
+            .find(|d| d.name == "Process")
+            .expect("Beta.Process decl");
+        let calls = incoming(&snap, PositionEncoding::Utf16, &item_data_of(process_decl));
+        let twice: Vec<_> = calls.iter().filter(|c| c.from.name == "Twice").collect();
+        assert_eq!(twice.len(), 1, "one item per caller; got {calls:#?}");
+        let lines: Vec<u32> = twice[0].from_ranges.iter().map(|r| r.start.line).collect();
+        assert_eq!(lines, [6, 7], "both call sites, in source order");
+    }
+
+    #[test]
+    fn unique_name_calls_resolve_a_bare_call_to_its_only_declaration() {
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("Delta.al"),
+            r#"codeunit 50103 "Delta"
+{
+    procedure Caller()
+    begin
+        Standalone();
+        Bar(1);
+    end;
+}
+"#,
+        )
+        .expect("write Delta.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let caller = snap.decls_by_file["Delta.al"]
+            .iter()
+            .find(|d| d.name == "Caller")
+            .expect("Delta.Caller decl");
+        let data = item_data_of(caller);
+        assert!(
+            outgoing(&snap, PositionEncoding::Utf16, &data).is_empty(),
+            "the resolver itself leaves both bare calls unresolved"
+        );
+
+        // `Standalone` is declared once (Gamma); `Bar` is overloaded in Beta.
+        let calls = unique_name_calls(&snap, PositionEncoding::Utf16, &data);
+        assert_eq!(calls.len(), 1, "{calls:#?}");
+        assert_eq!(calls[0].to.name, "Standalone");
+        assert!(
+            calls[0]
+                .to
+                .detail
+                .as_deref()
+                .is_some_and(|d| d.ends_with("[ByName]")),
+            "{calls:#?}"
+        );
+        assert_eq!(calls[0].from_ranges[0].start.line, 4);
+    }
+
+    #[test]
+    fn incoming_on_each_interface_implementer_lists_the_interface_caller() {
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("Shape.al"),
+            r#"interface IShape
+{
+    procedure Area(): Decimal;
+}
+
+codeunit 50110 Circle implements IShape
+{
+    procedure Area(): Decimal
+    begin
+        exit(3);
+    end;
+}
+
+codeunit 50111 Square implements IShape
+{
+    procedure Area(): Decimal
+    begin
+        exit(4);
+    end;
+}
+
+codeunit 50112 Painter
+{
+    procedure Paint(Shape: Interface IShape): Decimal
+    begin
+        exit(Shape.Area());
+    end;
+}
+"#,
+        )
+        .expect("write Shape.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let implementations: Vec<_> = snap.decls_by_file["Shape.al"]
+            .iter()
+            .filter(|d| d.name == "Area" && d.id.object.kind == al_syntax::ir::ObjectKind::Codeunit)
+            .collect();
+        assert_eq!(implementations.len(), 2, "Circle.Area and Square.Area");
+        for decl in implementations {
+            let calls = incoming(&snap, PositionEncoding::Utf16, &item_data_of(decl));
+            assert!(
+                calls.iter().any(|c| c.from.name == "Paint"),
+                "{:?}: {calls:#?}",
+                decl.id
+            );
+        }
+    }
+
+    #[test]
+    fn subscribers_by_object_id_and_to_business_events_are_linked() {
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("Delta.al"),
+            r#"codeunit 50103 "Delta"
+{
+    [BusinessEvent(false)]
+    procedure OnReady()
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write Delta.al");
+        std::fs::write(
+            dir.path().join("Epsilon.al"),
+            r#"codeunit 50104 "Epsilon"
+{
+    [EventSubscriber(ObjectType::Codeunit, Codeunit::50101, 'OnAfterProcess', '', false, false)]
+    local procedure ById()
+    begin
+    end;
+
+    [EventSubscriber(ObjectType::Codeunit, Codeunit::"Delta", 'OnReady', '', false, false)]
+    local procedure OnDeltaReady()
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write Epsilon.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let publisher = snap.decls_by_file["Beta.al"]
+            .iter()
+            .find(|d| d.name == "OnAfterProcess")
+            .expect("Beta.OnAfterProcess decl");
+        let mut subscribers: Vec<String> =
+            outgoing(&snap, PositionEncoding::Utf16, &item_data_of(publisher))
+                .into_iter()
+                .map(|c| c.to.name)
+                .collect();
+        subscribers.sort();
+        assert_eq!(subscribers, ["ById", "HandleAfterProcess"]);
+
+        let subscriber = snap.decls_by_file["Epsilon.al"]
+            .iter()
+            .find(|d| d.name == "OnDeltaReady")
+            .expect("Epsilon.OnDeltaReady decl");
+        let calls = incoming(&snap, PositionEncoding::Utf16, &item_data_of(subscriber));
+        assert_eq!(calls.len(), 1, "{calls:#?}");
+        assert_eq!(calls[0].from.name, "OnReady");
+    }
+
+    #[test]
+    fn same_named_procedures_of_different_arity_keep_their_own_callers() {
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("Delta.al"),
+            r#"codeunit 50103 "Delta"
+{
+    procedure Log(Msg: Text)
+    begin
+    end;
+
+    procedure Log(Msg: Text; Level: Integer)
+    begin
+    end;
+
+    procedure One()
+    begin
+        Log('a');
+    end;
+
+    procedure Two()
+    begin
+        Log('a', 1);
+    end;
+}
+"#,
+        )
+        .expect("write Delta.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let logs: Vec<&DeclEntry> = snap.decls_by_file["Delta.al"]
+            .iter()
+            .filter(|d| d.name == "Log")
+            .collect();
+        assert_eq!(logs.len(), 2, "both overloads keep their own declaration");
+        for decl in logs {
+            let callers: Vec<String> =
+                incoming(&snap, PositionEncoding::Utf16, &item_data_of(decl))
+                    .into_iter()
+                    .map(|c| c.from.name)
+                    .collect();
+            let expected = match decl.id.params_count {
+                1 => "One",
+                2 => "Two",
+                n => panic!("unexpected Log arity {n}"),
+            };
+            assert_eq!(callers, [expected], "Log/{}", decl.id.params_count);
+        }
+    }
+
+    /// A UTF-16 `didChange` range on a line with a non-ASCII identifier
+    /// lands on the right bytes, and the incrementally reparsed file has the
+    /// same declarations as a full parse of the edited text.
+    #[test]
+    fn content_changes_reparse_incrementally_to_the_full_parse_result() {
+        let mut text = ALPHA_SRC.to_string();
+        let (_, mut retained) = al_syntax::parse_retained(&text);
+        let row = text
+            .lines()
+            .position(|l| l.contains("Løbenr"))
+            .expect("Løbenr line") as u32;
+        let line = text.lines().nth(row as usize).unwrap();
+        let col = line[..line.find("Løbenr").unwrap()].encode_utf16().count() as u32;
+        let change =
+            |start: (u32, u32), end: (u32, u32), new: &str| TextDocumentContentChangeEvent {
+                range: Some(Range {
+                    start: Position {
+                        line: start.0,
+                        character: start.1,
+                    },
+                    end: Position {
+                        line: end.0,
+                        character: end.1,
+                    },
+                }),
+                range_length: None,
+                text: new.to_string(),
+            };
+
+        // Replace `Løbenr` (6 UTF-16 units, 7 bytes) with `Nummer`.
+        let edit = content_change_edit(
+            &text,
+            &change((row, col), (row, col + 6), "Nummer"),
+            PositionEncoding::Utf16,
+        );
+        assert_eq!(&text[edit.range.clone()], "Løbenr");
+        retained.edit(&mut text, &edit);
+        // Then add a procedure just inside the object's closing brace.
+        let last = text.trim_end().lines().count() as u32 - 1;
+        let edit = content_change_edit(
+            &text,
+            &change(
+                (last, 0),
+                (last, 0),
+                "    procedure Added()\n    begin\n    end;\n",
+            ),
+            PositionEncoding::Utf16,
+        );
+        retained.edit(&mut text, &edit);
+
+        let incremental = retained.reparse(&text);
+        let full = al_syntax::parse(&text);
+        let decls = |f: &al_syntax::ir::AlFile| -> Vec<(String, std::ops::Range<usize>)> {
+            f.objects
+                .iter()
+                .flat_map(|o| o.routines.iter())
+                .map(|r| (r.name.clone(), r.origin.byte.clone()))
+                .collect()
+        };
+        assert!(
+            !text.contains("Løbenr") && text.contains("Nummer"),
+            "{text}"
+        );
+        assert_eq!(decls(&incremental), decls(&full));
+        assert!(decls(&full).iter().any(|(name, _)| name == "Added"));
+
+        let whole = content_change_edit(
+            &text,
+            &TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: "x".into(),
+            },
+            PositionEncoding::Utf16,
+        );
+        assert_eq!(whole.range, 0..text.len());
+    }
+
     // ── incoming: subscriber's incoming lists the publisher ────────────────
 
     #[test]
@@ -873,6 +1484,74 @@ mod tests {
         assert_eq!(calls[0].from_ranges, vec![expected_range]);
     }
 
+    // ── incoming: subscriber to a SymbolOnly dependency publisher ─────────
+
+    /// A real `.app` (NAVX header + zip) whose `SymbolReference.json`
+    /// declares one `[IntegrationEvent]` publisher, `"Dep Pub".OnDoStuff`.
+    fn write_publisher_app(alpackages: &std::path::Path, guid: &str) {
+        use std::io::Write;
+
+        let manifest = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?><Package xmlns="http://schemas.microsoft.com/navx/2015/manifest"><App Id="{guid}" Name="PubDep" Publisher="probe" Version="1.0.0.0" Runtime="13.0" /></Package>"#
+        );
+        let symbol_reference = r#"{"Codeunits":[{"Id":60100,"Name":"Dep Pub","Methods":[{"Name":"OnDoStuff","Parameters":[],"Attributes":[{"Name":"IntegrationEvent","Arguments":[{"Value":"False"},{"Value":"False"}]}]}]}]}"#;
+
+        let mut zip_bytes = std::io::Cursor::new(Vec::new());
+        {
+            let mut zip = zip::ZipWriter::new(&mut zip_bytes);
+            let options: zip::write::SimpleFileOptions = zip::write::SimpleFileOptions::default();
+            zip.start_file("NavxManifest.xml", options).unwrap();
+            zip.write_all(manifest.as_bytes()).unwrap();
+            zip.start_file("SymbolReference.json", options).unwrap();
+            zip.write_all(symbol_reference.as_bytes()).unwrap();
+            zip.finish().unwrap();
+        }
+        std::fs::create_dir_all(alpackages).unwrap();
+        let mut out = std::fs::File::create(alpackages.join("probe_PubDep_1.0.0.0.app")).unwrap();
+        out.write_all(&[0u8; 40]).unwrap(); // NAVX header (content unused)
+        out.write_all(zip_bytes.get_ref()).unwrap();
+    }
+
+    #[test]
+    fn incoming_on_subscriber_to_symbol_only_publisher_shows_the_event() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let guid = "77777777-0000-0000-0000-000000000001";
+        std::fs::write(
+            dir.path().join("app.json"),
+            format!(
+                r#"{{"id":"77777777-0000-0000-0000-000000000002","name":"Subscriber App","publisher":"probe","version":"1.0.0.0",
+"dependencies":[{{"id":"{guid}","name":"PubDep","publisher":"probe","version":"1.0.0.0"}}]}}"#
+            ),
+        )
+        .unwrap();
+        write_publisher_app(&dir.path().join(".alpackages"), guid);
+        std::fs::write(
+            dir.path().join("Sub.al"),
+            r#"codeunit 50100 "Sub"
+{
+    [EventSubscriber(ObjectType::Codeunit, Codeunit::"Dep Pub", 'OnDoStuff', '', false, false)]
+    local procedure HandleDoStuff()
+    begin
+    end;
+}
+"#,
+        )
+        .unwrap();
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let sub_decl = snap.decls_by_file["Sub.al"]
+            .iter()
+            .find(|d| d.name == "HandleDoStuff")
+            .expect("Sub.HandleDoStuff decl");
+        let calls = incoming(&snap, PositionEncoding::Utf16, &item_data_of(sub_decl));
+        assert_eq!(calls.len(), 1, "{calls:#?}");
+        assert_eq!(calls[0].from.name, "OnDoStuff");
+        let detail = calls[0].from.detail.as_deref().unwrap_or("");
+        assert!(detail.contains("EventPublisher"), "{detail:?}");
+        assert!(detail.contains("PubDep"), "{detail:?}");
+        assert_eq!(calls[0].from_ranges, vec![ZERO_RANGE]);
+    }
+
     // ── outgoing: one resolved + one ambiguous (2 candidates) + one builtin ─
 
     #[test]
@@ -925,7 +1604,7 @@ mod tests {
             .unwrap();
         let mut bogus_id = dowork.id.clone();
         bogus_id.name_lc = "does_not_exist_xyz".to_string();
-        let data = ItemData { node: bogus_id };
+        let data = ItemData::new(bogus_id);
 
         assert!(incoming(&snap, PositionEncoding::Utf16, &data).is_empty());
         assert!(outgoing(&snap, PositionEncoding::Utf16, &data).is_empty());
@@ -979,9 +1658,7 @@ mod tests {
         let calls = outgoing(
             &new_snap,
             PositionEncoding::Utf16,
-            &ItemData {
-                node: dowork.id.clone(),
-            },
+            &ItemData::new(dowork.id.clone()),
         );
         let process_call = calls
             .iter()
@@ -1050,9 +1727,7 @@ mod tests {
         let calls = incoming(
             &new_snap,
             PositionEncoding::Utf16,
-            &ItemData {
-                node: sub_decl.id.clone(),
-            },
+            &ItemData::new(sub_decl.id.clone()),
         );
         assert_eq!(calls.len(), 1, "{calls:#?}");
 
@@ -1204,9 +1879,7 @@ mod tests {
         let calls = outgoing(
             &snap,
             PositionEncoding::Utf16,
-            &ItemData {
-                node: caller.id.clone(),
-            },
+            &ItemData::new(caller.id.clone()),
         );
         assert_eq!(calls.len(), 1, "{calls:#?}");
         let to = &calls[0].to;
@@ -1370,9 +2043,7 @@ mod tests {
         let pre_edit_calls = outgoing(
             &base,
             PositionEncoding::Utf16,
-            &ItemData {
-                node: dowork.clone(),
-            },
+            &ItemData::new(dowork.clone()),
         );
         let pre_edit_process = pre_edit_calls
             .iter()
@@ -1397,9 +2068,7 @@ mod tests {
         let post_edit_calls = outgoing(
             &new_snap,
             PositionEncoding::Utf16,
-            &ItemData {
-                node: dowork.clone(),
-            },
+            &ItemData::new(dowork.clone()),
         );
         let post_edit_process = post_edit_calls
             .iter()
@@ -1415,7 +2084,7 @@ mod tests {
         // The OLD snapshot, queried AGAIN after the swap, must report the
         // EXACT SAME (pre-edit) position it did before — its cache and text
         // must not have been mutated by the later rung-1 apply.
-        let re_query_calls = outgoing(&base, PositionEncoding::Utf16, &ItemData { node: dowork });
+        let re_query_calls = outgoing(&base, PositionEncoding::Utf16, &ItemData::new(dowork));
         let re_query_process = re_query_calls
             .iter()
             .find(|c| c.to.name == "Process")
@@ -0,0 +1,225 @@
+//! `al-call-hierarchy/interfaceHierarchy` — a type-hierarchy-style view of
+//! interface implementation on the engine-backed `LspSnapshot`.
+//!
+//! Given an interface, the response lists the objects implementing it (a
+//! codeunit, or an enum whose values carry implementations); given any
+//! other object, it lists the interfaces that object implements. Both
+//! directions read the same `implements` lists (the implementer side is
+//! [`LspSnapshot::interface_implementers`], built from the resolver's
+//! [`crate::program::resolve::index::ResolveIndex`]), so the tree never
+//! disagrees with interface dispatch in the call hierarchy.
+//!
+//! # Wire shape
+//!
+//! ```jsonc
+//! // request params
+//! { "objectType": string, "objectName": string }
+//! // response: null when the object is not in the graph, else
+//! {
+//!   "name": string, "objectType": string, "objectId": number | null,
+//!   "uri": string | null,
+//!   "children": [ /* same shape, children always empty */ ]
+//! }
+//! ```
+//! `objectType` is case-insensitive on the way in (`codeunit`, `Interface`,
+//! …) and the AL spelling on the way out. `uri` is the declaring workspace
+//! file; `null` for a dependency object, which has no workspace file.
+
+use al_syntax::IdentifierFoldExt;
+use al_syntax::ir::ObjectKind;
+use serde::{Deserialize, Serialize};
+
+use crate::config::parse_object_type;
+use crate::lsp::snapshot::LspSnapshot;
+use crate::program::graph::ProgramGraph;
+use crate::program::node_extract::ObjectNode;
+use crate::protocol::path_to_uri;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterfaceHierarchyParams {
+    pub object_type: String,
+    pub object_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterfaceHierarchyItem {
+    pub name: String,
+    pub object_type: String,
+    pub object_id: Option<i64>,
+    pub uri: Option<String>,
+    pub children: Vec<InterfaceHierarchyItem>,
+}
+
+/// Every object implementing `interface` (by id, so a same-named interface
+/// in another app contributes nothing), in graph order. Empty when
+/// `interface` is not an interface.
+#[must_use]
+pub fn get_implementations<'g>(
+    snap: &'g LspSnapshot,
+    interface: &ObjectNode,
+) -> Vec<&'g ObjectNode> {
+    let graph = &snap.graph;
+    snap.interface_implementers
+        .get(&interface.id)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|id| {
+            graph
+                .objects
+                .binary_search_by(|probe| probe.id.cmp(id))
+                .ok()
+                .map(|i| &graph.objects[i])
+        })
+        .collect()
+}
+
+/// The interfaces `object` implements, resolved from its own app. An
+/// `implements` name that does not resolve to exactly one visible
+/// interface is skipped.
+#[must_use]
+pub fn get_interfaces_of<'g>(graph: &'g ProgramGraph, object: &ObjectNode) -> Vec<&'g ObjectNode> {
+    object
+        .implements
+        .iter()
+        .filter_map(|name| graph.resolve_object(object.id.app, ObjectKind::Interface, name))
+        .collect()
+}
+
+/// `al-call-hierarchy/interfaceHierarchy`. The named object with its
+/// implementers (for an interface) or its interfaces (for anything else) as
+/// children; `None` when the type is unknown or the object does not resolve
+/// from the workspace app.
+#[must_use]
+pub fn interface_hierarchy(
+    snap: &LspSnapshot,
+    params: &InterfaceHierarchyParams,
+) -> Option<InterfaceHierarchyItem> {
+    let kind = parse_object_type(&params.object_type)?;
+    let workspace_app = snap.graph.apps.find(&snap.snap.workspace_app)?;
+    let object = snap
+        .graph
+        .resolve_object(workspace_app, kind, &params.object_name)?;
+    let related = if kind == ObjectKind::Interface {
+        get_implementations(snap, object)
+    } else {
+        get_interfaces_of(&snap.graph, object)
+    };
+    let mut item = hierarchy_item(snap, object);
+    item.children = related
+        .into_iter()
+        .map(|o| hierarchy_item(snap, o))
+        .collect();
+    Some(item)
+}
+
+fn hierarchy_item(snap: &LspSnapshot, object: &ObjectNode) -> InterfaceHierarchyItem {
+    InterfaceHierarchyItem {
+        name: object.name.clone(),
+        // `ObjectKind`'s variant names are the AL spellings.
+        object_type: format!("{:?}", object.id.kind),
+        object_id: object.declared_id,
+        uri: declaring_file_uri(snap, object),
+        children: Vec::new(),
+    }
+}
+
+/// The workspace file declaring `object`, or `None` for a dependency object.
+fn declaring_file_uri(snap: &LspSnapshot, object: &ObjectNode) -> Option<String> {
+    if snap.graph.apps.find(&snap.snap.workspace_app) != Some(object.id.app) {
+        return None;
+    }
+    let mut paths: Vec<&String> = snap
+        .parsed
+        .iter()
+        .filter(|(_, entry)| {
+            entry
+                .file
+                .objects
+                .iter()
+                .any(|o| o.kind == object.id.kind && o.name.eq_fold_identifier(&object.name))
+        })
+        .map(|(path, _)| path)
+        .collect();
+    paths.sort();
+    let path = paths.first()?;
+    Some(
+        path_to_uri(&snap.workspace_root.join(path))
+            .as_str()
+            .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_fixture(dir: &std::path::Path) {
+        std::fs::write(
+            dir.join("app.json"),
+            r#"{
+    "id": "77777777-0000-0000-0000-000000002212",
+    "name": "Interface Hierarchy Fixture",
+    "publisher": "probe",
+    "version": "1.0.0.0"
+}"#,
+        )
+        .expect("write app.json");
+        std::fs::write(
+            dir.join("IShipper.al"),
+            "interface \"IShipper\"\n{\n    procedure Ship();\n}\n",
+        )
+        .expect("write IShipper.al");
+        for (id, name) in [(50100, "Truck Shipper"), (50101, "Rail Shipper")] {
+            std::fs::write(
+                dir.join(format!("{}.al", name.replace(' ', ""))),
+                format!(
+                    "codeunit {id} \"{name}\" implements \"IShipper\"\n{{\n    procedure Ship()\n    begin\n    end;\n}}\n"
+                ),
+            )
+            .expect("write implementer");
+        }
+    }
+
+    fn params(object_type: &str, object_name: &str) -> InterfaceHierarchyParams {
+        InterfaceHierarchyParams {
+            object_type: object_type.to_string(),
+            object_name: object_name.to_string(),
+        }
+    }
+
+    #[test]
+    fn interface_lists_implementers_and_implementer_lists_interface() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_fixture(dir.path());
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let iface = interface_hierarchy(&snap, &params("interface", "IShipper"))
+            .expect("interface resolves");
+        assert_eq!(iface.object_type, "Interface");
+        assert!(
+            iface
+                .uri
+                .as_deref()
+                .is_some_and(|u| u.ends_with("IShipper.al")),
+            "workspace interface carries its file uri; got {:?}",
+            iface.uri
+        );
+        let mut implementers: Vec<&str> = iface.children.iter().map(|c| c.name.as_str()).collect();
+        implementers.sort_unstable();
+        assert_eq!(implementers, ["Rail Shipper", "Truck Shipper"]);
+        assert!(iface.children.iter().all(|c| c.object_type == "Codeunit"));
+
+        let truck = interface_hierarchy(&snap, &params("Codeunit", "truck shipper"))
+            .expect("codeunit resolves case-insensitively");
+        assert_eq!(truck.object_id, Some(50100));
+        assert_eq!(truck.children.len(), 1);
+        assert_eq!(truck.children[0].name, "IShipper");
+        assert_eq!(truck.children[0].object_type, "Interface");
+
+        assert!(interface_hierarchy(&snap, &params("Codeunit", "No Such Codeunit")).is_none());
+        assert!(interface_hierarchy(&snap, &params("Widget", "IShipper")).is_none());
+    }
+}
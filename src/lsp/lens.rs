@@ -55,7 +55,7 @@ pub fn code_lenses(
             // fail closed by skipping rather than guessing at metrics.
             continue;
         };
-        let complexity = crate::analysis::routine_complexity_ir(&entry.file.ir, routine);
+        let complexity = crate::analysis::routine_complexity_for(&entry.file.ir, routine, cfg);
         let parameter_count = parameter_count_of(routine);
         let line_count = decl.origin.end.row.saturating_sub(decl.origin.start.row) + 1;
         let ref_count = effective_incoming_count(snap, &decl.id);
@@ -4,11 +4,15 @@
 //! backend: position-encoding negotiation (H-12, this task) today, and the
 //! snapshot/updater/handlers modules later tasks add alongside it.
 
+pub mod call_tree;
 pub mod custom;
 pub mod def_surface;
 pub mod diagnostics;
+pub mod dot;
 pub mod encoding;
 pub mod handlers;
+pub mod interfaces;
 pub mod lens;
 pub mod snapshot;
+pub mod symbols;
 pub mod updater;
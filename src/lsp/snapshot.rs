@@ -45,22 +45,27 @@
 //! `RoutineNodeId` fixture — any future change that breaks that gate breaks
 //! the license, not just a test.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock};
+use std::time::Instant;
 
 use al_syntax::IdentifierFoldExt;
 use al_syntax::ir::AlFile;
 use rayon::prelude::*;
+use serde::Serialize;
 
 use crate::lsp::def_surface::{DefSurface, def_surface_fingerprint};
 use crate::lsp::encoding::LineTable;
 use crate::program::node::{AppRef, ObjKey, ObjectNodeId, RoutineNodeId};
 use crate::program::node_extract::ObjectNode;
 use crate::program::resolve::decl_surface::{DeclSurface, DepMetaMap};
-use crate::program::resolve::edge::{Edge, RouteTarget};
+use crate::program::resolve::edge::{Edge, ResolutionKind, RouteTarget};
 use crate::program::resolve::emit_event_flow_edges;
-use crate::program::resolve::full::{ClassifiedEdge, ObligationId, ProgramContext, build_context};
+use crate::program::resolve::event::ParsedSubscriberArgs;
+use crate::program::resolve::full::{
+    ClassifiedEdge, ObligationId, ProgramContext, build_context, build_context_timed,
+};
 use crate::program::resolve::index::ResolveIndex;
 use crate::program::sig_fp::source_routine_node_id;
 use crate::program::{DepLayer, ProgramGraph};
@@ -108,6 +113,100 @@ pub struct DeclEntry {
     /// Name-token span (`CallHierarchyItem.selectionRange`).
     pub name_origin: al_syntax::ir::Origin,
     pub virtual_path: String,
+    /// Attribute names in source order and raw casing (`EventSubscriber`,
+    /// `Test`, `TryFunction`, ...), shown in `CallHierarchyItem.detail`.
+    pub attributes: Vec<String>,
+    /// Parameters in declaration order, shown in `CallHierarchyItem.detail`.
+    pub params: Vec<DeclParam>,
+}
+
+/// One parameter of a [`DeclEntry`], as written in the declaration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeclParam {
+    pub name: String,
+    /// Declared type text, quotes kept (`Record "Sales Header"`); `None`
+    /// when the parameter has no type.
+    pub ty: Option<String>,
+    /// `var` (by-reference) parameter.
+    pub by_ref: bool,
+}
+
+/// `params` as AL writes them between a procedure's parentheses:
+/// `Customer: Record Customer; var Amount: Decimal`.
+#[must_use]
+pub fn param_list(params: &[DeclParam]) -> String {
+    params
+        .iter()
+        .map(|p| {
+            let var = if p.by_ref { "var " } else { "" };
+            match &p.ty {
+                Some(ty) => format!("{var}{}: {ty}", p.name),
+                None => format!("{var}{}", p.name),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl DeclEntry {
+    /// This declaration's bookmark id ([`RoutineNodeId::stable_id`]):
+    /// unchanged when only its position moves.
+    pub fn stable_id(&self) -> String {
+        self.id.stable_id()
+    }
+}
+
+/// Role of a workspace routine declaration, classified from its
+/// [`crate::program::node_extract::RoutineNode`] — the program-engine
+/// successor of the legacy `DefinitionKind`. Precedence when several apply:
+/// `Trigger`, then `EventSubscriber`, then `EventPublisher`, else `Procedure`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DefinitionKind {
+    Procedure,
+    Trigger,
+    EventSubscriber,
+    EventPublisher,
+}
+
+/// Layering classification of a workspace routine relative to the UI entry
+/// points — page/report (and their extensions') triggers, including action
+/// triggers. Informational only (see [`LspSnapshot::ui_reachability`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UiReachability {
+    /// Reachable from at least one UI entry point.
+    ReachableFromUi,
+    /// Not reachable from any UI entry point — only backend code (codeunits,
+    /// tables, event subscribers of backend publishers), or nothing, calls it.
+    BackendOnly,
+}
+
+/// A workspace variable typed as a dependency object that nothing in its
+/// scope uses (see [`LspSnapshot::unused_external_refs`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnusedExternalRef {
+    pub virtual_path: String,
+    /// 1-based line of the variable declaration.
+    pub line: u32,
+    /// Kind and display name of the object declaring the variable.
+    pub owner_kind: al_syntax::ir::ObjectKind,
+    pub owner_name: String,
+    /// The declaring routine for a local, `None` for a global.
+    pub routine: Option<String>,
+    pub variable: String,
+    /// The declared type as written, e.g. `Codeunit "Sales-Post"`.
+    pub type_text: String,
+}
+
+/// `true` for the object kinds whose triggers are UI entry points.
+fn is_ui_object_kind(kind: al_syntax::ir::ObjectKind) -> bool {
+    use al_syntax::ir::ObjectKind;
+    matches!(
+        kind,
+        ObjectKind::Page
+            | ObjectKind::PageExtension
+            | ObjectKind::Report
+            | ObjectKind::ReportExtension
+    )
 }
 
 /// A borrowed, source-agnostic view of one routine declaration's LSP-facing
@@ -126,6 +225,12 @@ pub struct DeclView<'a> {
     /// Name-token span (`CallHierarchyItem.selectionRange`).
     pub name_origin: &'a al_syntax::ir::Origin,
     pub virtual_path: &'a str,
+    /// Attribute names (see [`DeclEntry::attributes`]); always empty for a
+    /// dependency decl — `dep_meta` does not retain them.
+    pub attributes: &'a [String],
+    /// Parameters (see [`DeclEntry::params`]); `None` for a dependency decl,
+    /// whose `dep_meta` does not retain them either.
+    pub params: Option<&'a [DeclParam]>,
 }
 
 impl<'a> DeclView<'a> {
@@ -137,6 +242,8 @@ impl<'a> DeclView<'a> {
             origin: &e.origin,
             name_origin: &e.name_origin,
             virtual_path: &e.virtual_path,
+            attributes: &e.attributes,
+            params: Some(&e.params),
         }
     }
 }
@@ -303,6 +410,13 @@ pub struct LspSnapshot {
     /// recomputing (it used to be recomputed anyway, wastefully, alongside
     /// `incoming` — see `apply_rung1_core`'s own doc for the fix).
     pub publisher_fanout: Arc<HashMap<RoutineNodeId, usize>>,
+    /// DERIVED from `graph` via
+    /// [`ResolveIndex::implementers_by_interface_id`]: each interface's
+    /// implementers, keyed by the interface's id, for the
+    /// `interfaceHierarchy` request ([`crate::lsp::interfaces`]). Rebuilt
+    /// with the graph at rung 2/3; `Arc`-forwarded at rung 1, which reuses
+    /// `graph` unchanged.
+    pub interface_implementers: Arc<HashMap<ObjectNodeId, Vec<ObjectNodeId>>>,
     /// Sorted by `origin.byte.start` within each file. `Arc`-wrapped per file
     /// (T3 Task 9) so an incremental rung-1/rung-2 rebuild can share every
     /// UNCHANGED file's decl list via a cheap `Arc::clone` instead of
@@ -360,6 +474,22 @@ pub struct LspSnapshot {
     pub workspace_root: Arc<PathBuf>,
 }
 
+/// Phase timings and size counts for one [`LspSnapshot::build_full_profiled`]
+/// build, serialized as the CLI's `--profile` JSON.
+#[derive(Clone, Debug, Serialize)]
+pub struct BuildProfile {
+    /// Workspace source walk plus dependency `.app` loading.
+    pub walk_ms: u64,
+    /// Parsing every source-bearing app.
+    pub parse_ms: u64,
+    /// Graph assembly, per-file resolution and index derivation.
+    pub build_ms: u64,
+    /// Workspace source files indexed.
+    pub files: usize,
+    /// Workspace routine declarations indexed.
+    pub definitions: usize,
+}
+
 impl LspSnapshot {
     /// Full batch build — snapshot → dep layer → assemble → resolve per file
     /// → derive indexes. Returns `None` when the underlying snapshot/program
@@ -371,6 +501,35 @@ impl LspSnapshot {
         Some(Self::from_context(ctx, workspace_root).0)
     }
 
+    /// As [`Self::build_full`], but declarations only: no per-file call
+    /// resolution and no event-flow edges, so `edges_by_file`,
+    /// `event_edges` and `incoming` stay empty. For definition-only
+    /// consumers (outline, symbol listing) that never walk the call graph,
+    /// skipping resolution is most of the build's cost.
+    #[must_use]
+    pub fn build_symbols_only(workspace_root: &Path) -> Option<LspSnapshot> {
+        let ctx = build_context(workspace_root)?;
+        Some(Self::assemble(ctx, workspace_root, false).0)
+    }
+
+    /// As [`Self::build_full`], but also reports how long each build phase
+    /// took, plus the workspace file and declaration counts.
+    #[must_use]
+    pub fn build_full_profiled(workspace_root: &Path) -> Option<(LspSnapshot, BuildProfile)> {
+        let (ctx, timings) = build_context_timed(workspace_root).ok()?;
+        let t = Instant::now();
+        let snap = Self::from_context(ctx, workspace_root).0;
+        let build = timings.build + t.elapsed();
+        let profile = BuildProfile {
+            walk_ms: timings.walk.as_millis() as u64,
+            parse_ms: timings.parse.as_millis() as u64,
+            build_ms: build.as_millis() as u64,
+            files: snap.parsed.len(),
+            definitions: snap.decls_by_file.values().map(|v| v.len()).sum(),
+        };
+        Some((snap, profile))
+    }
+
     /// As [`Self::build_full`], but ALSO returns the ONE workspace
     /// [`ParsedUnit`] for T3 Task 9's incremental updater
     /// (`src/lsp/updater.rs`) to own as its mutable working state.
@@ -429,6 +588,16 @@ impl LspSnapshot {
     pub(crate) fn from_context(
         ctx: ProgramContext,
         workspace_root: &Path,
+    ) -> (LspSnapshot, ParsedUnit) {
+        Self::assemble(ctx, workspace_root, true)
+    }
+
+    /// [`Self::from_context`]'s body; `resolve_calls: false` is
+    /// [`Self::build_symbols_only`]'s declarations-only build.
+    fn assemble(
+        ctx: ProgramContext,
+        workspace_root: &Path,
+        resolve_calls: bool,
     ) -> (LspSnapshot, ParsedUnit) {
         let ProgramContext {
             snap,
@@ -458,11 +627,13 @@ impl LspSnapshot {
         let event_edges: Arc<Vec<ClassifiedEdge>>;
         let dep_texts: HashMap<(AppRef, String), Arc<str>>;
         let dep_meta: Arc<DepMetaMap>;
+        let interface_implementers: HashMap<ObjectNodeId, Vec<ObjectNodeId>>;
 
         {
             let obj_node_map: HashMap<ObjectNodeId, &ObjectNode> =
                 graph.objects.iter().map(|o| (o.id.clone(), o)).collect();
             let index = ResolveIndex::build(&graph);
+            interface_implementers = index.implementers_by_interface_id(&graph);
             // Build the two-tier surface with the dependency tier already
             // split out (T3 Task 12) — `build_split` fuses the old
             // `DeclSurface::build` + `freeze_dep_tier` into one pass,
@@ -496,6 +667,13 @@ impl LspSnapshot {
                         files
                             .par_iter()
                             .map(|pf| {
+                                if !resolve_calls {
+                                    return (
+                                        Vec::new(),
+                                        def_surface_fingerprint(pf),
+                                        file_decls(pf, primary_app_ref),
+                                    );
+                                }
                                 recompute_file(
                                     pf,
                                     primary_app_ref,
@@ -514,13 +692,19 @@ impl LspSnapshot {
                 }
             }
 
-            let raw_event_edges = emit_event_flow_edges(&graph, &index, &surface);
+            let raw_event_edges = if resolve_calls {
+                emit_event_flow_edges(&graph, &index, &surface)
+            } else {
+                Vec::new()
+            };
             event_edges = Arc::new(
                 raw_event_edges
                     .into_iter()
                     .map(|edge| ClassifiedEdge {
                         obligation_id: ObligationId::Publisher(edge.from.clone()),
                         edge,
+                        referenced_objects: Vec::new(),
+                        resolution_kind: Some(ResolutionKind::Event),
                     })
                     .collect(),
             );
@@ -573,6 +757,7 @@ impl LspSnapshot {
             event_edges,
             incoming,
             publisher_fanout: Arc::new(publisher_fanout),
+            interface_implementers: Arc::new(interface_implementers),
             decls_by_file,
             decl_by_id,
             dep_texts: Arc::new(dep_texts),
@@ -632,6 +817,352 @@ impl LspSnapshot {
         decls.iter().find(|d| point_in_origin(pos, &d.origin))
     }
 
+    /// Classify one routine by its graph node (see [`DefinitionKind`]). An id
+    /// with no `graph.routines` entry falls back to `Procedure`.
+    #[must_use]
+    pub fn definition_kind(&self, id: &RoutineNodeId) -> DefinitionKind {
+        let node = self
+            .graph
+            .routines
+            .binary_search_by(|probe| probe.id.cmp(id))
+            .ok()
+            .map(|i| &self.graph.routines[i]);
+        match node {
+            Some(n) if n.is_trigger => DefinitionKind::Trigger,
+            Some(n) if !n.event_subscribers.is_empty() => DefinitionKind::EventSubscriber,
+            Some(n) if n.publisher_kind.is_some() => DefinitionKind::EventPublisher,
+            _ => DefinitionKind::Procedure,
+        }
+    }
+
+    /// Every workspace declaration of the given [`DefinitionKind`], ordered by
+    /// `virtual_path` then source position (deterministic across builds —
+    /// `decls_by_file` is a `HashMap`).
+    #[must_use]
+    pub fn definitions_by_kind(&self, kind: DefinitionKind) -> Vec<&DeclEntry> {
+        let mut out: Vec<&DeclEntry> = self
+            .decls_by_file
+            .values()
+            .flat_map(|v| v.iter())
+            .filter(|d| self.definition_kind(&d.id) == kind)
+            .collect();
+        out.sort_by(|a, b| {
+            (a.virtual_path.as_str(), a.origin.byte.start)
+                .cmp(&(b.virtual_path.as_str(), b.origin.byte.start))
+        });
+        out
+    }
+
+    /// Every workspace event subscription: one `(subscriber, attribute)` pair
+    /// per `[EventSubscriber]` (a routine subscribing to several events
+    /// appears once per event), in [`Self::definitions_by_kind`]'s order.
+    /// The attribute's publisher type, object and event names are lowercased.
+    #[must_use]
+    pub fn event_subscriptions(&self) -> Vec<(&DeclEntry, &ParsedSubscriberArgs)> {
+        self.definitions_by_kind(DefinitionKind::EventSubscriber)
+            .into_iter()
+            .filter_map(|d| {
+                let i = self
+                    .graph
+                    .routines
+                    .binary_search_by(|probe| probe.id.cmp(&d.id))
+                    .ok()?;
+                Some((d, &self.graph.routines[i]))
+            })
+            .flat_map(|(d, node)| node.event_subscribers.iter().map(move |args| (d, args)))
+            .collect()
+    }
+
+    /// Classify every workspace declaration as [`UiReachability::ReachableFromUi`]
+    /// or [`UiReachability::BackendOnly`]. Seeds are the triggers of page/report
+    /// objects and their extensions; reachability follows every default-firing
+    /// `Routine` route of the workspace `Call`/`Run`/`ImplicitTrigger` edges and
+    /// the event-flow edges (manual-binding and ambiguous routes are excluded,
+    /// as in [`Edge::default_reachable_routes`]).
+    #[must_use]
+    pub fn ui_reachability(&self) -> HashMap<RoutineNodeId, UiReachability> {
+        let forward = self.default_routine_successors();
+        let decls = || self.decls_by_file.values().flat_map(|v| v.iter());
+        let mut stack: Vec<&RoutineNodeId> = decls()
+            .map(|d| &d.id)
+            .filter(|id| {
+                is_ui_object_kind(id.object.kind)
+                    && self.definition_kind(id) == DefinitionKind::Trigger
+            })
+            .collect();
+        let mut reached: HashSet<&RoutineNodeId> = HashSet::new();
+        while let Some(id) = stack.pop() {
+            if reached.insert(id)
+                && let Some(next) = forward.get(id)
+            {
+                stack.extend(next.iter().copied());
+            }
+        }
+
+        decls()
+            .map(|d| {
+                let class = if reached.contains(&d.id) {
+                    UiReachability::ReachableFromUi
+                } else {
+                    UiReachability::BackendOnly
+                };
+                (d.id.clone(), class)
+            })
+            .collect()
+    }
+
+    /// Every workspace declaration reached by at least one test procedure,
+    /// mapped to those tests (the CLI's `--test-coverage`). Test procedures
+    /// are the `[Test]` routines of `Subtype = Test` codeunits; they are
+    /// never keys themselves, and each value lists its tests in
+    /// [`Self::definitions_by_kind`] order. Reachability follows the same
+    /// default-firing routes as [`Self::ui_reachability`].
+    #[must_use]
+    pub fn tested_by(&self) -> HashMap<RoutineNodeId, Vec<RoutineNodeId>> {
+        let forward = self.default_routine_successors();
+        let mut tests: Vec<&DeclEntry> = self
+            .decls_by_file
+            .values()
+            .flat_map(|v| v.iter())
+            .filter(|d| self.is_test_procedure(d))
+            .collect();
+        tests.sort_by(|a, b| {
+            (a.virtual_path.as_str(), a.origin.byte.start)
+                .cmp(&(b.virtual_path.as_str(), b.origin.byte.start))
+        });
+        let test_ids: HashSet<&RoutineNodeId> = tests.iter().map(|d| &d.id).collect();
+
+        let mut out: HashMap<RoutineNodeId, Vec<RoutineNodeId>> = HashMap::new();
+        for test in &tests {
+            let mut stack: Vec<&RoutineNodeId> = vec![&test.id];
+            let mut reached: HashSet<&RoutineNodeId> = HashSet::new();
+            while let Some(id) = stack.pop() {
+                if reached.insert(id)
+                    && let Some(next) = forward.get(id)
+                {
+                    stack.extend(next.iter().copied());
+                }
+            }
+            for id in reached {
+                if !test_ids.contains(id) && self.decl_by_id.contains_key(id) {
+                    out.entry(id.clone()).or_default().push(test.id.clone());
+                }
+            }
+        }
+        out
+    }
+
+    /// For every workspace routine with at least one resolved call into
+    /// another object, the number of distinct objects its `Call`/`Run`/
+    /// `ImplicitTrigger` edges route to — a coupling measure that, unlike
+    /// fan-out, counts ten calls into one codeunit once. Calls within the
+    /// routine's own object, builtins and unresolved routes do not count;
+    /// a dependency's symbol-only routine counts as its object. Event
+    /// subscribers are not the publisher's callees here.
+    #[must_use]
+    pub fn coupling(&self) -> HashMap<RoutineNodeId, usize> {
+        let mut called: HashMap<&RoutineNodeId, HashSet<&ObjectNodeId>> = HashMap::new();
+        for ce in self.edges_by_file.values().flat_map(|v| v.iter()) {
+            let from = &ce.edge.from;
+            for route in &ce.edge.routes {
+                if let Some(object) = self
+                    .route_object(&route.target)
+                    .filter(|&o| o != &from.object)
+                {
+                    called.entry(from).or_default().insert(object);
+                }
+            }
+        }
+        called
+            .into_iter()
+            .map(|(id, objects)| (id.clone(), objects.len()))
+            .collect()
+    }
+
+    /// `id`'s outgoing call sites grouped by the object they call into —
+    /// its own object included, so a client can show "calls Customer (3),
+    /// Vendor (1), local (2)". Counted like [`Self::coupling`]: builtin and
+    /// unresolved routes have no object, and an ambiguous call site is listed
+    /// once under each distinct object its routes reach. Empty for a routine
+    /// without a workspace declaration.
+    #[must_use]
+    pub fn outgoing_calls_by_object(
+        &self,
+        id: &RoutineNodeId,
+    ) -> BTreeMap<&ObjectNodeId, Vec<&ClassifiedEdge>> {
+        let mut out: BTreeMap<&ObjectNodeId, Vec<&ClassifiedEdge>> = BTreeMap::new();
+        let Some(edges) = self
+            .decl_by_id
+            .get(id)
+            .and_then(|d| self.edges_by_file.get(&d.virtual_path))
+        else {
+            return out;
+        };
+        for ce in edges.iter().filter(|ce| &ce.edge.from == id) {
+            let objects: BTreeSet<&ObjectNodeId> = ce
+                .edge
+                .routes
+                .iter()
+                .filter_map(|route| self.route_object(&route.target))
+                .collect();
+            for object in objects {
+                out.entry(object).or_default().push(ce);
+            }
+        }
+        out
+    }
+
+    /// The object a route lands in: a routine's own object, or the object
+    /// a dependency's symbol-only routine belongs to. `None` for builtin and
+    /// unresolved routes.
+    fn route_object<'s>(&'s self, target: &'s RouteTarget) -> Option<&'s ObjectNodeId> {
+        use crate::program::abi_ingest::object_kind_from_abi_type;
+
+        match target {
+            RouteTarget::Routine(to) => Some(&to.object),
+            RouteTarget::AbiSymbol { key } => self
+                .graph
+                .resolve_object(
+                    key.app,
+                    object_kind_from_abi_type(&key.object_type),
+                    &key.object_name_lc,
+                )
+                .map(|o| &o.id),
+            _ => None,
+        }
+    }
+
+    /// `true` for a `[Test]` routine declared in a `Subtype = Test` codeunit.
+    #[must_use]
+    pub fn is_test_procedure(&self, d: &DeclEntry) -> bool {
+        if d.id.object.kind != al_syntax::ir::ObjectKind::Codeunit
+            || !d.attributes.iter().any(|a| a.eq_ignore_ascii_case("Test"))
+        {
+            return false;
+        }
+        let Some(entry) = self.parsed.get(&d.virtual_path) else {
+            return false;
+        };
+        entry.file.objects.iter().any(|o| {
+            o.kind == al_syntax::ir::ObjectKind::Codeunit
+                && match &d.id.object.key {
+                    ObjKey::Id(n) => o.id == Some(*n),
+                    ObjKey::Name(n) => o.name.eq_fold_identifier(n),
+                }
+                && o.properties.iter().any(|p| {
+                    p.name.eq_ignore_ascii_case("Subtype")
+                        && p.value.trim().eq_ignore_ascii_case("Test")
+                })
+        })
+    }
+
+    /// Workspace variables (globals and locals) typed as a codeunit, page,
+    /// report, query or xmlport from a dependency app that nothing in their
+    /// scope names — no member call on them, no use as an argument (a
+    /// global's scope is every routine of its object) — a sign the
+    /// dependency may be unnecessary. Records are not checked: they are
+    /// almost always used. In path and line order.
+    #[must_use]
+    pub fn unused_external_refs(&self) -> Vec<UnusedExternalRef> {
+        use crate::analysis::body_identifiers_ir;
+        use crate::program::node_extract::ObjectRef;
+        use crate::program::resolve::receiver::{
+            ParsedType, array_element_type_text, classify_type_text,
+        };
+        use al_syntax::ir::ObjectKind;
+
+        let Some(workspace_app) = self.graph.apps.find(&self.snap.workspace_app) else {
+            return Vec::new();
+        };
+
+        let is_external = |ty: &str| -> bool {
+            let ty = array_element_type_text(ty).unwrap_or(ty);
+            let ParsedType::Object { kind, object_ref } = classify_type_text(ty) else {
+                return false;
+            };
+            if !matches!(
+                kind,
+                ObjectKind::Codeunit
+                    | ObjectKind::Page
+                    | ObjectKind::Report
+                    | ObjectKind::Query
+                    | ObjectKind::XmlPort
+            ) {
+                return false;
+            }
+            let object = match object_ref {
+                ObjectRef::Name { raw, .. } => self.graph.resolve_object(workspace_app, kind, &raw),
+                ObjectRef::Id(n) => self.graph.resolve_object_by_number(workspace_app, kind, n),
+            };
+            object.is_some_and(|o| o.id.app != workspace_app)
+        };
+
+        let mut paths: Vec<&String> = self.parsed.keys().collect();
+        paths.sort();
+        let mut out = Vec::new();
+        for path in paths {
+            let entry = &self.parsed[path];
+            for obj in &entry.file.objects {
+                let owner_name = obj.name.trim_matches('"');
+                let bodies: Vec<HashSet<String>> = obj
+                    .routines
+                    .iter()
+                    .map(|r| body_identifiers_ir(&entry.text, &entry.file.ir, r))
+                    .collect();
+                let scoped = obj.globals.iter().map(|v| (None, v)).chain(
+                    obj.routines
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(i, r)| r.locals.iter().map(move |v| (Some(i), v))),
+                );
+                for (routine, var) in scoped {
+                    let Some(ty) = var.ty.as_deref() else {
+                        continue;
+                    };
+                    let name_lc = var.name.trim_matches('"').to_lowercase();
+                    let named = match routine {
+                        Some(i) => bodies[i].contains(&name_lc),
+                        None => bodies.iter().any(|b| b.contains(&name_lc)),
+                    };
+                    if !named && is_external(ty) {
+                        let routine = routine.map(|i| obj.routines[i].name.trim_matches('"'));
+                        out.push(UnusedExternalRef {
+                            virtual_path: path.clone(),
+                            line: var.origin.start.row + 1,
+                            owner_kind: obj.kind,
+                            owner_name: owner_name.to_string(),
+                            routine: routine.map(str::to_string),
+                            variable: var.name.trim_matches('"').to_string(),
+                            type_text: ty.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+        out.sort_by(|a, b| (&a.virtual_path, a.line).cmp(&(&b.virtual_path, b.line)));
+        out
+    }
+
+    /// Forward adjacency over every default-firing `Routine` route of the
+    /// workspace `Call`/`Run`/`ImplicitTrigger` edges and the event-flow
+    /// edges (see [`Edge::default_reachable_routes`]).
+    fn default_routine_successors(&self) -> HashMap<&RoutineNodeId, Vec<&RoutineNodeId>> {
+        let mut forward: HashMap<&RoutineNodeId, Vec<&RoutineNodeId>> = HashMap::new();
+        for ce in self
+            .edges_by_file
+            .values()
+            .flat_map(|v| v.iter())
+            .chain(self.event_edges.iter())
+        {
+            for route in ce.edge.default_reachable_routes() {
+                if let RouteTarget::Routine(to) = &route.target {
+                    forward.entry(&ce.edge.from).or_default().push(to);
+                }
+            }
+        }
+        forward
+    }
+
     /// Look up one classified edge by its [`EdgeRef`].
     #[must_use]
     pub fn edge(&self, r: &EdgeRef) -> &ClassifiedEdge {
@@ -667,6 +1198,8 @@ impl LspSnapshot {
                 origin: &m.origin,
                 name_origin: &m.name_origin,
                 virtual_path: &m.virtual_path,
+                attributes: &[],
+                params: None,
             },
             text.as_ref(),
         ))
@@ -710,6 +1243,8 @@ impl LspSnapshot {
                 origin: &m.origin,
                 name_origin: &m.name_origin,
                 virtual_path: &m.virtual_path,
+                attributes: &[],
+                params: None,
             },
             DeclLineTable::Owned(LineTable::new(Arc::clone(text))),
         ))
@@ -865,6 +1400,12 @@ pub(crate) fn recompute_file(
     );
     let def_surface = def_surface_fingerprint(pf);
 
+    (file_res.edges, def_surface, file_decls(pf, primary_app_ref))
+}
+
+/// Every routine declared in `pf`, in source order — [`recompute_file`]'s
+/// decl half, also run alone by [`LspSnapshot::build_symbols_only`].
+fn file_decls(pf: &ParsedFile, primary_app_ref: AppRef) -> Vec<DeclEntry> {
     let mut decls: Vec<DeclEntry> = Vec::new();
     for obj in &pf.file.objects {
         let obj_key = match obj.id {
@@ -884,12 +1425,25 @@ pub(crate) fn recompute_file(
                 origin: routine.origin.clone(),
                 name_origin: routine.name_origin.clone(),
                 virtual_path: pf.virtual_path.clone(),
+                attributes: routine
+                    .attributes_parsed
+                    .iter()
+                    .map(|a| a.name.clone())
+                    .collect(),
+                params: routine
+                    .params
+                    .iter()
+                    .map(|p| DeclParam {
+                        name: p.name.clone(),
+                        ty: p.ty.clone(),
+                        by_ref: p.by_ref,
+                    })
+                    .collect(),
             });
         }
     }
     decls.sort_by_key(|d| d.origin.byte.start);
-
-    (file_res.edges, def_surface, decls)
+    decls
 }
 
 /// DERIVED index (see [`LspSnapshot::decl_by_id`]'s doc): every `DeclEntry`
@@ -1115,6 +1669,143 @@ mod tests {
 
     // ── determinism across two builds (generation excluded) ───────────────
 
+    #[test]
+    fn symbols_only_build_has_every_declaration_and_no_call_sites() {
+        let dir = fixture_dir();
+        let full = LspSnapshot::build_full(dir.path()).expect("build_full");
+        let symbols = LspSnapshot::build_symbols_only(dir.path()).expect("symbols-only build");
+
+        let ids = |snap: &LspSnapshot| {
+            let mut ids: Vec<RoutineNodeId> = snap.decl_by_id.keys().cloned().collect();
+            ids.sort();
+            ids
+        };
+        assert!(!symbols.decl_by_id.is_empty());
+        assert_eq!(ids(&symbols), ids(&full));
+        assert!(full.edges_by_file.values().any(|edges| !edges.is_empty()));
+        assert!(symbols.edges_by_file.values().all(|edges| edges.is_empty()));
+        assert!(symbols.event_edges.is_empty());
+        assert!(symbols.incoming.is_empty());
+    }
+
+    #[test]
+    fn declarations_keep_their_parameter_signatures() {
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("Delta.al"),
+            r#"codeunit 50103 "Delta"
+{
+    procedure Post(Customer: Record Customer; var Amount: Decimal; var Header: Record "Sales Header"; Qty: Integer)
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write Delta.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let post = snap.decls_by_file["Delta.al"]
+            .iter()
+            .find(|d| d.name == "Post")
+            .expect("Delta.Post decl");
+        let param = |name: &str, ty: &str, by_ref: bool| DeclParam {
+            name: name.to_string(),
+            ty: Some(ty.to_string()),
+            by_ref,
+        };
+        assert_eq!(
+            post.params,
+            [
+                param("Customer", "Record Customer", false),
+                param("Amount", "Decimal", true),
+                param("Header", "Record \"Sales Header\"", true),
+                param("Qty", "Integer", false),
+            ]
+        );
+        assert_eq!(
+            param_list(&post.params),
+            "Customer: Record Customer; var Amount: Decimal; var Header: Record \"Sales Header\"; \
+             Qty: Integer"
+        );
+        let untyped = DeclParam {
+            name: "X".to_string(),
+            ty: None,
+            by_ref: true,
+        };
+        assert_eq!(param_list(&[untyped]), "var X");
+        assert_eq!(param_list(&[]), "");
+    }
+
+    #[test]
+    fn event_subscriptions_list_each_subscriber_with_its_target() {
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("Delta.al"),
+            r#"codeunit 50103 "Delta"
+{
+    [EventSubscriber(ObjectType::Codeunit, Codeunit::"Beta", 'OnAfterProcess', '', false, false)]
+    local procedure AlsoAfterProcess()
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write Delta.al");
+        let snap = LspSnapshot::build_symbols_only(dir.path()).expect("symbols-only build");
+
+        let listed: Vec<(&str, &str, &str, &str, &str)> = snap
+            .event_subscriptions()
+            .into_iter()
+            .map(|(d, args)| {
+                (
+                    d.virtual_path.as_str(),
+                    d.name.as_str(),
+                    args.publisher_object_type.as_str(),
+                    args.publisher_name.as_str(),
+                    args.event_name.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            listed,
+            [
+                (
+                    "Delta.al",
+                    "AlsoAfterProcess",
+                    "codeunit",
+                    "beta",
+                    "onafterprocess"
+                ),
+                (
+                    "Gamma.al",
+                    "HandleAfterProcess",
+                    "codeunit",
+                    "beta",
+                    "onafterprocess"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn build_full_profiled_reports_every_profile_field() {
+        let dir = fixture_dir();
+        let (snap, profile) = LspSnapshot::build_full_profiled(dir.path()).expect("profiled build");
+
+        let json = serde_json::to_value(&profile).expect("serialize profile");
+        for key in ["walk_ms", "parse_ms", "build_ms", "files", "definitions"] {
+            assert!(
+                json.get(key).is_some_and(|v| v.is_u64()),
+                "{key} missing: {json}"
+            );
+        }
+        assert_eq!(profile.files, snap.parsed.len());
+        assert_eq!(
+            profile.definitions,
+            snap.decls_by_file.values().map(|v| v.len()).sum::<usize>()
+        );
+    }
+
     #[test]
     fn build_full_is_deterministic_across_two_builds() {
         let dir = fixture_dir();
@@ -1443,6 +2134,8 @@ mod tests {
                     callee_fp: edge.site.callee_fingerprint,
                 },
                 edge,
+                referenced_objects: Vec::new(),
+                resolution_kind: None,
             }]),
         );
 
@@ -1532,6 +2225,8 @@ mod tests {
                         callee_fp: edge_a.site.callee_fingerprint,
                     },
                     edge: edge_a,
+                    referenced_objects: Vec::new(),
+                    resolution_kind: None,
                 },
                 ClassifiedEdge {
                     obligation_id: ObligationId::CallSite {
@@ -1540,6 +2235,8 @@ mod tests {
                         callee_fp: edge_b.site.callee_fingerprint,
                     },
                     edge: edge_b,
+                    referenced_objects: Vec::new(),
+                    resolution_kind: None,
                 },
             ]),
         );
@@ -1610,4 +2307,614 @@ mod tests {
             );
         }
     }
+
+    // ── definitions_by_kind ────────────────────────────────────────────────
+
+    #[test]
+    fn ui_reachability_separates_ui_reachable_from_backend_only() {
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("CustCard.al"),
+            r#"page 50111 "Cust Card"
+{
+    actions
+    {
+        area(Processing)
+        {
+            action(Process)
+            {
+                trigger OnAction()
+                var
+                    Beta: Codeunit "Beta";
+                begin
+                    Beta.Process();
+                end;
+            }
+        }
+    }
+}
+"#,
+        )
+        .expect("write CustCard.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+        let classes = snap.ui_reachability();
+
+        let class_of = |file: &str, name: &str| {
+            let decl = snap.decls_by_file[file]
+                .iter()
+                .find(|d| d.name == name)
+                .unwrap_or_else(|| panic!("{file}: {name} decl"));
+            classes[&decl.id]
+        };
+        assert_eq!(
+            class_of("CustCard.al", "OnAction"),
+            UiReachability::ReachableFromUi
+        );
+        assert_eq!(
+            class_of("Beta.al", "Process"),
+            UiReachability::ReachableFromUi
+        );
+        assert_eq!(class_of("Alpha.al", "DoWork"), UiReachability::BackendOnly);
+        assert_eq!(class_of("Alpha.al", "Løbenr"), UiReachability::BackendOnly);
+    }
+
+    #[test]
+    fn tested_by_links_production_procedures_to_their_covering_tests() {
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("AlphaTests.al"),
+            r#"codeunit 50150 "Alpha Tests"
+{
+    Subtype = Test;
+
+    [Test]
+    procedure TestDoWork()
+    var
+        Alpha: Codeunit "Alpha";
+    begin
+        Alpha.DoWork();
+    end;
+}
+"#,
+        )
+        .expect("write AlphaTests.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+        let tested_by = snap.tested_by();
+
+        let id_of = |file: &str, name: &str| {
+            snap.decls_by_file[file]
+                .iter()
+                .find(|d| d.name == name)
+                .unwrap_or_else(|| panic!("{file}: {name} decl"))
+                .id
+                .clone()
+        };
+        let test = id_of("AlphaTests.al", "TestDoWork");
+        assert_eq!(
+            tested_by.get(&id_of("Alpha.al", "DoWork")),
+            Some(&vec![test.clone()])
+        );
+        assert_eq!(
+            tested_by.get(&id_of("Beta.al", "Process")),
+            Some(&vec![test.clone()]),
+            "transitive callees are covered too"
+        );
+        assert!(!tested_by.contains_key(&id_of("Gamma.al", "Standalone")));
+        assert!(!tested_by.contains_key(&test), "tests are never keys");
+    }
+
+    #[test]
+    fn stable_id_survives_a_line_shift_and_separates_overloads() {
+        let dir = fixture_dir();
+        let calc_ids = |snap: &LspSnapshot| {
+            let mut calcs: Vec<(String, u32)> = snap
+                .decls_by_file
+                .values()
+                .flatten()
+                .filter(|d| d.name == "Calc")
+                .map(|d| (d.stable_id(), d.origin.start.row))
+                .collect();
+            calcs.sort_by_key(|&(_, row)| row);
+            calcs
+        };
+        let before = calc_ids(&LspSnapshot::build_full(dir.path()).expect("build_full"));
+
+        let alpha = dir.path().join("Alpha.al");
+        let text = std::fs::read_to_string(&alpha).expect("read Alpha.al");
+        std::fs::write(&alpha, format!("\n\n\n{text}")).expect("rewrite Alpha.al");
+        let after = calc_ids(&LspSnapshot::build_full(dir.path()).expect("build_full"));
+
+        assert_eq!(before.len(), 2, "both Calc overloads; got {before:?}");
+        assert_ne!(before[0].0, before[1].0, "overloads get distinct ids");
+        for (b, a) in before.iter().zip(&after) {
+            assert_eq!(a.1, b.1 + 3, "the declaration moved down three lines");
+            assert_eq!(a.0, b.0, "the id did not move with it");
+        }
+    }
+
+    #[test]
+    fn definitions_by_kind_returns_only_the_requested_kind() {
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("Cust.al"),
+            r#"table 50110 "Cust"
+{
+    fields
+    {
+        field(1; "No."; Code[20]) { }
+    }
+
+    trigger OnInsert()
+    begin
+        Helper();
+    end;
+
+    trigger OnDelete()
+    begin
+    end;
+
+    local procedure Helper()
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write Cust.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let names = |kind| {
+            snap.definitions_by_kind(kind)
+                .into_iter()
+                .map(|d| d.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(DefinitionKind::Trigger), vec!["OnInsert", "OnDelete"]);
+        assert_eq!(
+            names(DefinitionKind::EventSubscriber),
+            vec!["HandleAfterProcess"]
+        );
+        assert_eq!(
+            names(DefinitionKind::EventPublisher),
+            vec!["OnAfterProcess"]
+        );
+        let procedures = names(DefinitionKind::Procedure);
+        assert!(procedures.contains(&"Helper".to_string()), "{procedures:?}");
+        assert!(
+            !procedures.contains(&"OnInsert".to_string()),
+            "{procedures:?}"
+        );
+    }
+
+    /// A `controladdin`'s signature-only procedures are indexed as
+    /// declarations and graph routines (its JS-side `event`s never are —
+    /// they aren't AL-callable), and a `CurrPage.<usercontrol>` call to a
+    /// declared procedure resolves against that declared surface.
+    #[test]
+    fn controladdin_procedures_are_indexed_and_calls_to_them_resolve() {
+        use crate::program::node::ObjectKind;
+        use crate::program::resolve::edge::{BuiltinId, RouteTarget};
+
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("Editor.al"),
+            r#"controladdin "CDO.Editor"
+{
+    RequestedHeight = 500;
+
+    event StartupCompleted();
+
+    procedure InitEditor(mergeItemsAsJson: Text; localizationAsJson: Text)
+    procedure GetHTML()
+}
+"#,
+        )
+        .expect("write Editor.al");
+        std::fs::write(
+            dir.path().join("EditorPage.al"),
+            r#"page 50120 "Editor Page"
+{
+    layout
+    {
+        area(Content)
+        {
+            usercontrol(Editor; "CDO.Editor")
+            {
+            }
+        }
+    }
+
+    trigger OnOpenPage()
+    begin
+        CurrPage.Editor.InitEditor('[]', '{}');
+    end;
+}
+"#,
+        )
+        .expect("write EditorPage.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let decl_names: Vec<&str> = snap.decls_by_file["Editor.al"]
+            .iter()
+            .map(|d| d.name.as_str())
+            .collect();
+        assert_eq!(decl_names, vec!["InitEditor", "GetHTML"]);
+        let addin_routines: Vec<&str> = snap
+            .graph
+            .routines
+            .iter()
+            .filter(|r| r.id.object.kind == ObjectKind::ControlAddIn)
+            .map(|r| r.id.name_lc.as_str())
+            .collect();
+        assert!(addin_routines.contains(&"initeditor"), "{addin_routines:?}");
+        assert!(
+            !addin_routines.contains(&"startupcompleted"),
+            "{addin_routines:?}"
+        );
+
+        let targets: Vec<&RouteTarget> = snap.edges_by_file["EditorPage.al"]
+            .iter()
+            .filter(|ce| ce.edge.kind == EdgeKind::Call)
+            .flat_map(|ce| ce.edge.routes.iter().map(|r| &r.target))
+            .collect();
+        assert_eq!(
+            targets,
+            vec![&RouteTarget::Builtin(BuiltinId(
+                "ControlAddIn::initeditor".to_string()
+            ))]
+        );
+    }
+
+    #[test]
+    fn coupling_counts_distinct_called_objects() {
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("Delta.al"),
+            r#"codeunit 50103 "Delta"
+{
+    procedure Ping()
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write Delta.al");
+        std::fs::write(
+            dir.path().join("Hub.al"),
+            r#"codeunit 50110 "Hub"
+{
+    procedure Coordinate()
+    var
+        A: Codeunit "Alpha";
+        B: Codeunit "Beta";
+        D: Codeunit "Delta";
+    begin
+        A.Calc(1);
+        A.Calc('x');
+        B.Process();
+        D.Ping();
+        Helper();
+    end;
+
+    local procedure Helper()
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write Hub.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let coupling = snap.coupling();
+        let id_of = |file: &str, name: &str| {
+            snap.decls_by_file[file]
+                .iter()
+                .find(|d| d.name == name)
+                .map(|d| d.id.clone())
+                .expect("routine")
+        };
+        assert_eq!(
+            coupling.get(&id_of("Hub.al", "Coordinate")),
+            Some(&3),
+            "Alpha twice, Beta and Delta once; its own Helper() does not count"
+        );
+        assert_eq!(coupling.get(&id_of("Hub.al", "Helper")), None);
+        assert_eq!(
+            coupling.get(&id_of("Alpha.al", "DoWork")),
+            Some(&1),
+            "Beta.Process; the Calc overloads are Alpha's own"
+        );
+    }
+
+    #[test]
+    fn outgoing_calls_group_by_the_called_object() {
+        let dir = fixture_dir();
+        std::fs::write(
+            dir.path().join("Hub.al"),
+            r#"codeunit 50110 "Hub"
+{
+    procedure Coordinate()
+    var
+        A: Codeunit "Alpha";
+        B: Codeunit "Beta";
+    begin
+        A.Calc(1);
+        A.Calc('x');
+        B.Process();
+        Helper();
+        Helper();
+        Message('done');
+    end;
+
+    local procedure Helper()
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write Hub.al");
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+        let id_of = |file: &str, name: &str| {
+            snap.decls_by_file[file]
+                .iter()
+                .find(|d| d.name == name)
+                .map(|d| d.id.clone())
+                .expect("routine")
+        };
+        let coordinate = id_of("Hub.al", "Coordinate");
+        let alpha = id_of("Alpha.al", "DoWork").object;
+        let beta = id_of("Beta.al", "Process").object;
+
+        let by_object = snap.outgoing_calls_by_object(&coordinate);
+        let counts: Vec<(&ObjectNodeId, usize)> = by_object
+            .iter()
+            .map(|(&object, sites)| (object, sites.len()))
+            .collect();
+        assert_eq!(by_object.len(), 3, "Alpha, Beta and Hub itself: {counts:?}");
+        assert_eq!(by_object[&alpha].len(), 2);
+        assert_eq!(by_object[&beta].len(), 1);
+        assert_eq!(
+            by_object[&coordinate.object].len(),
+            2,
+            "the two local Helper() calls; Message() is a builtin"
+        );
+        assert!(
+            snap.outgoing_calls_by_object(&id_of("Hub.al", "Helper"))
+                .is_empty()
+        );
+    }
+
+    /// A symbol-only `.app` declaring codeunits `"Dep Used"` and
+    /// `"Dep Unused"`, each with one `DoIt` method.
+    fn write_two_codeunit_app(alpackages: &std::path::Path, guid: &str) {
+        use std::io::Write;
+
+        let manifest = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?><Package xmlns="http://schemas.microsoft.com/navx/2015/manifest"><App Id="{guid}" Name="RefDep" Publisher="probe" Version="1.0.0.0" Runtime="13.0" /></Package>"#
+        );
+        let symbol_reference = r#"{"Codeunits":[{"Id":60100,"Name":"Dep Used","Methods":[{"Name":"DoIt","Parameters":[]}]},{"Id":60101,"Name":"Dep Unused","Methods":[{"Name":"DoIt","Parameters":[]}]}]}"#;
+
+        let mut zip_bytes = std::io::Cursor::new(Vec::new());
+        {
+            let mut zip = zip::ZipWriter::new(&mut zip_bytes);
+            let options: zip::write::SimpleFileOptions = zip::write::SimpleFileOptions::default();
+            zip.start_file("NavxManifest.xml", options).unwrap();
+            zip.write_all(manifest.as_bytes()).unwrap();
+            zip.start_file("SymbolReference.json", options).unwrap();
+            zip.write_all(symbol_reference.as_bytes()).unwrap();
+            zip.finish().unwrap();
+        }
+        std::fs::create_dir_all(alpackages).unwrap();
+        let mut out = std::fs::File::create(alpackages.join("probe_RefDep_1.0.0.0.app")).unwrap();
+        out.write_all(&[0u8; 40]).unwrap(); // NAVX header (content unused)
+        out.write_all(zip_bytes.get_ref()).unwrap();
+    }
+
+    #[test]
+    fn unused_external_refs_reports_dependency_variables_never_called() {
+        use al_syntax::ir::ObjectKind;
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let guid = "77777777-0000-0000-0000-000000002223";
+        std::fs::write(
+            dir.path().join("app.json"),
+            format!(
+                r#"{{"id":"77777777-0000-0000-0000-000000002224","name":"Ref App","publisher":"probe","version":"1.0.0.0",
+"dependencies":[{{"id":"{guid}","name":"RefDep","publisher":"probe","version":"1.0.0.0"}}]}}"#
+            ),
+        )
+        .unwrap();
+        write_two_codeunit_app(&dir.path().join(".alpackages"), guid);
+        std::fs::write(
+            dir.path().join("Caller.al"),
+            r#"codeunit 50100 "Caller"
+{
+    var
+        Used: Codeunit "Dep Used";
+        Own: Codeunit "Caller";
+
+    procedure Run()
+    var
+        Unused: Codeunit "Dep Unused";
+    begin
+        Used.DoIt();
+    end;
+}
+"#,
+        )
+        .unwrap();
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let refs = snap.unused_external_refs();
+        assert_eq!(
+            refs.len(),
+            1,
+            "the called dependency and the workspace-typed variable are not reported; got {refs:#?}"
+        );
+        let unused = &refs[0];
+        assert_eq!(unused.variable, "Unused");
+        assert_eq!(
+            (unused.virtual_path.as_str(), unused.line),
+            ("Caller.al", 9)
+        );
+        assert_eq!(unused.owner_kind, ObjectKind::Codeunit);
+        assert_eq!(unused.owner_name, "Caller");
+        assert_eq!(unused.routine.as_deref(), Some("Run"));
+        assert!(
+            unused.type_text.contains("Dep Unused"),
+            "{:?}",
+            unused.type_text
+        );
+    }
+
+    /// A variable typed by object number (`Codeunit 60101`) resolves to the
+    /// dependency object directly by its declared id.
+    #[test]
+    fn unused_external_refs_resolves_variables_typed_by_number() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let guid = "77777777-0000-0000-0000-000000002225";
+        std::fs::write(
+            dir.path().join("app.json"),
+            format!(
+                r#"{{"id":"77777777-0000-0000-0000-000000002226","name":"Ref App","publisher":"probe","version":"1.0.0.0",
+"dependencies":[{{"id":"{guid}","name":"RefDep","publisher":"probe","version":"1.0.0.0"}}]}}"#
+            ),
+        )
+        .unwrap();
+        write_two_codeunit_app(&dir.path().join(".alpackages"), guid);
+        std::fs::write(
+            dir.path().join("Caller.al"),
+            r#"codeunit 50100 "Caller"
+{
+    procedure Run()
+    var
+        Used: Codeunit 60100;
+        Unused: Codeunit 60101;
+    begin
+        Used.DoIt();
+    end;
+}
+"#,
+        )
+        .unwrap();
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let refs = snap.unused_external_refs();
+        let names: Vec<&str> = refs.iter().map(|r| r.variable.as_str()).collect();
+        assert_eq!(names, ["Unused"], "got {refs:#?}");
+    }
+
+    /// Usage is keyed on the variable, not on resolved calls: one passed as
+    /// an argument, one only used in another routine (a global) and one
+    /// whose member call resolves to nothing all count as used.
+    #[test]
+    fn unused_external_refs_counts_any_use_of_the_variable() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let guid = "77777777-0000-0000-0000-000000002227";
+        std::fs::write(
+            dir.path().join("app.json"),
+            format!(
+                r#"{{"id":"77777777-0000-0000-0000-000000002228","name":"Ref App","publisher":"probe","version":"1.0.0.0",
+"dependencies":[{{"id":"{guid}","name":"RefDep","publisher":"probe","version":"1.0.0.0"}}]}}"#
+            ),
+        )
+        .unwrap();
+        write_two_codeunit_app(&dir.path().join(".alpackages"), guid);
+        std::fs::write(
+            dir.path().join("Caller.al"),
+            r#"codeunit 50100 "Caller"
+{
+    var
+        Bound: Codeunit "Dep Unused";
+        Idle: Codeunit "Dep Unused";
+
+    procedure Bind()
+    var
+        Missing: Codeunit "Dep Used";
+        Passed: Codeunit "Dep Used";
+    begin
+        BindSubscription(Bound);
+        Missing.NoSuchMethod();
+        Consume(Passed);
+    end;
+
+    local procedure Consume(var Passed: Codeunit "Dep Used")
+    begin
+    end;
+}
+"#,
+        )
+        .unwrap();
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let refs = snap.unused_external_refs();
+        let names: Vec<&str> = refs.iter().map(|r| r.variable.as_str()).collect();
+        assert_eq!(names, ["Idle"], "got {refs:#?}");
+    }
+
+    /// `resolution.fuzzyObjectNames`: `"Sales-Post"` names the `SalesPost`
+    /// codeunit through a typed variable and a `Codeunit.Run` target only
+    /// while the flag is on; off, both stay unresolved.
+    #[test]
+    fn fuzzy_object_names_resolve_punctuated_references_end_to_end() {
+        use crate::program::resolve::edge::RouteTarget;
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        std::fs::write(
+            dir.path().join("app.json"),
+            r#"{"id":"77777777-0000-0000-0000-000000002183","name":"Fuzzy App","publisher":"probe","version":"1.0.0.0"}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("SalesPost.al"),
+            r#"codeunit 50100 SalesPost
+{
+    trigger OnRun()
+    begin
+    end;
+
+    procedure Post()
+    begin
+    end;
+}
+
+codeunit 50101 Caller
+{
+    procedure Go()
+    var
+        Poster: Codeunit "Sales-Post";
+    begin
+        Poster.Post();
+        Codeunit.Run(Codeunit::"Sales-Post");
+    end;
+}
+"#,
+        )
+        .unwrap();
+        let targets = |snap: &LspSnapshot| -> Vec<String> {
+            let mut out: Vec<String> = snap
+                .edges_by_file
+                .values()
+                .flat_map(|edges| edges.iter())
+                .filter(|ce| ce.edge.from.name_lc == "go")
+                .flat_map(|ce| &ce.edge.routes)
+                .filter_map(|r| match &r.target {
+                    RouteTarget::Routine(id) => Some(id.name_lc.clone()),
+                    _ => None,
+                })
+                .collect();
+            out.sort();
+            out
+        };
+
+        let exact = LspSnapshot::build_full(dir.path()).expect("build_full");
+        assert!(targets(&exact).is_empty(), "got {:?}", targets(&exact));
+
+        std::fs::write(
+            dir.path().join(".al-call-hierarchy.json"),
+            r#"{ "resolution": { "fuzzyObjectNames": true } }"#,
+        )
+        .unwrap();
+        let fuzzy = LspSnapshot::build_full(dir.path()).expect("build_full");
+        assert_eq!(targets(&fuzzy), ["onrun", "post"]);
+    }
 }
@@ -0,0 +1,189 @@
+//! `workspace/symbol` on the engine-backed `LspSnapshot` — a ranked,
+//! capped name search over every workspace declaration (`decls_by_file`:
+//! procedures and triggers alike).
+//!
+//! # Ranking
+//!
+//! A declaration matches the query case-insensitively, best first:
+//! exact name, then name prefix, then substring, then subsequence (fuzzy,
+//! `cstpst` → `CustPost`). Within a rank, shorter names come first (a
+//! closer match), then name, file and position, so the order — and
+//! therefore which matches survive the cap — is deterministic. An empty
+//! query matches everything at the exact rank.
+//!
+//! # Result cap
+//!
+//! Large workspaces match thousands of routines for a short query. Results
+//! are sorted BEFORE truncating to the configured cap
+//! ([`crate::config::WorkspaceSymbolFileConfig`]), so the cap only ever
+//! drops the weakest matches. Only the survivors are turned into LSP
+//! values.
+
+use std::cmp::Ordering;
+
+use lsp_types::{Location, OneOf, WorkspaceSymbol};
+
+use crate::lsp::encoding::PositionEncoding;
+use crate::lsp::handlers::{
+    decl_uri, object_name_for, origin_to_range, symbol_kind_for, symbol_tags_for,
+};
+use crate::lsp::snapshot::{DeclEntry, DeclView, LspSnapshot};
+
+/// How well a name matches a query; lower is better.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchRank {
+    Exact,
+    Prefix,
+    Substring,
+    Fuzzy,
+}
+
+/// Rank `name` against `query` (case-insensitive), or `None` when the
+/// query's characters do not even appear in order.
+#[must_use]
+pub fn match_rank(name: &str, query: &str) -> Option<MatchRank> {
+    let name = name.to_lowercase();
+    let query = query.to_lowercase();
+    if name == query || query.is_empty() {
+        Some(MatchRank::Exact)
+    } else if name.starts_with(&query) {
+        Some(MatchRank::Prefix)
+    } else if name.contains(&query) {
+        Some(MatchRank::Substring)
+    } else {
+        let mut rest = name.chars();
+        query
+            .chars()
+            .all(|q| rest.any(|c| c == q))
+            .then_some(MatchRank::Fuzzy)
+    }
+}
+
+/// `workspace/symbol`. At most `max_results` workspace declarations whose
+/// names match `query`, best-ranked first (see the module doc).
+#[must_use]
+pub fn workspace_symbols(
+    snap: &LspSnapshot,
+    enc: PositionEncoding,
+    query: &str,
+    max_results: usize,
+) -> Vec<WorkspaceSymbol> {
+    let mut matches: Vec<(MatchRank, &DeclEntry)> = snap
+        .decls_by_file
+        .values()
+        .flat_map(|decls| decls.iter())
+        .filter_map(|d| match_rank(&d.name, query).map(|rank| (rank, d)))
+        .collect();
+    matches.sort_by(|(ra, a), (rb, b)| {
+        compare_matches((*ra, &a.name), (*rb, &b.name))
+            .then_with(|| a.virtual_path.cmp(&b.virtual_path))
+            .then_with(|| a.origin.byte.start.cmp(&b.origin.byte.start))
+    });
+    matches.truncate(max_results);
+
+    matches
+        .into_iter()
+        .filter_map(|(_, d)| {
+            let entry = snap.parsed.get(&d.virtual_path)?;
+            let view = DeclView::from_entry(d);
+            let object_name = object_name_for(&snap.graph, &d.id.object).unwrap_or("Unknown");
+            Some(WorkspaceSymbol {
+                name: d.name.clone(),
+                kind: symbol_kind_for(snap, &d.id),
+                tags: symbol_tags_for(&d.attributes),
+                container_name: Some(object_name.to_string()),
+                location: OneOf::Left(Location {
+                    uri: decl_uri(snap, view),
+                    range: origin_to_range(&d.origin, entry.line_table(), enc),
+                }),
+                data: None,
+            })
+        })
+        .collect()
+}
+
+/// Re-rank symbols gathered from several roots and keep the best
+/// `max_results` — the multi-root merge step, using the same order
+/// [`workspace_symbols`] applies within one root.
+pub fn merge_ranked(symbols: &mut Vec<WorkspaceSymbol>, query: &str, max_results: usize) {
+    symbols.sort_by(|a, b| {
+        let ra = match_rank(&a.name, query).unwrap_or(MatchRank::Fuzzy);
+        let rb = match_rank(&b.name, query).unwrap_or(MatchRank::Fuzzy);
+        compare_matches((ra, &a.name), (rb, &b.name))
+    });
+    symbols.truncate(max_results);
+}
+
+fn compare_matches(a: (MatchRank, &str), b: (MatchRank, &str)) -> Ordering {
+    a.0.cmp(&b.0)
+        .then_with(|| a.1.len().cmp(&b.1.len()))
+        .then_with(|| a.1.to_lowercase().cmp(&b.1.to_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_fixture(dir: &std::path::Path) {
+        std::fs::write(
+            dir.join("app.json"),
+            r#"{
+    "id": "77777777-0000-0000-0000-000000002203",
+    "name": "Workspace Symbol Fixture",
+    "publisher": "probe",
+    "version": "1.0.0.0"
+}"#,
+        )
+        .expect("write app.json");
+
+        let mut src = String::from("codeunit 50100 \"Symbol Probe\"\n{\n");
+        for i in 0..40 {
+            src.push_str(&format!(
+                "    procedure PostDocumentLine{i}()\n    begin\n    end;\n\n"
+            ));
+        }
+        src.push_str("    procedure XPostY()\n    begin\n    end;\n\n");
+        src.push_str("    procedure PstLater()\n    begin\n    end;\n\n");
+        src.push_str("    procedure Post()\n    begin\n    end;\n}\n");
+        std::fs::write(dir.join("Probe.al"), src).expect("write Probe.al");
+    }
+
+    #[test]
+    fn match_rank_orders_exact_prefix_substring_fuzzy() {
+        assert_eq!(match_rank("Post", "post"), Some(MatchRank::Exact));
+        assert_eq!(match_rank("PostLine", "post"), Some(MatchRank::Prefix));
+        assert_eq!(match_rank("XPostY", "post"), Some(MatchRank::Substring));
+        assert_eq!(match_rank("PstLater", "pst"), Some(MatchRank::Prefix));
+        assert_eq!(match_rank("CustPost", "cstpst"), Some(MatchRank::Fuzzy));
+        assert_eq!(match_rank("Release", "post"), None);
+    }
+
+    #[test]
+    fn workspace_symbols_caps_results_and_ranks_exact_matches_first() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_fixture(dir.path());
+        let snap = LspSnapshot::build_full(dir.path()).expect("build_full");
+
+        let all = workspace_symbols(&snap, PositionEncoding::Utf16, "post", usize::MAX);
+        assert_eq!(
+            all.len(),
+            42,
+            "40 prefix + XPostY + Post (PstLater never matches)"
+        );
+
+        let capped = workspace_symbols(&snap, PositionEncoding::Utf16, "post", 5);
+        assert_eq!(capped.len(), 5);
+        assert_eq!(capped[0].name, "Post", "the exact match ranks first");
+        assert_eq!(capped[0].container_name.as_deref(), Some("Symbol Probe"));
+        assert!(
+            capped[1..]
+                .iter()
+                .all(|s| s.name.starts_with("PostDocumentLine")),
+            "prefix matches outrank the substring match; got {capped:#?}"
+        );
+
+        let fuzzy = workspace_symbols(&snap, PositionEncoding::Utf16, "pdl", 3);
+        assert_eq!(fuzzy.len(), 3);
+        assert!(fuzzy.iter().all(|s| s.name.starts_with("PostDocumentLine")));
+    }
+}
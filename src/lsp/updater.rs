@@ -102,7 +102,7 @@
 //!   toward silently taking rung 1): rebuild the workspace layer
 //!   (`assemble_program_graph` over the cached, UNCHANGED [`DepLayer`]) and
 //!   re-resolve EVERY workspace file — see [`Updater::apply_rung2`].
-//! - **Rung 3** (`DepsChanged`/`Overflow`, OR a `FileSaved`/`FileRemoved`
+//! - **Rung 3** (`DepsChanged`/`Overflow`/`Reindex`, OR a `FileSaved`/`FileRemoved`
 //!   path that isn't workspace-shaped at all — e.g. under `.alpackages/`,
 //!   the Task-4-review dep-file-boundary scenario): full rebuild via
 //!   [`LspSnapshot::build_full_with_parsed`] — see [`Updater::apply_rung3`].
@@ -113,12 +113,13 @@
 
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::mpsc::{Receiver, RecvTimeoutError};
 use std::sync::{Arc, RwLock};
 use std::thread::{self, JoinHandle};
 use std::time::{Duration, Instant};
 
-use al_syntax::ir::ParseStatus;
+use al_syntax::ir::{AlFile, ParseStatus};
 use log::warn;
 use rayon::prelude::*;
 
@@ -131,6 +132,7 @@ use crate::program::assemble_program_graph;
 use crate::program::node::{ObjectNodeId, RoutineNodeId};
 use crate::program::node_extract::ObjectNode;
 use crate::program::resolve::decl_surface::DeclSurface;
+use crate::program::resolve::edge::ResolutionKind;
 use crate::program::resolve::emit_event_flow_edges;
 use crate::program::resolve::full::{ClassifiedEdge, ObligationId};
 use crate::program::resolve::index::ResolveIndex;
@@ -143,13 +145,32 @@ use crate::snapshot::{ParsedFile, ParsedUnit, Provenance, TrustTier};
 /// Swap-only publication point: readers clone the `Arc` (sub-microsecond,
 /// never blocked by a writer for longer than that clone); the ONE writer
 /// (the updater thread) replaces the whole `Arc` atomically. Never mutated
-/// in place (spec §3 / H-10 lesson).
-pub struct SharedSnapshot(RwLock<Arc<LspSnapshot>>);
+/// in place (spec §3 / H-10 lesson). Also carries the health flags the
+/// `al-call-hierarchy/health` request reports: whether the updater is
+/// mid-batch, and when a snapshot was last published.
+pub struct SharedSnapshot {
+    current: RwLock<Arc<LspSnapshot>>,
+    indexing: AtomicBool,
+    /// Unix epoch milliseconds of the last publish (the initial build counts).
+    published_at_ms: AtomicU64,
+}
+
+/// Milliseconds since the Unix epoch (`0` if the clock is before it).
+fn unix_millis_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
 
 impl SharedSnapshot {
     #[must_use]
     pub fn new(initial: Arc<LspSnapshot>) -> Self {
-        SharedSnapshot(RwLock::new(initial))
+        SharedSnapshot {
+            current: RwLock::new(initial),
+            indexing: AtomicBool::new(false),
+            published_at_ms: AtomicU64::new(unix_millis_now()),
+        }
     }
 
     /// Cheap: an `Arc` clone under a read lock.
@@ -157,7 +178,7 @@ impl SharedSnapshot {
     pub fn get(&self) -> Arc<LspSnapshot> {
         Arc::clone(
             &self
-                .0
+                .current
                 .read()
                 .unwrap_or_else(std::sync::PoisonError::into_inner),
         )
@@ -165,12 +186,32 @@ impl SharedSnapshot {
 
     /// Publish a new snapshot. The old `Arc` is dropped once every existing
     /// reader's clone goes out of scope — no reader ever observes a torn
-    /// state.
+    /// state. Ends the current indexing pass (see [`Self::is_indexing`]).
     pub fn swap(&self, s: Arc<LspSnapshot>) {
         *self
-            .0
+            .current
             .write()
             .unwrap_or_else(std::sync::PoisonError::into_inner) = s;
+        self.published_at_ms
+            .store(unix_millis_now(), Ordering::Relaxed);
+        self.indexing.store(false, Ordering::Relaxed);
+    }
+
+    /// `true` while the updater is applying a batch of changes that has not
+    /// been published yet.
+    #[must_use]
+    pub fn is_indexing(&self) -> bool {
+        self.indexing.load(Ordering::Relaxed)
+    }
+
+    fn set_indexing(&self, indexing: bool) {
+        self.indexing.store(indexing, Ordering::Relaxed);
+    }
+
+    /// Unix epoch milliseconds of the last published snapshot.
+    #[must_use]
+    pub fn published_at_ms(&self) -> u64 {
+        self.published_at_ms.load(Ordering::Relaxed)
     }
 }
 
@@ -184,9 +225,26 @@ impl SharedSnapshot {
 #[derive(Clone, Debug)]
 pub enum ChangeEvent {
     FileSaved(PathBuf),
+    /// An open document's unsaved buffer, already reparsed by the server's
+    /// `didChange` handling (incrementally, against the tree it retains for
+    /// the document). Indexed exactly like `FileSaved`, but from `text` and
+    /// `file` rather than a fresh read of `path`.
+    FileEdited {
+        path: PathBuf,
+        text: Arc<str>,
+        file: Arc<AlFile>,
+    },
     FileRemoved(PathBuf),
     DepsChanged,
     Overflow,
+    /// An explicit `al-call-hierarchy/reindex` request: a full rebuild from
+    /// disk, exactly like `Overflow`, for when the incremental index has
+    /// drifted.
+    Reindex,
+    /// `al-call-hierarchy/reindex` with `reconcile: true`: apply only the
+    /// files added or removed on disk behind the watcher's back — see
+    /// [`Updater::reconcile`].
+    Reconcile,
 }
 
 /// Which rung an apply actually took — the brief's "test hook," exposed
@@ -320,6 +378,68 @@ impl Updater {
         }
     }
 
+    /// Self-heal drift from missed watcher events: compare the `.al` files
+    /// on disk under the workspace root with `cur`'s indexed files and apply
+    /// the difference — a `FileSaved` for each unindexed file, a
+    /// `FileRemoved` for each indexed file that is gone. `None` when the two
+    /// already agree. Unlike [`ChangeEvent::Reindex`] this only touches the
+    /// drifted files, so it normally lands on rung 2, not rung 3.
+    pub fn reconcile(&mut self, cur: &LspSnapshot) -> Option<(LspSnapshot, Rung)> {
+        self.apply_batch(cur, &[ChangeEvent::Reconcile])
+    }
+
+    /// `batch` with every [`ChangeEvent::Reconcile`] replaced by the current
+    /// drift between disk and `cur`, coalesced so an explicit event for the
+    /// same path wins.
+    fn with_drift(&self, cur: &LspSnapshot, batch: &[ChangeEvent]) -> Vec<ChangeEvent> {
+        let drift = self.drift(cur);
+        if !drift.is_empty() {
+            warn!(
+                "reconcile: {} file(s) out of sync with the index under {}",
+                drift.len(),
+                self.workspace_root.display()
+            );
+        }
+        let explicit = batch
+            .iter()
+            .filter(|ev| !matches!(ev, ChangeEvent::Reconcile))
+            .cloned();
+        coalesce_batch(drift.into_iter().chain(explicit).collect())
+    }
+
+    /// The events [`Self::reconcile`] applies, in path order.
+    fn drift(&self, cur: &LspSnapshot) -> Vec<ChangeEvent> {
+        let mut on_disk: HashSet<String> = HashSet::new();
+        let mut events = Vec::new();
+        for entry in walkdir::WalkDir::new(&self.workspace_root)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+        {
+            let PathClass::Workspace(vp) =
+                classify_path(&self.workspace_root, entry.path(), &cur.parsed)
+            else {
+                continue;
+            };
+            if !cur.parsed.contains_key(&vp) {
+                events.push(ChangeEvent::FileSaved(entry.path().to_path_buf()));
+            }
+            on_disk.insert(vp);
+        }
+        let mut gone: Vec<&String> = cur
+            .parsed
+            .keys()
+            .filter(|vp| !on_disk.contains(*vp))
+            .collect();
+        gone.sort();
+        events.extend(
+            gone.into_iter()
+                .map(|vp| ChangeEvent::FileRemoved(self.workspace_root.join(vp))),
+        );
+        events
+    }
+
     /// Classify `batch` and, if (and only if) it lands on rung 1, apply it
     /// against the prebuilt `ctx` — the EXACT call [`spawn_updater`]'s inner
     /// loop makes. Returns `None` for a `Noop` batch or one that would
@@ -369,6 +489,13 @@ impl Updater {
     /// Read-only (`&self`) — never mutates `self`, so it composes freely
     /// with an outstanding `DeclSurface` borrow of `self.workspace`.
     fn classify(&self, cur: &LspSnapshot, batch: &[ChangeEvent]) -> Decision {
+        let reconciled;
+        let batch = if batch.iter().any(|ev| matches!(ev, ChangeEvent::Reconcile)) {
+            reconciled = self.with_drift(cur, batch);
+            reconciled.as_slice()
+        } else {
+            batch
+        };
         if batch.is_empty() {
             return Decision::Noop;
         }
@@ -378,7 +505,11 @@ impl Updater {
 
         for ev in batch {
             match ev {
-                ChangeEvent::DepsChanged | ChangeEvent::Overflow => force_rung3 = true,
+                ChangeEvent::DepsChanged | ChangeEvent::Overflow | ChangeEvent::Reindex => {
+                    force_rung3 = true
+                }
+                // Expanded into its drift above.
+                ChangeEvent::Reconcile => {}
                 ChangeEvent::FileRemoved(path) => {
                     match classify_path(&self.workspace_root, path, &cur.parsed) {
                         PathClass::Workspace(vp) => planned.push(Planned::Remove { vp }),
@@ -406,32 +537,21 @@ impl Updater {
                             let Ok(text) = std::fs::read_to_string(path) else {
                                 continue;
                             };
-                            let provenance = self.file_provenance(cur, &vp);
                             let file = Arc::new(al_syntax::parse(&text));
-                            let text: Arc<str> = text.into();
-                            // Fail-closed: a `Recovered` parse cannot be trusted
-                            // for rung 1's fingerprint-equality shortcut — the
-                            // IR may have silently dropped content (see
-                            // `crate::snapshot::parse::recovered_file_paths`'s
-                            // doc), so force this file's own "changed" verdict
-                            // regardless of what its computed fingerprint says.
-                            let recovered = file.parse_status != ParseStatus::Clean;
-                            let pf = ParsedFile {
-                                virtual_path: vp.clone(),
-                                file,
-                                provenance,
-                                text,
-                            };
-                            let fingerprint_changed = recovered
-                                || match cur.parsed.get(&vp) {
-                                    Some(old) => old.surface != def_surface_fingerprint(&pf),
-                                    None => true, // brand-new file: no prior surface to compare
-                                };
-                            planned.push(Planned::Save {
+                            planned.push(self.plan_save(cur, vp, text.into(), file));
+                        }
+                    }
+                }
+                ChangeEvent::FileEdited { path, text, file } => {
+                    match classify_path(&self.workspace_root, path, &cur.parsed) {
+                        PathClass::NotWorkspaceSource => force_rung3 = true,
+                        PathClass::Workspace(vp) => {
+                            planned.push(self.plan_save(
+                                cur,
                                 vp,
-                                pf: Box::new(pf),
-                                fingerprint_changed,
-                            });
+                                Arc::clone(text),
+                                Arc::clone(file),
+                            ));
                         }
                     }
                 }
@@ -471,6 +591,40 @@ impl Updater {
         Decision::Rung1(saves)
     }
 
+    /// The [`Planned::Save`] for workspace file `vp`'s new content: `file`
+    /// is `text` parsed.
+    fn plan_save(
+        &self,
+        cur: &LspSnapshot,
+        vp: String,
+        text: Arc<str>,
+        file: Arc<AlFile>,
+    ) -> Planned {
+        let provenance = self.file_provenance(cur, &vp);
+        // Fail-closed: a `Recovered` parse cannot be trusted for rung 1's
+        // fingerprint-equality shortcut — the IR may have silently dropped
+        // content (see `crate::snapshot::parse::recovered_file_paths`'s
+        // doc), so force this file's own "changed" verdict regardless of
+        // what its computed fingerprint says.
+        let recovered = file.parse_status != ParseStatus::Clean;
+        let pf = ParsedFile {
+            virtual_path: vp.clone(),
+            file,
+            provenance,
+            text,
+        };
+        let fingerprint_changed = recovered
+            || match cur.parsed.get(&vp) {
+                Some(old) => old.surface != def_surface_fingerprint(&pf),
+                None => true, // brand-new file: no prior surface to compare
+            };
+        Planned::Save {
+            vp,
+            pf: Box::new(pf),
+            fingerprint_changed,
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Rung 2: definition-surface change / file add / file delete
     // -----------------------------------------------------------------------
@@ -496,8 +650,11 @@ impl Updater {
             }
         }
 
-        let new_graph = assemble_program_graph(&cur.dep_layer, &self.workspace, &cur.snap);
+        let mut new_graph = assemble_program_graph(&cur.dep_layer, &self.workspace, &cur.snap);
+        new_graph.fuzzy_object_names = cur.graph.fuzzy_object_names;
+        new_graph.trigger_ops = cur.graph.trigger_ops.clone();
         let index = ResolveIndex::build(&new_graph);
+        let interface_implementers = Arc::new(index.implementers_by_interface_id(&new_graph));
         // T3 Task 12: rebuild ONLY the local (workspace) tier and compose it
         // with the ALREADY-FROZEN dependency tier forwarded from `cur` —
         // dependency source cannot change at rung 2 either (it reuses the
@@ -573,6 +730,8 @@ impl Updater {
                 .map(|edge| ClassifiedEdge {
                     obligation_id: ObligationId::Publisher(edge.from.clone()),
                     edge,
+                    referenced_objects: Vec::new(),
+                    resolution_kind: Some(ResolutionKind::Event),
                 })
                 .collect::<Vec<ClassifiedEdge>>(),
         );
@@ -592,6 +751,7 @@ impl Updater {
             event_edges,
             incoming,
             publisher_fanout,
+            interface_implementers,
             decls_by_file,
             decl_by_id,
             // Dependency source cannot change at rung 2 (it reuses the
@@ -936,6 +1096,8 @@ fn apply_rung1_core(
         event_edges,
         incoming,
         publisher_fanout,
+        // Derived from `graph` alone, which rung 1 reuses unchanged.
+        interface_implementers: Arc::clone(&cur.interface_implementers),
         decls_by_file,
         decl_by_id,
         // Rung 1 touches ONLY workspace files — dependency source is
@@ -1051,20 +1213,26 @@ fn classify_path(
 // Thread wrapper: debounce + per-path coalesce + apply + swap + notify
 // ---------------------------------------------------------------------------
 
-const DEBOUNCE_WINDOW: Duration = Duration::from_millis(100);
+/// How long the updater waits after the first change of a burst for the
+/// rest of it before reindexing once, unless `watcher.debounceMs` or
+/// `--watch-debounce-ms` says otherwise.
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(100);
 
 /// Per-path coalesce within one gathered batch: keep only the LAST event for
 /// a given path (a save immediately followed by a remove for the SAME path
 /// keeps the remove — "last wins," matching real editor semantics), while
-/// preserving first-seen ORDER for everything else. `DepsChanged`/`Overflow`
-/// have no path — every occurrence is kept (idempotent to see more than
-/// once: both force rung 3 regardless of count).
+/// preserving first-seen ORDER for everything else. `DepsChanged`/`Overflow`/
+/// `Reindex`/`Reconcile` have no path — every occurrence is kept (idempotent
+/// to see more than once: the first three force rung 3 regardless of count,
+/// and one drift scan covers every `Reconcile`).
 fn coalesce_batch(events: Vec<ChangeEvent>) -> Vec<ChangeEvent> {
     let mut index_of: HashMap<PathBuf, usize> = HashMap::new();
     let mut out: Vec<ChangeEvent> = Vec::new();
     for ev in events {
         match &ev {
-            ChangeEvent::FileSaved(p) | ChangeEvent::FileRemoved(p) => {
+            ChangeEvent::FileSaved(p)
+            | ChangeEvent::FileRemoved(p)
+            | ChangeEvent::FileEdited { path: p, .. } => {
                 if let Some(&idx) = index_of.get(p) {
                     out[idx] = ev;
                 } else {
@@ -1072,19 +1240,22 @@ fn coalesce_batch(events: Vec<ChangeEvent>) -> Vec<ChangeEvent> {
                     out.push(ev);
                 }
             }
-            ChangeEvent::DepsChanged | ChangeEvent::Overflow => out.push(ev),
+            ChangeEvent::DepsChanged
+            | ChangeEvent::Overflow
+            | ChangeEvent::Reindex
+            | ChangeEvent::Reconcile => out.push(ev),
         }
     }
     out
 }
 
 /// Block for one event, then drain everything else arriving within
-/// [`DEBOUNCE_WINDOW`] of the first, returning the coalesced batch. Returns
-/// `None` when the channel is closed (sender dropped).
-fn gather_batch(rx: &Receiver<ChangeEvent>) -> Option<Vec<ChangeEvent>> {
+/// `debounce` of the first, returning the coalesced batch. Returns `None`
+/// when the channel is closed (sender dropped).
+fn gather_batch(rx: &Receiver<ChangeEvent>, debounce: Duration) -> Option<Vec<ChangeEvent>> {
     let first = rx.recv().ok()?;
     let mut batch = vec![first];
-    let deadline = Instant::now() + DEBOUNCE_WINDOW;
+    let deadline = Instant::now() + debounce;
     loop {
         let now = Instant::now();
         if now >= deadline {
@@ -1150,6 +1321,26 @@ pub fn spawn_updater(
     workspace_root: PathBuf,
     initial_workspace: ParsedUnit,
     on_swap: impl Fn(&LspSnapshot, &SwapScope) + Send + 'static,
+) -> JoinHandle<()> {
+    spawn_updater_with_debounce(
+        shared,
+        rx,
+        workspace_root,
+        initial_workspace,
+        DEFAULT_DEBOUNCE,
+        on_swap,
+    )
+}
+
+/// [`spawn_updater`] with an explicit debounce window: each batch gathers
+/// every change arriving within `debounce` of its first.
+pub fn spawn_updater_with_debounce(
+    shared: Arc<SharedSnapshot>,
+    rx: Receiver<ChangeEvent>,
+    workspace_root: PathBuf,
+    initial_workspace: ParsedUnit,
+    debounce: Duration,
+    on_swap: impl Fn(&LspSnapshot, &SwapScope) + Send + 'static,
 ) -> JoinHandle<()> {
     thread::spawn(move || {
         let mut updater = Updater::new(workspace_root, initial_workspace);
@@ -1169,11 +1360,12 @@ pub fn spawn_updater(
 
                 let mut inner_cur = Arc::clone(&cur);
                 let escalated = loop {
-                    let Some(batch) = gather_batch(&rx) else {
+                    let Some(batch) = gather_batch(&rx, debounce) else {
                         return; // sender dropped — shut down cleanly
                     };
+                    shared.set_indexing(true);
                     match updater.classify(&inner_cur, &batch) {
-                        Decision::Noop => {}
+                        Decision::Noop => shared.set_indexing(false),
                         Decision::Rung1(saves) => {
                             let (new_snapshot, delta) = apply_rung1_core(
                                 &inner_cur,
@@ -1210,6 +1402,8 @@ pub fn spawn_updater(
                         shared.swap(Arc::clone(&new_arc));
                         on_swap(&new_arc, &SwapScope::Full);
                         cur = new_arc;
+                    } else {
+                        shared.set_indexing(false);
                     }
                 }
                 Decision::Noop | Decision::Rung1(_) => {
@@ -1227,7 +1421,7 @@ pub fn spawn_updater(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::program::resolve::edge::{Evidence, RouteTarget, UnknownReason};
+    use crate::program::resolve::edge::{EdgeKind, Evidence, RouteTarget, UnknownReason};
     use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::mpsc;
 
@@ -1778,6 +1972,94 @@ mod tests {
         assert_eq!(new_snap.generation, base.generation + 1);
     }
 
+    // ── DepsChanged re-resolves calls into a late-arriving dependency ──────
+
+    /// A real `.app` (NAVX header + zip) whose `SymbolReference.json`
+    /// declares codeunit `"Dep Cu"` with one method, `DoIt`.
+    fn write_dep_app(alpackages: &Path, guid: &str) {
+        use std::io::Write;
+
+        let manifest = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?><Package xmlns="http://schemas.microsoft.com/navx/2015/manifest"><App Id="{guid}" Name="LateDep" Publisher="probe" Version="1.0.0.0" Runtime="13.0" /></Package>"#
+        );
+        let symbol_reference = r#"{"Codeunits":[{"Id":60200,"Name":"Dep Cu","Methods":[{"Name":"DoIt","Parameters":[]}]}]}"#;
+
+        let mut zip_bytes = std::io::Cursor::new(Vec::new());
+        {
+            let mut zip = zip::ZipWriter::new(&mut zip_bytes);
+            let options: zip::write::SimpleFileOptions = zip::write::SimpleFileOptions::default();
+            zip.start_file("NavxManifest.xml", options).unwrap();
+            zip.write_all(manifest.as_bytes()).unwrap();
+            zip.start_file("SymbolReference.json", options).unwrap();
+            zip.write_all(symbol_reference.as_bytes()).unwrap();
+            zip.finish().unwrap();
+        }
+        std::fs::create_dir_all(alpackages).unwrap();
+        let mut out = std::fs::File::create(alpackages.join("probe_LateDep_1.0.0.0.app")).unwrap();
+        out.write_all(&[0u8; 40]).unwrap(); // NAVX header (content unused)
+        out.write_all(zip_bytes.get_ref()).unwrap();
+    }
+
+    /// Workspace files are indexed before the dependency they call is ever
+    /// on disk: `D.DoIt()` starts unresolved. Dropping the `.app` into
+    /// `.alpackages` (the watcher's `DepsChanged`) must re-resolve that call
+    /// against the newly loaded symbols — no stale unresolved edge survives
+    /// the swap.
+    #[test]
+    fn deps_changed_resolves_calls_into_a_newly_added_dependency() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let guid = "44444444-0000-0000-0000-0000000000d1";
+        std::fs::write(
+            dir.path().join("app.json"),
+            format!(
+                r#"{{"id":"44444444-0000-0000-0000-0000000000d2","name":"Late Dep Consumer","publisher":"probe","version":"1.0.0.0",
+"dependencies":[{{"id":"{guid}","name":"LateDep","publisher":"probe","version":"1.0.0.0"}}]}}"#
+            ),
+        )
+        .expect("write app.json");
+        std::fs::write(
+            dir.path().join("Caller.al"),
+            r#"codeunit 50100 "Caller"
+{
+    procedure Run()
+    var
+        D: Codeunit "Dep Cu";
+    begin
+        D.DoIt();
+    end;
+}
+"#,
+        )
+        .expect("write Caller.al");
+
+        let (base, parsed) = build(dir.path());
+        let run_call_targets = |snap: &LspSnapshot| -> Vec<RouteTarget> {
+            snap.edges_by_file["Caller.al"]
+                .iter()
+                .filter(|ce| ce.edge.kind == EdgeKind::Call && ce.edge.from.name_lc == "run")
+                .flat_map(|ce| ce.edge.routes.iter().map(|r| r.target.clone()))
+                .collect()
+        };
+        let before = run_call_targets(&base);
+        assert!(
+            !before.is_empty() && before.iter().all(|t| *t == RouteTarget::Unresolved),
+            "without the dependency on disk D.DoIt() must be unresolved: {before:?}"
+        );
+
+        write_dep_app(&dir.path().join(".alpackages"), guid);
+        let mut updater = Updater::new(dir.path().to_path_buf(), parsed);
+        let (new_snap, rung) = updater
+            .apply_batch(&base, &[ChangeEvent::DepsChanged])
+            .expect("apply_batch must succeed");
+        assert_eq!(rung, Rung::Three);
+
+        let after = run_call_targets(&new_snap);
+        assert!(
+            matches!(after.as_slice(), [RouteTarget::AbiSymbol { .. }]),
+            "after DepsChanged D.DoIt() must route to the dependency's symbol: {after:?}"
+        );
+    }
+
     // ── ChangeEvent::Overflow forces rung 3, exactly like DepsChanged ───────
     // (T3 Task 15 review fix-wave: `classify` already matches `Overflow` in
     // the SAME arm as `DepsChanged` — see that match — so this was
@@ -1806,6 +2088,38 @@ mod tests {
         assert_eq!(new_snap.generation, base.generation + 1);
     }
 
+    // ── reconcile: self-heal files the watcher never reported ──────────────
+
+    #[test]
+    fn reconcile_picks_up_a_file_added_without_a_watcher_event() {
+        let dir = fixture_dir();
+        let (base, parsed) = build(dir.path());
+        let mut updater = Updater::new(dir.path().to_path_buf(), parsed);
+        assert!(
+            updater.reconcile(&base).is_none(),
+            "fresh build has no drift"
+        );
+
+        std::fs::write(
+            dir.path().join("Late.al"),
+            "codeunit 50190 \"Late\"\n{\n    procedure Arrived()\n    begin\n    end;\n}\n",
+        )
+        .expect("write Late.al");
+        let (healed, rung) = updater.reconcile(&base).expect("drift must be applied");
+        assert_eq!(rung, Rung::Two);
+        assert!(healed.parsed.contains_key("Late.al"));
+        assert!(
+            healed.decls_by_file["Late.al"]
+                .iter()
+                .any(|d| d.name == "Arrived")
+        );
+
+        std::fs::remove_file(dir.path().join("Late.al")).expect("remove Late.al");
+        let (healed, _) = updater.reconcile(&healed).expect("removal must be applied");
+        assert!(!healed.parsed.contains_key("Late.al"));
+        assert!(updater.reconcile(&healed).is_none());
+    }
+
     // ── batch semantics: any rung-2 event forces the WHOLE batch to rung 2 ──
 
     #[test]
@@ -1975,6 +2289,57 @@ mod tests {
         assert_eq!(updater.pending.len(), 1, "both edits target the same file");
     }
 
+    // ── concurrent reads: `get` never waits on a rebuild or tears a swap ──
+
+    /// Request handlers read while the updater publishes: reader threads
+    /// calling [`SharedSnapshot::get`] in a loop during hundreds of swaps
+    /// always see one whole published snapshot, and every reader keeps
+    /// making progress (the write lock is held only for the `Arc` store).
+    #[test]
+    fn shared_snapshot_serves_concurrent_reads_during_swaps() {
+        use std::sync::atomic::AtomicBool;
+
+        let dir = fixture_dir();
+        let (base, parsed) = build(dir.path());
+        let mut updater = Updater::new(dir.path().to_path_buf(), parsed);
+        let (rebuilt, _) = updater
+            .apply_batch(&base, &[ChangeEvent::Overflow])
+            .expect("rung-3 rebuild");
+        let (old, new) = (Arc::new(base), Arc::new(rebuilt));
+        let shared = Arc::new(SharedSnapshot::new(Arc::clone(&old)));
+        let done = Arc::new(AtomicBool::new(false));
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let (shared, done) = (Arc::clone(&shared), Arc::clone(&done));
+                let (old, new) = (Arc::clone(&old), Arc::clone(&new));
+                std::thread::spawn(move || {
+                    let mut reads = 0usize;
+                    while !done.load(Ordering::SeqCst) || reads == 0 {
+                        let snap = shared.get();
+                        assert!(
+                            Arc::ptr_eq(&snap, &old) || Arc::ptr_eq(&snap, &new),
+                            "a read must return one of the published snapshots"
+                        );
+                        reads += 1;
+                    }
+                    reads
+                })
+            })
+            .collect();
+
+        for i in 0..500 {
+            let next = if i % 2 == 0 { &new } else { &old };
+            shared.swap(Arc::clone(next));
+        }
+        done.store(true, Ordering::SeqCst);
+
+        for reader in readers {
+            assert!(reader.join().expect("reader thread") > 0);
+        }
+        assert!(Arc::ptr_eq(&shared.get(), &old), "the last swap wins");
+    }
+
     // ── Step 3: debounce/coalesce — 5 rapid saves of one file → 1 apply ───
 
     #[test]
@@ -1,8 +1,12 @@
 use anyhow::Result;
 use clap::{Parser, ValueEnum};
 use log::info;
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
+#[cfg(feature = "git")]
+mod git_diff;
 mod server;
 mod watcher;
 
@@ -18,7 +22,11 @@ pub use al_call_hierarchy::{
     analysis, app_package, big_stack, config, dependencies, lsp, protocol, telemetry,
 };
 
-use lsp::snapshot::LspSnapshot;
+use al_syntax::ir::ObjectKind;
+use lsp::call_tree::{CallDirection, find_routines, get_call_tree};
+use lsp::diagnostics::DIAGNOSTIC_RULES;
+use lsp::dot::{GraphScope, call_graph_dot};
+use lsp::snapshot::{DefinitionKind, LspSnapshot};
 use server::run_server;
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -26,13 +34,101 @@ enum OutputFormat {
     Text,
     Json,
     Csv,
+    Html,
+}
+
+/// Lowest finding severity that makes `--fail-on` exit with
+/// [`EXIT_FINDINGS`]; ordered `info` < `warning` < `critical`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum FailOn {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl FailOn {
+    /// Whether a finding of `severity` (`"info"`, `"warning"` or
+    /// `"critical"`) is at or above this threshold.
+    fn is_met_by(self, severity: &str) -> bool {
+        let level = match severity {
+            "critical" => FailOn::Critical,
+            "warning" => FailOn::Warning,
+            _ => FailOn::Info,
+        };
+        level >= self
+    }
+}
+
+// Exit codes — a stable contract for scripts; keep README's table in sync.
+/// Success (also: findings below the `--fail-on` threshold).
+const EXIT_SUCCESS: u8 = 0;
+/// Any error not covered below (bad arguments, unreadable config, I/O).
+const EXIT_ERROR: u8 = 1;
+/// `--analyze --fail-on <SEVERITY>` found at least one finding at or above it.
+const EXIT_FINDINGS: u8 = 2;
+/// The project could not be indexed (no program snapshot could be built).
+const EXIT_INDEXING: u8 = 3;
+
+/// Building the program snapshot for a project failed — exits with
+/// [`EXIT_INDEXING`] rather than the generic [`EXIT_ERROR`].
+#[derive(Debug)]
+struct IndexingError(PathBuf);
+
+impl std::fmt::Display for IndexingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Failed to build the program snapshot for {} — is this a valid AL app \
+             workspace (a readable app.json at its root)?",
+            self.0.display()
+        )
+    }
+}
+
+impl std::error::Error for IndexingError {}
+
+/// Routine kind filter for `--list` (mirrors [`DefinitionKind`]).
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum ListKind {
+    Procedure,
+    Trigger,
+    EventSubscriber,
+    EventPublisher,
+}
+
+impl From<ListKind> for DefinitionKind {
+    fn from(k: ListKind) -> Self {
+        match k {
+            ListKind::Procedure => DefinitionKind::Procedure,
+            ListKind::Trigger => DefinitionKind::Trigger,
+            ListKind::EventSubscriber => DefinitionKind::EventSubscriber,
+            ListKind::EventPublisher => DefinitionKind::EventPublisher,
+        }
+    }
+}
+
+/// Walk direction for `--hierarchy` (mirrors [`CallDirection`]).
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Direction {
+    Incoming,
+    Outgoing,
+}
+
+impl From<Direction> for CallDirection {
+    fn from(d: Direction) -> Self {
+        match d {
+            Direction::Incoming => CallDirection::Incoming,
+            Direction::Outgoing => CallDirection::Outgoing,
+        }
+    }
 }
 
 #[derive(Parser, Debug)]
 #[command(name = "al-call-hierarchy")]
 #[command(about = "Blazing-fast call hierarchy server for AL (Business Central)")]
 struct Args {
-    /// Path to the AL project root (CLI mode - index and report stats)
+    /// Path to the AL project root (CLI mode - index and report stats). With
+    /// --analyze, may also be a `.app` package file
     #[arg(short, long)]
     project: Option<PathBuf>,
 
@@ -40,18 +136,146 @@ struct Args {
     #[arg(long)]
     lsp: bool,
 
-    /// Run code quality analysis (requires --project)
+    /// Serve the LSP over TCP instead of stdio: listen on this address
+    /// (e.g. `127.0.0.1:9257`; port 0 picks a free one) and serve the first
+    /// client that connects. The bound address is printed to stderr. TCP
+    /// only — Unix domain sockets are not supported
+    #[arg(long, value_name = "ADDR")]
+    socket: Option<String>,
+
+    /// Run code quality analysis (requires --project; a `.app` package is
+    /// analyzed from its embedded source, or inventoried when it has none)
     #[arg(short, long)]
     analyze: bool,
 
-    /// Output format for analysis results
-    #[arg(short, long, value_enum, default_value = "text")]
-    format: OutputFormat,
+    /// List the project's routine declarations instead of reporting index stats
+    /// (requires --project)
+    #[arg(long)]
+    list: bool,
+
+    /// Only list routines of this kind (with --list)
+    #[arg(long, value_enum)]
+    kind: Option<ListKind>,
+
+    /// Append each routine's content-derived stable id as a third column
+    /// (with --list)
+    #[arg(long)]
+    stable_ids: bool,
+
+    /// Print the call tree of one procedure, given as `Object.Procedure`, as
+    /// JSON (requires --project)
+    #[arg(long, value_name = "OBJECT.PROCEDURE")]
+    hierarchy: Option<String>,
+
+    /// Walk callers or callees (with --hierarchy)
+    #[arg(long, value_enum, default_value = "outgoing")]
+    direction: Direction,
+
+    /// How many call levels below the procedure to print (with --hierarchy);
+    /// unbounded when omitted
+    #[arg(long, value_name = "N")]
+    depth: Option<usize>,
+
+    /// List every event subscriber with the publisher object and event it
+    /// targets (requires --project)
+    #[arg(long)]
+    list_subscribers: bool,
+
+    /// Print which test procedures transitively call each workspace procedure
+    /// (requires --project)
+    #[arg(long)]
+    test_coverage: bool,
+
+    /// Export the resolved call graph as Graphviz DOT to PATH, or to stdout
+    /// when PATH is omitted or `-` (requires --project)
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = "-")]
+    export_graph: Option<PathBuf>,
+
+    /// Only export calls into or out of this object (with --export-graph)
+    #[arg(long, value_name = "OBJECT", conflicts_with = "graph_root")]
+    graph_object: Option<String>,
+
+    /// Only export the calls reachable from this procedure, given as
+    /// `Object.Procedure` (with --export-graph)
+    #[arg(long, value_name = "OBJECT.PROCEDURE")]
+    graph_root: Option<String>,
+
+    /// Output format for analysis results (default: text)
+    #[arg(short, long, value_enum)]
+    format: Option<OutputFormat>,
+
+    /// Report analysis file paths relative to the project root (portable
+    /// across machines) instead of bare file names
+    #[arg(long, overrides_with = "no_relative_paths")]
+    relative_paths: bool,
+
+    /// Report bare file names even when the --config file sets
+    /// `relativePaths`
+    #[arg(long, overrides_with = "relative_paths")]
+    no_relative_paths: bool,
+
+    /// Print only the aggregate analysis summary, without the per-procedure
+    /// metrics and findings
+    #[arg(long, overrides_with = "no_summary_only")]
+    summary_only: bool,
+
+    /// Print the full report even when the --config file sets `summaryOnly`
+    #[arg(long, overrides_with = "summary_only")]
+    no_summary_only: bool,
+
+    /// Write the analysis report (any `--format`) to this file instead of
+    /// stdout, creating its parent directories as needed (with --analyze)
+    #[arg(long, value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    /// Exit with code 2 when the analysis reports any finding at or above this
+    /// severity (with --analyze)
+    #[arg(long, value_enum, value_name = "SEVERITY")]
+    fail_on: Option<FailOn>,
+
+    /// Report only findings not already in this previously exported
+    /// `--format json` report (matched by category, location and procedure;
+    /// with --analyze)
+    #[arg(long, value_name = "FILE")]
+    baseline: Option<PathBuf>,
+
+    /// Report only procedures in `.al` files changed since this git ref
+    /// (`git diff --name-only <REF>`); the whole project is still indexed so
+    /// calls resolve across files (with --analyze; needs the `git` feature)
+    #[arg(long, value_name = "REF")]
+    since_git_ref: Option<String>,
+
+    /// Leave routines of these object types out of the analysis, e.g.
+    /// `report,xmlport` (adds to the config file's `excludeObjectTypes`)
+    #[arg(long, value_name = "TYPES", value_delimiter = ',')]
+    exclude_object_type: Vec<String>,
+
+    /// List the diagnostic rules the LSP server can publish, with their
+    /// descriptions and default severities, and exit (JSON with
+    /// `--format json`)
+    #[arg(long)]
+    list_rules: bool,
+
+    /// Print FILE's raw tree-sitter parse tree (an S-expression with byte
+    /// ranges) and exit, for grammar/parser bug reports
+    #[arg(long, value_name = "FILE")]
+    dump_tree: Option<PathBuf>,
+
+    /// Leave files whose header comment marks them generated (default marker
+    /// `<auto-generated>`, config `generatedMarker`) out of the analysis
+    #[arg(long)]
+    exclude_generated: bool,
 
     /// Disable the file system watcher (use LSP notifications for changes instead)
     #[arg(long)]
     no_watcher: bool,
 
+    /// How long the LSP server waits after a file change for further changes
+    /// before reindexing, in milliseconds (overrides `watcher.debounceMs`;
+    /// default 100)
+    #[arg(long, value_name = "MS")]
+    watch_debounce_ms: Option<u64>,
+
     /// Disable anonymous failure-diagnostics telemetry for this run.
     /// (Telemetry is also off by default in dev/CI builds.)
     #[arg(long)]
@@ -60,13 +284,84 @@ struct Args {
     /// Enable verbose logging
     #[arg(short, long)]
     verbose: bool,
+
+    /// Load settings from this config file (same JSON schema as
+    /// `.al-call-hierarchy.json`, plus a `cli` section for `format`,
+    /// `relativePaths` and `summaryOnly`). Layered over the global and
+    /// workspace files; flags given on the command line still win
+    /// (`--no-relative-paths` / `--no-summary-only` turn a file's `true`
+    /// off). A file that fails to read or parse, or has any other `cli`
+    /// key, is an error.
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+
+    /// Emit indexing phase timings as JSON (`walk_ms`, `parse_ms`, `build_ms`,
+    /// `files`, `definitions`) to stderr, or to PATH when given (CLI index
+    /// mode only)
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = "-")]
+    profile: Option<PathBuf>,
 }
 
-fn main() -> Result<()> {
-    let args = Args::parse();
+/// Exit codes: 0 success, 1 error, 2 `--fail-on` threshold met, 3 indexing
+/// error (see [`EXIT_SUCCESS`] and its siblings).
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(code) => ExitCode::from(code),
+        Err(e) => {
+            // Same rendering `fn main() -> anyhow::Result<()>` used.
+            eprintln!("Error: {e:?}");
+            if e.downcast_ref::<IndexingError>().is_some() {
+                ExitCode::from(EXIT_INDEXING)
+            } else {
+                ExitCode::from(EXIT_ERROR)
+            }
+        }
+    }
+}
+
+/// Dispatch on the parsed flags; `Ok` carries the process exit code.
+fn run(args: Args) -> Result<u8> {
+    // `--config` settings are defaults for the matching flags: an explicit flag
+    // (or its `--no-` form) overrides the file, the file overrides the
+    // built-in default.
+    let file_cli = match &args.config {
+        Some(path) if !path.is_file() => {
+            anyhow::bail!("--config file not found: {}", path.display())
+        }
+        Some(path) => config::CliFileConfig::load_at(path)
+            .map_err(|e| anyhow::anyhow!("--config {}: {e}", path.display()))?,
+        None => config::CliFileConfig::default(),
+    };
+    let format = match (args.format.clone(), file_cli.format.as_deref()) {
+        (Some(f), _) => f,
+        (None, Some(name)) => OutputFormat::from_str(name, true)
+            .map_err(|e| anyhow::anyhow!("invalid `cli.format` in --config file: {e}"))?,
+        (None, None) => OutputFormat::Text,
+    };
+    let flag_or_file =
+        |on: bool, off: bool, file: Option<bool>| on || (!off && file.unwrap_or(false));
+    let relative_paths = flag_or_file(
+        args.relative_paths,
+        args.no_relative_paths,
+        file_cli.relative_paths,
+    );
+    let summary_only = flag_or_file(
+        args.summary_only,
+        args.no_summary_only,
+        file_cli.summary_only,
+    );
+    let excluded_types = args
+        .exclude_object_type
+        .iter()
+        .map(|name| {
+            config::parse_object_type(name).ok_or_else(|| {
+                anyhow::anyhow!("--exclude-object-type: unknown object type `{name}`")
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
 
     // Initialize logging - suppress for JSON output
-    let log_level = if matches!(args.format, OutputFormat::Json) && args.analyze {
+    let log_level = if matches!(format, OutputFormat::Json | OutputFormat::Html) && args.analyze {
         log::LevelFilter::Off
     } else if args.verbose {
         log::LevelFilter::Debug
@@ -83,6 +378,33 @@ fn main() -> Result<()> {
     if args.analyze && args.project.is_none() {
         anyhow::bail!("--analyze requires --project <path>");
     }
+    if args.list && args.project.is_none() {
+        anyhow::bail!("--list requires --project <path>");
+    }
+    if args.list_subscribers && args.project.is_none() {
+        anyhow::bail!("--list-subscribers requires --project <path>");
+    }
+    if args.test_coverage && args.project.is_none() {
+        anyhow::bail!("--test-coverage requires --project <path>");
+    }
+    if args.hierarchy.is_some() && args.project.is_none() {
+        anyhow::bail!("--hierarchy requires --project <path>");
+    }
+    if args.export_graph.is_some() && args.project.is_none() {
+        anyhow::bail!("--export-graph requires --project <path>");
+    }
+
+    if args.list_rules {
+        print_rules(&format)?;
+        return Ok(EXIT_SUCCESS);
+    }
+
+    if let Some(file) = &args.dump_tree {
+        let source = std::fs::read_to_string(file)
+            .map_err(|e| anyhow::anyhow!("--dump-tree: cannot read {}: {e}", file.display()))?;
+        print!("{}", al_syntax::dump_tree(&source));
+        return Ok(EXIT_SUCCESS);
+    }
 
     if args.lsp {
         // `--lsp` was parsed but never consulted below — passing it alongside
@@ -90,24 +412,98 @@ fn main() -> Result<()> {
         // asked for. Give it real, unconditional effect (highest precedence): it
         // always starts the LSP server, regardless of --project/--analyze.
         info!("Starting AL Call Hierarchy LSP server (--lsp)");
-        run_server(args.no_watcher, args.no_telemetry)?;
+        run_server(
+            args.no_watcher,
+            args.no_telemetry,
+            args.watch_debounce_ms,
+            args.socket.as_deref(),
+        )?;
     } else if let Some(project) = args.project {
         if args.analyze {
-            // Analysis mode
-            run_analysis(&project, &args.format)?;
+            let baseline = load_baseline(args.baseline.as_deref())?;
+            let options = AnalyzeOptions {
+                config_file: args.config.as_deref(),
+                excluded_types: &excluded_types,
+                exclude_generated: args.exclude_generated,
+                baseline: baseline.as_ref(),
+                format: &format,
+                summary_only,
+                output: args.output.as_deref(),
+            };
+            let is_app_package = project.is_file()
+                && project
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("app"));
+            let findings = if is_app_package {
+                if args.since_git_ref.is_some() {
+                    anyhow::bail!("--since-git-ref needs a source folder, not a .app package");
+                }
+                analyze_app_package(&project, &options)?
+            } else {
+                run_analysis(
+                    &project,
+                    &options,
+                    relative_paths,
+                    args.since_git_ref.as_deref(),
+                )?
+            };
+            return Ok(findings_exit_code(&findings, args.fail_on));
+        } else if args.list {
+            list_definitions(
+                &project,
+                args.kind.map(DefinitionKind::from),
+                args.stable_ids,
+            )?;
+        } else if args.list_subscribers {
+            list_subscribers(&project)?;
+        } else if let Some(routine) = &args.hierarchy {
+            print_call_tree(&project, routine, args.direction.into(), args.depth)?;
+        } else if args.test_coverage {
+            report_test_coverage(&project)?;
+        } else if let Some(path) = &args.export_graph {
+            let root = args.graph_root.as_deref();
+            export_graph(&project, path, args.graph_object.as_deref(), root)?;
         } else {
             // CLI mode for testing/indexing (T3 Task 15: re-pointed at the
             // program-engine snapshot — see this block's own doc below).
             info!("Indexing project: {}", project.display());
-            report_index_stats(&project)?;
+            report_index_stats(&project, args.profile.as_deref())?;
         }
     } else {
         // LSP server mode (default)
         info!("Starting AL Call Hierarchy LSP server");
-        run_server(args.no_watcher, args.no_telemetry)?;
+        run_server(
+            args.no_watcher,
+            args.no_telemetry,
+            args.watch_debounce_ms,
+            args.socket.as_deref(),
+        )?;
     }
 
-    Ok(())
+    Ok(EXIT_SUCCESS)
+}
+
+/// Read the `--baseline` report, if one was given.
+fn load_baseline(path: Option<&Path>) -> Result<Option<analysis::Baseline>> {
+    let Some(path) = path else {
+        return Ok(None);
+    };
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("reading baseline {}: {e}", path.display()))?;
+    let baseline = analysis::Baseline::from_json(&json)
+        .map_err(|e| anyhow::anyhow!("parsing baseline {}: {e}", path.display()))?;
+    Ok(Some(baseline))
+}
+
+/// [`EXIT_FINDINGS`] when `fail_on` is set and any finding meets it,
+/// otherwise [`EXIT_SUCCESS`].
+fn findings_exit_code(findings: &[analysis::Finding], fail_on: Option<FailOn>) -> u8 {
+    match fail_on {
+        Some(threshold) if findings.iter().any(|f| threshold.is_met_by(&f.severity)) => {
+            EXIT_FINDINGS
+        }
+        _ => EXIT_SUCCESS,
+    }
 }
 
 /// CLI index-and-report mode: build the program-engine snapshot for
@@ -126,13 +522,13 @@ fn main() -> Result<()> {
 /// replaced by a count of dependency routines with EMBEDDED source
 /// (`dep_meta` — real per-routine identities, unlike a `.app`'s
 /// symbol-only ABI catalog, which has no equivalent "definition" to count).
-fn report_index_stats(project: &Path) -> Result<()> {
-    let Some(snap) = LspSnapshot::build_full(project) else {
-        anyhow::bail!(
-            "Failed to build the program snapshot for {} — is this a valid AL app \
-             workspace (a readable app.json at its root)?",
-            project.display()
-        );
+///
+/// `profile` (`--profile`) additionally writes the build's
+/// [`lsp::snapshot::BuildProfile`] as one JSON object — to stderr for `-`,
+/// otherwise to that file.
+fn report_index_stats(project: &Path, profile: Option<&Path>) -> Result<()> {
+    let Some((snap, build_profile)) = LspSnapshot::build_full_profiled(project) else {
+        return Err(IndexingError(project.to_path_buf()).into());
     };
 
     let definitions: usize = snap.decls_by_file.values().map(|v| v.len()).sum();
@@ -145,12 +541,267 @@ fn report_index_stats(project: &Path) -> Result<()> {
         dep_definitions
     );
     info!("Found {} call sites", call_sites);
+
+    if let Some(target) = profile {
+        let json = serde_json::to_string(&build_profile)?;
+        if target == Path::new("-") {
+            eprintln!("{json}");
+        } else {
+            std::fs::write(target, format!("{json}\n"))
+                .map_err(|e| anyhow::anyhow!("writing --profile {}: {e}", target.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// CLI `--list` mode: print every workspace routine declaration (optionally
+/// only those of one [`DefinitionKind`]) as `Object.Routine<TAB>path:line`,
+/// one per line, in [`LspSnapshot::definitions_by_kind`]'s deterministic
+/// order. `--stable-ids` appends a `<TAB>stable-id` column (see
+/// [`lsp::snapshot::DeclEntry::stable_id`]). Declarations only, so the snapshot skips call resolution
+/// ([`LspSnapshot::build_symbols_only`]).
+fn list_definitions(project: &Path, kind: Option<DefinitionKind>, stable_ids: bool) -> Result<()> {
+    let Some(snap) = LspSnapshot::build_symbols_only(project) else {
+        return Err(IndexingError(project.to_path_buf()).into());
+    };
+
+    let kinds = match kind {
+        Some(k) => vec![k],
+        None => vec![
+            DefinitionKind::Procedure,
+            DefinitionKind::Trigger,
+            DefinitionKind::EventSubscriber,
+            DefinitionKind::EventPublisher,
+        ],
+    };
+    for k in kinds {
+        for d in snap.definitions_by_kind(k) {
+            let line = format!(
+                "{}.{}\t{}:{}",
+                object_name(&snap, &d.id.object),
+                d.name,
+                d.virtual_path,
+                d.origin.start.row + 1
+            );
+            if stable_ids {
+                println!("{line}\t{}", d.stable_id());
+            } else {
+                println!("{line}");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// CLI `--list-subscribers` mode: print every event subscription as
+/// `Object.Routine<TAB>path:line<TAB>type publisher.event` (publisher names
+/// lowercased, as parsed), in [`LspSnapshot::event_subscriptions`]' order.
+fn list_subscribers(project: &Path) -> Result<()> {
+    let Some(snap) = LspSnapshot::build_symbols_only(project) else {
+        return Err(IndexingError(project.to_path_buf()).into());
+    };
+
+    for (d, args) in snap.event_subscriptions() {
+        println!(
+            "{}.{}\t{}:{}\t{} {}.{}",
+            object_name(&snap, &d.id.object),
+            d.name,
+            d.virtual_path,
+            d.origin.start.row + 1,
+            args.publisher_object_type,
+            args.publisher_name,
+            args.event_name
+        );
+    }
+    Ok(())
+}
+
+/// CLI `--hierarchy` mode: print the [`get_call_tree`] of the workspace
+/// procedure named `routine` (`Object.Procedure`) as pretty JSON. With
+/// overloads, the first declaration is used.
+fn print_call_tree(
+    project: &Path,
+    routine: &str,
+    direction: CallDirection,
+    depth: Option<usize>,
+) -> Result<()> {
+    let Some(snap) = LspSnapshot::build_full(project) else {
+        return Err(IndexingError(project.to_path_buf()).into());
+    };
+
+    let matches = find_routines(&snap, routine);
+    let Some(decl) = matches.first() else {
+        anyhow::bail!("--hierarchy: no workspace procedure named `{routine}`");
+    };
+    if matches.len() > 1 {
+        info!(
+            "{routine} has {} overloads; showing the one at {}:{}",
+            matches.len(),
+            decl.virtual_path,
+            decl.origin.start.row + 1
+        );
+    }
+    let tree = get_call_tree(&snap, &decl.id, direction, depth)
+        .ok_or_else(|| anyhow::anyhow!("--hierarchy: `{routine}` has no declaration"))?;
+    println!("{}", serde_json::to_string_pretty(&tree)?);
+    Ok(())
+}
+
+/// `--list-rules`: every [`DIAGNOSTIC_RULES`] entry as
+/// `code<TAB>severity<TAB>description` (`information/warning` for a rule with
+/// a critical threshold), or the JSON array with `--format json`.
+fn print_rules(format: &OutputFormat) -> Result<()> {
+    if matches!(format, OutputFormat::Json) {
+        println!("{}", serde_json::to_string_pretty(DIAGNOSTIC_RULES)?);
+        return Ok(());
+    }
+    for rule in DIAGNOSTIC_RULES {
+        let severity = match rule.critical_severity {
+            Some(critical) => format!("{}/{critical}", rule.default_severity),
+            None => rule.default_severity.to_string(),
+        };
+        println!("{}\t{severity}\t{}", rule.code, rule.description);
+    }
     Ok(())
 }
 
-/// Run code quality analysis on a project
-fn run_analysis(project: &PathBuf, format: &OutputFormat) -> Result<()> {
-    use analysis::{AnalysisResult, ProcedureMetrics, build_summary, generate_findings};
+/// CLI `--export-graph` mode: write the [`call_graph_dot`] of `project` to
+/// `path` (`-` for stdout), restricted to `object`'s calls (`--graph-object`)
+/// or to the subtree of every overload of `root` (`--graph-root`).
+fn export_graph(
+    project: &Path,
+    path: &Path,
+    object: Option<&str>,
+    root: Option<&str>,
+) -> Result<()> {
+    let Some(snap) = LspSnapshot::build_full(project) else {
+        return Err(IndexingError(project.to_path_buf()).into());
+    };
+
+    let scope = match (object, root) {
+        (_, Some(root)) => {
+            let roots: Vec<_> = find_routines(&snap, root)
+                .into_iter()
+                .map(|d| d.id.clone())
+                .collect();
+            if roots.is_empty() {
+                anyhow::bail!("--graph-root: no workspace procedure named `{root}`");
+            }
+            GraphScope::Subtree(roots)
+        }
+        (Some(object), None) => GraphScope::Object(object.to_string()),
+        (None, None) => GraphScope::Whole,
+    };
+    let dot = call_graph_dot(&snap, &scope);
+    if path == Path::new("-") {
+        print!("{dot}");
+    } else {
+        std::fs::write(path, dot)
+            .map_err(|e| anyhow::anyhow!("--export-graph: cannot write {}: {e}", path.display()))?;
+        info!("Wrote call graph to {}", path.display());
+    }
+    Ok(())
+}
+
+/// CLI `--test-coverage` mode: print every workspace procedure as
+/// `Object.Routine<TAB>path:line<TAB>tests`, where `tests` lists the test
+/// procedures that transitively call it ([`LspSnapshot::tested_by`]) or is
+/// `-` when none do. Test procedures themselves are not listed.
+fn report_test_coverage(project: &Path) -> Result<()> {
+    let Some(snap) = LspSnapshot::build_full(project) else {
+        return Err(IndexingError(project.to_path_buf()).into());
+    };
+
+    let tested_by = snap.tested_by();
+    let (mut total, mut covered) = (0usize, 0usize);
+    for d in snap.definitions_by_kind(DefinitionKind::Procedure) {
+        if snap.is_test_procedure(d) {
+            continue;
+        }
+        total += 1;
+        let names = match tested_by.get(&d.id) {
+            Some(ids) => {
+                covered += 1;
+                ids.iter()
+                    .map(|t| {
+                        let name = snap.decl_by_id.get(t).map_or("?", |e| e.name.as_str());
+                        format!("{}.{name}", object_name(&snap, &t.object))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+            None => "-".to_string(),
+        };
+        println!(
+            "{}.{}\t{}:{}\t{names}",
+            object_name(&snap, &d.id.object),
+            d.name,
+            d.virtual_path,
+            d.origin.start.row + 1
+        );
+    }
+    info!("{covered} of {total} procedures are covered by at least one test");
+    Ok(())
+}
+
+/// Display name of a graph object, `Unknown` when it is not in the graph.
+fn object_name<'a>(
+    snap: &'a LspSnapshot,
+    id: &al_call_hierarchy::program::ObjectNodeId,
+) -> &'a str {
+    snap.graph
+        .objects
+        .binary_search_by(|probe| probe.id.cmp(id))
+        .ok()
+        .map(|i| snap.graph.objects[i].name.as_str())
+        .unwrap_or("Unknown")
+}
+
+/// The `--analyze` flags shared by [`run_analysis`] and
+/// [`analyze_app_package`]. `config_file` is the `--config` file layered over
+/// the project's own config, `excluded_types` (`--exclude-object-type`) adds
+/// to its excluded kinds, `exclude_generated` (`--exclude-generated`) turns
+/// on its generated-file exclusion, and findings already in `baseline`
+/// (`--baseline`) are dropped. The report goes to `output` (`--output`)
+/// instead of stdout when set.
+struct AnalyzeOptions<'a> {
+    config_file: Option<&'a Path>,
+    excluded_types: &'a [ObjectKind],
+    exclude_generated: bool,
+    baseline: Option<&'a analysis::Baseline>,
+    format: &'a OutputFormat,
+    summary_only: bool,
+    output: Option<&'a Path>,
+}
+
+impl AnalyzeOptions<'_> {
+    /// The project's diagnostic config with the command-line overrides applied.
+    fn load_config(&self, root: &Path) -> config::DiagnosticConfig {
+        let mut config = config::DiagnosticConfig::load_with_file(root, self.config_file);
+        config
+            .excluded_object_types
+            .extend_from_slice(self.excluded_types);
+        config.exclude_generated |= self.exclude_generated;
+        config
+    }
+}
+
+/// Run code quality analysis on a project. `relative_paths` reports each
+/// file relative to `project` (see [`analysis::report_path`]); with
+/// `since_git_ref` (`--since-git-ref`) only procedures in files changed
+/// since that ref are reported. Returns the reported findings for the
+/// `--fail-on` check.
+///
+/// Whatever the display mode, files are keyed by their project-relative
+/// path until the result is complete, so two same-named files in different
+/// folders never share metrics, callers or changed-file scope; bare file
+/// names are only applied for printing.
+fn run_analysis(
+    project: &PathBuf,
+    options: &AnalyzeOptions,
+    relative_paths: bool,
+    since_git_ref: Option<&str>,
+) -> Result<Vec<analysis::Finding>> {
     use rayon::prelude::*;
     use std::fs;
     use std::time::Instant;
@@ -159,8 +810,10 @@ fn run_analysis(project: &PathBuf, format: &OutputFormat) -> Result<()> {
     let start = Instant::now();
     info!("Analyzing project: {}", project.display());
 
-    // Collect all .al files
+    // Collect all .al files, in a filesystem-independent order: the summary's
+    // averages and the copy-paste groups are built in this order.
     let al_files: Vec<PathBuf> = WalkDir::new(project)
+        .sort_by_file_name()
         .into_iter()
         .filter_map(|e| e.ok())
         .filter(|e| {
@@ -174,73 +827,580 @@ fn run_analysis(project: &PathBuf, format: &OutputFormat) -> Result<()> {
 
     info!("Found {} AL files", al_files.len());
 
-    // Parse + collect per-procedure metrics in parallel, from the owned IR, on
-    // a big-stack pool (T2.1: the CLI main thread's default pool has no
-    // guaranteed-generous stack; see `big_stack`'s doc).
+    // Config from project root (the IR-level findings below need it while the
+    // parsed file is still in hand).
+    let config = options.load_config(project);
+
+    let sources: Vec<(String, String)> = al_files
+        .par_iter()
+        .filter_map(|path| {
+            let source = fs::read_to_string(path).ok()?;
+            let file = analysis::report_path(path, Some(project.as_path()));
+            Some((file, source))
+        })
+        .collect();
+    let mut result = analyze_sources(&sources, &config);
+    if let Some(snap) = LspSnapshot::build_full(project) {
+        let (blast_radius, incoming_calls) = caller_scores(&snap, project, &config);
+        result.blast_radius = blast_radius;
+        result.churn_risk = analysis::churn_risk_ranking(&result.metrics, &incoming_calls);
+        analysis::apply_coupling(&mut result.metrics, &coupling_by_location(&snap, project));
+        analysis::apply_ui_reachability(
+            &mut result.metrics,
+            &ui_reachability_by_location(&snap, project),
+        );
+        result.callees_by_object = callee_breakdowns(&snap, project, &config);
+        result
+            .findings
+            .extend(analysis::coupling_findings(&result.metrics, &config));
+        if config.unused_external_refs {
+            result
+                .findings
+                .extend(unused_external_ref_findings(&snap, project, &config));
+        }
+        result.summary = analysis::build_summary(&result.metrics, &result.findings);
+    } else {
+        info!(
+            "Skipping blast radius, churn risk, coupling, UI reachability and unused external \
+             references: no program snapshot for {}",
+            project.display()
+        );
+    }
+    if let Some(git_ref) = since_git_ref {
+        let changed = changed_report_paths(project, git_ref)?;
+        info!("{} AL files changed since {git_ref}", changed.len());
+        result.retain_files(&changed);
+    }
+    if !relative_paths {
+        let display: std::collections::HashMap<String, String> = al_files
+            .iter()
+            .map(|path| {
+                (
+                    analysis::report_path(path, Some(project.as_path())),
+                    analysis::report_path(path, None),
+                )
+            })
+            .collect();
+        result.rename_files(&display);
+    }
+    if let Some(baseline) = options.baseline {
+        result.retain_new(baseline);
+    }
+    result.sort_for_output();
+
+    info!(
+        "Analyzed {} procedures in {:.1}ms",
+        result.summary.total_procedures,
+        start.elapsed().as_secs_f64() * 1000.0
+    );
+
+    print_analysis(&result, project, &config, options)?;
+    Ok(result.findings)
+}
+
+/// The project-relative report paths ([`analysis::report_path`]) of the
+/// `.al` files changed since `git_ref`.
+#[cfg(feature = "git")]
+fn changed_report_paths(
+    project: &Path,
+    git_ref: &str,
+) -> Result<std::collections::HashSet<String>> {
+    Ok(git_diff::changed_al_files(project, git_ref)?
+        .iter()
+        .map(|path| analysis::report_path(path, Some(project)))
+        .collect())
+}
+
+#[cfg(not(feature = "git"))]
+fn changed_report_paths(
+    _project: &Path,
+    _git_ref: &str,
+) -> Result<std::collections::HashSet<String>> {
+    anyhow::bail!("--since-git-ref: this build was compiled without the `git` feature")
+}
+
+/// `--analyze` on a `.app` package (`--project <file.app>`): analyze the AL
+/// source it embeds, or — for a symbol-only package — print the object and
+/// method inventory from its `SymbolReference.json` instead (no findings).
+fn analyze_app_package(app: &Path, options: &AnalyzeOptions) -> Result<Vec<analysis::Finding>> {
+    use al_call_hierarchy::snapshot::embedded::extract_embedded_source;
+
+    info!("Analyzing app package: {}", app.display());
+    let embedded = extract_embedded_source(app)
+        .map_err(|e| anyhow::anyhow!("reading {}: {e}", app.display()))?;
+    if !embedded.is_empty() {
+        info!("Found {} embedded AL files", embedded.len());
+        // The package's folder stands in for the project root.
+        let root = app.parent().unwrap_or_else(|| Path::new("."));
+        let config = options.load_config(root);
+        let sources: Vec<(String, String)> = embedded
+            .into_iter()
+            .map(|f| (f.virtual_path, f.text.to_string()))
+            .collect();
+        let mut result = analyze_sources(&sources, &config);
+        if let Some(baseline) = options.baseline {
+            result.retain_new(baseline);
+        }
+        result.sort_for_output();
+        print_analysis(&result, app, &config, options)?;
+        return Ok(result.findings);
+    }
+
+    let package = app_package::extract_app_package(app)
+        .map_err(|e| anyhow::anyhow!("reading {}: {e}", app.display()))?;
+    let inventory = package.inventory();
+    info!(
+        "{} ships no source; listing its {} objects",
+        package.metadata.name,
+        inventory.len()
+    );
+    if matches!(options.format, OutputFormat::Html) {
+        anyhow::bail!(
+            "--format html needs source to analyze; {} ships none (use text, json or csv)",
+            package.metadata.name
+        );
+    }
+    write_output(options, |out| {
+        write_inventory(out, &package.metadata, &inventory, options.format)
+    })?;
+    Ok(Vec::new())
+}
+
+/// Write a symbol-only package's object inventory to `out` in `format`
+/// (never HTML; [`analyze_app_package`] rejects that first).
+fn write_inventory(
+    out: &mut dyn Write,
+    metadata: &app_package::AppMetadata,
+    inventory: &[app_package::InventoryEntry],
+    format: &OutputFormat,
+) -> Result<()> {
+    match format {
+        OutputFormat::Json => writeln!(out, "{}", serde_json::to_string_pretty(inventory)?)?,
+        OutputFormat::Html => unreachable!("rejected by analyze_app_package"),
+        OutputFormat::Csv => {
+            writeln!(out, "object_type,id,name,methods")?;
+            for e in inventory {
+                writeln!(
+                    out,
+                    "{},{},\"{}\",{}",
+                    e.object_type,
+                    e.id,
+                    e.name.replace('"', "\"\""),
+                    e.methods
+                )?;
+            }
+        }
+        OutputFormat::Text => {
+            writeln!(
+                out,
+                "\nObject Inventory: {} {} by {}\n",
+                metadata.name, metadata.version, metadata.publisher
+            )?;
+            writeln!(
+                out,
+                "{:<16} {:>10} {:<40} {:>7}",
+                "Type", "Id", "Name", "Methods"
+            )?;
+            writeln!(out, "{}", "-".repeat(76))?;
+            for e in inventory {
+                writeln!(
+                    out,
+                    "{:<16} {:>10} {:<40} {:>7}",
+                    e.object_type, e.id, e.name, e.methods
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Per-procedure metrics and findings for already-read `(report path, text)`
+/// sources — the shared core of [`run_analysis`] and [`analyze_app_package`].
+/// Generated sources are skipped when the config excludes them.
+/// `blast_radius` is left empty; the caller sorts for output.
+fn analyze_sources(
+    sources: &[(String, String)],
+    config: &config::DiagnosticConfig,
+) -> analysis::AnalysisResult {
+    use analysis::{
+        AnalysisResult, BodyFingerprint, Finding, ProcedureMetrics, build_summary,
+        duplicate_code_findings, generate_findings,
+    };
+    use rayon::prelude::*;
+
+    // Parse + collect per-procedure metrics (and IR-level findings) in
+    // parallel, from the owned IR, on a big-stack pool (T2.1: the CLI main
+    // thread's default pool has no guaranteed-generous stack; see
+    // `big_stack`'s doc).
     let pool = big_stack::big_stack_pool();
-    let all_metrics: Vec<ProcedureMetrics> = pool.install(|| {
-        al_files
+    type PerFile = (Vec<ProcedureMetrics>, Vec<Finding>, Vec<BodyFingerprint>);
+    let per_file: Vec<PerFile> = pool.install(|| {
+        sources
             .par_iter()
-            .flat_map(|path| match fs::read_to_string(path) {
-                Ok(source) => extract_metrics_ir(&source, path),
-                Err(_) => vec![],
-            })
+            .filter(|(_, source)| !config.skips_generated(source))
+            .map(|(file, source)| extract_metrics_ir(source, file, config))
             .collect()
     });
-
-    // Generate findings using config from project root
-    let config = config::DiagnosticConfig::load(project);
+    let mut all_metrics = Vec::new();
     let mut all_findings = Vec::new();
-    for metrics in &all_metrics {
-        all_findings.extend(generate_findings(metrics, &config));
+    let mut all_fingerprints = Vec::new();
+    for (metrics, ir_findings, fingerprints) in per_file {
+        for m in &metrics {
+            all_findings.extend(generate_findings(m, config));
+        }
+        all_findings.extend(ir_findings);
+        all_metrics.extend(metrics);
+        all_fingerprints.extend(fingerprints);
+    }
+    // Copy-paste detection is project-wide, so it runs after every file is in.
+    if config.duplicate_code_enabled {
+        all_findings.extend(duplicate_code_findings(&all_fingerprints));
     }
 
     // Build summary
     let summary = build_summary(&all_metrics, &all_findings);
 
-    let result = AnalysisResult {
+    AnalysisResult {
         metrics: all_metrics,
         findings: all_findings,
+        blast_radius: Vec::new(),
+        churn_risk: Vec::new(),
+        callees_by_object: Vec::new(),
         summary,
-    };
+    }
+}
 
-    info!(
-        "Analyzed {} procedures in {:.1}ms",
-        result.summary.total_procedures,
-        start.elapsed().as_secs_f64() * 1000.0
-    );
+/// Print an analysis result in the options' format, to stdout or — with
+/// `--output` — to that file; `title` names the analyzed project or package
+/// in the text report headers.
+fn print_analysis(
+    result: &analysis::AnalysisResult,
+    title: &Path,
+    config: &config::DiagnosticConfig,
+    options: &AnalyzeOptions,
+) -> Result<()> {
+    write_output(options, |out| {
+        write_analysis(out, result, title, config, options)
+    })
+}
 
-    // Output results
-    match format {
+/// Run `write` against stdout or — with `--output` — that file (its parent
+/// directories created as needed).
+fn write_output(
+    options: &AnalyzeOptions,
+    write: impl FnOnce(&mut dyn Write) -> Result<()>,
+) -> Result<()> {
+    let Some(path) = options.output else {
+        let mut stdout = io::stdout().lock();
+        return write(&mut stdout);
+    };
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| anyhow::anyhow!("--output: cannot create {}: {e}", parent.display()))?;
+    }
+    let file = std::fs::File::create(path)
+        .map_err(|e| anyhow::anyhow!("--output: cannot create {}: {e}", path.display()))?;
+    let mut out = io::BufWriter::new(file);
+    write(&mut out)?;
+    out.flush()
+        .map_err(|e| anyhow::anyhow!("--output: cannot write {}: {e}", path.display()))?;
+    info!("Wrote analysis to {}", path.display());
+    Ok(())
+}
+
+/// Write an analysis result to `out` in the options' format.
+fn write_analysis(
+    out: &mut dyn Write,
+    result: &analysis::AnalysisResult,
+    title: &Path,
+    config: &config::DiagnosticConfig,
+    options: &AnalyzeOptions,
+) -> Result<()> {
+    let summary_only = options.summary_only;
+    match options.format {
         OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&result)?);
+            writeln!(out, "{}", result.to_json(summary_only)?)?;
+        }
+        OutputFormat::Csv if summary_only => {
+            print_csv_summary(out, &result.summary)?;
         }
         OutputFormat::Csv => {
-            print_csv(&result);
+            print_csv(out, result)?;
+        }
+        OutputFormat::Html => {
+            write!(
+                out,
+                "{}",
+                result.to_html(&title.display().to_string(), summary_only)
+            )?;
+        }
+        OutputFormat::Text if summary_only => {
+            writeln!(out, "\nCode Quality Analysis: {}", title.display())?;
+            print_text_summary(out, &result.summary)?;
         }
         OutputFormat::Text => {
-            print_text(&result, project, &config);
+            print_text(out, result, title, config)?;
         }
     }
 
     Ok(())
 }
 
+/// The post-index pass of `--analyze`: every workspace procedure's blast
+/// radius (see [`analysis::BlastRadius`]), highest first, and its number of
+/// incoming call sites keyed by `file:line` (the input to
+/// [`analysis::churn_risk_ranking`]), from the program-engine snapshot's
+/// incoming-call index — the same resolved call and event edges the LSP call
+/// hierarchy serves. Procedures no one calls, and those of an excluded
+/// object type or in an excluded generated file, are omitted. Locations use
+/// project-relative paths, so same-named files in different folders never
+/// share a key.
+fn caller_scores(
+    snap: &LspSnapshot,
+    project: &Path,
+    config: &config::DiagnosticConfig,
+) -> (
+    Vec<analysis::BlastRadius>,
+    std::collections::HashMap<String, usize>,
+) {
+    use al_call_hierarchy::program::RoutineNodeId;
+    use std::collections::HashMap;
+
+    // Dense indices for every routine that is a callee or a caller.
+    fn index_of<'a>(
+        ids: &mut HashMap<&'a RoutineNodeId, usize>,
+        callers: &mut Vec<Vec<usize>>,
+        id: &'a RoutineNodeId,
+    ) -> usize {
+        *ids.entry(id).or_insert_with(|| {
+            callers.push(Vec::new());
+            callers.len() - 1
+        })
+    }
+    let mut ids: HashMap<&RoutineNodeId, usize> = HashMap::new();
+    let mut callers: Vec<Vec<usize>> = Vec::new();
+    for (callee, refs) in &snap.incoming {
+        let c = index_of(&mut ids, &mut callers, callee);
+        for r in refs {
+            let caller = index_of(&mut ids, &mut callers, &snap.edge(r).edge.from);
+            if !callers[c].contains(&caller) {
+                callers[c].push(caller);
+            }
+        }
+    }
+    let counts = analysis::transitive_caller_counts(&callers);
+
+    let mut incoming_calls: HashMap<String, usize> = HashMap::new();
+    let mut out: Vec<analysis::BlastRadius> = Vec::new();
+    for d in snap.decls_by_file.values().flat_map(|decls| decls.iter()) {
+        if config.excludes(d.id.object.kind)
+            || snap
+                .parsed
+                .get(&d.virtual_path)
+                .is_some_and(|entry| config.skips_generated(&entry.text))
+        {
+            continue;
+        }
+        let Some(&i) = ids.get(&d.id) else {
+            continue;
+        };
+        let file = analysis::report_path(&project.join(&d.virtual_path), Some(project));
+        let location = format!("{file}:{}", d.origin.start.row + 1);
+        if let Some(refs) = snap.incoming.get(&d.id) {
+            incoming_calls.insert(location.clone(), refs.len());
+        }
+        if counts[i] > 0 {
+            let object_name = snap
+                .graph
+                .objects
+                .binary_search_by(|probe| probe.id.cmp(&d.id.object))
+                .ok()
+                .map(|i| snap.graph.objects[i].name.as_str())
+                .unwrap_or("Unknown");
+            out.push(analysis::BlastRadius {
+                procedure: format!("{object_name}.{}", d.name),
+                location,
+                score: counts[i],
+            });
+        }
+    }
+    out.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.procedure.cmp(&b.procedure))
+            .then_with(|| a.location.cmp(&b.location))
+    });
+    (out, incoming_calls)
+}
+
+/// [`LspSnapshot::coupling`] keyed by each procedure's `file:line` location,
+/// the key [`analysis::apply_coupling`] looks metrics up by. The file is
+/// always the project-relative path: a bare file name would merge two
+/// same-named files in different folders.
+fn coupling_by_location(
+    snap: &LspSnapshot,
+    project: &Path,
+) -> std::collections::HashMap<String, usize> {
+    let coupling = snap.coupling();
+    snap.decls_by_file
+        .values()
+        .flat_map(|decls| decls.iter())
+        .filter_map(|d| {
+            let count = *coupling.get(&d.id)?;
+            let file = analysis::report_path(&project.join(&d.virtual_path), Some(project));
+            Some((format!("{file}:{}", d.origin.start.row + 1), count))
+        })
+        .collect()
+}
+
+/// [`LspSnapshot::ui_reachability`] keyed like [`coupling_by_location`]:
+/// `true` for a procedure a page or report trigger reaches.
+fn ui_reachability_by_location(
+    snap: &LspSnapshot,
+    project: &Path,
+) -> std::collections::HashMap<String, bool> {
+    use lsp::snapshot::UiReachability;
+
+    let reachability = snap.ui_reachability();
+    snap.decls_by_file
+        .values()
+        .flat_map(|decls| decls.iter())
+        .filter_map(|d| {
+            let class = *reachability.get(&d.id)?;
+            let file = analysis::report_path(&project.join(&d.virtual_path), Some(project));
+            Some((
+                format!("{file}:{}", d.origin.start.row + 1),
+                class == UiReachability::ReachableFromUi,
+            ))
+        })
+        .collect()
+}
+
+/// Every workspace procedure's [`LspSnapshot::outgoing_calls_by_object`] as
+/// counts per object name (`local` for its own object), skipping excluded
+/// object types and generated files like [`caller_scores`]. Procedures
+/// calling into the most other objects come first; locations use
+/// project-relative paths.
+fn callee_breakdowns(
+    snap: &LspSnapshot,
+    project: &Path,
+    config: &config::DiagnosticConfig,
+) -> Vec<analysis::CalleeBreakdown> {
+    let mut out: Vec<analysis::CalleeBreakdown> = Vec::new();
+    for d in snap.decls_by_file.values().flat_map(|decls| decls.iter()) {
+        if config.excludes(d.id.object.kind)
+            || snap
+                .parsed
+                .get(&d.virtual_path)
+                .is_some_and(|entry| config.skips_generated(&entry.text))
+        {
+            continue;
+        }
+        let by_object = snap.outgoing_calls_by_object(&d.id);
+        if by_object.is_empty() {
+            continue;
+        }
+        let mut objects: Vec<analysis::ObjectCalls> = by_object
+            .iter()
+            .map(|(&object, sites)| analysis::ObjectCalls {
+                object: if object == &d.id.object {
+                    "local".to_string()
+                } else {
+                    object_name(snap, object).to_string()
+                },
+                calls: sites.len(),
+            })
+            .collect();
+        objects.sort_by(|a, b| b.calls.cmp(&a.calls).then_with(|| a.object.cmp(&b.object)));
+        let file = analysis::report_path(&project.join(&d.virtual_path), Some(project));
+        out.push(analysis::CalleeBreakdown {
+            procedure: format!("{}.{}", object_name(snap, &d.id.object), d.name),
+            location: format!("{file}:{}", d.origin.start.row + 1),
+            objects,
+        });
+    }
+    let other_objects =
+        |c: &analysis::CalleeBreakdown| c.objects.iter().filter(|o| o.object != "local").count();
+    out.sort_by(|a, b| {
+        other_objects(b)
+            .cmp(&other_objects(a))
+            .then_with(|| a.procedure.cmp(&b.procedure))
+            .then_with(|| a.location.cmp(&b.location))
+    });
+    out
+}
+
+/// One informational `unused_external_ref` finding per workspace variable
+/// typed as a dependency object that nothing in its scope uses (see
+/// [`LspSnapshot::unused_external_refs`]), skipping variables declared in an
+/// excluded object type or generated file. `procedure` is `Object.Routine`
+/// for a local and the bare object name for a global; locations use
+/// project-relative paths.
+fn unused_external_ref_findings(
+    snap: &LspSnapshot,
+    project: &Path,
+    config: &config::DiagnosticConfig,
+) -> Vec<analysis::Finding> {
+    snap.unused_external_refs()
+        .into_iter()
+        .filter(|r| {
+            !config.excludes(r.owner_kind)
+                && !snap
+                    .parsed
+                    .get(&r.virtual_path)
+                    .is_some_and(|entry| config.skips_generated(&entry.text))
+        })
+        .map(|r| {
+            let file = analysis::report_path(&project.join(&r.virtual_path), Some(project));
+            let procedure = match &r.routine {
+                Some(routine) => format!("{}.{routine}", r.owner_name),
+                None => r.owner_name.clone(),
+            };
+            analysis::Finding {
+                category: "unused_external_ref".to_string(),
+                severity: "info".to_string(),
+                location: format!("{file}:{}", r.line),
+                procedure,
+                description: format!(
+                    "{} is declared as {} from a dependency but never used; the \
+                     variable, and possibly the dependency, may be unnecessary",
+                    r.variable, r.type_text
+                ),
+            }
+        })
+        .collect()
+}
+
 /// Extract per-procedure quality metrics for one file from the owned IR. Each
 /// routine is attributed to its enclosing object (object type/name). Replaces the
-/// former tree-sitter walk; complexity comes from the canonical IR walker.
-fn extract_metrics_ir(source: &str, path: &Path) -> Vec<analysis::ProcedureMetrics> {
+/// former tree-sitter walk; complexity comes from the canonical IR walker. Also
+/// returns the file's IR-level findings ([`analysis::generate_ir_findings`]),
+/// which need the parsed routine rather than just its metrics (plus the
+/// file's marker-comment findings), and its body
+/// fingerprints for project-wide duplicate detection (empty when
+/// `duplicate_code_enabled` is off). Objects of an excluded type
+/// (`config.excluded_object_types`) contribute nothing.
+fn extract_metrics_ir(
+    source: &str,
+    file_str: &str,
+    config: &config::DiagnosticConfig,
+) -> (
+    Vec<analysis::ProcedureMetrics>,
+    Vec<analysis::Finding>,
+    Vec<analysis::BodyFingerprint>,
+) {
     use al_syntax::ir::RoutineKind;
-    use analysis::{calculate_quality_score, routine_complexity_ir};
+    use analysis::{
+        body_fingerprint_ir, calculate_quality_score, generate_ir_findings, routine_complexity_for,
+        var_param_count_ir,
+    };
 
     let f = al_syntax::parse(source);
-    let file_str = path
-        .file_name()
-        .map(|n| n.to_string_lossy().to_string())
-        .unwrap_or_else(|| path.display().to_string());
 
     let mut metrics = Vec::new();
-    for obj in &f.objects {
+    let mut findings = Vec::new();
+    let mut fingerprints = Vec::new();
+    for obj in f.objects.iter().filter(|o| !config.excludes(o.kind)) {
         let object_type = object_kind_label(obj.kind);
         let object_name = obj.name.trim_matches('"').to_string();
         for r in &obj.routines {
@@ -253,25 +1413,60 @@ fn extract_metrics_ir(source: &str, path: &Path) -> Vec<analysis::ProcedureMetri
             } else {
                 r.name.trim_matches('"').to_string()
             };
-            let complexity = routine_complexity_ir(&f.ir, r);
+            let complexity = routine_complexity_for(&f.ir, r, config);
             let line_count = r.origin.end.row.saturating_sub(r.origin.start.row) + 1;
             let parameter_count = r.params.len() as u32;
+            let var_param_count = var_param_count_ir(r);
             let quality_score = calculate_quality_score(complexity, line_count, parameter_count);
 
-            metrics.push(analysis::ProcedureMetrics {
+            let m = analysis::ProcedureMetrics {
                 object_type: object_type.clone(),
                 object_name: object_name.clone(),
                 procedure_name,
-                file: file_str.clone(),
+                file: file_str.to_string(),
                 line: r.origin.start.row + 1,
                 complexity,
                 line_count,
                 parameter_count,
+                var_param_count,
+                coupling: 0,
+                ui_reachable: None,
                 quality_score,
-            });
+            };
+            findings.extend(generate_ir_findings(&m, &f.ir, r, config));
+            if config.unused_parameters {
+                findings.extend(analysis::unused_parameter_findings(
+                    &m, source, &f.ir, obj, r,
+                ));
+            }
+            if config.dynamic_calls {
+                findings.extend(analysis::dynamic_call_findings(&m, source, &f.ir, r));
+            }
+            if let Some(pattern) = &config.naming_pattern {
+                findings.extend(analysis::naming_convention_finding(&m, source, r, pattern));
+            }
+            if config.duplicate_code_enabled
+                && let Some(hash) = body_fingerprint_ir(
+                    source,
+                    &f.ir,
+                    r,
+                    config.duplicate_code_normalize_identifiers,
+                    config.duplicate_code_min_tokens as usize,
+                )
+            {
+                fingerprints.push(analysis::BodyFingerprint {
+                    procedure: format!("{}.{}", m.object_name, m.procedure_name),
+                    location: format!("{}:{}", m.file, m.line),
+                    hash,
+                });
+            }
+            metrics.push(m);
         }
     }
-    metrics
+    findings.extend(analysis::marker_comment_findings(
+        source, &f, file_str, config,
+    ));
+    (metrics, findings, fingerprints)
 }
 
 /// Human-readable object-type label (e.g. `Codeunit`, `Pageextension`), matching
@@ -302,13 +1497,15 @@ fn object_kind_label(k: al_syntax::ir::ObjectKind) -> String {
 }
 
 /// Print results in CSV format
-fn print_csv(result: &analysis::AnalysisResult) {
-    println!(
-        "object_type,object_name,procedure_name,file,line,complexity,line_count,parameter_count,quality_score"
-    );
+fn print_csv(out: &mut dyn Write, result: &analysis::AnalysisResult) -> io::Result<()> {
+    writeln!(
+        out,
+        "object_type,object_name,procedure_name,file,line,complexity,line_count,parameter_count,var_param_count,coupling,ui_reachable,quality_score"
+    )?;
     for m in &result.metrics {
-        println!(
-            "{},{},{},{},{},{},{},{},{:.1}",
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{},{},{},{:.1}",
             m.object_type,
             m.object_name,
             m.procedure_name,
@@ -317,30 +1514,56 @@ fn print_csv(result: &analysis::AnalysisResult) {
             m.complexity,
             m.line_count,
             m.parameter_count,
+            m.var_param_count,
+            m.coupling,
+            m.ui_reachable.map(|r| r.to_string()).unwrap_or_default(),
             m.quality_score
-        );
+        )?;
     }
+    Ok(())
+}
+
+/// Print only the summary as a one-row CSV (`--summary-only`)
+fn print_csv_summary(out: &mut dyn Write, summary: &analysis::AnalysisSummary) -> io::Result<()> {
+    writeln!(
+        out,
+        "total_procedures,avg_complexity,avg_quality_score,critical_findings,warning_findings"
+    )?;
+    writeln!(
+        out,
+        "{},{:.1},{:.1},{},{}",
+        summary.total_procedures,
+        summary.avg_complexity,
+        summary.avg_quality_score,
+        summary.critical_findings,
+        summary.warning_findings
+    )
 }
 
 /// Print results in human-readable text format
 fn print_text(
+    out: &mut dyn Write,
     result: &analysis::AnalysisResult,
     project: &std::path::Path,
     config: &config::DiagnosticConfig,
-) {
-    println!("\nCode Quality Analysis: {}\n", project.display());
-    println!("═══════════════════════════════════════════════════════════════════════════════\n");
+) -> io::Result<()> {
+    writeln!(out, "\nCode Quality Analysis: {}\n", project.display())?;
+    writeln!(
+        out,
+        "═══════════════════════════════════════════════════════════════════════════════\n"
+    )?;
 
     // Sort by complexity (descending)
     let mut sorted_metrics = result.metrics.clone();
     sorted_metrics.sort_by_key(|m| std::cmp::Reverse(m.complexity));
 
-    println!("PROCEDURES (sorted by complexity):\n");
-    println!(
+    writeln!(out, "PROCEDURES (sorted by complexity):\n")?;
+    writeln!(
+        out,
         "{:<40} {:>4} {:>6} {:>6} {:>8}",
         "Procedure", "CC", "Lines", "Params", "Score"
-    );
-    println!("{}", "-".repeat(70));
+    )?;
+    writeln!(out, "{}", "-".repeat(70))?;
 
     for m in sorted_metrics.iter().take(20) {
         let name = format!("{}.{}", m.object_name, m.procedure_name);
@@ -358,7 +1581,8 @@ fn print_text(
             ""
         };
 
-        println!(
+        writeln!(
+            out,
             "{:<40} {:>4} {:>6} {:>6} {:>7.1}{}",
             name_truncated,
             m.complexity,
@@ -366,47 +1590,90 @@ fn print_text(
             m.parameter_count,
             m.quality_score,
             severity
-        );
+        )?;
     }
 
     if sorted_metrics.len() > 20 {
-        println!("  ... and {} more procedures", sorted_metrics.len() - 20);
+        writeln!(
+            out,
+            "  ... and {} more procedures",
+            sorted_metrics.len() - 20
+        )?;
+    }
+
+    if !result.blast_radius.is_empty() {
+        writeln!(out, "\nBLAST RADIUS (transitive callers):\n")?;
+        for b in result.blast_radius.iter().take(20) {
+            writeln!(out, "  {:>5}  {} ({})", b.score, b.procedure, b.location)?;
+        }
+        if result.blast_radius.len() > 20 {
+            writeln!(
+                out,
+                "  ... and {} more procedures",
+                result.blast_radius.len() - 20
+            )?;
+        }
+    }
+
+    if !result.churn_risk.is_empty() {
+        writeln!(out, "\nCHURN RISK (complexity × ln(1 + incoming calls)):\n")?;
+        for c in result.churn_risk.iter().take(20) {
+            writeln!(
+                out,
+                "  {:>7.1}  {} ({}; CC {}, {} incoming)",
+                c.score, c.procedure, c.location, c.complexity, c.incoming_calls
+            )?;
+        }
+        if result.churn_risk.len() > 20 {
+            writeln!(
+                out,
+                "  ... and {} more procedures",
+                result.churn_risk.len() - 20
+            )?;
+        }
+    }
+
+    if !result.callees_by_object.is_empty() {
+        writeln!(out, "\nCALLEES BY OBJECT:\n")?;
+        for c in result.callees_by_object.iter().take(20) {
+            writeln!(out, "  {} ({}): {c}", c.procedure, c.location)?;
+        }
+        if result.callees_by_object.len() > 20 {
+            writeln!(
+                out,
+                "  ... and {} more procedures",
+                result.callees_by_object.len() - 20
+            )?;
+        }
     }
 
     // Findings
     if !result.findings.is_empty() {
-        println!("\nFINDINGS:\n");
+        writeln!(out, "\nFINDINGS:\n")?;
         for f in &result.findings {
             let severity_str = match f.severity.as_str() {
                 "critical" => "[CRITICAL]",
                 "warning" => "[WARNING]",
                 _ => "[INFO]",
             };
-            println!("  {} {} - {}", severity_str, f.location, f.description);
+            writeln!(out, "  {} {} - {}", severity_str, f.location, f.description)?;
         }
     }
 
-    // Summary
-    println!("\nSUMMARY:\n");
-    println!(
-        "  Total procedures:     {}",
-        result.summary.total_procedures
-    );
-    println!(
-        "  Average complexity:   {:.1}",
-        result.summary.avg_complexity
-    );
-    println!(
+    print_text_summary(out, &result.summary)
+}
+
+/// Print the SUMMARY block of the text report
+fn print_text_summary(out: &mut dyn Write, summary: &analysis::AnalysisSummary) -> io::Result<()> {
+    writeln!(out, "\nSUMMARY:\n")?;
+    writeln!(out, "  Total procedures:     {}", summary.total_procedures)?;
+    writeln!(out, "  Average complexity:   {:.1}", summary.avg_complexity)?;
+    writeln!(
+        out,
         "  Average quality score: {:.1}",
-        result.summary.avg_quality_score
-    );
-    println!(
-        "  Critical findings:    {}",
-        result.summary.critical_findings
-    );
-    println!(
-        "  Warning findings:     {}",
-        result.summary.warning_findings
-    );
-    println!();
+        summary.avg_quality_score
+    )?;
+    writeln!(out, "  Critical findings:    {}", summary.critical_findings)?;
+    writeln!(out, "  Warning findings:     {}", summary.warning_findings)?;
+    writeln!(out)
 }
@@ -4,7 +4,7 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 
-use crate::app_package::open_app_zip;
+use crate::app_package::{open_app_zip, read_symbol_reference_bytes};
 use crate::engine::deps::symbol_reference::{
     AbiEventKind as SrAbiEventKind, AbiRoutine, AbiTable, SymbolReferenceAbi,
     parse_symbol_reference,
@@ -229,18 +229,11 @@ impl AbiCache {
 
 pub(crate) fn read_symbol_reference_from_app(path: &Path) -> anyhow::Result<SymbolReferenceAbi> {
     let mut archive = open_app_zip(path)?;
-    let sr_file = archive.by_name("SymbolReference.json")?;
-    // T2.2: belt-and-suspenders cap — reject a hostile declared size before
-    // decompressing, then bound the read itself (a lying central directory).
-    // Errors here propagate through the SAME `abi.error` channel as a JSON
-    // parse failure (see `get_or_load`'s `unwrap_or_else` above) — no new
-    // wiring needed.
-    crate::capped_io::check_declared_size(
-        sr_file.size(),
-        crate::capped_io::SYMBOL_REFERENCE_JSON_CAP,
-    )?;
-    let content =
-        crate::capped_io::read_capped(sr_file, crate::capped_io::SYMBOL_REFERENCE_JSON_CAP)?;
+    // T2.2: the shared reader caps both the entry and any gzip-inflated
+    // content. Errors here propagate through the SAME `abi.error` channel as
+    // a JSON parse failure (see `get_or_load`'s `unwrap_or_else` above) — no
+    // new wiring needed.
+    let content = read_symbol_reference_bytes(&mut archive)?;
     let json_str = if content.starts_with(&[0xEF, 0xBB, 0xBF]) {
         std::str::from_utf8(&content[3..])?
     } else {
@@ -0,0 +1,241 @@
+//! The workspace app's **public API surface** and a breaking-change diff
+//! between two versions of it.
+//!
+//! A surface entry is one logical procedure — `(object, name)`, both
+//! case-insensitive — carrying the set of signatures its overloads declare,
+//! so overloads are compared as a group rather than as unrelated routines:
+//!
+//! - a signature that appears is **non-breaking** — an existing call keeps
+//!   binding to the overload it always did (`overload_added`, or `added`
+//!   for a brand-new procedure);
+//! - a signature that disappears is **breaking** — a call bound to it no
+//!   longer compiles (`overload_removed`, or `removed` when it was the last
+//!   one and the procedure is gone).
+//!
+//! A changed parameter list is therefore a removal plus an addition.
+//! Signatures are built from [`RoutineNode::param_sig_key`] (case-folded
+//! parameter types) and the declared return type.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+
+use al_syntax::IdentifierFoldExt;
+use serde::Serialize;
+
+use crate::program::graph::ProgramGraph;
+use crate::program::node::{AppRef, ObjectNodeId};
+use crate::program::node_extract::{Access, ObjectNode, RoutineNode};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiEntry {
+    /// `"<Kind> <Name>"`, e.g. `"Codeunit Sales Post"`.
+    pub object: String,
+    pub procedure: String,
+    /// One per overload, e.g. `"(code[20], integer): boolean"`.
+    pub signatures: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiChangeKind {
+    Added,
+    Removed,
+    OverloadAdded,
+    OverloadRemoved,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiChange {
+    pub object: String,
+    pub procedure: String,
+    pub kind: ApiChangeKind,
+    pub signature: String,
+    pub breaking: bool,
+}
+
+/// Build the API surface of a workspace (resolve, then project).
+#[must_use]
+pub fn api_surface_workspace(workspace_root: &Path) -> Option<Vec<ApiEntry>> {
+    let (graph, _edges, primary) =
+        crate::program::resolve::full::resolve_full_program_for_export(workspace_root)?;
+    Some(build_api_surface(&graph, primary))
+}
+
+/// Every public, non-trigger procedure `app` declares, overloads grouped
+/// into one entry, sorted by object then procedure (case-insensitive).
+#[must_use]
+pub fn build_api_surface(graph: &ProgramGraph, app: AppRef) -> Vec<ApiEntry> {
+    let obj_by_id: HashMap<&ObjectNodeId, &ObjectNode> =
+        graph.objects.iter().map(|o| (&o.id, o)).collect();
+    let mut entries: BTreeMap<(String, String), ApiEntry> = BTreeMap::new();
+    for r in &graph.routines {
+        if r.id.object.app != app || r.is_trigger || r.access != Access::Public {
+            continue;
+        }
+        let Some(object) = obj_by_id.get(&r.id.object) else {
+            continue;
+        };
+        // `ObjectKind`'s variant names are the AL spellings.
+        let object = format!("{:?} {}", object.id.kind, object.name);
+        entries
+            .entry((object.fold_identifier(), r.name.fold_identifier()))
+            .or_insert_with(|| ApiEntry {
+                object,
+                procedure: r.name.clone(),
+                signatures: BTreeSet::new(),
+            })
+            .signatures
+            .insert(signature(r));
+    }
+    entries.into_values().collect()
+}
+
+fn signature(r: &RoutineNode) -> String {
+    let params = r.param_sig_key.split('|').collect::<Vec<_>>().join(", ");
+    match &r.return_type {
+        Some(ty) => format!("({params}): {}", ty.trim().fold_identifier()),
+        None => format!("({params})"),
+    }
+}
+
+/// The signature-level changes from `old` to `new`, in object, procedure,
+/// signature order. See the module doc for what counts as breaking.
+#[must_use]
+pub fn diff_api_surface(old: &[ApiEntry], new: &[ApiEntry]) -> Vec<ApiChange> {
+    let key = |e: &ApiEntry| (e.object.fold_identifier(), e.procedure.fold_identifier());
+    let old_by_key: BTreeMap<_, &ApiEntry> = old.iter().map(|e| (key(e), e)).collect();
+    let new_by_key: BTreeMap<_, &ApiEntry> = new.iter().map(|e| (key(e), e)).collect();
+    let keys: BTreeSet<_> = old_by_key.keys().chain(new_by_key.keys()).collect();
+
+    let mut changes = Vec::new();
+    for k in keys {
+        let before = old_by_key.get(k);
+        let after = new_by_key.get(k);
+        let Some(entry) = after.or(before) else {
+            continue;
+        };
+        let empty = BTreeSet::new();
+        let old_sigs = before.map_or(&empty, |e| &e.signatures);
+        let new_sigs = after.map_or(&empty, |e| &e.signatures);
+        let change = |kind, signature: &String, breaking| ApiChange {
+            object: entry.object.clone(),
+            procedure: entry.procedure.clone(),
+            kind,
+            signature: signature.clone(),
+            breaking,
+        };
+        for sig in old_sigs.difference(new_sigs) {
+            let kind = if new_sigs.is_empty() {
+                ApiChangeKind::Removed
+            } else {
+                ApiChangeKind::OverloadRemoved
+            };
+            changes.push(change(kind, sig, true));
+        }
+        for sig in new_sigs.difference(old_sigs) {
+            let kind = if old_sigs.is_empty() {
+                ApiChangeKind::Added
+            } else {
+                ApiChangeKind::OverloadAdded
+            };
+            changes.push(change(kind, sig, false));
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn surface_of(source: &str) -> Vec<ApiEntry> {
+        let dir = tempfile::tempdir().expect("tempdir");
+        std::fs::write(
+            dir.path().join("app.json"),
+            r#"{
+    "id": "77777777-0000-0000-0000-000000002230",
+    "name": "Api Surface Fixture",
+    "publisher": "probe",
+    "version": "1.0.0.0"
+}"#,
+        )
+        .expect("write app.json");
+        std::fs::write(dir.path().join("Pricing.al"), source).expect("write Pricing.al");
+        api_surface_workspace(dir.path()).expect("surface")
+    }
+
+    const ONE_OVERLOAD: &str = r#"codeunit 50100 Pricing
+{
+    procedure Price(ItemNo: Code[20]): Decimal
+    begin
+    end;
+
+    local procedure Helper()
+    begin
+    end;
+}
+"#;
+
+    const TWO_OVERLOADS: &str = r#"codeunit 50100 Pricing
+{
+    procedure Price(ItemNo: Code[20]): Decimal
+    begin
+    end;
+
+    procedure Price(ItemNo: Code[20]; Qty: Integer): Decimal
+    begin
+    end;
+
+    local procedure Helper()
+    begin
+    end;
+}
+"#;
+
+    const NO_PRICE: &str = r#"codeunit 50100 Pricing
+{
+    local procedure Helper()
+    begin
+    end;
+}
+"#;
+
+    #[test]
+    fn overloads_group_under_one_entry() {
+        let surface = surface_of(TWO_OVERLOADS);
+        assert_eq!(surface.len(), 1, "local Helper is not public: {surface:#?}");
+        assert_eq!(surface[0].object, "Codeunit Pricing");
+        assert_eq!(surface[0].procedure, "Price");
+        assert_eq!(
+            surface[0].signatures.iter().collect::<Vec<_>>(),
+            ["(code[20]): decimal", "(code[20], integer): decimal"]
+        );
+    }
+
+    #[test]
+    fn adding_an_overload_is_non_breaking_and_removing_the_last_is_breaking() {
+        let one = surface_of(ONE_OVERLOAD);
+        let two = surface_of(TWO_OVERLOADS);
+        let none = surface_of(NO_PRICE);
+
+        let added = diff_api_surface(&one, &two);
+        assert_eq!(added.len(), 1, "{added:#?}");
+        assert_eq!(added[0].kind, ApiChangeKind::OverloadAdded);
+        assert_eq!(added[0].signature, "(code[20], integer): decimal");
+        assert!(!added[0].breaking);
+
+        let dropped_one = diff_api_surface(&two, &one);
+        assert_eq!(dropped_one.len(), 1, "{dropped_one:#?}");
+        assert_eq!(dropped_one[0].kind, ApiChangeKind::OverloadRemoved);
+        assert!(dropped_one[0].breaking);
+
+        let dropped_last = diff_api_surface(&one, &none);
+        assert_eq!(dropped_last.len(), 1, "{dropped_last:#?}");
+        assert_eq!(dropped_last[0].kind, ApiChangeKind::Removed);
+        assert!(dropped_last[0].breaking);
+
+        assert!(diff_api_surface(&two, &two).is_empty());
+    }
+}
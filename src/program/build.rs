@@ -166,6 +166,11 @@ pub fn build_dep_layer(
 /// (`abi_overload_collapsed`/`source_overload_aliased`) survive unchanged
 /// through a second pass (see `dedup_routines_preserving_genuine_overloads`'s
 /// doc: both flags are only ever SET, never cleared, by that function).
+///
+/// The same dedup is what merges one workspace object declared across
+/// several files: every part extracts the same `ObjectNodeId`, the object
+/// collapses to its first part, and every part's routines stay keyed under
+/// that one id — so own-object resolution sees all of them.
 pub fn assemble_program_graph(
     dep: &DepLayer,
     ws_unit: &ParsedUnit,
@@ -204,6 +209,8 @@ pub fn assemble_program_graph(
         obj_index,
         friends: dep.friends.clone(),
         abi_ingest_errors: dep.abi_ingest_errors.clone(),
+        fuzzy_object_names: false,
+        trigger_ops: Default::default(),
     };
 
     // ── Inject synthetic platform-event publishers ───────────────────────────
@@ -414,7 +421,7 @@ pub(crate) fn inject_platform_event_publishers(graph: &mut ProgramGraph) {
             };
             // Resolve the publisher object from the subscriber's app (fail-closed).
             let Some(pub_obj) =
-                graph.resolve_object(sub.id.object.app, pub_kind, &args.publisher_name)
+                graph.resolve_subscribed_object(sub.id.object.app, pub_kind, &args.publisher_name)
             else {
                 continue;
             };
@@ -938,6 +945,7 @@ codeunit 50100 "Ws2 Cu"
         let snap = crate::snapshot::SnapshotBuilder {
             workspace_root: ws,
             local_providers: vec![],
+            referenced_dependencies_only: false,
         }
         .build()
         .expect("snapshot");
@@ -980,6 +988,7 @@ codeunit 50100 "Ws2 Cu"
         let snap = crate::snapshot::SnapshotBuilder {
             workspace_root: ws,
             local_providers: vec![],
+            referenced_dependencies_only: false,
         }
         .build()
         .expect("snapshot");
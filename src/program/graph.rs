@@ -6,24 +6,55 @@ use std::collections::{BTreeSet, HashMap};
 
 use crate::program::node::{AppRef, AppRegistry};
 use crate::program::node_extract::{ObjectNode, RoutineNode};
+use crate::program::resolve::resolver::normalize_object_name;
 use crate::program::topology::DependencyGraph;
 
-/// Index from (app, kind, lowercase-name) to position in `ProgramGraph::objects`.
+/// The resolution key for an object name: identifier-folded, with every
+/// internal whitespace run collapsed to one space (and the ends trimmed), so
+/// a declaration `"Customer  Mgt."` and a call site `"Customer Mgt."` meet.
+/// Only for matching — `ObjectNode::name` keeps the declared spelling for
+/// display.
+pub fn object_name_key(name: &str) -> String {
+    name.fold_identifier()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Index from (app, kind, [`object_name_key`]) — and from (app, kind,
+/// declared object number) — to position in `ProgramGraph::objects`.
 ///
 /// Built once after `objects` is sorted; first entry wins on a same-app duplicate.
+/// The [`normalize_object_name`] index keeps every position instead, since
+/// several names can normalize alike and the fuzzy lookup declines on that.
 #[derive(Default)]
 pub struct ObjectIndex {
     by_app_kind_name: HashMap<(AppRef, ObjectKind, String), usize>,
+    by_app_kind_number: HashMap<(AppRef, ObjectKind, i64), usize>,
+    by_app_kind_normalized: HashMap<(AppRef, ObjectKind, String), Vec<usize>>,
 }
 
 impl ObjectIndex {
     /// Build the index from an already-sorted `objects` slice.
-    /// On a duplicate `(app, kind, name_lc)` key the first (lowest-`NodeId`) entry wins.
+    /// On a duplicate `(app, kind, name_lc)` or `(app, kind, number)` key the
+    /// first (lowest-`NodeId`) entry wins.
     pub fn build(objects: &[ObjectNode]) -> Self {
         let mut idx = ObjectIndex::default();
         for (i, obj) in objects.iter().enumerate() {
-            let key = (obj.id.app, obj.id.kind, obj.name.fold_identifier());
+            let key = (obj.id.app, obj.id.kind, object_name_key(&obj.name));
             idx.by_app_kind_name.entry(key).or_insert(i);
+            if let Some(number) = obj.declared_id {
+                idx.by_app_kind_number
+                    .entry((obj.id.app, obj.id.kind, number))
+                    .or_insert(i);
+            }
+            let normalized = normalize_object_name(&obj.name);
+            if !normalized.is_empty() {
+                idx.by_app_kind_normalized
+                    .entry((obj.id.app, obj.id.kind, normalized))
+                    .or_default()
+                    .push(i);
+            }
         }
         idx
     }
@@ -60,6 +91,19 @@ pub struct ProgramGraph {
     /// case) and in every in-memory test fixture that doesn't explicitly
     /// wire it.
     pub abi_ingest_errors: Vec<AbiIngestError>,
+    /// Opt-in (`resolution.fuzzyObjectNames`) fallback for a by-name object
+    /// reference with no exact match: [`Self::resolve_object_by_name`] and
+    /// `ResolveIndex::resolve_object_ref` retry it through
+    /// [`Self::resolve_object_normalized`] instead of reporting it
+    /// unresolved. Set by `resolve::full::build_context_res` from the
+    /// workspace config; `false` in every in-memory fixture.
+    pub fuzzy_object_names: bool,
+    /// Configured (`resolution.triggerOps`) record method → trigger
+    /// mappings beyond the built-in ones, both case-folded — see
+    /// [`crate::program::resolve::resolver::resolve_implicit_trigger`]. Set
+    /// alongside [`Self::fuzzy_object_names`]; empty in every in-memory
+    /// fixture.
+    pub trigger_ops: HashMap<String, String>,
 }
 
 /// One dependency-ABI ingest failure (H-3) — see
@@ -90,7 +134,7 @@ impl ProgramGraph {
         kind: ObjectKind,
         name: &str,
     ) -> Option<&ObjectNode> {
-        let name_lc = name.fold_identifier();
+        let name_lc = object_name_key(name);
 
         // Prefer `from` itself — short-circuit before building the full closure.
         if let Some(&idx) = self
@@ -127,6 +171,141 @@ impl ProgramGraph {
         found.map(|i| &self.objects[i])
     }
 
+    /// Resolve an AL object-reference literal — `Database::"Customer"`,
+    /// `Codeunit::Util`, `Page::21`, … (an `ExprKind::DatabaseReference`'s
+    /// text) — as seen FROM `from`. `Database::` names a TABLE. A name goes
+    /// through [`Self::resolve_object`] (same own-app-first, fail-closed
+    /// rules); a numeric id matches `ObjectNode::declared_id` under the same
+    /// rules (own app first, else exactly one dependency match). `None` for
+    /// an unknown prefix, an unresolved target, or an ambiguous one.
+    pub fn resolve_object_reference(&self, from: AppRef, reference: &str) -> Option<&ObjectNode> {
+        let (prefix, target) = reference.split_once("::")?;
+        let kind = match prefix.trim().to_ascii_lowercase().as_str() {
+            "database" | "table" => ObjectKind::Table,
+            "codeunit" => ObjectKind::Codeunit,
+            "page" => ObjectKind::Page,
+            "report" => ObjectKind::Report,
+            "query" => ObjectKind::Query,
+            "xmlport" => ObjectKind::XmlPort,
+            "enum" => ObjectKind::Enum,
+            "interface" => ObjectKind::Interface,
+            _ => return None,
+        };
+        let target = target.trim();
+        let Ok(number) = target.parse::<i64>() else {
+            let name = target
+                .strip_prefix('"')
+                .and_then(|t| t.strip_suffix('"'))
+                .unwrap_or(target);
+            return self.resolve_object_by_name(from, kind, name);
+        };
+        self.resolve_object_by_number(from, kind, number)
+    }
+
+    /// The object an `[EventSubscriber]` names as its publisher, as seen FROM
+    /// `from`: by number for `Codeunit::50100` (whose parsed publisher name
+    /// is the bare id), otherwise by name through [`Self::resolve_object`].
+    pub fn resolve_subscribed_object(
+        &self,
+        from: AppRef,
+        kind: ObjectKind,
+        name_or_number: &str,
+    ) -> Option<&ObjectNode> {
+        match name_or_number.parse::<i64>() {
+            Ok(number) => self.resolve_object_by_number(from, kind, number),
+            Err(_) => self.resolve_object(from, kind, name_or_number),
+        }
+    }
+
+    /// The `kind` object whose `declared_id` is `number`: own app first,
+    /// else exactly one dependency match (several decline).
+    pub fn resolve_object_by_number(
+        &self,
+        from: AppRef,
+        kind: ObjectKind,
+        number: i64,
+    ) -> Option<&ObjectNode> {
+        let by_number = |app: AppRef| {
+            self.obj_index
+                .by_app_kind_number
+                .get(&(app, kind, number))
+                .map(|&i| &self.objects[i])
+        };
+        if let Some(own) = by_number(from) {
+            return Some(own);
+        }
+        let mut found = None;
+        for app in self.topology.closure(from) {
+            if app == from {
+                continue;
+            }
+            if let Some(o) = by_number(app) {
+                if found.is_some() {
+                    return None; // >1 dependency declares this id — decline.
+                }
+                found = Some(o);
+            }
+        }
+        found
+    }
+
+    /// Fuzzy fallback for [`Self::resolve_object`]: match `name` against
+    /// every visible object of `kind` by
+    /// [`normalize_object_name`] (case, spaces and punctuation ignored), so
+    /// `"Sales-Post"` finds `SalesPost`.
+    ///
+    /// Same fail-closed shape as the exact lookup: a unique own-app match
+    /// wins, otherwise exactly one dependency match resolves. Two normalized
+    /// matches at the same level DECLINE (`None`) — a guessed object is worse
+    /// than an unresolved one.
+    pub fn resolve_object_normalized(
+        &self,
+        from: AppRef,
+        kind: ObjectKind,
+        name: &str,
+    ) -> Option<&ObjectNode> {
+        let wanted = normalize_object_name(name);
+        if wanted.is_empty() {
+            return None;
+        }
+        let matches = |app: AppRef| {
+            self.obj_index
+                .by_app_kind_normalized
+                .get(&(app, kind, wanted.clone()))
+                .map_or(&[][..], Vec::as_slice)
+        };
+        let own = matches(from);
+        let mut deps: Vec<usize> = Vec::new();
+        for app in self.topology.closure(from) {
+            if app != from {
+                deps.extend_from_slice(matches(app));
+            }
+        }
+        match (own, deps.as_slice()) {
+            ([only], _) => Some(&self.objects[*only]),
+            ([], [only]) => Some(&self.objects[*only]),
+            _ => None,
+        }
+    }
+
+    /// [`Self::resolve_object`], falling back to
+    /// [`Self::resolve_object_normalized`] when the exact lookup misses and
+    /// [`Self::fuzzy_object_names`] is on — the lookup every by-name object
+    /// reference in source (typed receivers, `Codeunit.Run` targets,
+    /// `Database::` references) goes through.
+    pub fn resolve_object_by_name(
+        &self,
+        from: AppRef,
+        kind: ObjectKind,
+        name: &str,
+    ) -> Option<&ObjectNode> {
+        self.resolve_object(from, kind, name).or_else(|| {
+            self.fuzzy_object_names
+                .then(|| self.resolve_object_normalized(from, kind, name))
+                .flatten()
+        })
+    }
+
     /// Look up an interned `AppRef` by name (case-insensitive).
     /// Panics if the name is not present — intended for tests and CLI helpers.
     pub fn app_ref_by_name(&self, name: &str) -> AppRef {
@@ -336,4 +515,177 @@ mod tests {
         );
         assert_ne!(resolved.id.app, b);
     }
+
+    #[test]
+    fn resolve_object_normalized_matches_punctuated_names() {
+        let mut g = build_two_app_fixture();
+        let a = g.app_ref_by_name("AppA");
+        let b = g.app_ref_by_name("AppB");
+        g.objects.push(ObjectNode {
+            id: ObjectNodeId {
+                app: b,
+                kind: ObjectKind::Codeunit,
+                key: ObjKey::Name("salespost".into()),
+            },
+            name: "SalesPost".into(),
+            declared_id: None,
+            extends_target: None,
+            implements: vec![],
+            tier: TrustTier::Workspace,
+            source_table: None,
+            table_no: None,
+            source_table_temporary: false,
+            page_controls: vec![],
+            fields: vec![],
+            dataitems: vec![],
+            parse_incomplete: false,
+        });
+        g.objects.sort_by(|x, y| x.id.cmp(&y.id));
+        g.obj_index = ObjectIndex::build(&g.objects);
+
+        assert!(
+            g.resolve_object(a, ObjectKind::Codeunit, "Sales-Post")
+                .is_none(),
+            "the exact lookup stays exact"
+        );
+        let hit = g
+            .resolve_object_normalized(a, ObjectKind::Codeunit, "Sales-Post")
+            .expect("normalized fallback finds SalesPost");
+        assert_eq!(hit.name, "SalesPost");
+        assert_eq!(hit.id.app, b);
+
+        // "Util" exists in both apps: the own-app match still wins.
+        let util = g
+            .resolve_object_normalized(a, ObjectKind::Codeunit, "u-t-i-l")
+            .unwrap();
+        assert_eq!(util.id.app, a);
+
+        assert!(
+            g.resolve_object_by_name(a, ObjectKind::Codeunit, "Sales-Post")
+                .is_none(),
+            "no fallback while fuzzyObjectNames is off"
+        );
+        g.fuzzy_object_names = true;
+        let hit = g
+            .resolve_object_by_name(a, ObjectKind::Codeunit, "Sales-Post")
+            .expect("fallback once fuzzyObjectNames is on");
+        assert_eq!(hit.name, "SalesPost");
+        assert!(
+            g.resolve_object_reference(a, "Codeunit::\"Sales-Post\"")
+                .is_some(),
+            "object references take the same fallback"
+        );
+
+        // A second dependency object normalizing alike makes it ambiguous.
+        let mut twin = g
+            .objects
+            .iter()
+            .find(|o| o.name == "SalesPost")
+            .unwrap()
+            .clone();
+        twin.id.key = ObjKey::Name("sales post".into());
+        twin.name = "Sales Post".into();
+        g.objects.push(twin);
+        g.objects.sort_by(|x, y| x.id.cmp(&y.id));
+        g.obj_index = ObjectIndex::build(&g.objects);
+        assert!(
+            g.resolve_object_normalized(a, ObjectKind::Codeunit, "Sales-Post")
+                .is_none(),
+            "two normalized dependency matches decline"
+        );
+    }
+
+    #[test]
+    fn resolve_object_ignores_internal_whitespace_differences() {
+        let mut g = build_two_app_fixture();
+        let a = g.app_ref_by_name("AppA");
+        let b = g.app_ref_by_name("AppB");
+        g.objects.push(ObjectNode {
+            id: ObjectNodeId {
+                app: b,
+                kind: ObjectKind::Codeunit,
+                key: ObjKey::Id(50100),
+            },
+            name: "Customer  Mgt.".to_string(),
+            declared_id: Some(50100),
+            extends_target: None,
+            implements: vec![],
+            tier: TrustTier::Workspace,
+            source_table: None,
+            table_no: None,
+            source_table_temporary: false,
+            page_controls: vec![],
+            fields: vec![],
+            dataitems: vec![],
+            parse_incomplete: false,
+        });
+        g.objects.sort_by(|x, y| x.id.cmp(&y.id));
+        g.obj_index = ObjectIndex::build(&g.objects);
+
+        assert_eq!(object_name_key(" Customer \t Mgt. "), "customer mgt.");
+        for call in ["Customer Mgt.", "customer  mgt.", "Customer\tMgt."] {
+            let hit = g
+                .resolve_object(a, ObjectKind::Codeunit, call)
+                .unwrap_or_else(|| panic!("{call:?} must resolve"));
+            assert_eq!(
+                hit.name, "Customer  Mgt.",
+                "display name keeps its spelling"
+            );
+        }
+        assert!(
+            g.resolve_object(a, ObjectKind::Codeunit, "CustomerMgt.")
+                .is_none(),
+            "whitespace is collapsed, not removed"
+        );
+    }
+
+    #[test]
+    fn resolve_object_reference_links_database_references_to_tables() {
+        let mut g = build_two_app_fixture();
+        let a = g.app_ref_by_name("AppA");
+        let b = g.app_ref_by_name("AppB");
+        g.objects.push(ObjectNode {
+            id: ObjectNodeId {
+                app: b,
+                kind: ObjectKind::Table,
+                key: ObjKey::Id(18),
+            },
+            name: "Customer".to_string(),
+            declared_id: Some(18),
+            extends_target: None,
+            implements: vec![],
+            tier: TrustTier::Workspace,
+            source_table: None,
+            table_no: None,
+            source_table_temporary: false,
+            page_controls: vec![],
+            fields: vec![],
+            dataitems: vec![],
+            parse_incomplete: false,
+        });
+        g.objects.sort_by(|x, y| x.id.cmp(&y.id));
+        g.obj_index = ObjectIndex::build(&g.objects);
+
+        for reference in [
+            "Database::Customer",
+            "Database::\"Customer\"",
+            "Database::18",
+        ] {
+            let table = g
+                .resolve_object_reference(a, reference)
+                .unwrap_or_else(|| panic!("{reference} must resolve from A (A depends on B)"));
+            assert_eq!(table.id.kind, ObjectKind::Table);
+            assert_eq!(table.name, "Customer");
+        }
+        assert_eq!(
+            g.resolve_object_reference(a, "Codeunit::Util")
+                .unwrap()
+                .id
+                .app,
+            a
+        );
+        assert!(g.resolve_object_reference(a, "Page::Customer").is_none());
+        assert!(g.resolve_object_reference(a, "Database::19").is_none());
+        assert!(g.resolve_object_reference(a, "Customer").is_none());
+    }
 }
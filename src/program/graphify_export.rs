@@ -29,7 +29,7 @@
 //! `build_merge` incrementally.
 
 use std::collections::{BTreeMap, HashMap, HashSet};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use al_syntax::IdentifierFoldExt;
 use al_syntax::ir::ObjectKind;
@@ -40,7 +40,8 @@ use crate::program::node::{AppRef, AppRegistry, ObjKey, ObjectNodeId, RoutineNod
 use crate::program::node_extract::{ObjectNode, RoutineNode};
 use crate::program::resolve::edge::{
     AbiRoutineKey, BuiltinId, Condition, DispatchShape, Edge, EdgeKind, Evidence,
-    ObligationOutcome, OpenWorldReason, RouteTarget, SetCompleteness, Witness, classify_obligation,
+    ObligationOutcome, OpenWorldReason, ResolutionKind, RouteTarget, SetCompleteness, Witness,
+    classify_obligation,
 };
 use crate::program::resolve::full::ClassifiedEdge;
 use crate::snapshot::TrustTier;
@@ -70,6 +71,10 @@ pub struct GNode {
     pub al_app: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub al_tier: Option<&'static str>,
+    /// Routine nodes only: [`RoutineNodeId::stable_id`], which survives a
+    /// reindex that only moves the declaration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub al_stable_id: Option<String>,
 }
 
 /// One graphify edge. `source`/`target`/`relation`/`confidence`/`confidence_score`
@@ -117,6 +122,18 @@ pub struct GEdge {
     /// byte-identical. NEVER `Some(false)`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub may_fire: Option<bool>,
+    /// ADDITIVE key (appended last): how a resolved route found its target —
+    /// `self`, `variable`, `direct_object`, `external`, `builtin`, `event` or
+    /// `implicit_trigger` (see [`ResolutionKind`] and
+    /// [`route_resolution_kind`]). `None` on `contains`
+    /// edges and on edges without a concrete target (dynamic / unknown).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution_kind: Option<&'static str>,
+    /// ADDITIVE key (appended last): the trimmed source line of the call site,
+    /// only in a [`export_workspace_with_snippets`] export. `None` on
+    /// `contains` edges and when the site's file cannot be read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
 }
 
 /// A graphify extraction document. Fed to `build_from_json` / `build_merge`.
@@ -134,12 +151,101 @@ pub struct GraphifyDocument {
 // ---------------------------------------------------------------------------
 
 /// Build the graphify document for a workspace: resolve the whole program, then
-/// project the graph. Returns `None` when the snapshot build fails (fail-closed).
+/// project the graph. Workspace `source_file`s are workspace-root-relative, or
+/// absolute paths under `workspace_root` with `absolute_paths` (see
+/// [`SourcePaths`]). Returns `None` when the snapshot build fails (fail-closed).
+#[must_use]
+pub fn export_workspace(workspace_root: &Path, absolute_paths: bool) -> Option<GraphifyDocument> {
+    let (graph, edges, primary) =
+        crate::program::resolve::full::resolve_full_program_for_export(workspace_root)?;
+    let paths = SourcePaths::for_workspace(workspace_root, absolute_paths);
+    Some(build_graphify_document(&graph, &edges, primary, paths))
+}
+
+/// [`export_workspace`] with each call edge's [`GEdge::snippet`] filled from
+/// the call site's source line (files read once each, from
+/// `workspace_root`). `None` when the snapshot build fails.
 #[must_use]
-pub fn export_workspace(workspace_root: &Path) -> Option<GraphifyDocument> {
+pub fn export_workspace_with_snippets(
+    workspace_root: &Path,
+    absolute_paths: bool,
+) -> Option<GraphifyDocument> {
     let (graph, edges, primary) =
         crate::program::resolve::full::resolve_full_program_for_export(workspace_root)?;
-    Some(build_graphify_document(&graph, &edges, primary))
+    let paths = SourcePaths::for_workspace(workspace_root, absolute_paths);
+    let mut lines = SourceLines::new(workspace_root);
+    Some(build_graphify_document_with_snippets(
+        &graph, &edges, primary, paths, &mut lines,
+    ))
+}
+
+/// How a workspace source unit (a workspace-root-relative path such as
+/// `src/Cu.al`) is written into a `source_file` attribute. A dependency's
+/// units are package-virtual paths with no file on disk, so they are always
+/// written as-is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourcePaths<'a> {
+    /// The unit path itself — portable across machines. The default.
+    Relative,
+    /// The unit joined onto this workspace root — the file on this machine.
+    Absolute(&'a Path),
+}
+
+impl<'a> SourcePaths<'a> {
+    /// [`SourcePaths::Absolute`] under `workspace_root` when
+    /// `absolute_paths`, else [`SourcePaths::Relative`] (the default).
+    #[must_use]
+    pub fn for_workspace(workspace_root: &'a Path, absolute_paths: bool) -> Self {
+        if absolute_paths {
+            SourcePaths::Absolute(workspace_root)
+        } else {
+            SourcePaths::Relative
+        }
+    }
+
+    /// The `source_file` for `unit`, a file of `app`.
+    fn render(self, unit: &str, app: AppRef, primary_app_ref: AppRef) -> String {
+        match self {
+            SourcePaths::Absolute(root) if app == primary_app_ref && !unit.is_empty() => {
+                root.join(unit).display().to_string()
+            }
+            _ => unit.to_string(),
+        }
+    }
+}
+
+/// Source files read for call-site snippets, cached by call-site unit (a
+/// path relative to the workspace root) so each file is read at most once.
+#[derive(Debug)]
+pub struct SourceLines {
+    root: PathBuf,
+    files: HashMap<String, Option<Vec<String>>>,
+}
+
+impl SourceLines {
+    #[must_use]
+    pub fn new(root: &Path) -> Self {
+        SourceLines {
+            root: root.to_path_buf(),
+            files: HashMap::new(),
+        }
+    }
+
+    /// The trimmed text of 0-based `line` in `unit`; `None` when the file is
+    /// unreadable or shorter than that.
+    pub fn line(&mut self, unit: &str, line: u32) -> Option<String> {
+        let root = &self.root;
+        self.files
+            .entry(unit.to_string())
+            .or_insert_with(|| {
+                std::fs::read_to_string(root.join(unit))
+                    .ok()
+                    .map(|text| text.lines().map(str::to_string).collect())
+            })
+            .as_ref()?
+            .get(line as usize)
+            .map(|l| l.trim().to_string())
+    }
 }
 
 /// Pure projection: `(ProgramGraph, resolved edges)` → graphify document.
@@ -150,7 +256,31 @@ pub fn export_workspace(workspace_root: &Path) -> Option<GraphifyDocument> {
 pub fn build_graphify_document(
     graph: &ProgramGraph,
     edges: &[ClassifiedEdge],
-    _primary_app_ref: AppRef,
+    primary_app_ref: AppRef,
+    paths: SourcePaths<'_>,
+) -> GraphifyDocument {
+    build_document(graph, edges, primary_app_ref, paths, None)
+}
+
+/// [`build_graphify_document`] plus a [`GEdge::snippet`] on every edge
+/// projected from a call site, read through `lines`.
+#[must_use]
+pub fn build_graphify_document_with_snippets(
+    graph: &ProgramGraph,
+    edges: &[ClassifiedEdge],
+    primary_app_ref: AppRef,
+    paths: SourcePaths<'_>,
+    lines: &mut SourceLines,
+) -> GraphifyDocument {
+    build_document(graph, edges, primary_app_ref, paths, Some(lines))
+}
+
+fn build_document(
+    graph: &ProgramGraph,
+    edges: &[ClassifiedEdge],
+    primary_app_ref: AppRef,
+    paths: SourcePaths<'_>,
+    mut snippets: Option<&mut SourceLines>,
 ) -> GraphifyDocument {
     let obj_by_id: HashMap<&ObjectNodeId, &ObjectNode> =
         graph.objects.iter().map(|o| (&o.id, o)).collect();
@@ -160,7 +290,13 @@ pub fn build_graphify_document(
     // Best-effort source-file hints (the node tables carry no def location):
     //   caller  → the file its call site sits in (`site.span.unit`)
     //   callee  → the file its resolved Source route witnesses (`Witness::SourceSpan`)
-    let loc_by_rtn = build_location_hints(edges);
+    let loc_by_rtn: HashMap<RoutineNodeId, String> = build_location_hints(edges)
+        .into_iter()
+        .map(|(nid, unit)| {
+            let file = paths.render(&unit, nid.object.app, primary_app_ref);
+            (nid, file)
+        })
+        .collect();
 
     // Object source file ← any contained routine's known file (first wins).
     let mut obj_file: HashMap<ObjectNodeId, String> = HashMap::new();
@@ -191,6 +327,7 @@ pub fn build_graphify_document(
                 al_kind: Some("object"),
                 al_app: app_slug(&graph.apps, obj.id.app),
                 al_tier: Some(tier_str(obj.tier)),
+                al_stable_id: None,
             });
         }
     }
@@ -210,6 +347,7 @@ pub fn build_graphify_document(
                 al_kind: Some("routine"),
                 al_app: app_slug(&graph.apps, rtn.id.object.app),
                 al_tier: Some(tier_str(rtn.tier)),
+                al_stable_id: Some(rtn.id.stable_id()),
             });
         }
         // Containment edge object → routine.
@@ -228,13 +366,23 @@ pub fn build_graphify_document(
             unknown_reason: None,
             unknown_receiver_tier: None,
             may_fire: None,
+            resolution_kind: None,
+            snippet: None,
         });
     }
 
     // ── Call / dispatch / event edges (the moat) ─────────────────────────────
     for ce in edges {
+        let first = edges_out.len();
+        let site_file = paths.render(
+            &ce.edge.site.span.unit,
+            ce.edge.from.object.app,
+            primary_app_ref,
+        );
         project_edge(
             &ce.edge,
+            ce.resolution_kind,
+            site_file.clone(),
             graph,
             &obj_by_id,
             &rtn_by_id,
@@ -243,6 +391,14 @@ pub fn build_graphify_document(
             &mut extra_nodes,
             &mut edges_out,
         );
+        project_referenced_objects(ce, site_file, graph, &node_ids, &mut edges_out);
+        if let Some(lines) = snippets.as_deref_mut() {
+            let span = &ce.edge.site.span;
+            let snippet = lines.line(&span.unit, span.start.line);
+            for e in &mut edges_out[first..] {
+                e.snippet.clone_from(&snippet);
+            }
+        }
     }
 
     // Group relationships over 3+ nodes (event neighbourhoods, interface families).
@@ -352,12 +508,53 @@ fn build_hyperedges(
 // Edge projection + the confidence bridge
 // ---------------------------------------------------------------------------
 
+/// One `references` edge from the calling routine to each object the site
+/// depends on besides its routes ([`ClassifiedEdge::referenced_objects`]:
+/// the table of `RecordRef.Open(Database::"Customer")`, the record passed to
+/// `Codeunit.Run`). Objects without a node are skipped — graphify prunes
+/// dangling edges anyway.
+fn project_referenced_objects(
+    ce: &ClassifiedEdge,
+    site_file: String,
+    graph: &ProgramGraph,
+    emitted_ids: &HashSet<String>,
+    edges_out: &mut Vec<GEdge>,
+) {
+    let source = routine_id_str(&ce.edge.from, &graph.apps);
+    for oid in &ce.referenced_objects {
+        let target = object_id_str(oid, &graph.apps);
+        if !emitted_ids.contains(&target) {
+            continue;
+        }
+        edges_out.push(GEdge {
+            source: source.clone(),
+            target,
+            relation: "references",
+            confidence: "EXTRACTED",
+            confidence_score: 1.0,
+            source_file: site_file.clone(),
+            obligation: None,
+            evidence: None,
+            dispatch_shape: None,
+            condition: None,
+            open_world_reason: None,
+            unknown_reason: None,
+            unknown_receiver_tier: None,
+            may_fire: None,
+            resolution_kind: None,
+            snippet: None,
+        });
+    }
+}
+
 /// Project one resolved [`Edge`] into 0+ graphify edges (one per real route, or a
 /// single synthetic edge for honest-dynamic / honest-empty / unknown), ensuring
 /// every referenced target node exists (graphify prunes dangling edges).
 #[allow(clippy::too_many_arguments)]
 fn project_edge(
     edge: &Edge,
+    recorded_kind: Option<ResolutionKind>,
+    site_file: String,
     graph: &ProgramGraph,
     obj_by_id: &HashMap<&ObjectNodeId, &ObjectNode>,
     rtn_by_id: &HashMap<&RoutineNodeId, &RoutineNode>,
@@ -367,7 +564,6 @@ fn project_edge(
     edges_out: &mut Vec<GEdge>,
 ) {
     let source = routine_id_str(&edge.from, &graph.apps);
-    let site_file = edge.site.span.unit.clone();
     let outcome = classify_obligation(edge);
     let dispatch_shape = Some(dispatch_shape_str(edge.shape));
 
@@ -409,6 +605,9 @@ fn project_edge(
                     unknown_reason: None,
                     unknown_receiver_tier: None,
                     may_fire: may_fire_str(&route.conditions),
+                    resolution_kind: route_resolution_kind(recorded_kind, edge, &route.target)
+                        .map(ResolutionKind::as_str),
+                    snippet: None,
                 });
             }
         }
@@ -468,6 +667,9 @@ fn project_edge(
                     unknown_reason: None,
                     unknown_receiver_tier: None,
                     may_fire: may_fire_str(&route.conditions),
+                    resolution_kind: route_resolution_kind(recorded_kind, edge, &route.target)
+                        .map(ResolutionKind::as_str),
+                    snippet: None,
                 });
             }
         }
@@ -501,6 +703,8 @@ fn project_edge(
                 unknown_reason: None,
                 unknown_receiver_tier: None,
                 may_fire: None,
+                resolution_kind: None,
+                snippet: None,
             });
         }
         ObligationOutcome::Unknown => {
@@ -542,6 +746,8 @@ fn project_edge(
                     .flatten()
                     .map(|t| t.as_str()),
                 may_fire: None,
+                resolution_kind: None,
+                snippet: None,
             });
         }
     }
@@ -582,6 +788,7 @@ fn ensure_target_node(
                         al_kind: Some("routine"),
                         al_app: app_slug(&graph.apps, nid.object.app),
                         al_tier: None,
+                        al_stable_id: Some(nid.stable_id()),
                     },
                 );
             }
@@ -602,6 +809,7 @@ fn ensure_target_node(
                         al_kind: Some("builtin"),
                         al_app: None,
                         al_tier: None,
+                        al_stable_id: None,
                     },
                 );
             }
@@ -622,6 +830,7 @@ fn ensure_target_node(
                         al_kind: Some("external"),
                         al_app: app_slug(&graph.apps, key.app),
                         al_tier: Some("symbol_only"),
+                        al_stable_id: None,
                     },
                 );
             }
@@ -655,6 +864,7 @@ fn synthetic_node(
                 al_kind: Some(al_kind),
                 al_app: None,
                 al_tier: None,
+                al_stable_id: None,
             },
         );
     }
@@ -882,6 +1092,34 @@ fn condition_str(conditions: &[Condition]) -> Option<&'static str> {
     }
 }
 
+/// The `resolution_kind` of one route: the kind the resolver recorded for
+/// the call site ([`ClassifiedEdge::resolution_kind`]), except that a builtin
+/// target is `Builtin` and an ABI symbol or a routine in another app is
+/// `External` — what the target is, not how the site named it. Event and
+/// implicit-trigger edges keep their recorded kind. `None` for an unresolved
+/// target or a site the resolver recorded no kind for.
+fn route_resolution_kind(
+    recorded: Option<ResolutionKind>,
+    edge: &Edge,
+    target: &RouteTarget,
+) -> Option<ResolutionKind> {
+    if matches!(
+        recorded,
+        Some(ResolutionKind::Event | ResolutionKind::ImplicitTrigger)
+    ) {
+        return recorded;
+    }
+    match target {
+        RouteTarget::Builtin(_) => Some(ResolutionKind::Builtin),
+        RouteTarget::AbiSymbol { .. } => Some(ResolutionKind::External),
+        RouteTarget::Unresolved => None,
+        RouteTarget::Routine(callee) if callee.object.app != edge.from.object.app => {
+            Some(ResolutionKind::External)
+        }
+        RouteTarget::Routine(_) => recorded,
+    }
+}
+
 /// `Condition::AmbiguousDispatch` MAY-FIRE projection (Task 3 round-2 closer
 /// #3, BINDING): `graphify_export` must never let BC-Brain read
 /// `fires_by_default:false`-shaped output as dead code for these routes —
@@ -906,6 +1144,7 @@ fn open_world_reason_str(c: SetCompleteness) -> Option<&'static str> {
             OpenWorldReason::ReverseDependentSubscribers => "reverse_dependent_subscribers",
             OpenWorldReason::ReverseDependentExtensions => "reverse_dependent_extensions",
             OpenWorldReason::RuntimeTypeUnbounded => "runtime_type_unbounded",
+            OpenWorldReason::TriggerSuppressed => "trigger_suppressed",
         }),
     }
 }
@@ -936,12 +1175,17 @@ pub struct FragmentSet {
     pub shared: GraphifyDocument,
 }
 
-/// Build the per-object fragment set for a workspace (resolve, project, partition).
+/// Build the per-object fragment set for a workspace (resolve, project, partition),
+/// with `source_file`s rendered as in [`export_workspace`].
 #[must_use]
-pub fn export_workspace_fragments(workspace_root: &Path) -> Option<FragmentSet> {
+pub fn export_workspace_fragments(
+    workspace_root: &Path,
+    absolute_paths: bool,
+) -> Option<FragmentSet> {
     let (graph, edges, primary) =
         crate::program::resolve::full::resolve_full_program_for_export(workspace_root)?;
-    Some(build_fragment_set(&graph, &edges, primary))
+    let paths = SourcePaths::for_workspace(workspace_root, absolute_paths);
+    Some(build_fragment_set(&graph, &edges, primary, paths))
 }
 
 /// Partition a built document into per-object fragments + a content-hash manifest.
@@ -950,8 +1194,9 @@ pub fn build_fragment_set(
     graph: &ProgramGraph,
     edges: &[ClassifiedEdge],
     primary_app_ref: AppRef,
+    paths: SourcePaths<'_>,
 ) -> FragmentSet {
-    let doc = build_graphify_document(graph, edges, primary_app_ref);
+    let doc = build_graphify_document(graph, edges, primary_app_ref, paths);
     let empty = || GraphifyDocument {
         nodes: Vec::new(),
         edges: Vec::new(),
@@ -1165,6 +1410,8 @@ mod tests {
             obj_index,
             friends: Default::default(),
             abi_ingest_errors: Default::default(),
+            fuzzy_object_names: false,
+            trigger_ops: Default::default(),
         };
 
         let caller = rid(a, 50100, "Foo", 0);
@@ -1193,6 +1440,8 @@ mod tests {
                 callee_fp: 42,
             },
             edge,
+            referenced_objects: Vec::new(),
+            resolution_kind: Some(ResolutionKind::Variable),
         };
         (graph, vec![ce], a)
     }
@@ -1200,7 +1449,7 @@ mod tests {
     #[test]
     fn objects_routines_and_contains_edges_emitted() {
         let (g, edges, primary) = fixture();
-        let doc = build_graphify_document(&g, &edges, primary);
+        let doc = build_graphify_document(&g, &edges, primary, SourcePaths::Relative);
 
         // 2 objects + 2 routines = 4 base nodes.
         assert_eq!(doc.nodes.len(), 4, "2 objects + 2 routines");
@@ -1219,10 +1468,29 @@ mod tests {
         assert!(contains.iter().all(|e| e.confidence == "EXTRACTED"));
     }
 
+    #[test]
+    fn routine_nodes_carry_their_stable_id() {
+        let (g, edges, primary) = fixture();
+        let doc = build_graphify_document(&g, &edges, primary, SourcePaths::Relative);
+
+        for rtn in &g.routines {
+            let id = routine_id_str(&rtn.id, &g.apps);
+            let node = doc.nodes.iter().find(|n| n.id == id).expect("routine node");
+            assert_eq!(node.al_stable_id, Some(rtn.id.stable_id()));
+        }
+        assert!(
+            doc.nodes
+                .iter()
+                .filter(|n| n.al_kind == Some("object"))
+                .all(|n| n.al_stable_id.is_none()),
+            "objects carry no stable id"
+        );
+    }
+
     #[test]
     fn source_call_becomes_extracted_calls_edge() {
         let (g, edges, primary) = fixture();
-        let doc = build_graphify_document(&g, &edges, primary);
+        let doc = build_graphify_document(&g, &edges, primary, SourcePaths::Relative);
 
         let calls: Vec<&GEdge> = doc.edges.iter().filter(|e| e.relation == "calls").collect();
         assert_eq!(calls.len(), 1, "exactly one resolved call edge");
@@ -1236,10 +1504,158 @@ mod tests {
         assert_eq!(e.dispatch_shape, Some("exact"));
     }
 
+    #[test]
+    fn referenced_objects_become_references_edges() {
+        let (g, mut edges, primary) = fixture();
+        let callee_object = g.objects[1].id.clone();
+        edges[0].referenced_objects = vec![callee_object];
+        let doc = build_graphify_document(&g, &edges, primary, SourcePaths::Relative);
+
+        let refs: Vec<&GEdge> = doc
+            .edges
+            .iter()
+            .filter(|e| e.relation == "references")
+            .collect();
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].source, "al:rtn:myapp/codeunit/50100#foo/0/0");
+        assert_eq!(refs[0].target, "al:obj:myapp/codeunit/50101");
+        assert_eq!(refs[0].confidence, "EXTRACTED");
+        assert_eq!(refs[0].source_file, "src/Cu.al");
+        assert_eq!(
+            doc.edges.iter().filter(|e| e.relation == "calls").count(),
+            1,
+            "the call edge itself is unchanged"
+        );
+    }
+
+    #[test]
+    fn snippets_carry_the_call_site_source_line() {
+        let (g, edges, primary) = fixture();
+        let dir = tempfile::tempdir().expect("tempdir");
+        std::fs::create_dir_all(dir.path().join("src")).expect("mkdir src");
+        std::fs::write(
+            dir.path().join("src/Cu.al"),
+            "codeunit 50100 \"Caller\"\n{\n    procedure Foo()\n    begin Callee.Bar(); end;\n}\n",
+        )
+        .expect("write Cu.al");
+
+        let mut lines = SourceLines::new(dir.path());
+        let doc = build_graphify_document_with_snippets(
+            &g,
+            &edges,
+            primary,
+            SourcePaths::Relative,
+            &mut lines,
+        );
+        let call = doc
+            .edges
+            .iter()
+            .find(|e| e.relation == "calls")
+            .expect("call edge");
+        assert_eq!(call.snippet.as_deref(), Some("begin Callee.Bar(); end;"));
+        assert!(
+            doc.edges
+                .iter()
+                .filter(|e| e.relation == "contains")
+                .all(|e| e.snippet.is_none())
+        );
+        assert_eq!(lines.files.len(), 1, "the file is read once and cached");
+        assert_eq!(lines.line("src/Missing.al", 0), None);
+
+        let plain = build_graphify_document(&g, &edges, primary, SourcePaths::Relative);
+        let json = serde_json::to_value(&plain.edges).expect("serialize");
+        assert!(
+            json.as_array()
+                .expect("array")
+                .iter()
+                .all(|e| e.get("snippet").is_none()),
+            "without snippets the export is unchanged"
+        );
+    }
+
+    #[test]
+    fn call_through_a_variable_is_tagged_variable_resolved() {
+        let (g, mut edges, primary) = fixture();
+        // A second call from `Foo` back into its own object.
+        let caller = rid(primary, 50100, "Foo", 0);
+        let mut own = edges[0].edge.clone();
+        own.site = site(caller.clone(), 43);
+        own.routes[0].target = RouteTarget::Routine(caller.clone());
+        edges.push(ClassifiedEdge {
+            obligation_id: crate::program::resolve::full::ObligationId::CallSite {
+                caller,
+                span: own.site.span.clone(),
+                callee_fp: 43,
+            },
+            edge: own,
+            referenced_objects: Vec::new(),
+            resolution_kind: Some(ResolutionKind::SelfReference),
+        });
+        let doc = build_graphify_document(&g, &edges, primary, SourcePaths::Relative);
+
+        let kind_of = |target: &str| {
+            doc.edges
+                .iter()
+                .find(|e| e.relation == "calls" && e.target == target)
+                .and_then(|e| e.resolution_kind)
+        };
+        assert_eq!(
+            kind_of("al:rtn:myapp/codeunit/50101#bar/0/0"),
+            Some("variable")
+        );
+        assert_eq!(kind_of("al:rtn:myapp/codeunit/50100#foo/0/0"), Some("self"));
+        assert!(
+            doc.edges
+                .iter()
+                .filter(|e| e.relation == "contains")
+                .all(|e| e.resolution_kind.is_none())
+        );
+    }
+
+    /// The export keeps the kind the resolver recorded — a variable call
+    /// into the caller's own object stays `Variable` — and only overrides it
+    /// for what the target is: builtin, ABI symbol, or another app.
+    #[test]
+    fn route_resolution_kind_keeps_the_recorded_kind() {
+        let (mut g, edges, _) = fixture();
+        let edge = &edges[0].edge;
+        let other_app = g.apps.intern(&app_id("OtherApp"));
+        let own = RouteTarget::Routine(edge.from.clone());
+        assert_eq!(
+            route_resolution_kind(Some(ResolutionKind::Variable), edge, &own),
+            Some(ResolutionKind::Variable)
+        );
+        assert_eq!(
+            route_resolution_kind(
+                Some(ResolutionKind::SelfReference),
+                edge,
+                &RouteTarget::Routine(rid(other_app, 1, "Ext", 0))
+            ),
+            Some(ResolutionKind::External)
+        );
+        assert_eq!(
+            route_resolution_kind(
+                Some(ResolutionKind::Event),
+                edge,
+                &RouteTarget::Routine(rid(other_app, 1, "Sub", 0))
+            ),
+            Some(ResolutionKind::Event)
+        );
+        assert_eq!(
+            route_resolution_kind(
+                Some(ResolutionKind::Variable),
+                edge,
+                &RouteTarget::Unresolved
+            ),
+            None
+        );
+        assert_eq!(route_resolution_kind(None, edge, &own), None);
+    }
+
     #[test]
     fn callee_source_file_recovered_from_witness() {
         let (g, edges, primary) = fixture();
-        let doc = build_graphify_document(&g, &edges, primary);
+        let doc = build_graphify_document(&g, &edges, primary, SourcePaths::Relative);
         let callee = doc
             .nodes
             .iter()
@@ -1248,6 +1664,63 @@ mod tests {
         assert_eq!(callee.source_file, "src/Callee.al");
     }
 
+    /// Workspace units are written joined onto the workspace root unless
+    /// relative paths are asked for; a dependency's package-virtual units
+    /// are written as-is either way.
+    #[test]
+    fn source_files_follow_the_source_paths_option() {
+        let (mut g, edges, primary) = fixture();
+        let root = Path::new("/work/app");
+        let source_files = |doc: &GraphifyDocument| {
+            let callee = doc
+                .nodes
+                .iter()
+                .find(|n| n.id == "al:rtn:myapp/codeunit/50101#bar/0/0")
+                .expect("callee node")
+                .source_file
+                .clone();
+            let call = doc
+                .edges
+                .iter()
+                .find(|e| e.relation == "calls")
+                .expect("call edge")
+                .source_file
+                .clone();
+            (callee, call)
+        };
+
+        let relative = build_graphify_document(&g, &edges, primary, SourcePaths::Relative);
+        assert_eq!(
+            source_files(&relative),
+            ("src/Callee.al".to_string(), "src/Cu.al".to_string())
+        );
+
+        let absolute = build_graphify_document(&g, &edges, primary, SourcePaths::Absolute(root));
+        assert_eq!(
+            source_files(&absolute),
+            (
+                root.join("src/Callee.al").display().to_string(),
+                root.join("src/Cu.al").display().to_string()
+            )
+        );
+        assert_eq!(
+            SourcePaths::for_workspace(root, false),
+            SourcePaths::Relative
+        );
+        assert_eq!(
+            SourcePaths::for_workspace(root, true),
+            SourcePaths::Absolute(root)
+        );
+
+        let dependency_view = g.apps.intern(&app_id("OtherApp"));
+        let doc = build_graphify_document(&g, &edges, dependency_view, SourcePaths::Absolute(root));
+        assert_eq!(
+            source_files(&doc),
+            ("src/Callee.al".to_string(), "src/Cu.al".to_string()),
+            "units of a non-workspace app are package-virtual"
+        );
+    }
+
     /// The confidence bridge must NOT launder: an Unknown obligation → AMBIGUOUS
     /// edge to a synthetic unresolved node (no dangling edge).
     #[test]
@@ -1278,10 +1751,12 @@ mod tests {
                 callee_fp: 7,
             },
             edge,
+            referenced_objects: Vec::new(),
+            resolution_kind: None,
         };
         // Drop the routines so only the unknown edge is present.
         g.routines.clear();
-        let doc = build_graphify_document(&g, &[ce], primary);
+        let doc = build_graphify_document(&g, &[ce], primary, SourcePaths::Relative);
 
         let amb: Vec<&GEdge> = doc
             .edges
@@ -1303,7 +1778,7 @@ mod tests {
     #[test]
     fn document_serializes_to_networkx_fragment_shape() {
         let (g, edges, primary) = fixture();
-        let doc = build_graphify_document(&g, &edges, primary);
+        let doc = build_graphify_document(&g, &edges, primary, SourcePaths::Relative);
         let v = serde_json::to_value(&doc).unwrap();
         assert!(v.get("nodes").is_some());
         assert!(v.get("edges").is_some());
@@ -1341,6 +1816,8 @@ mod tests {
             obj_index,
             friends: Default::default(),
             abi_ingest_errors: Default::default(),
+            fuzzy_object_names: false,
+            trigger_ops: Default::default(),
         };
 
         let pubr = rid(a, 50100, "OnAfterPost", 0);
@@ -1370,8 +1847,10 @@ mod tests {
         let ce = ClassifiedEdge {
             obligation_id: crate::program::resolve::full::ObligationId::Publisher(pubr),
             edge,
+            referenced_objects: Vec::new(),
+            resolution_kind: Some(ResolutionKind::Event),
         };
-        let doc = build_graphify_document(&graph, &[ce], a);
+        let doc = build_graphify_document(&graph, &[ce], a, SourcePaths::Relative);
 
         let hev: Vec<&serde_json::Value> = doc
             .hyperedges
@@ -1389,7 +1868,7 @@ mod tests {
     #[test]
     fn fragments_partition_by_object_with_stable_manifest() {
         let (g, edges, primary) = fixture();
-        let fs = build_fragment_set(&g, &edges, primary);
+        let fs = build_fragment_set(&g, &edges, primary, SourcePaths::Relative);
 
         // One fragment per object; the calls edge lives in the caller's fragment.
         assert_eq!(fs.fragments.len(), 2);
@@ -1406,7 +1885,7 @@ mod tests {
         assert!(callee.edges.iter().all(|e| e.relation != "calls"));
 
         // Manifest must be run-stable (prerequisite for change detection).
-        let fs2 = build_fragment_set(&g, &edges, primary);
+        let fs2 = build_fragment_set(&g, &edges, primary, SourcePaths::Relative);
         assert_eq!(fs.manifest, fs2.manifest, "manifest must be deterministic");
     }
 
@@ -1461,8 +1940,10 @@ mod tests {
                 callee_fp: 99,
             },
             edge,
+            referenced_objects: Vec::new(),
+            resolution_kind: None,
         };
-        let doc = build_graphify_document(&g, &[ce], primary);
+        let doc = build_graphify_document(&g, &[ce], primary, SourcePaths::Relative);
 
         let amb: Vec<&GEdge> = doc
             .edges
@@ -1498,7 +1979,7 @@ mod tests {
     #[test]
     fn may_fire_absent_on_every_pre_task3_edge_shape() {
         let (g, edges, primary) = fixture();
-        let doc = build_graphify_document(&g, &edges, primary);
+        let doc = build_graphify_document(&g, &edges, primary, SourcePaths::Relative);
         assert!(
             doc.edges.iter().all(|e| e.may_fire.is_none()),
             "may_fire must stay None on every edge shape Task 3 didn't touch"
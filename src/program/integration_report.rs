@@ -421,6 +421,8 @@ mod tests {
             obj_index,
             friends: Default::default(),
             abi_ingest_errors: Default::default(),
+            fuzzy_object_names: false,
+            trigger_ops: Default::default(),
         };
 
         let pubr = rid(dep, 80, "OnAfterPost", 1);
@@ -445,6 +447,8 @@ mod tests {
         let ce = ClassifiedEdge {
             obligation_id: crate::program::resolve::full::ObligationId::Publisher(pubr),
             edge,
+            referenced_objects: Vec::new(),
+            resolution_kind: None,
         };
 
         let rep = build_report(&graph, &[ce], ws);
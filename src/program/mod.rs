@@ -2,6 +2,7 @@
 //! (charter §3). Plan 1B.1 = nodes + app-qualified identity + topology index.
 
 pub mod abi_ingest;
+pub mod api_surface;
 pub mod build;
 pub mod graph;
 pub mod graphify_export;
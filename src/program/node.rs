@@ -200,6 +200,30 @@ pub struct RoutineNodeId {
     pub sig_fp: u64,
 }
 
+impl RoutineNodeId {
+    /// A content-derived id for bookmarking this routine across reindexes:
+    /// 16 hex digits hashing the object's kind and key, the routine name,
+    /// its enclosing member and its parameter signature. Positions never
+    /// enter it, so moving the declaration keeps the id; neither does the
+    /// run-local `AppRef`. Shares `sig_fp`'s caveat: not stable across
+    /// engine versions.
+    pub fn stable_id(&self) -> String {
+        use crate::program::sig_fp::{fnv1a, write_len_prefixed};
+
+        let mut buf = String::new();
+        write_len_prefixed(&mut buf, &format!("{:?}", self.object.kind));
+        match &self.object.key {
+            ObjKey::Id(n) => write_len_prefixed(&mut buf, &format!("id {n}")),
+            ObjKey::Name(name) => write_len_prefixed(&mut buf, &format!("name {name}")),
+        }
+        write_len_prefixed(&mut buf, &self.name_lc);
+        write_len_prefixed(&mut buf, self.enclosing_member_lc.as_deref().unwrap_or(""));
+        write_len_prefixed(&mut buf, &self.params_count.to_string());
+        write_len_prefixed(&mut buf, &self.sig_fp.to_string());
+        format!("{:016x}", fnv1a(&buf))
+    }
+}
+
 /// `u64`-as-JSON-string serde helper — see [`RoutineNodeId::sig_fp`]'s doc
 /// for why this exists. `RoutineNodeId` is currently the only `u64` field
 /// anywhere in this identity chain, so this module is private and narrowly
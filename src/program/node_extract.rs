@@ -9,7 +9,7 @@ use crate::program::resolve::event::{
     ParsedSubscriberArgs, PublisherKind, is_event_publisher, parse_event_subscriber_ir,
     publisher_include_sender, read_event_subscriber_instance,
 };
-use crate::program::resolve::receiver::unquote_identifier;
+use crate::program::resolve::receiver::{strip_namespace_qualifier, unquote_identifier};
 use crate::program::sig_fp::source_routine_node_id;
 use crate::snapshot::TrustTier;
 
@@ -387,12 +387,13 @@ fn param_sig_key(params: &[Param]) -> String {
 /// Parse an object-property value (`SourceTable`/`TableNo`) or a page-control
 /// target into an [`ObjectRef`], plus whether a trailing `temporary` marker
 /// was present and stripped. A numeric value → [`ObjectRef::Id`]; anything
-/// else → [`ObjectRef::Name`] with quotes stripped (mirrors the unquoting
+/// else → [`ObjectRef::Name`] with any namespace qualifier and quotes
+/// stripped (mirrors what
 /// [`crate::program::resolve::receiver::classify_type_text`] applies to a
 /// `Record <name>` type).
 fn parse_object_ref_value(value: &str) -> (ObjectRef, bool) {
     let (base, is_temporary) = strip_temporary_marker(value.trim());
-    let base = base.trim();
+    let base = strip_namespace_qualifier(base);
     if let Ok(n) = base.parse::<i64>() {
         (ObjectRef::Id(n), is_temporary)
     } else {
@@ -634,14 +635,34 @@ pub fn extract_nodes(
         let subscriber_instance_manual = read_event_subscriber_instance(obj);
         for r in &obj.routines {
             let has_sub_attr = r.attributes.iter().any(|a| a == "eventsubscriber");
-            let event_subscribers: Vec<ParsedSubscriberArgs> = if has_sub_attr {
+            // Only procedures subscribe; an attribute the parser attached to a
+            // trigger, or one whose arguments do not parse, is skipped (and
+            // logged) rather than turned into a subscription.
+            let event_subscribers: Vec<ParsedSubscriberArgs> = if !has_sub_attr {
+                vec![]
+            } else if matches!(r.kind, RoutineKind::Trigger) {
+                log::debug!(
+                    "ignoring [EventSubscriber] on trigger {}.{}",
+                    obj.name,
+                    r.name
+                );
+                vec![]
+            } else {
                 r.attributes_parsed
                     .iter()
                     .filter(|a| a.name.eq_ignore_ascii_case("eventsubscriber"))
-                    .filter_map(|a| parse_event_subscriber_ir(a, &file.ir))
+                    .filter_map(|a| {
+                        let parsed = parse_event_subscriber_ir(a, &file.ir);
+                        if parsed.is_none() {
+                            log::debug!(
+                                "skipping malformed [EventSubscriber] on {}.{}",
+                                obj.name,
+                                r.name
+                            );
+                        }
+                        parsed
+                    })
                     .collect()
-            } else {
-                vec![]
             };
             let publisher_kind = is_event_publisher(r);
             // Only meaningful when `publisher_kind.is_some()`; the parser itself
@@ -740,6 +761,62 @@ codeunit 50100 "Sales Helper"
         assert_eq!(post.return_type, None);
     }
 
+    /// A malformed `[EventSubscriber]` — arguments missing or of the wrong
+    /// shape — or one on a trigger never panics and never yields a
+    /// subscription, while a well-formed sibling still does.
+    #[test]
+    fn malformed_or_misplaced_event_subscriber_is_skipped() {
+        let src = r#"
+codeunit 50100 Subscribers
+{
+    [EventSubscriber(ObjectType::Codeunit, Codeunit::"Pub")]
+    local procedure TooFewArgs()
+    begin
+    end;
+
+    [EventSubscriber('Codeunit', 42, OnAfterPost)]
+    local procedure WrongShapes()
+    begin
+    end;
+
+    [EventSubscriber()]
+    local procedure Empty()
+    begin
+    end;
+
+    [EventSubscriber(ObjectType::Codeunit, Codeunit::"Pub", 'OnAfterPost', '', false, false)]
+    local procedure Valid()
+    begin
+    end;
+
+    [EventSubscriber(ObjectType::Codeunit, Codeunit::"Pub", 'OnAfterPost', '', false, false)]
+    trigger OnRun()
+    begin
+    end;
+}
+"#;
+        let file = al_syntax::parse(src);
+        let mut objs = Vec::new();
+        let mut routs = Vec::new();
+        extract_nodes(
+            AppRef(0),
+            &file,
+            TrustTier::Workspace,
+            &mut objs,
+            &mut routs,
+        );
+
+        let subscribing: Vec<&str> = routs
+            .iter()
+            .filter(|r| !r.event_subscribers.is_empty())
+            .map(|r| r.id.name_lc.as_str())
+            .collect();
+        assert_eq!(subscribing, ["valid"]);
+        let valid = routs.iter().find(|r| r.id.name_lc == "valid").unwrap();
+        assert_eq!(valid.event_subscribers.len(), 1);
+        assert_eq!(valid.event_subscribers[0].event_name, "onafterpost");
+    }
+
     /// Parse `src` and return every extracted `ObjectNode`, document order.
     fn extract_objs(src: &str) -> Vec<ObjectNode> {
         let file = al_syntax::parse(src);
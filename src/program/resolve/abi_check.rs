@@ -197,7 +197,7 @@ pub struct AbiIntegrityReport {
 /// `opaque_boundary_route` fallback in `resolver.rs::resolve_object_run`,
 /// beyond-1B.3b Task 5.5), not a genuine ABI-ingested Method.
 ///
-/// Entry triggers (`OnRun`/`OnOpenPage`/`OnPreReport`) are PLATFORM-INTRINSIC
+/// Entry triggers (`OnRun`/`OnOpenPage`/`OnPreReport`/…) are PLATFORM-INTRINSIC
 /// per object kind — every Page implicitly has an `OnOpenPage` hook whether or
 /// not source overrides it — and structurally NEVER appear in a `.app`'s
 /// `SymbolReference.json` `Methods` array (triggers are declared with the
@@ -217,7 +217,8 @@ pub struct AbiIntegrityReport {
 /// exempting this shape changes no other outcome.
 ///
 /// Keep in sync with `resolver.rs::entry_trigger_name` (Page -> "onopenpage",
-/// Report -> "onprereport", everything else -> "onrun").
+/// Report -> "onprereport", Query -> "onbeforeopen", XmlPort ->
+/// "onprexmlport", everything else -> "onrun").
 fn is_entry_trigger_boundary_key(key: &AbiRoutineKey) -> bool {
     if key.routine_kind != AbiRoutineKind::Procedure
         || key.event_kind != AbiEventKind::None
@@ -228,6 +229,8 @@ fn is_entry_trigger_boundary_key(key: &AbiRoutineKey) -> bool {
     let entry_trigger_name = match key.object_type.as_str() {
         "page" => "onopenpage",
         "report" => "onprereport",
+        "query" => "onbeforeopen",
+        "xmlport" => "onprexmlport",
         _ => "onrun",
     };
     key.routine_name_lc == entry_trigger_name
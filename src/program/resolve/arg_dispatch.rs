@@ -2215,6 +2215,7 @@ mod tests {
             name: name.to_string(),
             ty: Some(ty.to_string()),
             temporary: false,
+            label: None,
             origin: test_origin(),
         }
     }
@@ -2257,6 +2258,7 @@ mod tests {
             ir: al_syntax::ir::Ir::new(),
             issues: vec![],
             parse_status: al_syntax::ir::ParseStatus::Clean,
+            comments: vec![],
         }
     }
 
@@ -98,6 +98,45 @@ pub enum EdgeKind {
     EventFlow,
 }
 
+/// How a call site bound its callee — recorded by the resolver on
+/// `full::ClassifiedEdge::resolution_kind` and exported as graphify's
+/// `resolution_kind` edge attribute, an analytics signal for judging
+/// resolution confidence. `External` and `Builtin` describe the target rather
+/// than the site, so the export derives them per route.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ResolutionKind {
+    /// A bare (or `this.`) call, resolved in the caller's own object or the
+    /// object it extends.
+    SelfReference,
+    /// A member call through a variable or other receiver
+    /// (`Helper.Process()`, `Rec.Method()`).
+    Variable,
+    /// An object run naming its target statically (`Codeunit.Run(Codeunit::X)`).
+    DirectObject,
+    /// A routine in another app, or a symbol-only dependency member.
+    External,
+    /// A platform builtin method.
+    Builtin,
+    /// A publisher → subscriber event route.
+    Event,
+    /// A table trigger fired implicitly by a record operation.
+    ImplicitTrigger,
+}
+
+impl ResolutionKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ResolutionKind::SelfReference => "self",
+            ResolutionKind::Variable => "variable",
+            ResolutionKind::DirectObject => "direct_object",
+            ResolutionKind::External => "external",
+            ResolutionKind::Builtin => "builtin",
+            ResolutionKind::Event => "event",
+            ResolutionKind::ImplicitTrigger => "implicit_trigger",
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum DispatchShape {
     Exact,
@@ -124,6 +163,11 @@ pub enum OpenWorldReason {
     ReverseDependentSubscribers,
     ReverseDependentExtensions,
     RuntimeTypeUnbounded,
+    /// Not open at all: `Insert()` / `Modify(false)` / `Delete()` — no
+    /// `true` run-trigger argument — tells the platform to skip the table
+    /// trigger, so the empty route set is the exact answer. Carried as `Partial` only so the obligation
+    /// classifies `HonestEmpty` like any other legal empty fan-out.
+    TriggerSuppressed,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -32,9 +32,9 @@
 
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 use al_syntax::IdentifierFoldExt;
-use al_syntax::ir::ObjectKind;
 use rayon::prelude::*;
 
 use crate::program::build::{DepLayer, assemble_program_graph, build_dep_layer};
@@ -47,12 +47,14 @@ use crate::program::resolve::abi_check::{
 use crate::program::resolve::arg_dispatch::{self, ArgDispatchInfo};
 use crate::program::resolve::decl_surface::DeclSurface;
 use crate::program::resolve::edge::{
-    CanonicalSpan, DispatchShape, Edge, EdgeKind, Evidence, EvidenceKind, Histogram,
-    OpenWorldReason, Route, RouteTarget, SetCompleteness, SiteId, UnknownReason, Witness,
-    callee_fp, classify_obligation,
+    CanonicalSpan, Condition, DispatchShape, Edge, EdgeKind, Evidence, EvidenceKind, Histogram,
+    OpenWorldReason, ResolutionKind, Route, RouteTarget, SetCompleteness, SiteId, UnknownReason,
+    Witness, callee_fp, classify_obligation,
 };
 use crate::program::resolve::extract::{
-    CalleeShape, WithState, extract_sites_for_routine, static_database_reference_target,
+    CalleeShape, RunTriggerArg, WithState, extract_sites_for_routine, is_codeunit_var,
+    object_run_object_kind, record_op_args, record_var_table, static_database_reference_target,
+    strip_quote_chars,
 };
 use crate::program::resolve::index::ResolveIndex;
 use crate::program::resolve::member_catalog::is_entry_dispatch_builtin;
@@ -60,7 +62,8 @@ use crate::program::resolve::receiver::{
     FrameworkKind, ReceiverType, infer_receiver_type, is_atomic_receiver_token,
 };
 use crate::program::resolve::resolver::{
-    emit_event_flow_edges, resolve_bare, resolve_bare_with_args, resolve_implicit_trigger,
+    emit_event_flow_edges, implicit_rec_table_id, implicit_trigger_name, is_field_trigger,
+    resolve_bare, resolve_bare_with_args, resolve_field_trigger, resolve_implicit_trigger,
     resolve_member_with_args, resolve_object_run,
 };
 use crate::program::sig_fp::source_routine_node_id;
@@ -91,6 +94,20 @@ pub enum ObligationId {
 pub struct ClassifiedEdge {
     pub obligation_id: ObligationId,
     pub edge: Edge,
+    /// Objects the call site depends on besides its routes — the table
+    /// `RecordRef.Open(Database::"Customer")` names, or the one behind
+    /// `Codeunit.Run(Codeunit::X, Cust)`'s record argument (the `Rec` the
+    /// codeunit's `OnRun` receives). See [`site_referenced_objects`].
+    /// Additive dependency information: never a route, never consulted by
+    /// `classify_obligation`. Empty for `EventFlow` edges.
+    pub referenced_objects: Vec<ObjectNodeId>,
+    /// How the site bound its callee, as the resolver saw it: a bare or
+    /// `this.` call is `SelfReference`, any other member call `Variable`, a
+    /// static object run `DirectObject`, a record operation
+    /// `ImplicitTrigger`, a publisher's edge `Event`. `None` when the site
+    /// could not be bound at all (an unclassified callee, a dynamic run
+    /// target, no calling object).
+    pub resolution_kind: Option<ResolutionKind>,
 }
 
 /// Result of resolving ALL call-site obligations in ONE workspace file —
@@ -354,9 +371,10 @@ fn builtin_dispatch_finding(
 }
 
 /// Resolve one call-site obligation to `(kind, shape, completeness, routes,
-/// builtin_dispatch_finding)`. The 5th element is the T0.3 audit signal
-/// (`Some` only from the `CalleeShape::Member` arm — see
-/// [`builtin_dispatch_finding`]); every other arm returns `None`.
+/// builtin_dispatch_finding, resolution_kind)`. The 5th element is the T0.3
+/// audit signal (`Some` only from the `CalleeShape::Member` arm — see
+/// [`builtin_dispatch_finding`]); every other arm returns `None`. The 6th is
+/// [`ClassifiedEdge::resolution_kind`].
 #[allow(clippy::too_many_arguments)]
 fn resolve_call_site_obligation(
     shape: &CalleeShape,
@@ -388,6 +406,7 @@ fn resolve_call_site_obligation(
     SetCompleteness,
     Vec<Route>,
     Option<BuiltinDispatchFinding>,
+    Option<ResolutionKind>,
 ) {
     // Built ONCE per obligation (not per-arm): SOURCE-tier only (`arg_
     // dispatch`'s own SymbolOnly gate lives in `resolve_in_object`, but
@@ -415,6 +434,20 @@ fn resolve_call_site_obligation(
     match shape {
         CalleeShape::Bare { name } => {
             let name_lc = name.fold_identifier();
+            if let Some(obj_node) = obj_node_opt
+                && let Some((shape, completeness, routes)) = implicit_rec_record_op(
+                    obj_node, &name_lc, with_state, file, call_args, graph, index, surface,
+                )
+            {
+                return (
+                    EdgeKind::ImplicitTrigger,
+                    shape,
+                    completeness,
+                    routes,
+                    None,
+                    Some(ResolutionKind::ImplicitTrigger),
+                );
+            }
             // Task 4 (sigfp-and-ambiguous-reclassification plan): thread the
             // REAL shape `resolve_bare` determined through — a bare call is
             // `DispatchShape::Exact` in every case except a genuine
@@ -441,6 +474,7 @@ fn resolve_call_site_obligation(
                 completeness_for_shape(shape),
                 routes,
                 None,
+                obj_node_opt.map(|_| ResolutionKind::SelfReference),
             )
         }
 
@@ -452,6 +486,7 @@ fn resolve_call_site_obligation(
             let receiver_lc = receiver_text.fold_identifier();
             let method_lc = method.fold_identifier();
             let mut finding: Option<BuiltinDispatchFinding> = None;
+            let mut resolution_kind = None;
             let (member_shape, mut routes) = if let Some(obj_node) = obj_node_opt {
                 let recv = infer_receiver_type(
                     &receiver_lc,
@@ -467,6 +502,10 @@ fn resolve_call_site_obligation(
                     &recv, &method_lc, arity, obj_node, graph, index, surface, &args_info,
                 );
                 finding = builtin_dispatch_finding(&recv, &method_lc, &r, file, call_args);
+                resolution_kind = Some(match recv {
+                    ReceiverType::SelfObject => ResolutionKind::SelfReference,
+                    _ => ResolutionKind::Variable,
+                });
                 (s, r)
             } else {
                 (
@@ -502,21 +541,23 @@ fn resolve_call_site_obligation(
                 }
             }
             let completeness = completeness_for_shape(member_shape);
-            (EdgeKind::Call, member_shape, completeness, routes, finding)
+            (
+                EdgeKind::Call,
+                member_shape,
+                completeness,
+                routes,
+                finding,
+                resolution_kind,
+            )
         }
 
         CalleeShape::ObjectRun {
             object_kind,
             target_ref,
             target_is_name,
+            ..
         } => {
-            let okind_opt = match object_kind.as_str() {
-                "Codeunit" => Some(ObjectKind::Codeunit),
-                "Page" => Some(ObjectKind::Page),
-                "Report" => Some(ObjectKind::Report),
-                _ => None,
-            };
-            if let Some(okind) = okind_opt {
+            if let Some(okind) = object_run_object_kind(object_kind) {
                 let (shape, completeness, routes) = resolve_object_run(
                     primary_app_ref,
                     okind,
@@ -526,7 +567,15 @@ fn resolve_call_site_obligation(
                     index,
                     surface,
                 );
-                (EdgeKind::Run, shape, completeness, routes, None)
+                let resolution_kind = target_ref.as_ref().map(|_| ResolutionKind::DirectObject);
+                (
+                    EdgeKind::Run,
+                    shape,
+                    completeness,
+                    routes,
+                    None,
+                    resolution_kind,
+                )
             } else {
                 // Unrecognised object kind — honest Unknown.
                 (
@@ -535,11 +584,17 @@ fn resolve_call_site_obligation(
                     SetCompleteness::Complete,
                     vec![unknown_route(UnknownReason::UnclassifiedCallee)],
                     None,
+                    None,
                 )
             }
         }
 
-        CalleeShape::RecordOp { receiver_text, op } => {
+        CalleeShape::RecordOp {
+            receiver_text,
+            op,
+            field_arg,
+            run_trigger,
+        } => {
             let receiver_lc = receiver_text.fold_identifier();
             let op_lc = op.fold_identifier();
 
@@ -570,20 +625,23 @@ fn resolve_call_site_obligation(
                 None
             };
 
-            let (shape, completeness, routes) = if let Some(table_node) = table_node_opt {
-                resolve_implicit_trigger(&op_lc, table_node, graph, index, surface)
-            } else {
-                // No table resolved: honest-empty Multicast (open-world, no
-                // known triggers, but we cannot say there are none).
-                (
-                    DispatchShape::Multicast,
-                    SetCompleteness::Partial {
-                        reason: OpenWorldReason::ReverseDependentExtensions,
-                    },
-                    vec![],
-                )
-            };
-            (EdgeKind::ImplicitTrigger, shape, completeness, routes, None)
+            let (shape, completeness, routes) = record_op_routes(
+                &op_lc,
+                field_arg.as_deref(),
+                *run_trigger,
+                table_node_opt,
+                graph,
+                index,
+                surface,
+            );
+            (
+                EdgeKind::ImplicitTrigger,
+                shape,
+                completeness,
+                routes,
+                None,
+                Some(ResolutionKind::ImplicitTrigger),
+            )
         }
 
         CalleeShape::Commit => {
@@ -612,6 +670,7 @@ fn resolve_call_site_obligation(
                 completeness_for_shape(shape),
                 routes,
                 None,
+                obj_node_opt.map(|_| ResolutionKind::SelfReference),
             )
         }
 
@@ -623,11 +682,205 @@ fn resolve_call_site_obligation(
                 SetCompleteness::Complete,
                 vec![unknown_route(unclassified_callee_reason(callee_text))],
                 None,
+                None,
             )
         }
     }
 }
 
+/// The trigger routes of record operation `op_lc` on `table_node_opt`'s
+/// table — the `CalleeShape::RecordOp` arm of
+/// [`resolve_call_site_obligation`], shared with a bare `Validate(...)` on a
+/// table's implicit `Rec` (see [`implicit_rec_record_op`]). `Insert`/
+/// `Modify`/`Delete` route only on a literal `true` run-trigger argument; a
+/// computed one routes with every trigger [`Condition::RunTriggerGuarded`].
+fn record_op_routes(
+    op_lc: &str,
+    field_arg: Option<&str>,
+    run_trigger: Option<RunTriggerArg>,
+    table_node_opt: Option<&ObjectNode>,
+    graph: &ProgramGraph,
+    index: &ResolveIndex,
+    surface: &DeclSurface,
+) -> (DispatchShape, SetCompleteness, Vec<Route>) {
+    if matches!(
+        run_trigger,
+        Some(RunTriggerArg::Absent | RunTriggerArg::Literal(false))
+    ) {
+        // `Insert()` / `Modify(false)` / `Delete()`: without a `true`
+        // run-trigger argument the platform skips the table trigger, so
+        // nothing fires. Honest-empty (not `Unknown`), with its own reason —
+        // no extension can add a route to a suppressed trigger.
+        (
+            DispatchShape::Multicast,
+            SetCompleteness::Partial {
+                reason: OpenWorldReason::TriggerSuppressed,
+            },
+            vec![],
+        )
+    } else if let Some(table_node) = table_node_opt {
+        let field_trigger =
+            implicit_trigger_name(op_lc, graph).is_some_and(|trigger| is_field_trigger(&trigger));
+        match field_arg {
+            Some(field_lc) if field_trigger => {
+                resolve_field_trigger(op_lc, field_lc, table_node, graph, index, surface)
+            }
+            _ => {
+                let (shape, completeness, mut routes) =
+                    resolve_implicit_trigger(op_lc, table_node, graph, index, surface);
+                if run_trigger == Some(RunTriggerArg::Computed) {
+                    for route in &mut routes {
+                        if matches!(route.target, RouteTarget::Routine(_)) {
+                            route.conditions.push(Condition::RunTriggerGuarded);
+                        }
+                    }
+                }
+                (shape, completeness, routes)
+            }
+        }
+    } else {
+        // No table resolved: honest-empty Multicast (open-world, no
+        // known triggers, but we cannot say there are none).
+        (
+            DispatchShape::Multicast,
+            SetCompleteness::Partial {
+                reason: OpenWorldReason::ReverseDependentExtensions,
+            },
+            vec![],
+        )
+    }
+}
+
+/// A bare field-trigger record method inside a table or table extension —
+/// `Validate("No.")` — is a call on the implicit `Rec`, so it routes like
+/// `Rec.Validate("No.")`: to the field trigger of the table (the extension's
+/// base table). See [`implicit_rec_record_op_site`] for when it applies;
+/// `None` leaves the site to [`resolve_bare_with_args`].
+#[allow(clippy::too_many_arguments)]
+fn implicit_rec_record_op(
+    obj_node: &ObjectNode,
+    name_lc: &str,
+    with_state: WithState,
+    file: &al_syntax::ir::AlFile,
+    call_args: &[al_syntax::ir::ExprId],
+    graph: &ProgramGraph,
+    index: &ResolveIndex,
+    surface: &DeclSurface,
+) -> Option<(DispatchShape, SetCompleteness, Vec<Route>)> {
+    let (table_node, field_arg, run_trigger) =
+        implicit_rec_record_op_site(obj_node, name_lc, with_state, file, call_args, graph, index)?;
+    Some(record_op_routes(
+        name_lc,
+        field_arg.as_deref(),
+        run_trigger,
+        Some(table_node),
+        graph,
+        index,
+        surface,
+    ))
+}
+
+/// The table and `(field_arg, run_trigger)` ([`record_op_args`]) of a bare
+/// call `name_lc` that runs on `obj_node`'s implicit `Rec` — shared by the
+/// resolver ([`implicit_rec_record_op`]) and the semantic-golden site
+/// context, so both agree on which bare calls are record operations. `None`
+/// in any object kind but a table or table extension, inside a `with` block
+/// that may rebind the call, when `name_lc` fires no field trigger
+/// ([`implicit_trigger_name`]), when the object declares its own routine of
+/// that name, or when the table is not uniquely resolvable.
+pub(crate) fn implicit_rec_record_op_site<'g>(
+    obj_node: &ObjectNode,
+    name_lc: &str,
+    with_state: WithState,
+    file: &al_syntax::ir::AlFile,
+    call_args: &[al_syntax::ir::ExprId],
+    graph: &'g ProgramGraph,
+    index: &ResolveIndex,
+) -> Option<(&'g ObjectNode, Option<String>, Option<RunTriggerArg>)> {
+    if !matches!(
+        obj_node.id.kind,
+        al_syntax::ir::ObjectKind::Table | al_syntax::ir::ObjectKind::TableExtension
+    ) || with_state != WithState::NoWithProven
+        || implicit_trigger_name(name_lc, graph).is_none_or(|t| !is_field_trigger(&t))
+        || !index.routines_in_object(&obj_node.id, name_lc).is_empty()
+    {
+        return None;
+    }
+    let table_id = implicit_rec_table_id(obj_node, graph, index)?;
+    let table_node = ResolveIndex::find_object(graph, &table_id)?;
+    let (field_arg, run_trigger) = record_op_args(file, name_lc, call_args);
+    Some((table_node, field_arg, run_trigger))
+}
+
+/// The objects one call site references besides its routes (see
+/// [`ClassifiedEdge::referenced_objects`]), in argument order without
+/// duplicates:
+///
+/// - every `Database::"Customer"` / `Codeunit::Util` / `Page::21`-style
+///   object-reference argument (`RecordRef.Open(Database::"Customer")`),
+///   through [`ProgramGraph::resolve_object_reference`] — except an object
+///   run's first argument, which is already the edge's routed target;
+/// - for a codeunit run with a record argument — `Codeunit.Run(Codeunit::X,
+///   Cust)` or a `Codeunit`-typed variable's `CuVar.Run(Cust)` — the table
+///   that record variable is declared over.
+///
+/// Everything resolves as seen from the calling app (`from`), fail-closed:
+/// an unknown or ambiguous object adds nothing.
+fn site_referenced_objects(
+    shape: &CalleeShape,
+    file: &al_syntax::ir::AlFile,
+    args: &[al_syntax::ir::ExprId],
+    routine: &al_syntax::ir::RoutineDecl,
+    obj: &al_syntax::ir::ObjectDecl,
+    from: AppRef,
+    graph: &ProgramGraph,
+) -> Vec<ObjectNodeId> {
+    let mut out: Vec<ObjectNodeId> = Vec::new();
+    let is_run = matches!(shape, CalleeShape::ObjectRun { .. });
+    for &arg in args.iter().skip(usize::from(is_run)) {
+        if let al_syntax::ir::ExprKind::DatabaseReference(text) = &file.ir.expr(arg).kind
+            && let Some(node) = graph.resolve_object_reference(from, text)
+            && !out.contains(&node.id)
+        {
+            out.push(node.id.clone());
+        }
+    }
+    let record_arg = match shape {
+        CalleeShape::ObjectRun { record_arg, .. } => record_arg.clone(),
+        CalleeShape::Member {
+            receiver_text,
+            method,
+            ..
+        } if method.eq_fold_identifier("run")
+            && is_codeunit_var(
+                routine,
+                &obj.globals,
+                &strip_quote_chars(receiver_text).fold_identifier(),
+            ) =>
+        {
+            match args {
+                [arg] => match &file.ir.expr(*arg).kind {
+                    al_syntax::ir::ExprKind::Identifier(n)
+                    | al_syntax::ir::ExprKind::QuotedIdentifier(n) => {
+                        Some(strip_quote_chars(n).fold_identifier())
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    if let Some(var_lc) = &record_arg
+        && let Some(table) = record_var_table(routine, &obj.globals, var_lc)
+        && let Some(node) = graph.resolve_object(from, al_syntax::ir::ObjectKind::Table, &table)
+        && !out.contains(&node.id)
+    {
+        out.push(node.id.clone());
+    }
+    out
+}
+
 /// Resolve ALL call-site obligations of ONE workspace file (T3 Task 6, the
 /// LSP-migration arc's rung-1 incremental-updater primitive: re-resolving a
 /// single saved file's obligations is exactly this call). Extracted
@@ -695,21 +948,22 @@ pub(crate) fn resolve_file_obligations(
                     callee_fp: fp,
                 };
 
-                let (kind, shape, completeness, routes, finding) = resolve_call_site_obligation(
-                    &site.shape,
-                    site.arity,
-                    &site.callee_text,
-                    obj_node_opt,
-                    routine,
-                    obj,
-                    primary_app_ref,
-                    graph,
-                    index,
-                    surface,
-                    site.with_state,
-                    &pf.file,
-                    &site.args,
-                );
+                let (kind, shape, completeness, routes, finding, resolution_kind) =
+                    resolve_call_site_obligation(
+                        &site.shape,
+                        site.arity,
+                        &site.callee_text,
+                        obj_node_opt,
+                        routine,
+                        obj,
+                        primary_app_ref,
+                        graph,
+                        index,
+                        surface,
+                        site.with_state,
+                        &pf.file,
+                        &site.args,
+                    );
 
                 match finding {
                     Some(BuiltinDispatchFinding::Flagged { object, method }) => {
@@ -730,6 +984,15 @@ pub(crate) fn resolve_file_obligations(
                     None => {}
                 }
 
+                let referenced_objects = site_referenced_objects(
+                    &site.shape,
+                    &pf.file,
+                    &site.args,
+                    routine,
+                    obj,
+                    primary_app_ref,
+                    graph,
+                );
                 edges.push(ClassifiedEdge {
                     obligation_id: obl_id,
                     edge: Edge {
@@ -744,6 +1007,8 @@ pub(crate) fn resolve_file_obligations(
                         completeness,
                         routes,
                     },
+                    referenced_objects,
+                    resolution_kind,
                 });
             }
         }
@@ -854,6 +1119,8 @@ fn resolve_full_program_from_parts(
         classified_edges.push(ClassifiedEdge {
             obligation_id: obl_id,
             edge,
+            referenced_objects: Vec::new(),
+            resolution_kind: Some(ResolutionKind::Event),
         });
     }
 
@@ -1061,14 +1328,39 @@ impl ProgramContext {
     }
 }
 
+/// Wall-clock time [`build_context_timed`] spent in each setup phase.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PhaseTimings {
+    /// Snapshot build: the workspace source walk plus dependency `.app` loading.
+    pub walk: Duration,
+    /// [`parse_snapshot`] over every source-bearing app.
+    pub parse: Duration,
+    /// Dep layer + program graph assembly.
+    pub build: Duration,
+}
+
 pub fn build_context_res(workspace_root: &Path) -> Result<ProgramContext, String> {
+    build_context_timed(workspace_root).map(|(ctx, _)| ctx)
+}
+
+/// As [`build_context_res`], but also reports how long each phase took (the
+/// CLI's `--profile`).
+pub fn build_context_timed(
+    workspace_root: &Path,
+) -> Result<(ProgramContext, PhaseTimings), String> {
+    let mut timings = PhaseTimings::default();
+
     // ── Step 1: Build snapshot ────────────────────────────────────────────────
+    let t = Instant::now();
+    let resolution = crate::config::ResolutionFileConfig::load_merged(workspace_root);
     let snap = (SnapshotBuilder {
         workspace_root: workspace_root.to_path_buf(),
         local_providers: vec![],
+        referenced_dependencies_only: resolution.lazy_dependencies(),
     })
     .build()
     .map_err(|e| format!("snapshot build failed: {e:#}"))?;
+    timings.walk = t.elapsed();
 
     // ws_file_set: the true workspace source virtual paths (first AppUnit).
     // Excludes embedded dep apps whose AppId matches the workspace AppId.
@@ -1094,7 +1386,10 @@ pub fn build_context_res(workspace_root: &Path) -> Result<ProgramContext, String
     // same order (see that function's own doc, and the
     // `assemble_program_graph_matches_build_program_graph_field_by_field`
     // characterization test in `program::build`).
+    let t = Instant::now();
     let parsed = parse_snapshot(&snap);
+    timings.parse = t.elapsed();
+    let t = Instant::now();
     let dep_layer = build_dep_layer(&snap, &crate::program::abi_ingest::AbiCache::new(), &parsed);
 
     // `snap.apps` is GUID-deduped upstream (H-2), so at most one parsed unit
@@ -1110,7 +1405,10 @@ pub fn build_context_res(workspace_root: &Path) -> Result<ProgramContext, String
             &empty_ws_unit
         }
     };
-    let graph = assemble_program_graph(&dep_layer, ws_unit, &snap);
+    let mut graph = assemble_program_graph(&dep_layer, ws_unit, &snap);
+    graph.fuzzy_object_names = resolution.fuzzy_object_names();
+    graph.trigger_ops = resolution.trigger_ops();
+    timings.build = t.elapsed();
 
     // ── Step 3: Locate primary (workspace) app ────────────────────────────────
     let primary_app_ref = graph.apps.find(&snap.workspace_app).ok_or_else(|| {
@@ -1120,14 +1418,17 @@ pub fn build_context_res(workspace_root: &Path) -> Result<ProgramContext, String
         )
     })?;
 
-    Ok(ProgramContext {
-        snap,
-        graph,
-        parsed,
-        primary_app_ref,
-        ws_file_set,
-        dep_layer,
-    })
+    Ok((
+        ProgramContext {
+            snap,
+            graph,
+            parsed,
+            primary_app_ref,
+            ws_file_set,
+            dep_layer,
+        },
+        timings,
+    ))
 }
 
 #[must_use]
@@ -1310,7 +1611,8 @@ fn count_into_histogram(h: &mut Histogram, e: &Edge) {
 mod tests {
     use super::*;
     use crate::program::node::ObjKey;
-    use crate::program::resolve::edge::{Condition, SourcePos};
+    use crate::program::resolve::edge::{BuiltinId, ObligationOutcome, SourcePos};
+    use al_syntax::ir::ObjectKind;
 
     fn rid(name: &str) -> RoutineNodeId {
         RoutineNodeId {
@@ -1459,6 +1761,1448 @@ mod tests {
         );
     }
 
+    /// `Codeunit.Run(Codeunit::X, Cust)` — the record-argument form — routes
+    /// to `X`'s `OnRun` exactly like the one-argument form.
+    #[test]
+    fn codeunit_run_with_record_argument_routes_to_on_run() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Cust.al"),
+            r#"table 50000 Customer
+{
+    fields
+    {
+        field(1; "No."; Code[20]) { }
+    }
+}
+
+codeunit 50001 "Cust Handler"
+{
+    TableNo = Customer;
+
+    trigger OnRun()
+    begin
+    end;
+}
+
+codeunit 50002 Poster
+{
+    procedure Post()
+    var
+        Cust: Record Customer;
+    begin
+        Codeunit.Run(Codeunit::"Cust Handler", Cust);
+    end;
+}
+"#,
+        )
+        .expect("write Cust.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let run = report
+            .edges
+            .iter()
+            .find(|ce| ce.edge.kind == EdgeKind::Run)
+            .expect("the Codeunit.Run site must produce a Run edge");
+        assert!(
+            run.edge.routes.iter().any(|r| matches!(
+                &r.target,
+                RouteTarget::Routine(id) if id.name_lc == "onrun"
+            )),
+            "Run edge must route to Cust Handler's OnRun"
+        );
+    }
+
+    /// The record argument of `Codeunit.Run(Codeunit::X, Cust)` puts a
+    /// dependency on `Cust`'s table on the Run edge; the one-argument form
+    /// references nothing beyond its route.
+    #[test]
+    fn codeunit_run_record_argument_references_its_table() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Cust.al"),
+            r#"table 50000 Customer
+{
+    fields
+    {
+        field(1; "No."; Code[20]) { }
+    }
+}
+
+codeunit 50001 "Cust Handler"
+{
+    TableNo = Customer;
+
+    trigger OnRun()
+    begin
+    end;
+}
+
+codeunit 50002 Poster
+{
+    procedure Post()
+    var
+        Cust: Record Customer;
+    begin
+        Codeunit.Run(Codeunit::"Cust Handler", Cust);
+    end;
+
+    procedure PostPlain()
+    begin
+        Codeunit.Run(Codeunit::"Cust Handler");
+    end;
+}
+"#,
+        )
+        .expect("write Cust.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let run_from = |caller: &str| {
+            report
+                .edges
+                .iter()
+                .find(|ce| ce.edge.kind == EdgeKind::Run && ce.edge.from.name_lc == caller)
+                .unwrap_or_else(|| panic!("no Run edge from {caller}"))
+        };
+        let referenced: Vec<(ObjectKind, &ObjKey)> = run_from("post")
+            .referenced_objects
+            .iter()
+            .map(|o| (o.kind, &o.key))
+            .collect();
+        assert_eq!(
+            referenced,
+            vec![(ObjectKind::Table, &ObjKey::Id(50000))],
+            "the Run edge depends on Customer through its record argument"
+        );
+        assert!(run_from("postplain").referenced_objects.is_empty());
+    }
+
+    /// `CuVar.Run(Cust)` on a `Codeunit`-typed variable routes to the
+    /// codeunit's `OnRun` and, like `Codeunit.Run(Codeunit::X, Cust)`,
+    /// depends on the table the record argument is declared over.
+    #[test]
+    fn codeunit_variable_run_record_argument_references_its_table() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Cust.al"),
+            r#"table 50000 Customer
+{
+    fields
+    {
+        field(1; "No."; Code[20]) { }
+    }
+}
+
+codeunit 50001 "Cust Handler"
+{
+    TableNo = Customer;
+
+    trigger OnRun()
+    begin
+    end;
+}
+
+codeunit 50002 Poster
+{
+    procedure Post()
+    var
+        CuVar: Codeunit "Cust Handler";
+        Cust: Record Customer;
+    begin
+        CuVar.Run(Cust);
+    end;
+
+    procedure PostPlain()
+    var
+        CuVar: Codeunit "Cust Handler";
+    begin
+        CuVar.Run();
+    end;
+}
+"#,
+        )
+        .expect("write Cust.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let run_from = |caller: &str| {
+            report
+                .edges
+                .iter()
+                .find(|ce| {
+                    ce.edge.from.name_lc == caller
+                        && ce.edge.routes.iter().any(|r| {
+                            matches!(
+                                &r.target,
+                                RouteTarget::Routine(id) if id.name_lc == "onrun"
+                            )
+                        })
+                })
+                .unwrap_or_else(|| panic!("no OnRun edge from {caller}"))
+        };
+        let referenced: Vec<(ObjectKind, &ObjKey)> = run_from("post")
+            .referenced_objects
+            .iter()
+            .map(|o| (o.kind, &o.key))
+            .collect();
+        assert_eq!(
+            referenced,
+            vec![(ObjectKind::Table, &ObjKey::Id(50000))],
+            "the OnRun edge depends on Customer through its record argument"
+        );
+        assert!(run_from("postplain").referenced_objects.is_empty());
+    }
+
+    /// `RecordRef.Open(Database::"Customer")` and a `Database::` reference
+    /// by number link the call to the table they name; an unknown table
+    /// links nothing.
+    #[test]
+    fn database_reference_arguments_link_the_named_table() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Cust.al"),
+            r#"table 50000 Customer
+{
+    fields
+    {
+        field(1; "No."; Code[20]) { }
+    }
+}
+
+codeunit 50002 Inspector
+{
+    procedure ByName()
+    var
+        RecRef: RecordRef;
+    begin
+        RecRef.Open(Database::"Customer");
+    end;
+
+    procedure ByNumber()
+    var
+        RecRef: RecordRef;
+    begin
+        RecRef.Open(Database::50000);
+    end;
+
+    procedure Missing()
+    var
+        RecRef: RecordRef;
+    begin
+        RecRef.Open(Database::"No Such Table");
+    end;
+}
+"#,
+        )
+        .expect("write Cust.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let referenced = |caller: &str| -> Vec<(ObjectKind, ObjKey)> {
+            report
+                .edges
+                .iter()
+                .find(|ce| ce.edge.from.name_lc == caller)
+                .unwrap_or_else(|| panic!("no edge from {caller}"))
+                .referenced_objects
+                .iter()
+                .map(|o| (o.kind, o.key.clone()))
+                .collect()
+        };
+        let customer = vec![(ObjectKind::Table, ObjKey::Id(50000))];
+        assert_eq!(referenced("byname"), customer);
+        assert_eq!(referenced("bynumber"), customer);
+        assert!(referenced("missing").is_empty());
+    }
+
+    /// `Codeunit.Run(Codeunit::X)` names its target statically — no
+    /// `Codeunit` variable in scope — and routes to `X`'s `OnRun`, whose own
+    /// calls then continue the hierarchy from there.
+    #[test]
+    fn variable_less_codeunit_run_routes_to_on_run_and_its_callees() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Runner.al"),
+            r#"codeunit 50001 Runner
+{
+    trigger OnRun()
+    begin
+        DoWork();
+    end;
+
+    procedure DoWork()
+    begin
+    end;
+}
+
+codeunit 50002 Starter
+{
+    procedure Start()
+    begin
+        Codeunit.Run(Codeunit::Runner);
+    end;
+}
+"#,
+        )
+        .expect("write Runner.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let run = report
+            .edges
+            .iter()
+            .find(|ce| ce.edge.kind == EdgeKind::Run && ce.edge.from.name_lc == "start")
+            .expect("the Codeunit.Run site must produce a Run edge");
+        assert!(
+            run.edge.routes.iter().any(|r| matches!(
+                &r.target,
+                RouteTarget::Routine(id) if id.name_lc == "onrun"
+            )),
+            "Run edge must route to Runner's OnRun; got {:?}",
+            run.edge.routes
+        );
+        assert!(
+            report.edges.iter().any(|ce| ce.edge.from.name_lc == "onrun"
+                && ce.edge.routes.iter().any(|r| matches!(
+                    &r.target,
+                    RouteTarget::Routine(id) if id.name_lc == "dowork"
+                ))),
+            "OnRun's own call to DoWork must be an edge out of OnRun"
+        );
+    }
+
+    /// AL has no `#include` or partial objects, but a workspace can still
+    /// carry one object's declaration in two files (a `#if`-split copy, a
+    /// file mid-move). Both parts share one `ObjectNodeId`, so their
+    /// routines land on the same logical object and a bare call from one
+    /// part resolves to a procedure declared in the other.
+    #[test]
+    fn bare_call_resolves_across_two_files_declaring_the_same_object() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("PosterA.al"),
+            r#"codeunit 50020 Poster
+{
+    procedure Post()
+    begin
+        Release();
+    end;
+}
+"#,
+        )
+        .expect("write PosterA.al");
+        std::fs::write(
+            dir.path().join("PosterB.al"),
+            r#"codeunit 50020 Poster
+{
+    procedure Release()
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write PosterB.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let call = report
+            .edges
+            .iter()
+            .find(|ce| ce.edge.from.name_lc == "post")
+            .expect("Post's call to Release must produce an edge");
+        assert!(
+            call.edge.routes.iter().any(|r| matches!(
+                &r.target,
+                RouteTarget::Routine(id) if id.name_lc == "release"
+                    && id.object == call.edge.from.object
+            )),
+            "the cross-file call must resolve on the shared object; got {:?}",
+            call.edge.routes
+        );
+    }
+
+    /// Page/Report/Query/XmlPort object-reference calls route to the target's
+    /// entry trigger like `Codeunit.Run` does — `RunModal`'s result being
+    /// compared does not change the link.
+    #[test]
+    fn object_reference_calls_route_to_each_kinds_entry_trigger() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Targets.al"),
+            r#"page 50010 "Cust Card"
+{
+    trigger OnOpenPage()
+    begin
+    end;
+}
+
+report 50011 Statement
+{
+    trigger OnPreReport()
+    begin
+    end;
+}
+
+query 50012 "Top Customers"
+{
+    trigger OnBeforeOpen()
+    begin
+    end;
+}
+
+xmlport 50013 "Import Items"
+{
+    trigger OnPreXmlPort()
+    begin
+    end;
+}
+
+codeunit 50014 Launcher
+{
+    procedure ShowCard()
+    begin
+        if Page.RunModal(Page::"Cust Card") = Action::LookupOK then
+            exit;
+    end;
+
+    procedure PrintStatement()
+    begin
+        Report.Run(Report::Statement);
+    end;
+
+    procedure ExportTop()
+    begin
+        Query.SaveAsCsv(Query::"Top Customers", 'top.csv');
+    end;
+
+    procedure ImportItems()
+    begin
+        XmlPort.Run(XmlPort::"Import Items");
+    end;
+}
+"#,
+        )
+        .expect("write Targets.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        for (caller, trigger) in [
+            ("showcard", "onopenpage"),
+            ("printstatement", "onprereport"),
+            ("exporttop", "onbeforeopen"),
+            ("importitems", "onprexmlport"),
+        ] {
+            let run = report
+                .edges
+                .iter()
+                .find(|ce| ce.edge.kind == EdgeKind::Run && ce.edge.from.name_lc == caller)
+                .unwrap_or_else(|| panic!("{caller} must produce a Run edge"));
+            assert!(
+                run.edge.routes.iter().any(|r| matches!(
+                    &r.target,
+                    RouteTarget::Routine(id) if id.name_lc == trigger
+                )),
+                "{caller}'s Run edge must route to {trigger}; got {:?}",
+                run.edge.routes
+            );
+        }
+    }
+
+    /// An unqualified call between two interface methods inside the
+    /// implementing codeunit resolves to the codeunit's OWN implementation
+    /// (own-object lookup, `resolve_bare`'s step 1), never to the interface's
+    /// bodiless declaration.
+    #[test]
+    fn unqualified_sibling_interface_method_call_resolves_to_the_implementation() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Shape.al"),
+            r#"interface IShape
+{
+    procedure Area(): Decimal;
+    procedure Describe();
+}
+
+codeunit 50010 Circle implements IShape
+{
+    procedure Area(): Decimal
+    begin
+        exit(3);
+    end;
+
+    procedure Describe()
+    begin
+        Area();
+    end;
+}
+"#,
+        )
+        .expect("write Shape.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let call = report
+            .edges
+            .iter()
+            .find(|ce| ce.edge.kind == EdgeKind::Call && ce.edge.from.name_lc == "describe")
+            .expect("Describe's Area() call must produce a Call edge");
+        assert!(
+            matches!(
+                call.edge.routes.as_slice(),
+                [route] if matches!(
+                    &route.target,
+                    RouteTarget::Routine(id)
+                        if id.name_lc == "area" && id.object.kind == ObjectKind::Codeunit
+                )
+            ),
+            "must route to Circle.Area: {:?}",
+            call.edge.routes
+        );
+    }
+
+    /// A member call on a GLOBAL interface variable (`Shape.Area()`, with
+    /// `Shape: Interface IShape` declared in the object's `var` section)
+    /// types the receiver through the object-scope bindings and dispatches
+    /// to every implementer.
+    #[test]
+    fn global_interface_variable_call_resolves_to_every_implementation() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Shape.al"),
+            r#"interface IShape
+{
+    procedure Area(): Decimal;
+}
+
+codeunit 50010 Circle implements IShape
+{
+    procedure Area(): Decimal
+    begin
+        exit(3);
+    end;
+}
+
+codeunit 50011 Square implements IShape
+{
+    procedure Area(): Decimal
+    begin
+        exit(4);
+    end;
+}
+
+codeunit 50012 Painter
+{
+    var
+        Shape: Interface IShape;
+
+    procedure Paint(): Decimal
+    begin
+        exit(Shape.Area());
+    end;
+}
+"#,
+        )
+        .expect("write Shape.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let call = report
+            .edges
+            .iter()
+            .find(|ce| ce.edge.kind == EdgeKind::Call && ce.edge.from.name_lc == "paint")
+            .expect("Paint's Shape.Area() call must produce a Call edge");
+        let mut targets: Vec<&ObjKey> = call
+            .edge
+            .routes
+            .iter()
+            .filter_map(|route| match &route.target {
+                RouteTarget::Routine(id) if id.name_lc == "area" => Some(&id.object.key),
+                _ => None,
+            })
+            .collect();
+        targets.sort();
+        assert_eq!(
+            targets,
+            [&ObjKey::Id(50010), &ObjKey::Id(50011)],
+            "must route to Circle.Area and Square.Area: {:?}",
+            call.edge.routes
+        );
+    }
+
+    /// AL has no `base.` qualifier: a tableextension reaches its base
+    /// table's procedures bare or through `Rec.`, and both route to the
+    /// base table's declaration.
+    #[test]
+    fn table_extension_calls_route_to_the_base_table_procedure() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("ItemBase.al"),
+            r#"table 50030 "Item Base"
+{
+    fields
+    {
+        field(1; "No."; Code[20]) { }
+    }
+
+    procedure Recalc()
+    begin
+    end;
+}
+
+tableextension 50031 "Item Ext" extends "Item Base"
+{
+    procedure RefreshBare()
+    begin
+        Recalc();
+    end;
+
+    procedure RefreshRec()
+    begin
+        Rec.Recalc();
+    end;
+}
+"#,
+        )
+        .expect("write ItemBase.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        for caller in ["refreshbare", "refreshrec"] {
+            let call = report
+                .edges
+                .iter()
+                .find(|ce| ce.edge.kind == EdgeKind::Call && ce.edge.from.name_lc == caller)
+                .unwrap_or_else(|| panic!("{caller} must produce a Call edge"));
+            assert!(
+                call.edge.routes.iter().any(|route| matches!(
+                    &route.target,
+                    RouteTarget::Routine(id)
+                        if id.name_lc == "recalc"
+                            && id.object.kind == ObjectKind::Table
+                            && id.object.key == ObjKey::Id(50030)
+                )),
+                "{caller} must route to Item Base.Recalc: {:?}",
+                call.edge.routes
+            );
+        }
+    }
+
+    /// A call through an object-scope `Record` variable resolves on its
+    /// table, and a same-named local variable shadows the global.
+    #[test]
+    fn global_record_variable_call_resolves_and_locals_shadow_it() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Cust.al"),
+            r#"table 50040 "Cust"
+{
+    fields
+    {
+        field(1; "No."; Code[20]) { }
+    }
+
+    procedure Check()
+    begin
+    end;
+}
+
+codeunit 50041 "Cust Helper"
+{
+    procedure Check()
+    begin
+    end;
+}
+
+codeunit 50042 "Cust Mgt"
+{
+    var
+        Cust: Record "Cust";
+
+    procedure ViaGlobal()
+    begin
+        Cust.Check();
+    end;
+
+    procedure ViaLocal()
+    var
+        Cust: Codeunit "Cust Helper";
+    begin
+        Cust.Check();
+    end;
+}
+"#,
+        )
+        .expect("write Cust.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        for (caller, kind, id) in [
+            ("viaglobal", ObjectKind::Table, 50040),
+            ("vialocal", ObjectKind::Codeunit, 50041),
+        ] {
+            let call = report
+                .edges
+                .iter()
+                .find(|ce| ce.edge.kind == EdgeKind::Call && ce.edge.from.name_lc == caller)
+                .unwrap_or_else(|| panic!("{caller} must produce a Call edge"));
+            let targets: Vec<(ObjectKind, &ObjKey)> = call
+                .edge
+                .routes
+                .iter()
+                .filter_map(|route| match &route.target {
+                    RouteTarget::Routine(rid) if rid.name_lc == "check" => {
+                        Some((rid.object.kind, &rid.object.key))
+                    }
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(targets, [(kind, &ObjKey::Id(id))], "{caller}");
+        }
+    }
+
+    /// `Copy`, `CopyFilters` and `SetView` between two record variables are
+    /// builtin calls on the receiver; they must not retype either variable,
+    /// so member calls afterwards still resolve on the declared table.
+    #[test]
+    fn record_copy_and_view_calls_keep_member_calls_on_the_declared_table() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Cust.al"),
+            r#"table 50045 "Cust"
+{
+    fields
+    {
+        field(1; "No."; Code[20]) { }
+    }
+
+    procedure Check()
+    begin
+    end;
+}
+
+codeunit 50046 "Cust Copier"
+{
+    procedure CopyAndCheck()
+    var
+        Cust1: Record "Cust";
+        Cust2: Record "Cust";
+    begin
+        Cust1.SetRange("No.", '10000');
+        Cust2.Copy(Cust1);
+        Cust2.CopyFilters(Cust1);
+        Cust2.SetView(Cust1.GetView());
+        Cust2.Check();
+        Cust1.Check();
+    end;
+}
+"#,
+        )
+        .expect("write Cust.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let checks: Vec<&Edge> = report
+            .edges
+            .iter()
+            .map(|ce| &ce.edge)
+            .filter(|e| {
+                e.kind == EdgeKind::Call
+                    && e.from.name_lc == "copyandcheck"
+                    && e.routes.iter().any(|route| {
+                        matches!(&route.target, RouteTarget::Routine(rid) if rid.name_lc == "check")
+                    })
+            })
+            .collect();
+        assert_eq!(checks.len(), 2, "Cust2.Check() and Cust1.Check()");
+        for edge in checks {
+            let targets: Vec<(ObjectKind, &ObjKey)> = edge
+                .routes
+                .iter()
+                .filter_map(|route| match &route.target {
+                    RouteTarget::Routine(rid) => Some((rid.object.kind, &rid.object.key)),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(targets, [(ObjectKind::Table, &ObjKey::Id(50045))]);
+        }
+    }
+
+    /// `Helpers[1].Process()` types the receiver by the array's element type
+    /// (`array[2] of Codeunit Helper`) and routes to `Helper.Process`.
+    #[test]
+    fn array_element_call_resolves_on_the_element_type() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Helper.al"),
+            r#"codeunit 50020 Helper
+{
+    procedure Process()
+    begin
+    end;
+}
+
+codeunit 50021 Dispatcher
+{
+    procedure RunAll()
+    var
+        Helpers: array[2] of Codeunit Helper;
+    begin
+        Helpers[1].Process();
+    end;
+}
+"#,
+        )
+        .expect("write Helper.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let call = report
+            .edges
+            .iter()
+            .find(|ce| ce.edge.kind == EdgeKind::Call && ce.edge.from.name_lc == "runall")
+            .expect("RunAll's Helpers[1].Process() call must produce a Call edge");
+        assert!(
+            matches!(
+                call.edge.routes.as_slice(),
+                [route] if matches!(
+                    &route.target,
+                    RouteTarget::Routine(id)
+                        if id.name_lc == "process" && id.object.key == ObjKey::Id(50020)
+                )
+            ),
+            "must route to Helper.Process: {:?}",
+            call.edge.routes
+        );
+    }
+
+    /// A procedure whose QUOTED name is an AL built-in (`"Insert"`) is an
+    /// ordinary user routine: both a qualified `Helper."Insert"(..)` and a
+    /// bare `"Insert"(..)` inside the declaring codeunit route to it, never
+    /// to a builtin.
+    #[test]
+    fn quoted_keyword_procedure_name_resolves_to_the_user_routine() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Helper.al"),
+            r#"codeunit 50030 Helper
+{
+    procedure "Insert"(Value: Integer)
+    begin
+    end;
+
+    procedure InsertTwice(Value: Integer)
+    begin
+        "Insert"(Value);
+        "Insert"(Value);
+    end;
+}
+
+codeunit 50031 Caller
+{
+    procedure Run()
+    var
+        H: Codeunit Helper;
+    begin
+        H."Insert"(1);
+    end;
+}
+"#,
+        )
+        .expect("write Helper.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let is_user_insert = |ce: &ClassifiedEdge| {
+            matches!(
+                ce.edge.routes.as_slice(),
+                [route] if matches!(
+                    &route.target,
+                    RouteTarget::Routine(id)
+                        if id.name_lc == "insert" && id.object.key == ObjKey::Id(50030)
+                )
+            )
+        };
+        for caller in ["inserttwice", "run"] {
+            let calls: Vec<&ClassifiedEdge> = report
+                .edges
+                .iter()
+                .filter(|ce| ce.edge.kind == EdgeKind::Call && ce.edge.from.name_lc == caller)
+                .collect();
+            assert!(!calls.is_empty(), "{caller} must produce Call edges");
+            assert!(
+                calls.iter().all(|ce| is_user_insert(ce)),
+                "{caller}'s calls must route to Helper.\"Insert\": {:?}",
+                calls.iter().map(|ce| &ce.edge.routes).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    /// A receiver typed with a namespace-qualified object name
+    /// (`Codeunit Contoso.Tools.Helper`) resolves to the object declared
+    /// inside that namespace, exactly like the unqualified name a `using`
+    /// directive brings into scope.
+    #[test]
+    fn namespace_qualified_receiver_type_resolves_to_the_object() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Helper.al"),
+            r#"namespace Contoso.Tools;
+
+codeunit 50040 Helper
+{
+    procedure Process()
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write Helper.al");
+        std::fs::write(
+            dir.path().join("Caller.al"),
+            r#"namespace Contoso.App;
+
+using Contoso.Tools;
+
+codeunit 50041 Caller
+{
+    procedure Qualified()
+    var
+        H: Codeunit Contoso.Tools.Helper;
+    begin
+        H.Process();
+    end;
+
+    procedure ViaUsing()
+    var
+        H: Codeunit Helper;
+    begin
+        H.Process();
+    end;
+}
+"#,
+        )
+        .expect("write Caller.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        for caller in ["qualified", "viausing"] {
+            let call = report
+                .edges
+                .iter()
+                .find(|ce| ce.edge.kind == EdgeKind::Call && ce.edge.from.name_lc == caller)
+                .unwrap_or_else(|| panic!("{caller} must produce a Call edge"));
+            assert!(
+                matches!(
+                    call.edge.routes.as_slice(),
+                    [route] if matches!(
+                        &route.target,
+                        RouteTarget::Routine(id)
+                            if id.name_lc == "process" && id.object.key == ObjKey::Id(50040)
+                    )
+                ),
+                "{caller} must route to Helper.Process: {:?}",
+                call.edge.routes
+            );
+        }
+    }
+
+    /// A record local assigned from a procedure's return value
+    /// (`Cust := GetCustomer();`) is typed by its own declaration, so its
+    /// member calls dispatch on Customer; the inline `GetCustomer().X()`
+    /// form is typed by the procedure's return type (receiver Step 5).
+    #[test]
+    fn record_returned_by_a_procedure_dispatches_on_its_table() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Cust.al"),
+            r#"table 50000 Customer
+{
+    fields
+    {
+        field(1; "No."; Code[20]) { }
+    }
+
+    procedure Describe(): Text
+    begin
+        exit("No.");
+    end;
+}
+"#,
+        )
+        .expect("write Cust.al");
+        std::fs::write(
+            dir.path().join("Caller.al"),
+            r#"codeunit 50050 Caller
+{
+    procedure GetCustomer(): Record Customer
+    var
+        Customer: Record Customer;
+    begin
+        exit(Customer);
+    end;
+
+    procedure ViaLocal()
+    var
+        Cust: Record Customer;
+    begin
+        Cust := GetCustomer();
+        Cust.Get('10000');
+        Cust.Describe();
+    end;
+
+    procedure Inline()
+    begin
+        GetCustomer().Describe();
+    end;
+}
+"#,
+        )
+        .expect("write Caller.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let targets_of = |caller: &str| {
+            report
+                .edges
+                .iter()
+                .filter(|ce| ce.edge.kind == EdgeKind::Call && ce.edge.from.name_lc == caller)
+                .flat_map(|ce| ce.edge.routes.iter().map(|r| r.target.clone()))
+                .collect::<Vec<_>>()
+        };
+        let is_describe = |t: &RouteTarget| {
+            matches!(
+                t,
+                RouteTarget::Routine(id)
+                    if id.name_lc == "describe"
+                        && id.object.kind == ObjectKind::Table
+                        && id.object.key == ObjKey::Id(50000)
+            )
+        };
+
+        let via_local = targets_of("vialocal");
+        assert!(
+            via_local.contains(&RouteTarget::Builtin(BuiltinId("Record::get".to_string()))),
+            "Cust.Get() must dispatch on the record: {via_local:?}"
+        );
+        assert!(
+            via_local.iter().any(is_describe),
+            "Cust.Describe() must route to Customer.Describe: {via_local:?}"
+        );
+        let inline = targets_of("inline");
+        assert!(
+            inline.iter().any(is_describe),
+            "GetCustomer().Describe() must route to Customer.Describe: {inline:?}"
+        );
+    }
+
+    /// `Rec.Validate(Field)` routes to THAT field's `OnValidate`
+    /// (`Table.Field.OnValidate`), not to every field trigger on the table.
+    #[test]
+    fn validate_routes_to_the_validated_fields_on_validate_trigger() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Cust.al"),
+            r#"table 50000 Customer
+{
+    fields
+    {
+        field(1; "No."; Code[20])
+        {
+            trigger OnValidate()
+            begin
+            end;
+        }
+        field(2; "Post Code"; Code[20])
+        {
+            trigger OnValidate()
+            begin
+            end;
+        }
+    }
+
+    trigger OnInsert()
+    begin
+        Rec.Validate("Post Code");
+    end;
+}
+"#,
+        )
+        .expect("write Cust.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let validate = report
+            .edges
+            .iter()
+            .find(|ce| ce.edge.kind == EdgeKind::ImplicitTrigger)
+            .expect("the Validate site must produce an ImplicitTrigger edge");
+        let targets: Vec<Option<&str>> = validate
+            .edge
+            .routes
+            .iter()
+            .filter_map(|r| match &r.target {
+                RouteTarget::Routine(id) => Some(id.enclosing_member_lc.as_deref()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(targets, vec![Some("post code")]);
+    }
+
+    /// A record method mapped in `resolution.triggerOps` routes like a
+    /// built-in one: `CalcFields → OnValidate` links `Rec.CalcFields(Amount)`
+    /// to the `Amount` field's `OnValidate`; unmapped, it routes nowhere.
+    #[test]
+    fn configured_trigger_op_routes_calcfields_to_the_fields_on_validate() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Ledger.al"),
+            r#"table 50000 Ledger
+{
+    fields
+    {
+        field(1; "No."; Code[20])
+        {
+            trigger OnValidate()
+            begin
+            end;
+        }
+        field(2; Amount; Decimal)
+        {
+            FieldClass = FlowField;
+            trigger OnValidate()
+            begin
+            end;
+        }
+    }
+}
+
+codeunit 50001 Totals
+{
+    procedure Refresh()
+    var
+        Rec: Record Ledger;
+    begin
+        Rec.CalcFields(Amount);
+    end;
+}
+"#,
+        )
+        .expect("write Ledger.al");
+
+        let calcfields_targets = |report: &ProgramReport| -> Vec<Option<String>> {
+            report
+                .edges
+                .iter()
+                .filter(|ce| ce.edge.kind == EdgeKind::ImplicitTrigger)
+                .flat_map(|ce| &ce.edge.routes)
+                .filter_map(|r| match &r.target {
+                    RouteTarget::Routine(id) => Some(id.enclosing_member_lc.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let unmapped = resolve_full_program(dir.path()).expect("resolve_full_program");
+        assert!(calcfields_targets(&unmapped).is_empty());
+
+        std::fs::write(
+            dir.path().join(".al-call-hierarchy.json"),
+            r#"{ "resolution": { "triggerOps": { "CalcFields": "OnValidate" } } }"#,
+        )
+        .expect("write config");
+        let mapped = resolve_full_program(dir.path()).expect("resolve_full_program");
+        assert_eq!(
+            calcfields_targets(&mapped),
+            vec![Some("amount".to_string())]
+        );
+    }
+
+    /// A bare `Validate("No.")` inside a table or table extension runs on
+    /// the implicit `Rec`, so it routes to the field's `OnValidate` like
+    /// `Rec.Validate("No.")` does.
+    #[test]
+    fn bare_validate_on_the_implicit_rec_routes_to_the_field_trigger() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Ledger.al"),
+            r#"table 50000 Ledger
+{
+    fields
+    {
+        field(1; "No."; Code[20])
+        {
+            trigger OnValidate()
+            begin
+            end;
+        }
+    }
+
+    procedure Renumber()
+    begin
+        Validate("No.");
+    end;
+}
+
+tableextension 50001 LedgerExt extends Ledger
+{
+    procedure RenumberFromExt()
+    begin
+        Validate("No.");
+    end;
+}
+"#,
+        )
+        .expect("write Ledger.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let mut validate_sites: Vec<(String, Vec<Option<String>>)> = report
+            .edges
+            .iter()
+            .filter(|ce| ce.edge.kind == EdgeKind::ImplicitTrigger)
+            .map(|ce| {
+                let targets = ce
+                    .edge
+                    .routes
+                    .iter()
+                    .filter_map(|r| match &r.target {
+                        RouteTarget::Routine(id) => Some(id.enclosing_member_lc.clone()),
+                        _ => None,
+                    })
+                    .collect();
+                (ce.edge.from.name_lc.clone(), targets)
+            })
+            .collect();
+        validate_sites.sort();
+        assert_eq!(
+            validate_sites,
+            vec![
+                ("renumber".to_string(), vec![Some("no.".to_string())]),
+                ("renumberfromext".to_string(), vec![Some("no.".to_string())]),
+            ]
+        );
+        assert!(
+            report
+                .edges
+                .iter()
+                .filter(|ce| ce.edge.kind == EdgeKind::ImplicitTrigger)
+                .all(|ce| ce.resolution_kind == Some(ResolutionKind::ImplicitTrigger))
+        );
+        let appl = crate::program::resolve::semantic_golden::run_route_applicability(dir.path());
+        assert_eq!(appl.implicit_trigger_violations, 0);
+    }
+
+    /// The resolver records how each site bound its callee: bare and
+    /// `this.` calls are `SelfReference`, a variable call is `Variable` even
+    /// when the variable's type is the caller's own codeunit, a static run
+    /// is `DirectObject` and a record operation `ImplicitTrigger`.
+    #[test]
+    fn call_sites_record_their_resolution_kind() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Kinds.al"),
+            r#"table 50000 Customer
+{
+    fields
+    {
+        field(1; "No."; Code[20]) { }
+    }
+
+    trigger OnInsert()
+    begin
+    end;
+}
+
+codeunit 50100 Worker
+{
+    procedure Go()
+    var
+        Same: Codeunit Worker;
+        Customer: Record Customer;
+    begin
+        Helper();
+        this.Helper();
+        Same.Helper();
+        Codeunit.Run(Codeunit::Worker);
+        Customer.Insert(true);
+    end;
+
+    procedure Helper()
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write Kinds.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let mut kinds: Vec<(u32, Option<ResolutionKind>)> = report
+            .edges
+            .iter()
+            .filter(|ce| ce.edge.from.name_lc == "go")
+            .map(|ce| (ce.edge.site.span.start.line, ce.resolution_kind))
+            .collect();
+        kinds.sort_by_key(|(line, _)| *line);
+        let kinds: Vec<Option<ResolutionKind>> = kinds.into_iter().map(|(_, k)| k).collect();
+        assert_eq!(
+            kinds,
+            [
+                Some(ResolutionKind::SelfReference),
+                Some(ResolutionKind::SelfReference),
+                Some(ResolutionKind::Variable),
+                Some(ResolutionKind::DirectObject),
+                Some(ResolutionKind::ImplicitTrigger),
+            ]
+        );
+        assert!(
+            report
+                .edges
+                .iter()
+                .filter(|ce| ce.edge.kind == EdgeKind::EventFlow)
+                .all(|ce| ce.resolution_kind == Some(ResolutionKind::Event))
+        );
+    }
+
+    /// `Rec.Insert(true)` / `Rec.Modify(true)` route to the table's
+    /// `OnInsert` / `OnModify`; a `false` or absent run-trigger argument
+    /// routes to nothing, and a computed one routes guarded.
+    #[test]
+    fn insert_and_modify_with_run_trigger_route_to_table_triggers() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_minimal_workspace(dir.path());
+        std::fs::write(
+            dir.path().join("Cust.al"),
+            r#"table 50000 Customer
+{
+    fields
+    {
+        field(1; "No."; Code[20]) { }
+    }
+
+    trigger OnInsert()
+    begin
+    end;
+
+    trigger OnModify()
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write Cust.al");
+        std::fs::write(
+            dir.path().join("Writer.al"),
+            r#"codeunit 50100 Writer
+{
+    procedure InsertWithTrigger()
+    var
+        Customer: Record Customer;
+    begin
+        Customer.Insert(true);
+    end;
+
+    procedure ModifyWithTrigger()
+    var
+        Customer: Record Customer;
+    begin
+        Customer.Modify(true);
+    end;
+
+    procedure InsertWithoutTrigger()
+    var
+        Customer: Record Customer;
+    begin
+        Customer.Insert(false);
+    end;
+
+    procedure ModifyWithoutArgument()
+    var
+        Customer: Record Customer;
+    begin
+        Customer.Modify();
+    end;
+
+    procedure InsertMaybe(RunTrigger: Boolean)
+    var
+        Customer: Record Customer;
+    begin
+        Customer.Insert(RunTrigger);
+    end;
+}
+"#,
+        )
+        .expect("write Writer.al");
+
+        let report = resolve_full_program(dir.path()).expect("resolve_full_program");
+        let triggers_of = |caller: &str| -> Vec<String> {
+            report
+                .edges
+                .iter()
+                .filter(|ce| {
+                    ce.edge.kind == EdgeKind::ImplicitTrigger && ce.edge.from.name_lc == caller
+                })
+                .flat_map(|ce| ce.edge.routes.iter())
+                .filter_map(|r| match &r.target {
+                    RouteTarget::Routine(id) => Some(id.name_lc.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(triggers_of("insertwithtrigger"), vec!["oninsert"]);
+        assert_eq!(triggers_of("modifywithtrigger"), vec!["onmodify"]);
+        assert!(
+            triggers_of("insertwithouttrigger").is_empty(),
+            "Insert(false) must not route to OnInsert"
+        );
+        assert!(
+            triggers_of("modifywithoutargument").is_empty(),
+            "Modify() runs no trigger"
+        );
+        for caller in ["insertwithouttrigger", "modifywithoutargument"] {
+            let suppressed = report
+                .edges
+                .iter()
+                .find(|ce| ce.edge.from.name_lc == caller)
+                .expect("suppressed record-op edge");
+            assert_eq!(
+                suppressed.edge.completeness,
+                SetCompleteness::Partial {
+                    reason: OpenWorldReason::TriggerSuppressed
+                },
+                "{caller}"
+            );
+            assert_eq!(
+                classify_obligation(&suppressed.edge),
+                ObligationOutcome::HonestEmpty,
+                "{caller}"
+            );
+        }
+
+        assert_eq!(triggers_of("insertmaybe"), vec!["oninsert"]);
+        let guarded = report
+            .edges
+            .iter()
+            .find(|ce| ce.edge.from.name_lc == "insertmaybe")
+            .expect("Insert(RunTrigger) edge");
+        assert_eq!(
+            guarded.edge.completeness,
+            SetCompleteness::Partial {
+                reason: OpenWorldReason::ReverseDependentExtensions
+            }
+        );
+        assert!(
+            guarded
+                .edge
+                .routes
+                .iter()
+                .all(|r| r.conditions == vec![Condition::RunTriggerGuarded])
+        );
+    }
+
     #[test]
     fn resolve_full_program_recovered_files_empty_when_workspace_is_clean() {
         let dir = tempfile::tempdir().expect("tempdir");
@@ -1541,6 +3285,7 @@ mod tests {
             let snap = (SnapshotBuilder {
                 workspace_root: ws.clone(),
                 local_providers: vec![],
+                referenced_dependencies_only: false,
             })
             .build()
             .expect("CDO snapshot build");
@@ -1915,6 +3660,7 @@ mod tests {
         let snap = (SnapshotBuilder {
             workspace_root: ws.clone(),
             local_providers: vec![],
+            referenced_dependencies_only: false,
         })
         .build()
         .expect("r3a4 fixture snapshot builds");
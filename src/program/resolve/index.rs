@@ -25,7 +25,7 @@ use std::collections::HashMap;
 use al_syntax::IdentifierFoldExt;
 use al_syntax::ir::ObjectKind;
 
-use crate::program::graph::ProgramGraph;
+use crate::program::graph::{ProgramGraph, object_name_key};
 use crate::program::node::{AppRef, ObjectNodeId, RoutineNodeId};
 use crate::program::node_extract::{FieldNode, ObjectNode, ObjectRef};
 use crate::program::resolve::edge::Condition;
@@ -153,8 +153,8 @@ pub struct ResolveIndex {
     /// collision (`ObjectRefResolution::Ambiguous`) that `objs_by_number`'s
     /// single-slot-per-app shape cannot represent.
     objects_by_id: HashMap<(ObjectKind, i64), Vec<ObjectNodeId>>,
-    /// `(kind, name_lc)` → every `ObjectNodeId` across ALL apps sharing that
-    /// (kind, name), in `graph.objects` sort order. The Name-arm counterpart
+    /// `(kind, object_name_key)` → every `ObjectNodeId` across ALL apps
+    /// sharing that (kind, name), in `graph.objects` sort order. The Name-arm counterpart
     /// to `objects_by_id`, used only by [`Self::resolve_object_ref`].
     objects_by_name: HashMap<(ObjectKind, String), Vec<ObjectNodeId>>,
     /// Lowercased `extends_target` of a `TableExtension` → all extension ids.
@@ -185,6 +185,22 @@ pub struct ResolveIndex {
     orphaned_subscriptions: Vec<OrphanSub>,
 }
 
+/// Lowercased interface name → every object in `objects` that declares
+/// `implements` on it, in `objects` order. The interface-implementation index
+/// behind [`ResolveIndex::implementers_of`].
+fn implementers_by_interface(objects: &[ObjectNode]) -> HashMap<String, Vec<ObjectNodeId>> {
+    let mut implementers: HashMap<String, Vec<ObjectNodeId>> = HashMap::new();
+    for obj in objects {
+        for iface in &obj.implements {
+            implementers
+                .entry(iface.fold_identifier())
+                .or_default()
+                .push(obj.id.clone());
+        }
+    }
+    implementers
+}
+
 impl ResolveIndex {
     /// Build all indexes from `graph`.
     ///
@@ -222,7 +238,6 @@ impl ResolveIndex {
         let mut table_extensions: HashMap<String, Vec<ObjectNodeId>> = HashMap::new();
         let mut page_extensions: HashMap<String, Vec<ObjectNodeId>> = HashMap::new();
         let mut report_extensions: HashMap<String, Vec<ObjectNodeId>> = HashMap::new();
-        let mut implementers: HashMap<String, Vec<ObjectNodeId>> = HashMap::new();
 
         for obj in &graph.objects {
             // By-number: first sorted entry wins for a given (app, kind, id).
@@ -236,7 +251,7 @@ impl ResolveIndex {
                     .push(obj.id.clone());
             }
             objects_by_name
-                .entry((obj.id.kind, obj.name.fold_identifier()))
+                .entry((obj.id.kind, object_name_key(&obj.name)))
                 .or_default()
                 .push(obj.id.clone());
 
@@ -272,15 +287,8 @@ impl ResolveIndex {
                     .or_default()
                     .push(obj.id.clone());
             }
-
-            // Interface implementers.
-            for iface in &obj.implements {
-                implementers
-                    .entry(iface.fold_identifier())
-                    .or_default()
-                    .push(obj.id.clone());
-            }
         }
+        let implementers = implementers_by_interface(&graph.objects);
 
         // ── Event subscriber index ────────────────────────────────────────────
         let mut subscribers_map: HashMap<RoutineNodeId, Vec<SubscriberEntry>> = HashMap::new();
@@ -301,7 +309,8 @@ impl ResolveIndex {
                 };
 
                 // (b) Resolve publisher object; unresolvable → drop.
-                let Some(pub_obj) = graph.resolve_object(sub_app, kind, &args.publisher_name)
+                let Some(pub_obj) =
+                    graph.resolve_subscribed_object(sub_app, kind, &args.publisher_name)
                 else {
                     continue;
                 };
@@ -519,10 +528,10 @@ impl ResolveIndex {
     ///   unreachable from here).
     ///
     /// No namespace data exists on `ObjectNode` today, so a namespace-qualified
-    /// name is matched purely as literal text via `normalized_lc` (whatever the
-    /// caller wrote); when namespace data lands on the graph, a qualified and
-    /// an unqualified reference to the same object will naturally compare
-    /// unequal here without any change to this function.
+    /// name is matched by its object-name segment alone: the qualifier is
+    /// stripped before `normalized_lc` is built (`receiver::
+    /// strip_namespace_qualifier`), so a qualified and an unqualified
+    /// reference to the same object compare equal here.
     pub fn resolve_object_ref(
         &self,
         graph: &ProgramGraph,
@@ -560,14 +569,22 @@ impl ResolveIndex {
                     _ => ObjectRefResolution::Ambiguous,
                 }
             }
-            ObjectRef::Name { normalized_lc, .. } => {
+            ObjectRef::Name { raw, normalized_lc } => {
                 let candidates = self
                     .objects_by_name
-                    .get(&(kind, normalized_lc.clone()))
+                    .get(&(kind, object_name_key(normalized_lc)))
                     .map(Vec::as_slice)
                     .unwrap_or(&[]);
                 if candidates.is_empty() {
-                    return ObjectRefResolution::Unresolved;
+                    // `resolution.fuzzyObjectNames`: the normalized fallback
+                    // is itself closure-scoped and declines on ambiguity.
+                    return graph
+                        .fuzzy_object_names
+                        .then(|| graph.resolve_object_normalized(from.app, kind, raw))
+                        .flatten()
+                        .map_or(ObjectRefResolution::Unresolved, |o| {
+                            ObjectRefResolution::Unique(o.id.clone())
+                        });
                 }
                 // Own-app shadow: `from`'s own declaration always wins over any
                 // dependency's same-named object — short-circuits before
@@ -638,6 +655,38 @@ impl ResolveIndex {
             .unwrap_or(&[])
     }
 
+    /// [`Self::implementers_of`] keyed by the interface's `ObjectNodeId`: an
+    /// implementer is listed under an interface only when its `implements`
+    /// name resolves to that interface from the implementer's own app, so
+    /// same-named interfaces in different apps keep separate implementer
+    /// lists. Interfaces without implementers are absent.
+    pub fn implementers_by_interface_id(
+        &self,
+        graph: &ProgramGraph,
+    ) -> HashMap<ObjectNodeId, Vec<ObjectNodeId>> {
+        let mut by_id: HashMap<ObjectNodeId, Vec<ObjectNodeId>> = HashMap::new();
+        for iface in graph
+            .objects
+            .iter()
+            .filter(|o| o.id.kind == ObjectKind::Interface)
+        {
+            let implementers: Vec<ObjectNodeId> = self
+                .implementers_of(&iface.name.fold_identifier())
+                .iter()
+                .filter(|imp| {
+                    graph
+                        .resolve_object(imp.app, ObjectKind::Interface, &iface.name)
+                        .is_some_and(|resolved| resolved.id == iface.id)
+                })
+                .cloned()
+                .collect();
+            if !implementers.is_empty() {
+                by_id.insert(iface.id.clone(), implementers);
+            }
+        }
+        by_id
+    }
+
     /// Resolve field `field_lc` against the VISIBILITY-SCOPED table field
     /// surface for `base` — the base table's own fields plus every
     /// `TableExtension` field visible in `from_object`'s compile-time app
@@ -819,7 +868,10 @@ impl ResolveIndex {
     /// sorted by `ObjectNodeId` at construction (`build_program_graph` Step 4
     /// / every in-memory test fixture), mirroring `object_extends`'s
     /// identical `graph.objects.binary_search_by` lookup pattern.
-    fn find_object<'g>(graph: &'g ProgramGraph, id: &ObjectNodeId) -> Option<&'g ObjectNode> {
+    pub(crate) fn find_object<'g>(
+        graph: &'g ProgramGraph,
+        id: &ObjectNodeId,
+    ) -> Option<&'g ObjectNode> {
         graph
             .objects
             .binary_search_by(|probe| probe.id.cmp(id))
@@ -1004,7 +1056,9 @@ pub fn count_unknown_include_sender_plus1_subscribers(graph: &ProgramGraph) -> u
             let Some(kind) = kind_from_object_type_str(&args.publisher_object_type) else {
                 continue;
             };
-            let Some(pub_obj) = graph.resolve_object(sub_app, kind, &args.publisher_name) else {
+            let Some(pub_obj) =
+                graph.resolve_subscribed_object(sub_app, kind, &args.publisher_name)
+            else {
                 continue;
             };
             let event_name_lc = args.event_name.fold_identifier();
@@ -2138,6 +2192,51 @@ mod tests {
         assert!(idx.implementers_of("ibar").is_empty());
     }
 
+    #[test]
+    fn implementers_by_interface_id_keeps_same_named_interfaces_apart() {
+        // Both apps declare an `IFoo`; each implementer resolves the name
+        // from its own app.
+        let (mut graph, a, b) = build_fixture();
+        graph.objects.extend([
+            make_obj(a, ObjectKind::Interface, None, "IFoo", None, vec![]),
+            make_obj(b, ObjectKind::Interface, None, "IFoo", None, vec![]),
+            make_obj(
+                b,
+                ObjectKind::Codeunit,
+                Some(50202),
+                "TheirImpl",
+                None,
+                vec!["IFoo"],
+            ),
+        ]);
+        graph.objects.sort_by(|x, y| x.id.cmp(&y.id));
+        graph.obj_index = ObjectIndex::build(&graph.objects);
+        let idx = ResolveIndex::build(&graph);
+
+        let by_id = idx.implementers_by_interface_id(&graph);
+        let implementer_names = |app: AppRef| -> Vec<&str> {
+            let iface = graph
+                .objects
+                .iter()
+                .find(|o| o.id.kind == ObjectKind::Interface && o.id.app == app)
+                .expect("interface");
+            by_id[&iface.id]
+                .iter()
+                .map(|id| {
+                    graph
+                        .objects
+                        .iter()
+                        .find(|o| &o.id == id)
+                        .expect("implementer")
+                        .name
+                        .as_str()
+                })
+                .collect()
+        };
+        assert_eq!(implementer_names(a), ["SomeImpl"]);
+        assert_eq!(implementer_names(b), ["TheirImpl"]);
+    }
+
     // -- routines_in_object test ----------------------------------------------
 
     #[test]
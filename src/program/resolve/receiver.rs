@@ -508,7 +508,7 @@ pub fn classify_type_text(ty: &str) -> ParsedType {
             // Mirrors `node_extract::parse_object_ref_value`'s identical
             // numeric-vs-quoted-name distinction for `SourceTable`/`TableNo`.
             let stripped = strip_trailing_temporary(rest);
-            let stripped = stripped.trim();
+            let stripped = strip_namespace_qualifier(stripped);
             let table_ref = if let Ok(n) = stripped.parse::<i64>() {
                 ObjectRef::Id(n)
             } else {
@@ -524,10 +524,10 @@ pub fn classify_type_text(ty: &str) -> ParsedType {
         "query" => parse_object_kind_type(ObjectKind::Query, rest),
         "xmlport" => parse_object_kind_type(ObjectKind::XmlPort, rest),
         "interface" => ParsedType::Interface {
-            name: unquote_identifier(rest).fold_identifier(),
+            name: unquote_identifier(strip_namespace_qualifier(rest)).fold_identifier(),
         },
         "enum" => ParsedType::EnumType {
-            name: unquote_identifier(rest).fold_identifier(),
+            name: unquote_identifier(strip_namespace_qualifier(rest)).fold_identifier(),
         },
         // Ref types
         "recordref" => ParsedType::RecordRef,
@@ -583,7 +583,7 @@ pub fn classify_type_text(ty: &str) -> ParsedType {
         "numbersequence" => ParsedType::Framework(FrameworkKind::NumberSequence),
         "version" => ParsedType::Framework(FrameworkKind::Version),
         "controladdin" => ParsedType::ControlAddIn {
-            name: unquote_identifier(rest).fold_identifier(),
+            name: unquote_identifier(strip_namespace_qualifier(rest)).fold_identifier(),
         },
         // Variant — runtime-typed, genuinely dynamic
         "variant" => ParsedType::Dynamic,
@@ -592,6 +592,29 @@ pub fn classify_type_text(ty: &str) -> ParsedType {
     }
 }
 
+/// The element type text of an AL array declaration —
+/// `array[10] of Codeunit "Sales-Post"` → `Codeunit "Sales-Post"`. `None`
+/// for any non-array type. Kept separate from [`classify_type_text`], which
+/// still classifies the array itself as [`ParsedType::Primitive`] (an array
+/// is not a receiver; only an indexed element is — see
+/// [`infer_receiver_type`]'s Step 0c).
+pub fn array_element_type_text(ty: &str) -> Option<&str> {
+    let t = ty.trim();
+    if !t.get(..5)?.eq_ignore_ascii_case("array") {
+        return None;
+    }
+    let dims = t[5..].trim_start().strip_prefix('[')?;
+    let rest = dims[dims.find(']')? + 1..].trim_start();
+    if !rest.get(..2)?.eq_ignore_ascii_case("of") {
+        return None;
+    }
+    let elem = &rest[2..];
+    if !elem.starts_with(char::is_whitespace) {
+        return None;
+    }
+    Some(elem.trim())
+}
+
 // ---------------------------------------------------------------------------
 // infer_receiver_type
 // ---------------------------------------------------------------------------
@@ -607,6 +630,9 @@ pub fn classify_type_text(ty: &str) -> ParsedType {
 /// 0. **`CurrPage.<part>.Page` subpage-instance receivers** — see the module
 ///    doc's Step 0. Checked first because it is a COMPOUND (dotted) receiver
 ///    text that none of steps 1-4 would otherwise positively type.
+/// 0c. **Array element receivers** — `<name>[<index>]` where `<name>` is an
+///    `array[..] of <T>` variable types as `<T>` (see
+///    [`array_element_type_text`]).
 /// 1. **Singletons** — `this`, `currpage`/`page`, `currreport`/`report`, and
 ///    other platform-provided names that are never declared as AL variables.
 /// 2. **Variable lookup** — `routine.params` → `routine.locals` →
@@ -725,6 +751,25 @@ pub fn infer_receiver_type(
         return resolve_control_addin_receiver(&control.target, from_object, graph, index);
     }
 
+    // -----------------------------------------------------------------------
+    // Step 0c — array ELEMENT receivers: `Helpers[1].Process()`. The
+    // receiver text is `<name>[<index>]`; `<name>` is looked up exactly like
+    // Step 2 below and, when its declared type is `array[..] of <T>`, the
+    // receiver is typed as `<T>`. Any other shape (an unknown name, a
+    // non-array variable, a malformed subscript) falls through and ends in
+    // `Unknown` — an indexed receiver never matches a plain variable.
+    // -----------------------------------------------------------------------
+    if let Some((name_lc, _)) = receiver_lc.split_once('[')
+        && receiver_lc.ends_with(']')
+        && !name_lc.is_empty()
+        && is_atomic_receiver_token(name_lc)
+        && let CallerScopeSymbol::Found(Some(ty)) =
+            caller_scope_symbol(&unquote_identifier(name_lc), routine, object_globals)
+        && let Some(elem) = array_element_type_text(ty)
+    {
+        return parsed_type_to_receiver(classify_type_text(elem), from_object, graph, index);
+    }
+
     // -----------------------------------------------------------------------
     // Step 2 — variable lookup (params → locals → the routine's own
     // named-return binding → object globals), via the shared
@@ -1479,6 +1524,38 @@ fn infer_receiver_type_for_expr(
                         name_lc: String::new(),
                     }
                 }
+                // `"My Enum"::Value` — the enum TYPE named directly on the
+                // left of `::` (no `Enum::` prefix). Only when the name is
+                // no variable/field in scope (the base typed `Unknown`), and
+                // under the same fail-closed gate as Step 4b's bare
+                // type-name receiver: exactly one `Enum` of that name in the
+                // closure and no other-kind object sharing it. The left of
+                // `::` is never a call, so Step 4b's routine-shadow check
+                // has nothing to guard here.
+                ReceiverType::Unknown => match &file.ir.expr(*enum_type).kind {
+                    ExprKind::Identifier(n) | ExprKind::QuotedIdentifier(n) => {
+                        let name_raw = unquote_identifier(n);
+                        let name_lc = name_raw.fold_identifier();
+                        let object_ref = ObjectRef::Name {
+                            raw: name_raw,
+                            normalized_lc: name_lc.clone(),
+                        };
+                        match index.resolve_object_ref(
+                            graph,
+                            from_object.id.clone(),
+                            ObjectKind::Enum,
+                            &object_ref,
+                        ) {
+                            ObjectRefResolution::Unique(_)
+                                if enum_type_name_collision_free(&name_lc, graph) =>
+                            {
+                                ReceiverType::EnumType { name_lc }
+                            }
+                            _ => ReceiverType::Unknown,
+                        }
+                    }
+                    _ => ReceiverType::Unknown,
+                },
                 _ => ReceiverType::Unknown,
             }
         }
@@ -2866,7 +2943,7 @@ fn object_ref_fallback_lc(object_ref: &ObjectRef) -> String {
 /// `"80"`) can never be conflated by a later re-parse of an already-unquoted
 /// string.
 fn parse_object_kind_type(kind: ObjectKind, name_rest: &str) -> ParsedType {
-    let trimmed = name_rest.trim();
+    let trimmed = strip_namespace_qualifier(name_rest);
     let object_ref = if let Ok(n) = trimmed.parse::<i64>() {
         ObjectRef::Id(n)
     } else {
@@ -2935,6 +3012,27 @@ pub(crate) fn is_atomic_receiver_token(s: &str) -> bool {
     !s.contains('(') && !s.contains('.')
 }
 
+/// The object-name segment of a possibly namespace-qualified object
+/// reference — `Contoso.Sales."Sales Helper"` → `"Sales Helper"`,
+/// quotes preserved for [`unquote_identifier`]. Only a `.` OUTSIDE quotes
+/// separates segments: a quoted name may embed one (`"Sales Cr.Memo
+/// Header"`). `ObjectNode` carries no namespace data (see
+/// `ResolveIndex::resolve_object_ref`'s doc), so the qualifier — like a
+/// `using` directive — is dropped and the object is matched by name alone.
+pub(crate) fn strip_namespace_qualifier(s: &str) -> &str {
+    let s = s.trim();
+    let mut in_quotes = false;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '.' if !in_quotes => start = i + 1,
+            _ => {}
+        }
+    }
+    s[start..].trim()
+}
+
 /// Strip surrounding double-quotes from an identifier token.  Returns the
 /// token unchanged if not quoted; returns an empty string for an empty input.
 ///
@@ -3269,30 +3367,35 @@ mod tests {
                     name: "Cust".into(),
                     ty: Some("Record Customer".into()),
                     temporary: false,
+                    label: None,
                     origin: o.clone(),
                 },
                 VarDecl {
                     name: "J".into(),
                     ty: Some("JsonObject".into()),
                     temporary: false,
+                    label: None,
                     origin: o.clone(),
                 },
                 VarDecl {
                     name: "RecTmp".into(),
                     ty: Some("Record Customer temporary".into()),
                     temporary: true,
+                    label: None,
                     origin: o.clone(),
                 },
                 VarDecl {
                     name: "Iface".into(),
                     ty: Some("Interface \"IMyInterface\"".into()),
                     temporary: false,
+                    label: None,
                     origin: o.clone(),
                 },
                 VarDecl {
                     name: "EnumVar".into(),
                     ty: Some("Enum \"Color\"".into()),
                     temporary: false,
+                    label: None,
                     origin: o.clone(),
                 },
             ],
@@ -3513,6 +3616,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn classify_codeunit_namespace_qualified_matches_by_object_name() {
+        let helper = ParsedType::Object {
+            kind: ObjectKind::Codeunit,
+            object_ref: ObjectRef::Name {
+                raw: "Sales Helper".into(),
+                normalized_lc: "sales helper".into(),
+            },
+        };
+        assert_eq!(
+            classify_type_text("Codeunit Contoso.Sales.\"Sales Helper\""),
+            helper
+        );
+        assert_eq!(
+            classify_type_text("Codeunit \"Contoso.Sales\".\"Sales Helper\""),
+            helper
+        );
+        // A dot INSIDE a quoted name is not a namespace separator.
+        assert_eq!(
+            classify_type_text("Record \"Sales Cr.Memo Header\""),
+            ParsedType::Record {
+                table_ref: ObjectRef::Name {
+                    raw: "Sales Cr.Memo Header".into(),
+                    normalized_lc: "sales cr.memo header".into(),
+                }
+            }
+        );
+    }
+
     #[test]
     fn classify_codeunit_quoted_numeric_name() {
         // `Codeunit "80"` is a codeunit literally NAMED "80" — must NOT be
@@ -4810,6 +4942,7 @@ mod tests {
                 name: "R".into(),
                 ty: Some("Record \"AmbTable\"".into()),
                 temporary: false,
+                label: None,
                 origin: o.clone(),
             }],
             attributes: vec![],
@@ -5303,6 +5436,20 @@ mod tests {
     // `is_atomic_receiver_token` (centralized quote-aware token guard).
     // -----------------------------------------------------------------------
 
+    #[test]
+    fn array_element_type_text_extracts_the_element_type() {
+        assert_eq!(
+            array_element_type_text("array[10] of Codeunit \"Sales-Post\""),
+            Some("Codeunit \"Sales-Post\"")
+        );
+        assert_eq!(
+            array_element_type_text("Array [2, 3] OF Record Customer"),
+            Some("Record Customer")
+        );
+        assert_eq!(array_element_type_text("Record Customer"), None);
+        assert_eq!(array_element_type_text("array[10] ofInteger"), None);
+    }
+
     #[test]
     fn is_atomic_receiver_token_cases() {
         assert!(is_atomic_receiver_token("cust"), "plain unquoted");
@@ -5504,6 +5651,7 @@ mod tests {
             name: "GlobalCu".into(),
             ty: Some("Codeunit \"MyCodeunit\"".into()),
             temporary: false,
+            label: None,
             origin: o,
         }];
 
@@ -5589,6 +5737,7 @@ mod tests {
             name: "CuParam".into(),
             ty: Some("JsonObject".into()),
             temporary: false,
+            label: None,
             origin: o,
         }];
 
@@ -5623,6 +5772,7 @@ mod tests {
                 name: "R".into(),
                 ty: Some("Record \"NonExistentTable\"".into()),
                 temporary: false,
+                label: None,
                 origin: o.clone(),
             }],
             attributes: vec![],
@@ -5672,6 +5822,7 @@ mod tests {
                 name: "Rec".into(),
                 ty: Some("Record Customer".into()),
                 temporary: false,
+                label: None,
                 origin: o.clone(),
             }],
             attributes: vec![],
@@ -6686,6 +6837,7 @@ codeunit 50100 "C"
             name: name.into(),
             ty: Some(ty.into()),
             temporary: false,
+            label: None,
             origin: test_origin(),
         }
     }
@@ -7872,6 +8024,103 @@ codeunit 50100 "C"
         assert_eq!(result, ReceiverType::Unknown);
     }
 
+    /// POSITIVE: the enum TYPE may sit on either side of `::` —
+    /// `"My Enum"::Value` (type name qualifying a value) and
+    /// `Enum::"My Enum"` (the type reference) — and AL names are
+    /// case-insensitive, so differently-cased spellings of both forms all
+    /// resolve to the one `My Enum` object.
+    #[test]
+    fn enum_type_name_on_either_side_of_double_colon_resolves_the_same_enum() {
+        let src = r#"
+codeunit 50100 "C"
+{
+    procedure Run()
+    var
+        N: Integer;
+    begin
+        N := "MY ENUM"::Value.AsInteger();
+        N := ENUM::"my enum".Ordinals().Count();
+    end;
+}
+"#;
+        let (graph, app) = build_test_graph_with(vec![make_object_node(
+            AppRef(0),
+            ObjectKind::Enum,
+            "My Enum",
+            None,
+            None,
+        )]);
+        let index = ResolveIndex::build(&graph);
+        let routine = routine_with_locals(vec![var_decl("N", "Integer")]);
+        let from_obj = make_object_node(app, ObjectKind::Codeunit, "CallerCu", Some(999), None);
+        let infer = |method: &str| {
+            let (file, receiver_text, receiver_id) = parse_member_site(src, method);
+            infer_receiver_type(
+                &receiver_text.to_ascii_lowercase(),
+                &routine,
+                &[],
+                &from_obj,
+                &graph,
+                &index,
+                Some((&file, receiver_id)),
+                None,
+            )
+        };
+
+        let value = infer("asinteger");
+        assert_eq!(
+            value,
+            ReceiverType::EnumType {
+                name_lc: "my enum".to_string()
+            }
+        );
+        let type_ref = infer("ordinals");
+        assert_eq!(
+            type_ref,
+            ReceiverType::EnumTypeStatic {
+                name_lc: "my enum".to_string()
+            }
+        );
+    }
+
+    /// NEGATIVE: `"My Enum"::Value` declines when a same-named object of
+    /// another kind exists — the same collision rule as the bare type-name
+    /// receiver (Step 4b).
+    #[test]
+    fn enum_type_name_qualifier_with_colliding_object_declines() {
+        let src = r#"
+codeunit 50100 "C"
+{
+    procedure Run()
+    var
+        N: Integer;
+    begin
+        N := "My Enum"::Value.AsInteger();
+    end;
+}
+"#;
+        let (file, receiver_text, receiver_id) = parse_member_site(src, "asinteger");
+        let (graph, app) = build_test_graph_with(vec![
+            make_object_node(AppRef(0), ObjectKind::Enum, "My Enum", None, None),
+            make_object_node(AppRef(0), ObjectKind::Table, "My Enum", Some(50123), None),
+        ]);
+        let index = ResolveIndex::build(&graph);
+        let routine = routine_with_locals(vec![var_decl("N", "Integer")]);
+        let from_obj = make_object_node(app, ObjectKind::Codeunit, "CallerCu", Some(999), None);
+
+        let result = infer_receiver_type(
+            &receiver_text.to_ascii_lowercase(),
+            &routine,
+            &[],
+            &from_obj,
+            &graph,
+            &index,
+            Some((&file, receiver_id)),
+            None,
+        );
+        assert_eq!(result, ReceiverType::Unknown);
+    }
+
     /// POSITIVE, site (G) shape: a bare QUOTED enum-type-name receiver,
     /// `"CDO Send on Posting".FromInteger(...)` — unique Enum match, zero
     /// same-name objects of any other kind, no routine shadow. Reduces to a
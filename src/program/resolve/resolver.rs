@@ -106,6 +106,18 @@ fn tier_evidence(tier: TrustTier) -> Evidence {
     }
 }
 
+/// Normalize an object name for the fuzzy fallback
+/// ([`ProgramGraph::resolve_object_normalized`]): identifier-folded, with
+/// everything but letters and digits dropped — `"Sales-Post"`,
+/// `"Sales Post"` and `SalesPost` all normalize to `salespost`.
+pub fn normalize_object_name(name: &str) -> String {
+    name.trim_matches('"')
+        .fold_identifier()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
 /// For an extension object kind, return the corresponding base object kind.
 ///
 /// Returns `None` for non-extension kinds.
@@ -120,6 +132,40 @@ fn extension_base_kind(kind: ObjectKind) -> Option<ObjectKind> {
     }
 }
 
+/// The [`AbiRoutineKey`] identifying SymbolOnly routine `rid` at its ABI
+/// boundary. Reads the ABI-sourced routine/event kinds from the graph node
+/// (`graph.routines` is sorted by RoutineNodeId — see build.rs — enabling
+/// O(log n) lookup); falls back to Procedure/None when the node is absent
+/// (integration gap — should not happen for a valid SymbolOnly boundary).
+pub(crate) fn abi_routine_key(rid: &RoutineNodeId, graph: &ProgramGraph) -> AbiRoutineKey {
+    let (obj_num, obj_name_lc) = match &rid.object.key {
+        ObjKey::Id(n) => (*n, String::new()),
+        ObjKey::Name(s) => (0i64, s.clone()),
+    };
+    let opt_node = graph
+        .routines
+        .binary_search_by(|probe| probe.id.cmp(rid))
+        .ok()
+        .map(|i| &graph.routines[i]);
+    let routine_kind = opt_node
+        .and_then(|n| n.abi_routine_kind.clone())
+        .unwrap_or(AbiRoutineKind::Procedure);
+    let event_kind = opt_node
+        .and_then(|n| n.abi_event_kind.clone())
+        .unwrap_or(AbiEventKind::None);
+    AbiRoutineKey {
+        app: rid.object.app,
+        object_type: format!("{:?}", rid.object.kind).to_ascii_lowercase(),
+        object_number: obj_num,
+        object_name_lc: obj_name_lc,
+        routine_name_lc: rid.name_lc.clone(),
+        params_count: rid.params_count,
+        param_type_fp: rid.sig_fp,
+        routine_kind,
+        event_kind,
+    }
+}
+
 /// Build a `Route` for a resolved routine.
 ///
 /// - If the routine is in the `DeclSurface` (source-bearing): `Evidence::Source` +
@@ -152,36 +198,7 @@ fn make_routine_route(
         // return Opaque rather than Unknown.  This preserves identity across the
         // boundary and classifies the route as `Resolved` (not `Unknown`) in the
         // obligation metric, matching L3's External treatment of dep symbols.
-        let (obj_num, obj_name_lc) = match &rid.object.key {
-            ObjKey::Id(n) => (*n, String::new()),
-            ObjKey::Name(s) => (0i64, s.clone()),
-        };
-        // Read the ABI-sourced routine/event kinds from the graph node.
-        // `graph.routines` is sorted by RoutineNodeId (see build.rs), enabling
-        // O(log n) lookup. Falls back to Procedure/None when the node is absent
-        // (integration gap — should not happen for a valid SymbolOnly boundary).
-        let opt_node = graph
-            .routines
-            .binary_search_by(|probe| probe.id.cmp(rid))
-            .ok()
-            .map(|i| &graph.routines[i]);
-        let routine_kind = opt_node
-            .and_then(|n| n.abi_routine_kind.clone())
-            .unwrap_or(AbiRoutineKind::Procedure);
-        let event_kind = opt_node
-            .and_then(|n| n.abi_event_kind.clone())
-            .unwrap_or(AbiEventKind::None);
-        let key = AbiRoutineKey {
-            app: rid.object.app,
-            object_type: format!("{:?}", rid.object.kind).to_ascii_lowercase(),
-            object_number: obj_num,
-            object_name_lc: obj_name_lc,
-            routine_name_lc: rid.name_lc.clone(),
-            params_count: rid.params_count,
-            param_type_fp: rid.sig_fp,
-            routine_kind,
-            event_kind,
-        };
+        let key = abi_routine_key(rid, graph);
         Route {
             target: RouteTarget::AbiSymbol { key: key.clone() },
             evidence: Evidence::Opaque,
@@ -1593,7 +1610,10 @@ pub(crate) fn resolve_bare_with_args(
     with_state: WithState,
     args: &[ArgDispatchInfo],
 ) -> (DispatchShape, Vec<Route>) {
-    // 1. Own object.
+    // 1. Own object. This also covers an implementing codeunit calling a
+    // sibling interface method unqualified: every interface method is declared
+    // on the implementer itself, so the call lands on that implementation
+    // rather than on the interface's bodiless declaration.
     if let Some((shape, routes)) = resolve_in_object(
         &from_object.id,
         from_object.tier,
@@ -1809,11 +1829,15 @@ pub(crate) fn resolve_bare_with_args(
 /// | Codeunit   | `"onrun"`       |
 /// | Page       | `"onopenpage"`  |
 /// | Report     | `"onprereport"` |
-/// | Query/Other| `"onrun"` (best-effort) |
+/// | Query      | `"onbeforeopen"` |
+/// | XmlPort    | `"onprexmlport"` |
+/// | Other      | `"onrun"` (best-effort) |
 fn entry_trigger_name(kind: ObjectKind) -> &'static str {
     match kind {
         ObjectKind::Page => "onopenpage",
         ObjectKind::Report => "onprereport",
+        ObjectKind::Query => "onbeforeopen",
+        ObjectKind::XmlPort => "onprexmlport",
         _ => "onrun",
     }
 }
@@ -1934,7 +1958,7 @@ pub fn resolve_object_run(
 
     // Resolve the target object.
     let target_obj: Option<&ObjectNode> = if target_is_name {
-        graph.resolve_object(from, object_kind, target_ref)
+        graph.resolve_object_by_name(from, object_kind, target_ref)
     } else {
         match target_ref.parse::<i64>() {
             Ok(n) => index
@@ -1994,6 +2018,10 @@ pub fn resolve_object_run(
 /// | `"validate"`| `"onvalidate"`  |
 /// | `"rename"`  | `"onrename"`    |
 ///
+/// Any other `op` the workspace config maps (`resolution.triggerOps`,
+/// [`ProgramGraph::trigger_ops`]) fans out to its configured trigger the
+/// same way; see [`implicit_trigger_name`].
+///
 /// # Validate-field approximation (Phase 2)
 ///
 /// `Rec.Validate(FieldName)` targets `OnValidate` on **one specific field**,
@@ -2003,6 +2031,8 @@ pub fn resolve_object_run(
 /// [`DispatchShape::Multicast`] with
 /// `SetCompleteness::Partial{ReverseDependentExtensions}`.  The Task-6
 /// differential gate measures the residual versus L3's per-field resolution.
+/// When the extraction layer DID capture a static field name, use
+/// [`resolve_field_validate_trigger`] instead.
 ///
 /// For `insert/modify/delete/rename` (object-level triggers,
 /// `enclosing_member_lc == None`) the fan-out is clean.
@@ -2013,23 +2043,17 @@ pub fn resolve_implicit_trigger(
     index: &ResolveIndex,
     surface: &DeclSurface,
 ) -> (DispatchShape, SetCompleteness, Vec<Route>) {
-    let trigger_name: &str = match op.fold_identifier().as_str() {
-        "insert" => "oninsert",
-        "modify" => "onmodify",
-        "delete" => "ondelete",
-        "validate" => "onvalidate",
-        "rename" => "onrename",
-        _ => {
-            // Unrecognised op: honest empty Multicast.
-            return (
-                DispatchShape::Multicast,
-                SetCompleteness::Partial {
-                    reason: OpenWorldReason::ReverseDependentExtensions,
-                },
-                vec![],
-            );
-        }
+    let Some(trigger_name) = implicit_trigger_name(op, graph) else {
+        // Unrecognised op: honest empty Multicast.
+        return (
+            DispatchShape::Multicast,
+            SetCompleteness::Partial {
+                reason: OpenWorldReason::ReverseDependentExtensions,
+            },
+            vec![],
+        );
     };
+    let trigger_name = trigger_name.as_str();
 
     let mut routes: Vec<Route> = Vec::new();
 
@@ -2078,6 +2102,65 @@ pub fn resolve_implicit_trigger(
     )
 }
 
+/// The trigger record method `op` runs (lowercased): the built-in mapping
+/// in [`resolve_implicit_trigger`]'s table, else the graph's configured
+/// [`ProgramGraph::trigger_ops`]. `None` for a method that runs no trigger.
+pub fn implicit_trigger_name(op: &str, graph: &ProgramGraph) -> Option<String> {
+    let op_lc = op.fold_identifier();
+    let builtin = match op_lc.as_str() {
+        "insert" => "oninsert",
+        "modify" => "onmodify",
+        "delete" => "ondelete",
+        "validate" => "onvalidate",
+        "rename" => "onrename",
+        _ => return graph.trigger_ops.get(&op_lc).cloned(),
+    };
+    Some(builtin.to_string())
+}
+
+/// `true` for a trigger declared on a table field (`Table.Field.OnValidate`)
+/// rather than on the table itself.
+pub fn is_field_trigger(trigger_lc: &str) -> bool {
+    matches!(trigger_lc, "onvalidate" | "onlookup")
+}
+
+/// `Rec.Validate(Field)` with a statically known field (`field_lc`, the
+/// lowercased unquoted name from `CalleeShape::RecordOp::field_arg`): the
+/// [`resolve_implicit_trigger`] `"validate"` fan-out narrowed to the
+/// `OnValidate` triggers declared on THAT field — `Table.Field.OnValidate`,
+/// i.e. `enclosing_member_lc == Some(field_lc)` — on the table and its
+/// extensions. Collapse-marked `Unresolved` routes are kept (they might be
+/// the field's trigger); shape and completeness are unchanged.
+pub fn resolve_field_validate_trigger(
+    field_lc: &str,
+    table_object: &ObjectNode,
+    graph: &ProgramGraph,
+    index: &ResolveIndex,
+    surface: &DeclSurface,
+) -> (DispatchShape, SetCompleteness, Vec<Route>) {
+    resolve_field_trigger("validate", field_lc, table_object, graph, index, surface)
+}
+
+/// [`resolve_field_validate_trigger`] for any record method `op` whose
+/// trigger is a field trigger ([`is_field_trigger`]) — a configured
+/// `triggerOps` entry such as `CalcFields` → `OnValidate` included.
+pub fn resolve_field_trigger(
+    op: &str,
+    field_lc: &str,
+    table_object: &ObjectNode,
+    graph: &ProgramGraph,
+    index: &ResolveIndex,
+    surface: &DeclSurface,
+) -> (DispatchShape, SetCompleteness, Vec<Route>) {
+    let (shape, completeness, mut routes) =
+        resolve_implicit_trigger(op, table_object, graph, index, surface);
+    routes.retain(|r| match &r.target {
+        RouteTarget::Routine(rid) => rid.enclosing_member_lc.as_deref() == Some(field_lc),
+        _ => true,
+    });
+    (shape, completeness, routes)
+}
+
 // ---------------------------------------------------------------------------
 // Member-call resolution (Phase 3 Task 2)
 // ---------------------------------------------------------------------------
@@ -2445,7 +2528,7 @@ pub(crate) fn resolve_member_with_args(
             // subpage for the one the control's `target` actually names.
             let target = match id {
                 Some(id) => graph.objects.iter().find(|o| &o.id == id),
-                None => graph.resolve_object(from_object.id.app, *kind, name_lc),
+                None => graph.resolve_object_by_name(from_object.id.app, *kind, name_lc),
             };
             let Some(target) = target else {
                 // Target not in the graph — honest Unknown (not Opaque: we have no
@@ -3304,6 +3387,8 @@ mod tests {
             obj_index,
             friends: friends_map,
             abi_ingest_errors: Default::default(),
+            fuzzy_object_names: false,
+            trigger_ops: Default::default(),
         }
     }
 
@@ -3316,6 +3401,23 @@ mod tests {
             .unwrap_or_else(|| panic!("object {name_lc} not found in graph"))
     }
 
+    #[test]
+    fn object_names_match_ignoring_case_and_punctuation() {
+        assert_eq!(normalize_object_name("\"Sales-Post\""), "salespost");
+        assert_eq!(
+            normalize_object_name("Sales-Post"),
+            normalize_object_name("SalesPost")
+        );
+        assert_eq!(
+            normalize_object_name("Sales Post"),
+            normalize_object_name("SALES_POST")
+        );
+        assert_ne!(
+            normalize_object_name("Sales-Post"),
+            normalize_object_name("Sales Posting")
+        );
+    }
+
     // -----------------------------------------------------------------------
     // (a) bare call to an own-object procedure → Source evidence + SourceSpan
     // -----------------------------------------------------------------------
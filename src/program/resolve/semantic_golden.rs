@@ -84,7 +84,7 @@ use crate::program::resolve::abi_check::{
 };
 use crate::program::resolve::anon::{self, AnonId};
 use crate::program::resolve::applicability::{
-    RecordOpCtx, RecordOpKind, RunTrigger, implicit_trigger_route_applicable,
+    FieldRef, RecordOpCtx, RecordOpKind, RunTrigger, implicit_trigger_route_applicable,
     instance_builtin_route_applicable, interface_route_applicable,
 };
 use crate::program::resolve::differential::{
@@ -94,7 +94,8 @@ use crate::program::resolve::differential::{
 use crate::program::resolve::edge::{
     DispatchShape, Edge, EdgeKind, RouteTarget, SiteId, callee_fp,
 };
-use crate::program::resolve::extract::{CalleeShape, extract_sites_for_routine};
+use crate::program::resolve::extract::{CalleeShape, RunTriggerArg, extract_sites_for_routine};
+use crate::program::resolve::full::implicit_rec_record_op_site;
 use crate::program::resolve::index::ResolveIndex;
 use crate::program::resolve::member_catalog::{MemberCatalogKind, member_builtin};
 use crate::program::resolve::receiver::{FrameworkKind, ReceiverType, infer_receiver_type};
@@ -1198,11 +1199,11 @@ pub struct ApplicabilityReport {
     pub instance_builtin_violations: usize,
     /// SOUNDNESS: `DispatchShape::Multicast` (`EdgeKind::ImplicitTrigger`)
     /// `Routine` routes that fail [`implicit_trigger_route_applicable`]
-    /// against the record-op call site's `RecordOpCtx` — `Validate` sites
-    /// fall back to the coarser table/extension-identity check (the
-    /// validated field name is not recoverable from `CalleeShape::RecordOp`,
+    /// against the record-op call site's `RecordOpCtx` — a `Validate` site
+    /// whose field is not a static identifier (no `CalleeShape::RecordOp`
+    /// `field_arg`) falls back to the coarser table/extension-identity check,
     /// the same documented limitation as the live
-    /// `differential::run_implicit_trigger_harness` FreshOnly gate) — or, when
+    /// `differential::run_implicit_trigger_harness` FreshOnly gate — or, when
     /// no call-site context was recovered, ANY non-`Unresolved` route
     /// (fail-closed, same rationale as the Interface case).
     pub implicit_trigger_violations: usize,
@@ -1639,8 +1640,10 @@ fn record_op_kind_for_method(op_lc: &str) -> Option<RecordOpKind> {
     }
 }
 
-/// Re-walk every workspace `Member`/`RecordOp` call site to recover the
-/// [`FanOutSiteContext`] the fan-out applicability predicates need.
+/// Re-walk every workspace `Member`/`RecordOp` call site — and every bare
+/// trigger-firing call on a table's implicit `Rec` (`Validate("No.")`) — to
+/// recover the [`FanOutSiteContext`] the fan-out applicability predicates
+/// need.
 ///
 /// Mirrors `full::resolve_full_program_from_parts`'s own Phase-1 walk
 /// (same snapshot/parsed/`ws_file_set`/`primary_app_ref` scoping) — this is
@@ -1753,7 +1756,12 @@ fn build_fan_out_site_context(
                                     );
                                 }
                             }
-                            CalleeShape::RecordOp { receiver_text, op } => {
+                            CalleeShape::RecordOp {
+                                receiver_text,
+                                op,
+                                field_arg,
+                                run_trigger,
+                            } => {
                                 let receiver_lc = receiver_text.fold_identifier();
                                 let op_lc = op.fold_identifier();
                                 let Some(op_kind) = record_op_kind_for_method(&op_lc) else {
@@ -1778,31 +1786,51 @@ fn build_fan_out_site_context(
                                         FanOutSiteContext::Trigger(RecordOpCtx {
                                             kind: op_kind,
                                             table: table_id,
-                                            // The validated field name is not
-                                            // recoverable from `CalleeShape::RecordOp`
-                                            // (it carries no argument text) — same
-                                            // documented limitation as the live
-                                            // `run_implicit_trigger_harness` FreshOnly
-                                            // gate; `route_applicability` falls back to
-                                            // the coarser table/extension-identity
+                                            // The validated field name, when the
+                                            // site's first argument is a static
+                                            // field identifier; otherwise `None`
+                                            // and `route_applicability` falls back
+                                            // to the coarser table/extension-identity
                                             // check for `Validate` sites (see its
                                             // doc comment).
-                                            field: None,
-                                            // The run-trigger boolean argument is not
-                                            // statically recoverable at this layer
-                                            // either (same conservative default the live
-                                            // gate uses) — `Guarded` never short-circuits
-                                            // the predicate to `false`, so it never masks
-                                            // a real violation; it only means we cannot
-                                            // independently confirm an `Insert(false)`
-                                            // site suppressed its trigger edges (a gap
-                                            // pre-existing in the ported logic, not
-                                            // introduced here).
-                                            run_trigger: RunTrigger::Guarded,
+                                            field: field_arg.clone().map(FieldRef),
+                                            run_trigger: site_run_trigger(*run_trigger),
                                         }),
                                     );
                                 }
                             }
+                            // A bare `Validate("No.")` in a table or table
+                            // extension runs on the implicit `Rec`; the
+                            // resolver routes it as a record operation on
+                            // that table (`full::implicit_rec_record_op`).
+                            CalleeShape::Bare { name } => {
+                                let name_lc = name.fold_identifier();
+                                let Some(op_kind) = record_op_kind_for_method(&name_lc) else {
+                                    continue;
+                                };
+                                let Some((table, field_arg, run_trigger)) =
+                                    implicit_rec_record_op_site(
+                                        obj_node,
+                                        &name_lc,
+                                        site.with_state,
+                                        &pf.file,
+                                        &site.args,
+                                        graph,
+                                        index,
+                                    )
+                                else {
+                                    continue;
+                                };
+                                ctx_map.insert(
+                                    site_id,
+                                    FanOutSiteContext::Trigger(RecordOpCtx {
+                                        kind: op_kind,
+                                        table: table.id.clone(),
+                                        field: field_arg.map(FieldRef),
+                                        run_trigger: site_run_trigger(run_trigger),
+                                    }),
+                                );
+                            }
                             _ => {}
                         }
                     }
@@ -1814,12 +1842,27 @@ fn build_fan_out_site_context(
     ctx_map
 }
 
+/// A record-op site's run-trigger argument, as the resolver reads it
+/// (`full::record_op_routes`): absent or literal `false` runs no trigger, a
+/// computed one is `Guarded`, and an op without the argument always runs
+/// its trigger.
+fn site_run_trigger(run_trigger: Option<RunTriggerArg>) -> RunTrigger {
+    match run_trigger {
+        None | Some(RunTriggerArg::Literal(true)) => RunTrigger::True,
+        Some(RunTriggerArg::Absent | RunTriggerArg::Literal(false)) => RunTrigger::False,
+        Some(RunTriggerArg::Computed) => RunTrigger::Guarded,
+    }
+}
+
 /// `target_object == table_id` OR a `TableExtension` of it.
 ///
-/// Used for `Validate` `ImplicitTrigger` routes, where
-/// [`FanOutSiteContext::Trigger`]'s `field` is always `None` (see
-/// [`build_fan_out_site_context`]'s doc comment) — with `field: None`, the
-/// full [`implicit_trigger_route_applicable`] ALWAYS returns `false` for a
+/// Used for `Validate` `ImplicitTrigger` routes whose
+/// [`FanOutSiteContext::Trigger`] has no `field` — the site's first argument
+/// is not a static field identifier, so the resolver fans out to every
+/// field's `OnValidate` (see [`build_fan_out_site_context`], which records
+/// the field for both `Rec.Validate(F)` and a bare `Validate(F)` on the
+/// implicit `Rec`). With `field: None`, the full
+/// [`implicit_trigger_route_applicable`] ALWAYS returns `false` for a
 /// `Validate` target (it requires `(Some(ctx_field), Some(target_field))` to
 /// match), so this coarser table-identity check is the fallback, mirroring
 /// `differential::run_implicit_trigger_harness`'s identical, documented
@@ -1954,7 +1997,9 @@ pub fn route_applicability(
                             let ok = match &route.target {
                                 RouteTarget::Routine(rid) => {
                                     implicit_trigger_routes_checked += 1;
-                                    if matches!(ctx.kind, RecordOpKind::Validate) {
+                                    if matches!(ctx.kind, RecordOpKind::Validate)
+                                        && ctx.field.is_none()
+                                    {
                                         target_is_on_table_or_extension(
                                             &rid.object,
                                             &ctx.table,
@@ -2108,6 +2153,7 @@ pub fn run_semantic_diff(workspace_root: &Path, golden: &SemanticGolden) -> Sema
     let snap = match (SnapshotBuilder {
         workspace_root: workspace_root.to_path_buf(),
         local_providers: vec![],
+        referenced_dependencies_only: false,
     })
     .build()
     {
@@ -3,7 +3,8 @@
 //!
 //! Server state is now `Arc<SharedSnapshot>` (the published, immutable
 //! `LspSnapshot`) + an `mpsc::Sender<ChangeEvent>` feeding a background
-//! [`spawn_updater`] thread, instead of the legacy `Arc<RwLock<Indexer>>`.
+//! [`spawn_updater_with_debounce`] thread, instead of the legacy
+//! `Arc<RwLock<Indexer>>`.
 //! Every request handler only ever `Arc`-clones the current snapshot
 //! (`SharedSnapshot::get`) — no parsing, no graph rebuild, ever happens under
 //! a request-facing lock; the updater thread owns all of that work and
@@ -19,6 +20,11 @@
 //! status) and dispatch to `lsp::custom`'s implementations (relocated
 //! verbatim from legacy `src/handlers.rs` at Task 17's legacy deletion —
 //! Task 15's cutover already pointed here unchanged before the move).
+//! `al-call-hierarchy/health` sums every root's published snapshot into a
+//! readiness report (`lsp::custom::health_status`);
+//! `al-call-hierarchy/reindex` queues a full rebuild on every root's updater
+//! ([`ChangeEvent::Reindex`]), or with `reconcile: true` just the files added
+//! or removed behind the watcher's back ([`ChangeEvent::Reconcile`]).
 //!
 //! Diagnostics follow "recompute-diff-publish-clear": every snapshot swap
 //! (including the very first, batch-built one) runs `lsp::diagnostics::
@@ -76,37 +82,50 @@
 //! `docs/OUTSTANDING.md` since it has no correctness impact (browsing a
 //! dependency's symbols, not call-hierarchy identity).
 
+use al_syntax::RetainedParse;
 use anyhow::{Context, Result};
 use log::{debug, info, warn};
 use lsp_server::{Connection, Message, Notification, Request, Response};
 use lsp_types::{
     CallHierarchyIncomingCallsParams, CallHierarchyItem, CallHierarchyOutgoingCallsParams,
     CallHierarchyPrepareParams, CodeLensOptions, CodeLensParams, Diagnostic,
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
     DidSaveTextDocumentParams, InitializeParams, InitializeResult, PositionEncodingKind,
-    PublishDiagnosticsParams, ServerCapabilities, Uri,
+    PublishDiagnosticsParams, ServerCapabilities, Uri, WorkspaceSymbolParams,
 };
 use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::net::{TcpListener, TcpStream};
 use std::path::{Path, PathBuf};
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
 use std::time::Duration;
 
-use crate::config::DiagnosticConfig;
+use crate::config::{DiagnosticConfig, WorkspaceSymbolFileConfig};
 use crate::lsp::custom::{
     DependencyDocumentSymbol, DependencyDocumentSymbolParams, EventPublishersInFileParams,
-    EventReferenceAtPositionParams, SymbolPropertiesParams, action_properties,
+    EventReferenceAtPositionParams, ReindexParams, SymbolPropertiesParams, action_properties,
     dependency_document_symbol, event_publishers_in_file, event_reference_at_position,
-    field_properties,
+    field_properties, health_status, initial_build_health,
+};
+use crate::lsp::diagnostics::{
+    DIAGNOSTIC_RULES, DiagnosticsState, compute_all, compute_for_files, rung1_cover,
 };
-use crate::lsp::diagnostics::{DiagnosticsState, compute_all, compute_for_files, rung1_cover};
 use crate::lsp::encoding::{PositionEncoding, negotiate};
-use crate::lsp::handlers::{ItemData, incoming, outgoing, prepare};
+use crate::lsp::handlers::{
+    ItemData, content_change_edit, incoming, outgoing, prepare, resolve_virtual_path,
+    unique_name_calls,
+};
+use crate::lsp::interfaces::{InterfaceHierarchyParams, interface_hierarchy};
 use crate::lsp::lens::code_lenses;
 use crate::lsp::snapshot::LspSnapshot;
-use crate::lsp::updater::{ChangeEvent, Rung1Delta, SharedSnapshot, SwapScope, spawn_updater};
+use crate::lsp::symbols::{merge_ranked, workspace_symbols};
+use crate::lsp::updater::{
+    ChangeEvent, Rung1Delta, SharedSnapshot, SwapScope, spawn_updater_with_debounce,
+};
 use crate::protocol::uri_to_path;
-use crate::watcher::{AlFileWatcher, FileChange};
+use crate::watcher::{AlFileWatcher, FileChange, WatcherConfig, WatcherStats};
 
 /// Everything the server needs once a valid workspace snapshot exists for
 /// ONE root. Wrapped in `Option` by [`RootState`] — `None` there means "no
@@ -124,6 +143,37 @@ struct ServerState {
     updater_handle: JoinHandle<()>,
     encoding: PositionEncoding,
     config: DiagnosticConfig,
+    /// `workspace/symbol` result cap for this root
+    /// ([`WorkspaceSymbolFileConfig::max_results`]).
+    symbol_limit: usize,
+    /// What this root last published — shared with the updater's on-swap
+    /// publish so `didOpen`/`didClose` diff against the same state.
+    diag_state: Arc<Mutex<DiagnosticsState>>,
+    /// `virtual_path`s the client currently has open. Only consulted when
+    /// `config.open_files_only` is set (see [`diagnostics_scope`]), and only
+    /// changed while `diag_state` is locked.
+    open_files: Arc<Mutex<BTreeSet<String>>>,
+    /// The client's open `.al` documents, keyed by uri (see
+    /// [`OpenDocument`]).
+    documents: Mutex<HashMap<String, OpenDocument>>,
+    /// Sends one message to the client (a `connection.sender` clone).
+    send: Arc<dyn Fn(Message) + Send + Sync>,
+    /// This root's watcher settings, resolved once at build (the updater
+    /// already runs with its debounce window).
+    watcher_config: WatcherConfig,
+    /// Event/change/reindex totals for `al-call-hierarchy/health`, fed by
+    /// this root's file watcher and updater.
+    watcher_stats: Arc<WatcherStats>,
+}
+
+/// An open document's buffer plus the parse tree retained for it, so each
+/// `didChange` reparses incrementally instead of from scratch.
+struct OpenDocument {
+    text: String,
+    parse: RetainedParse,
+    /// Set by the first `didChange`: the index then holds this buffer rather
+    /// than the file on disk, so `didClose` must reindex from disk.
+    edited: bool,
 }
 
 /// One configured workspace root plus whatever `ServerState` its OWN
@@ -148,6 +198,10 @@ struct RootState {
 /// didChangeWorkspaceFolders` is not implemented (see [`handle_notification`]).
 struct Workspace {
     roots: Vec<RootState>,
+    /// How many roots have a running file watcher (reported by
+    /// `al-call-hierarchy/health`); set once by [`run_server`] after the
+    /// watchers start.
+    watched_roots: usize,
 }
 
 impl Workspace {
@@ -161,12 +215,115 @@ impl Workspace {
     }
 }
 
-/// Run the LSP server
-pub fn run_server(no_watcher: bool, no_telemetry: bool) -> Result<()> {
+/// Joins a transport's I/O threads once the main loop has returned.
+type IoJoin = Box<dyn FnOnce() -> std::io::Result<()> + Send>;
+
+/// Run the LSP server — over stdio, or, with `socket` set, over the first
+/// TCP client that connects to that address (`--socket`). The listener is
+/// bound here and the bound address printed to stderr, so `127.0.0.1:0`
+/// serves on a free port the client can read back. TCP only: no Unix
+/// domain sockets.
+pub fn run_server(
+    no_watcher: bool,
+    no_telemetry: bool,
+    watch_debounce_ms: Option<u64>,
+    socket: Option<&str>,
+) -> Result<()> {
     info!("Starting AL Call Hierarchy LSP server (program-engine backend)");
 
-    let (connection, io_threads) = Connection::stdio();
+    match socket {
+        Some(addr) => {
+            let listener = TcpListener::bind(addr)
+                .with_context(|| format!("--socket: cannot listen on {addr}"))?;
+            let bound = listener
+                .local_addr()
+                .with_context(|| format!("--socket: cannot read the address bound for {addr}"))?;
+            eprintln!("al-call-hierarchy: listening for an LSP client on {bound}");
+            serve_socket(&listener, no_watcher, no_telemetry, watch_debounce_ms)
+        }
+        None => {
+            let (connection, io_threads) = Connection::stdio();
+            serve(
+                connection,
+                Box::new(move || io_threads.join()),
+                no_watcher,
+                no_telemetry,
+                watch_debounce_ms,
+            )
+        }
+    }
+}
+
+/// Serve the first client that connects to the already-bound `listener`.
+fn serve_socket(
+    listener: &TcpListener,
+    no_watcher: bool,
+    no_telemetry: bool,
+    watch_debounce_ms: Option<u64>,
+) -> Result<()> {
+    let (stream, peer) = listener
+        .accept()
+        .context("--socket: accepting a client failed")?;
+    info!("LSP client connected from {peer}");
+    let (connection, io_threads) = socket_connection(stream)?;
+    serve(
+        connection,
+        io_threads,
+        no_watcher,
+        no_telemetry,
+        watch_debounce_ms,
+    )
+}
+
+/// A [`Connection`] over an accepted TCP stream. `lsp_server` only builds
+/// its socket transport inside `Connection::listen`, which binds the
+/// address itself, so this pumps an in-memory connection instead — with the
+/// same thread shape: the reader stops after `exit`, the writer once every
+/// sender clone is dropped.
+fn socket_connection(stream: TcpStream) -> Result<(Connection, IoJoin)> {
+    let read_half = stream
+        .try_clone()
+        .context("--socket: cannot split the client stream")?;
+    let (connection, pump) = Connection::memory();
+    let Connection {
+        sender: to_server,
+        receiver: from_server,
+    } = pump;
+    let reader = thread::spawn(move || -> std::io::Result<()> {
+        let mut input = std::io::BufReader::new(read_half);
+        while let Some(msg) = Message::read(&mut input)? {
+            let is_exit = matches!(&msg, Message::Notification(n) if n.method == "exit");
+            if to_server.send(msg).is_err() || is_exit {
+                break;
+            }
+        }
+        Ok(())
+    });
+    let writer = thread::spawn(move || -> std::io::Result<()> {
+        let mut output = stream;
+        for msg in from_server {
+            msg.write(&mut output)?;
+        }
+        Ok(())
+    });
+    let join: IoJoin = Box::new(move || {
+        let panicked = |_| std::io::Error::other("--socket I/O thread panicked");
+        let read = reader.join().map_err(panicked)?;
+        let write = writer.join().map_err(panicked)?;
+        read.and(write)
+    });
+    Ok((connection, join))
+}
 
+/// The session proper, over an initialized-but-not-yet-handshaken
+/// `connection`; `io_threads` joins its transport on the way out.
+fn serve(
+    connection: Connection,
+    io_threads: IoJoin,
+    no_watcher: bool,
+    no_telemetry: bool,
+    watch_debounce_ms: Option<u64>,
+) -> Result<()> {
     // Initialize
     let (id, params) = connection.initialize_start()?;
     let init_params: InitializeParams = serde_json::from_value(params)?;
@@ -207,10 +364,11 @@ pub fn run_server(no_watcher: bool, no_telemetry: bool) -> Result<()> {
         code_lens_provider: Some(CodeLensOptions {
             resolve_provider: Some(false),
         }),
+        workspace_symbol_provider: Some(lsp_types::OneOf::Left(true)),
         text_document_sync: Some(lsp_types::TextDocumentSyncCapability::Options(
             lsp_types::TextDocumentSyncOptions {
                 open_close: Some(true),
-                change: Some(lsp_types::TextDocumentSyncKind::NONE),
+                change: Some(lsp_types::TextDocumentSyncKind::INCREMENTAL),
                 will_save: None,
                 will_save_wait_until: None,
                 save: Some(lsp_types::TextDocumentSyncSaveOptions::SaveOptions(
@@ -239,8 +397,15 @@ pub fn run_server(no_watcher: bool, no_telemetry: bool) -> Result<()> {
     info!("Server initialized");
 
     // Build one `RootState` per configured root — see `build_workspace`'s
-    // doc for the per-root fail-loud-but-isolated build semantics.
-    let workspace = build_workspace(&roots, position_encoding, &connection);
+    // doc for the per-root fail-loud-but-isolated build semantics. Health
+    // requests sent meanwhile are answered `indexing`; everything else
+    // waits for the main loop.
+    let (mut workspace, deferred) = build_workspace_answering_health(
+        &roots,
+        position_encoding,
+        watch_debounce_ms.map(Duration::from_millis),
+        &connection,
+    );
 
     #[cfg(feature = "telemetry")]
     {
@@ -276,13 +441,18 @@ pub fn run_server(no_watcher: bool, no_telemetry: bool) -> Result<()> {
         info!("File watcher disabled (--no-watcher). Using LSP notifications for changes.");
         false
     } else {
-        let mut any_started = false;
         for root_state in &workspace.roots {
             if let Some(st) = &root_state.state {
-                start_file_watcher(st.tx.clone(), root_state.root.clone());
-                any_started = true;
+                start_file_watcher(
+                    st.tx.clone(),
+                    root_state.root.clone(),
+                    st.watcher_config,
+                    Arc::clone(&st.watcher_stats),
+                );
+                workspace.watched_roots += 1;
             }
         }
+        let any_started = workspace.watched_roots > 0;
         if !any_started {
             info!(
                 "File watcher not started (no active workspace snapshot in any configured root)."
@@ -306,7 +476,7 @@ pub fn run_server(no_watcher: bool, no_telemetry: bool) -> Result<()> {
     // passed by value). This bug predates this cutover (the legacy
     // `main_loop(connection: &Connection, ...)` had the identical shape) but
     // was never exercised end to end until this task's own verification.
-    main_loop(connection, &workspace)?;
+    main_loop(connection, &workspace, deferred)?;
 
     // Dropping each root's `tx` is a best-effort shutdown signal to ITS
     // updater thread — but we deliberately do NOT `st.updater_handle.join()`
@@ -343,7 +513,7 @@ pub fn run_server(no_watcher: bool, no_telemetry: bool) -> Result<()> {
     // giving up after a short grace period loses nothing.
     let (io_done_tx, io_done_rx) = mpsc::channel();
     thread::spawn(move || {
-        let _ = io_done_tx.send(io_threads.join());
+        let _ = io_done_tx.send(io_threads());
     });
     match io_done_rx.recv_timeout(Duration::from_millis(500)) {
         Ok(Ok(())) => {}
@@ -449,13 +619,15 @@ fn primary_workspace_root(params: &InitializeParams) -> Option<PathBuf> {
 }
 
 /// Build the initial snapshot, publish its diagnostics, and spawn the
-/// background updater. Returns `None` when [`LspSnapshot::build_full_with_parsed`]
+/// background updater, debouncing changes over `watcher_config.debounce`.
+/// Returns `None` when [`LspSnapshot::build_full_with_parsed`]
 /// fails (see the module doc's "no valid workspace" section) — the caller
 /// logs the reason.
 fn build_server_state(
     workspace_root: &Path,
     encoding: PositionEncoding,
     config: DiagnosticConfig,
+    watcher_config: WatcherConfig,
     connection: &Connection,
 ) -> Option<ServerState> {
     let (initial, workspace) = LspSnapshot::build_full_with_parsed(workspace_root)?;
@@ -463,6 +635,7 @@ fn build_server_state(
     let shared = Arc::new(SharedSnapshot::new(Arc::clone(&initial)));
 
     let diag_state = Arc::new(Mutex::new(DiagnosticsState::new()));
+    let open_files = Arc::new(Mutex::new(BTreeSet::new()));
     {
         let sender = connection.sender.clone();
         publish_full_diagnostics_diff(
@@ -475,6 +648,7 @@ fn build_server_state(
             &initial,
             encoding,
             &config,
+            &open_files,
         );
     }
 
@@ -482,12 +656,17 @@ fn build_server_state(
     let diag_state_bg = Arc::clone(&diag_state);
     let sender_bg = connection.sender.clone();
     let config_bg = config.clone();
-    let updater_handle = spawn_updater(
+    let open_files_bg = Arc::clone(&open_files);
+    let watcher_stats = Arc::new(WatcherStats::default());
+    let watcher_stats_bg = Arc::clone(&watcher_stats);
+    let updater_handle = spawn_updater_with_debounce(
         Arc::clone(&shared),
         rx,
         workspace_root.to_path_buf(),
         workspace,
+        watcher_config.debounce,
         move |new, scope| {
+            watcher_stats_bg.record_reindex();
             let sender = sender_bg.clone();
             let send = move |m| {
                 if let Err(e) = sender.send(m) {
@@ -496,7 +675,14 @@ fn build_server_state(
             };
             match scope {
                 SwapScope::Full => {
-                    publish_full_diagnostics_diff(send, &diag_state_bg, new, encoding, &config_bg);
+                    publish_full_diagnostics_diff(
+                        send,
+                        &diag_state_bg,
+                        new,
+                        encoding,
+                        &config_bg,
+                        &open_files_bg,
+                    );
                 }
                 SwapScope::Rung1(delta) => {
                     publish_rung1_diagnostics_diff(
@@ -506,6 +692,7 @@ fn build_server_state(
                         encoding,
                         &config_bg,
                         delta,
+                        &open_files_bg,
                     );
                 }
             }
@@ -518,6 +705,20 @@ fn build_server_state(
         updater_handle,
         encoding,
         config,
+        symbol_limit: WorkspaceSymbolFileConfig::load_merged(workspace_root).max_results(),
+        diag_state,
+        open_files,
+        documents: Mutex::new(HashMap::new()),
+        send: {
+            let sender = connection.sender.clone();
+            Arc::new(move |m| {
+                if let Err(e) = sender.send(m) {
+                    warn!("Failed to publish diagnostics: {}", e);
+                }
+            })
+        },
+        watcher_config,
+        watcher_stats,
     })
 }
 
@@ -537,9 +738,13 @@ fn build_server_state(
 /// (e.g. a test) that hands this function a raw, un-normalized path: EVERY
 /// `RootState.root` this function produces must be normalized, unconditionally,
 /// because `route_uri`/`route_item` compare against it directly.
+///
+/// `debounce` (`--watch-debounce-ms`) overrides every root's configured
+/// `watcher.debounceMs`.
 fn build_workspace(
     roots: &[PathBuf],
     encoding: PositionEncoding,
+    debounce: Option<Duration>,
     connection: &Connection,
 ) -> Workspace {
     if roots.is_empty() {
@@ -553,7 +758,11 @@ fn build_workspace(
         .map(|raw_root| {
             let root = crate::protocol::normalize_path(raw_root);
             let config = DiagnosticConfig::load(&root);
-            let state = build_server_state(&root, encoding, config, connection);
+            let mut watcher_config = WatcherConfig::load(&root);
+            if let Some(debounce) = debounce {
+                watcher_config.debounce = debounce;
+            }
+            let state = build_server_state(&root, encoding, config, watcher_config, connection);
             if state.is_none() {
                 warn!(
                     "Failed to build the program snapshot for workspace root {} \
@@ -567,7 +776,59 @@ fn build_workspace(
             RootState { root, state }
         })
         .collect();
-    Workspace { roots }
+    Workspace {
+        roots,
+        watched_roots: 0,
+    }
+}
+
+/// [`build_workspace`] on a scoped thread while this one answers every
+/// `al-call-hierarchy/health` request with [`initial_build_health`], so a
+/// client polling readiness sees `indexing` until the first snapshots are
+/// published rather than a reply queued behind the build. Any other message
+/// that arrives meanwhile is returned, in order, for [`main_loop`] to handle
+/// first; a `shutdown` request stops the answering so the `exit` after it
+/// stays on the connection for [`Connection::handle_shutdown`].
+fn build_workspace_answering_health(
+    roots: &[PathBuf],
+    encoding: PositionEncoding,
+    debounce: Option<Duration>,
+    connection: &Connection,
+) -> (Workspace, Vec<Message>) {
+    thread::scope(|s| {
+        let build = s.spawn(|| build_workspace(roots, encoding, debounce, connection));
+        let mut deferred = Vec::new();
+        while !roots.is_empty() {
+            match connection.receiver.recv_timeout(Duration::from_millis(50)) {
+                Ok(Message::Request(req)) if req.method == "al-call-hierarchy/health" => {
+                    let health = serde_json::to_value(initial_build_health())
+                        .expect("HealthStatus always serializes");
+                    if let Err(e) = connection
+                        .sender
+                        .send(Message::Response(Response::new_ok(req.id, health)))
+                    {
+                        warn!("Failed to answer health during the first build: {}", e);
+                    }
+                }
+                Ok(msg) => {
+                    let shutdown = matches!(&msg, Message::Request(r) if r.method == "shutdown");
+                    deferred.push(msg);
+                    if shutdown {
+                        break;
+                    }
+                }
+                Err(e) if e.is_disconnected() => break,
+                Err(_) => {}
+            }
+            if build.is_finished() {
+                break;
+            }
+        }
+        let workspace = build
+            .join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+        (workspace, deferred)
+    })
 }
 
 /// Recompute-diff-publish: run [`compute_all`] over `snap`, diff it through
@@ -577,20 +838,30 @@ fn build_workspace(
 /// swap — see [`publish_rung1_diagnostics_diff`] for the rung-1-scoped
 /// counterpart, and [`publish_changed`] for the shared "send what changed"
 /// tail both funnel through so the two recompute scopes can never drift in
-/// how they're published.
+/// how they're published. Under `diagnostics.openFilesOnly` the recompute
+/// is restricted to `open_files` ([`diagnostics_scope`]); the full diff then
+/// clears anything published outside them.
+///
+/// `diag_state` stays locked from reading the open set through the publish,
+/// so a concurrent [`update_open_file`] is ordered entirely before or after
+/// this pass — a `didClose` can never be followed by a stale republish of
+/// the file it just cleared.
 fn publish_full_diagnostics_diff(
     send: impl Fn(Message),
     diag_state: &Mutex<DiagnosticsState>,
     snap: &LspSnapshot,
     enc: PositionEncoding,
     cfg: &DiagnosticConfig,
+    open_files: &Mutex<BTreeSet<String>>,
 ) {
-    let all = compute_all(snap, enc, cfg);
-    let changed = diag_state
+    let mut diag_state = diag_state
         .lock()
-        .unwrap_or_else(std::sync::PoisonError::into_inner)
-        .diff(all);
-    publish_changed(send, changed);
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let all = match diagnostics_scope(cfg, open_files) {
+        Some(files) => compute_for_files(snap, enc, cfg, &files),
+        None => compute_all(snap, enc, cfg),
+    };
+    publish_changed(send, diag_state.diff(all));
 }
 
 /// The rung-1-scoped counterpart of [`publish_full_diagnostics_diff`]
@@ -598,7 +869,9 @@ fn publish_full_diagnostics_diff(
 /// recompute cover (`rung1_cover`) via `compute_for_files`, and diffs it
 /// through `DiagnosticsState::diff_partial` — never a full workspace
 /// recompute. See `rung1_cover`'s own doc for why this cover is a complete
-/// substitute for `compute_all` on a rung-1 swap.
+/// substitute for `compute_all` on a rung-1 swap. Under
+/// `diagnostics.openFilesOnly` the cover is narrowed to `open_files`, with
+/// `diag_state` held across the pass as in the full counterpart.
 fn publish_rung1_diagnostics_diff(
     send: impl Fn(Message),
     diag_state: &Mutex<DiagnosticsState>,
@@ -606,14 +879,72 @@ fn publish_rung1_diagnostics_diff(
     enc: PositionEncoding,
     cfg: &DiagnosticConfig,
     delta: &Rung1Delta,
+    open_files: &Mutex<BTreeSet<String>>,
 ) {
-    let cover = rung1_cover(snap, delta);
+    let mut diag_state = diag_state
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let mut cover = rung1_cover(snap, delta);
+    if let Some(files) = diagnostics_scope(cfg, open_files) {
+        cover.retain(|vp| files.contains(vp));
+    }
     let touched = compute_for_files(snap, enc, cfg, &cover);
-    let changed = diag_state
+    publish_changed(send, diag_state.diff_partial(touched));
+}
+
+/// The files diagnostics are computed for: every workspace file (`None`),
+/// or — with `diagnostics.openFilesOnly` — a copy of the currently open
+/// set, so nothing is published for a file until the client opens it.
+/// Callers hold the `diag_state` lock across the read and the publish that
+/// follows it; the open set is only changed under that same lock.
+fn diagnostics_scope(
+    cfg: &DiagnosticConfig,
+    open_files: &Mutex<BTreeSet<String>>,
+) -> Option<BTreeSet<String>> {
+    cfg.open_files_only.then(|| {
+        open_files
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    })
+}
+
+/// `didOpen`/`didClose` under `diagnostics.openFilesOnly`: add `uri`'s file
+/// to (or drop it from) the open set, then publish its diagnostics — or
+/// clear them on close. A no-op for a uri outside the snapshot. Runs
+/// under the `diag_state` lock, serialized against the updater's publishes.
+fn update_open_file(state: &ServerState, uri: &str, open: bool) {
+    let mut diag_state = state
+        .diag_state
         .lock()
-        .unwrap_or_else(std::sync::PoisonError::into_inner)
-        .diff_partial(touched);
-    publish_changed(send, changed);
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    // Read under the lock: a swap published after this point recomputes
+    // with the updated open set, one published before it is not undone.
+    let snap = state.shared.get();
+    let Some(virtual_path) = resolve_virtual_path(&snap, uri) else {
+        return;
+    };
+    {
+        let mut open_files = state
+            .open_files
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if open {
+            open_files.insert(virtual_path.clone());
+        } else {
+            open_files.remove(&virtual_path);
+        }
+    }
+    let mut touched = compute_for_files(
+        &snap,
+        state.encoding,
+        &state.config,
+        &BTreeSet::from([virtual_path]),
+    );
+    if !open {
+        touched.values_mut().for_each(Vec::clear);
+    }
+    publish_changed(|m| (state.send)(m), diag_state.diff_partial(touched));
 }
 
 /// Send every changed `(uri, diagnostics)` pair as a
@@ -648,9 +979,14 @@ fn publish_changed(send: impl Fn(Message), changed: Vec<(String, Vec<Diagnostic>
 /// by the watcher's own notification of that same write no longer causes two
 /// separate rebuilds (legacy's didSave + watcher paths each reindexed
 /// independently).
-fn start_file_watcher(tx: mpsc::Sender<ChangeEvent>, workspace_root: PathBuf) {
+fn start_file_watcher(
+    tx: mpsc::Sender<ChangeEvent>,
+    workspace_root: PathBuf,
+    config: WatcherConfig,
+    stats: Arc<WatcherStats>,
+) {
     thread::spawn(move || {
-        let watcher = match AlFileWatcher::new(&workspace_root) {
+        let watcher = match AlFileWatcher::with_stats(&workspace_root, config, stats) {
             Ok(w) => w,
             Err(e) => {
                 warn!(
@@ -716,10 +1052,11 @@ fn path_is_dependency_source(path: &Path) -> bool {
 }
 
 /// Main message processing loop. Takes `connection` BY VALUE — see
-/// `run_server`'s call site for why that (not `&Connection`) is required for
-/// a clean process exit.
-fn main_loop(connection: Connection, workspace: &Workspace) -> Result<()> {
-    for msg in &connection.receiver {
+/// `serve`'s call site for why that (not `&Connection`) is required for
+/// a clean process exit. Handles the messages `deferred` during the first
+/// build ([`build_workspace_answering_health`]) before reading on.
+fn main_loop(connection: Connection, workspace: &Workspace, deferred: Vec<Message>) -> Result<()> {
+    for msg in deferred.into_iter().chain(&connection.receiver) {
         match msg {
             Message::Request(req) => {
                 if connection.handle_shutdown(&req)? {
@@ -950,8 +1287,9 @@ fn item_data(item: &CallHierarchyItem) -> Result<ItemData> {
 /// (no valid workspace snapshot there — see the module doc) both degrade to
 /// an empty result, exactly the single-root "no valid workspace" fail path
 /// generalized per root. `fieldProperties`/`actionProperties`/
-/// `telemetryStatus` are graph-independent and answer unconditionally, with
-/// no routing at all.
+/// `telemetryStatus`/`diagnosticRules` are graph-independent and answer unconditionally, with
+/// no routing at all. `workspace/symbol` carries no uri and fans out to
+/// every root instead.
 fn dispatch_request(req: &Request, workspace: &Workspace) -> Result<Value> {
     debug!("Request: {} - {:?}", req.method, req.params);
 
@@ -1008,6 +1346,9 @@ fn dispatch_request(req: &Request, workspace: &Workspace) -> Result<Value> {
             let snap = state.shared.get();
             let data = item_data(&params.item)?;
             let mut result = outgoing(&snap, state.encoding, &data);
+            if state.config.unique_name_fallback {
+                result.extend(unique_name_calls(&snap, state.encoding, &data));
+            }
             for call in &mut result {
                 tag_item_root_gated(workspace, &root_state.root, &mut call.to);
             }
@@ -1026,6 +1367,39 @@ fn dispatch_request(req: &Request, workspace: &Workspace) -> Result<Value> {
             let result = code_lenses(&snap, state.encoding, uri, &state.config);
             Ok(serde_json::to_value(result)?)
         }
+        "workspace/symbol" => {
+            // Not document-scoped: every root with a snapshot answers, each
+            // capped by its own limit, then the union is re-ranked and capped
+            // by the largest of them.
+            let params: WorkspaceSymbolParams = serde_json::from_value(req.params.clone())?;
+            let mut symbols = Vec::new();
+            let mut limit = 0;
+            for state in workspace.roots.iter().filter_map(|r| r.state.as_ref()) {
+                let snap = state.shared.get();
+                symbols.extend(workspace_symbols(
+                    &snap,
+                    state.encoding,
+                    &params.query,
+                    state.symbol_limit,
+                ));
+                limit = limit.max(state.symbol_limit);
+            }
+            if workspace.is_multi_root() {
+                merge_ranked(&mut symbols, &params.query, limit);
+            }
+            Ok(serde_json::to_value(symbols)?)
+        }
+        "al-call-hierarchy/interfaceHierarchy" => {
+            // Names an object rather than a document: the first root whose
+            // workspace app resolves it answers.
+            let params: InterfaceHierarchyParams = serde_json::from_value(req.params.clone())?;
+            let result = workspace
+                .roots
+                .iter()
+                .filter_map(|r| r.state.as_ref())
+                .find_map(|state| interface_hierarchy(&state.shared.get(), &params));
+            Ok(serde_json::to_value(result)?)
+        }
         "al-call-hierarchy/fieldProperties" => {
             let params: SymbolPropertiesParams = serde_json::from_value(req.params.clone())?;
             Ok(serde_json::to_value(field_properties(params)?)?)
@@ -1037,6 +1411,46 @@ fn dispatch_request(req: &Request, workspace: &Workspace) -> Result<Value> {
         "al-call-hierarchy/telemetryStatus" => {
             Ok(serde_json::to_value(crate::telemetry::status())?)
         }
+        "al-call-hierarchy/diagnosticRules" => Ok(serde_json::to_value(DIAGNOSTIC_RULES)?),
+        "al-call-hierarchy/health" => {
+            let roots = workspace
+                .roots
+                .iter()
+                .map(|r| r.state.as_ref().map(|st| st.shared.as_ref()));
+            let watcher = workspace
+                .roots
+                .iter()
+                .filter_map(|r| r.state.as_ref())
+                .map(|st| st.watcher_stats.counters())
+                .sum();
+            Ok(serde_json::to_value(health_status(
+                roots,
+                workspace.watched_roots,
+                watcher,
+            ))?)
+        }
+        "al-call-hierarchy/reindex" => {
+            let params: ReindexParams = if req.params.is_null() {
+                ReindexParams::default()
+            } else {
+                serde_json::from_value(req.params.clone())?
+            };
+            let event = if params.reconcile {
+                ChangeEvent::Reconcile
+            } else {
+                ChangeEvent::Reindex
+            };
+            // Queued, not awaited: each root's updater rebuilds from disk and
+            // publishes by atomic swap (republishing diagnostics), so a request
+            // already in flight keeps answering from the snapshot it cloned.
+            let queued = workspace
+                .roots
+                .iter()
+                .filter_map(|r| r.state.as_ref())
+                .filter(|st| st.tx.send(event.clone()).is_ok())
+                .count();
+            Ok(serde_json::json!({ "roots": queued }))
+        }
         "al-call-hierarchy/dependencyDocumentSymbol" => {
             let params: DependencyDocumentSymbolParams =
                 serde_json::from_value(req.params.clone())?;
@@ -1076,13 +1490,17 @@ fn dispatch_request(req: &Request, workspace: &Workspace) -> Result<Value> {
     }
 }
 
-/// Handle an LSP notification. Only `didSave` does anything: it routes the
-/// saved document's uri to its owning root ([`route_uri_or_warn`]) and
-/// queues a [`ChangeEvent::FileSaved`] onto the SAME channel that root's
-/// file watcher feeds (see [`start_file_watcher`]'s doc) —
-/// `didOpen`/`didClose`/`didChange` are no-ops, mirroring legacy
-/// (`text_document_sync.change` is negotiated as `NONE`, so the server never
-/// relies on editor-buffer content anyway). `workspace/
+/// Handle an LSP notification. `didSave` routes the saved document's uri to
+/// its owning root ([`route_uri_or_warn`]) and queues a
+/// [`ChangeEvent::FileSaved`] onto the SAME channel that root's file watcher
+/// feeds (see [`start_file_watcher`]'s doc). Text sync is negotiated as
+/// `INCREMENTAL`: `didOpen` retains a parse of the buffer
+/// ([`open_document`]), each `didChange` applies its edits to that tree and
+/// queues the incrementally reparsed buffer as a
+/// [`ChangeEvent::FileEdited`] ([`change_document`]), and `didClose`
+/// reindexes an edited file from disk, discarding unsaved changes
+/// ([`close_document`]). `didOpen`/`didClose` also track the open set for a
+/// root with `diagnostics.openFilesOnly` (see [`update_open_file`]). `workspace/
 /// didChangeWorkspaceFolders` is NOT implemented (see the module doc's
 /// multi-root section for the real blocker) — logged loudly rather than
 /// silently swallowed by the catch-all arm, so a dynamic add/remove is never
@@ -1118,9 +1536,48 @@ fn handle_notification(workspace: &Workspace, notif: &lsp_server::Notification)
                 }
             }
         }
-        "textDocument/didClose" => {}
-        "textDocument/didOpen" => {}
-        "textDocument/didChange" => {}
+        "textDocument/didOpen" => {
+            let Ok(params) =
+                serde_json::from_value::<DidOpenTextDocumentParams>(notif.params.clone())
+            else {
+                return;
+            };
+            let uri = &params.text_document.uri;
+            let Some(state) = document_root(workspace, &notif.method, uri) else {
+                return;
+            };
+            open_document(state, uri, params.text_document.text);
+            if state.config.open_files_only {
+                update_open_file(state, uri.as_str(), true);
+            }
+        }
+        "textDocument/didChange" => {
+            let Ok(params) =
+                serde_json::from_value::<DidChangeTextDocumentParams>(notif.params.clone())
+            else {
+                return;
+            };
+            let Some(state) = document_root(workspace, &notif.method, &params.text_document.uri)
+            else {
+                return;
+            };
+            change_document(state, &params);
+        }
+        "textDocument/didClose" => {
+            let Ok(params) =
+                serde_json::from_value::<DidCloseTextDocumentParams>(notif.params.clone())
+            else {
+                return;
+            };
+            let uri = &params.text_document.uri;
+            let Some(state) = document_root(workspace, &notif.method, uri) else {
+                return;
+            };
+            close_document(state, uri);
+            if state.config.open_files_only {
+                update_open_file(state, uri.as_str(), false);
+            }
+        }
         "workspace/didChangeWorkspaceFolders" => {
             warn!(
                 "workspace/didChangeWorkspaceFolders received but NOT implemented — \
@@ -1135,6 +1592,91 @@ fn handle_notification(workspace: &Workspace, notif: &lsp_server::Notification)
     }
 }
 
+/// The built root owning `uri`, for a document-sync notification — `None`
+/// for anything but a `.al` file (no other document is indexed) or a uri
+/// outside every root.
+fn document_root<'a>(workspace: &'a Workspace, method: &str, uri: &Uri) -> Option<&'a ServerState> {
+    let path = uri_to_path(uri)?;
+    if !path
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("al"))
+    {
+        return None;
+    }
+    route_uri_or_warn(workspace, method, uri.as_str())?
+        .state
+        .as_ref()
+}
+
+/// `didOpen`: retain a parse of the opened buffer for later `didChange`s.
+/// The buffer matches the file on disk at open, so nothing is reindexed.
+fn open_document(state: &ServerState, uri: &Uri, text: String) {
+    let (_, parse) = al_syntax::parse_retained(&text);
+    state
+        .documents
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert(
+            uri.as_str().to_string(),
+            OpenDocument {
+                text,
+                parse,
+                edited: false,
+            },
+        );
+}
+
+/// `didChange`: apply each content change, in order, to the document's
+/// buffer and retained tree, reparse incrementally and queue the result for
+/// the updater. A change to a document that was never opened is dropped.
+fn change_document(state: &ServerState, params: &DidChangeTextDocumentParams) {
+    let uri = &params.text_document.uri;
+    let Some(path) = uri_to_path(uri) else {
+        return;
+    };
+    let (text, file) = {
+        let mut documents = state
+            .documents
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let Some(doc) = documents.get_mut(uri.as_str()) else {
+            warn!("didChange for {} without a didOpen; ignoring", uri.as_str());
+            return;
+        };
+        for change in &params.content_changes {
+            let edit = content_change_edit(&doc.text, change, state.encoding);
+            doc.parse.edit(&mut doc.text, &edit);
+        }
+        doc.edited = true;
+        let file = doc.parse.reparse(&doc.text);
+        (Arc::<str>::from(doc.text.as_str()), Arc::new(file))
+    };
+    debug!("Queueing edit event for {}", path.display());
+    if state
+        .tx
+        .send(ChangeEvent::FileEdited { path, text, file })
+        .is_err()
+    {
+        warn!("Updater channel closed; dropping didChange event");
+    }
+}
+
+/// `didClose`: forget the document. If it was edited, the index holds its
+/// last buffer, so reindex the file from disk to discard unsaved changes.
+fn close_document(state: &ServerState, uri: &Uri) {
+    let doc = state
+        .documents
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .remove(uri.as_str());
+    if doc.is_some_and(|d| d.edited)
+        && let Some(path) = uri_to_path(uri)
+        && state.tx.send(ChangeEvent::FileSaved(path)).is_err()
+    {
+        warn!("Updater channel closed; dropping didClose event");
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -1235,6 +1777,7 @@ mod tests {
             dir.path(),
             PositionEncoding::Utf8,
             DiagnosticConfig::default(),
+            WatcherConfig::default(),
             &server_conn,
         )
         .expect("build_server_state must succeed for a valid fixture workspace");
@@ -1253,6 +1796,7 @@ mod tests {
                 root,
                 state: Some(state),
             }],
+            watched_roots: 0,
         };
         let state = workspace.roots[0]
             .state
@@ -1424,6 +1968,454 @@ mod tests {
             .expect("updater thread must exit cleanly");
     }
 
+    /// The next `publishDiagnostics` for `uri` within `timeout`, if any.
+    fn next_diagnostics_for(
+        client_conn: &Connection,
+        uri: &str,
+        timeout: Duration,
+    ) -> Option<Vec<Diagnostic>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let msg = client_conn.receiver.recv_timeout(remaining).ok()?;
+            if let Message::Notification(n) = msg
+                && n.method == "textDocument/publishDiagnostics"
+            {
+                let params: PublishDiagnosticsParams =
+                    serde_json::from_value(n.params).expect("valid publishDiagnostics params");
+                if params.uri.as_str() == uri {
+                    return Some(params.diagnostics);
+                }
+            }
+        }
+    }
+
+    /// With `diagnostics.openFilesOnly`, nothing is published at startup;
+    /// opening a file publishes its diagnostics and closing it clears them.
+    #[test]
+    fn open_files_only_publishes_on_did_open_and_clears_on_did_close() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_fixture_workspace(dir.path());
+
+        let (server_conn, client_conn) = Connection::memory();
+        let config = DiagnosticConfig {
+            open_files_only: true,
+            ..DiagnosticConfig::default()
+        };
+        let state = build_server_state(
+            dir.path(),
+            PositionEncoding::Utf8,
+            config,
+            WatcherConfig::default(),
+            &server_conn,
+        )
+        .expect("build_server_state must succeed for a valid fixture workspace");
+        let root = state.shared.get().workspace_root.as_path().to_path_buf();
+        let alpha_uri = path_to_uri(&state.shared.get().workspace_root.join("Alpha.al"));
+        let workspace = Workspace {
+            roots: vec![RootState {
+                root,
+                state: Some(state),
+            }],
+            watched_roots: 0,
+        };
+
+        assert!(
+            client_conn
+                .receiver
+                .recv_timeout(Duration::from_millis(200))
+                .is_err(),
+            "no diagnostics may be published before any file is opened"
+        );
+
+        let did_open = Notification::new(
+            "textDocument/didOpen".to_string(),
+            serde_json::json!({ "textDocument": {
+                "uri": alpha_uri.as_str(),
+                "languageId": "al",
+                "version": 1,
+                "text": ""
+            }}),
+        );
+        handle_notification(&workspace, &did_open);
+        let opened = next_diagnostics_for(&client_conn, alpha_uri.as_str(), Duration::from_secs(1))
+            .expect("didOpen must publish Alpha.al's diagnostics");
+        assert!(
+            opened.iter().any(|d| d.message.contains("Extra")),
+            "Extra() is unused and must be flagged once Alpha.al is open; got {opened:#?}"
+        );
+
+        let did_close = Notification::new(
+            "textDocument/didClose".to_string(),
+            serde_json::json!({ "textDocument": {"uri": alpha_uri.as_str()} }),
+        );
+        handle_notification(&workspace, &did_close);
+        let closed = next_diagnostics_for(&client_conn, alpha_uri.as_str(), Duration::from_secs(1))
+            .expect("didClose must republish Alpha.al");
+        assert!(closed.is_empty(), "didClose must clear; got {closed:#?}");
+
+        let RootState {
+            state: root_state, ..
+        } = workspace
+            .roots
+            .into_iter()
+            .next()
+            .expect("exactly one configured root");
+        let ServerState {
+            tx, updater_handle, ..
+        } = root_state.expect("this root's snapshot build succeeded above");
+        drop(tx);
+        updater_handle
+            .join()
+            .expect("updater thread must exit cleanly");
+    }
+
+    /// `al-call-hierarchy/health` reports readiness and the index sizes of
+    /// the configured roots.
+    #[test]
+    fn health_request_reports_ready_with_index_counts() {
+        use crate::lsp::custom::{HealthState, HealthStatus, WatcherCounters};
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_fixture_workspace(dir.path());
+        let (server_conn, _client_conn) = Connection::memory();
+        let workspace = build_workspace(
+            &[dir.path().to_path_buf()],
+            PositionEncoding::Utf8,
+            None,
+            &server_conn,
+        );
+
+        let req = Request::new(
+            RequestId::from(1),
+            "al-call-hierarchy/health".to_string(),
+            Value::Null,
+        );
+        let result = dispatch_request(&req, &workspace).expect("health never errors");
+        let health: HealthStatus =
+            serde_json::from_value(result).expect("response must be a HealthStatus");
+        assert_eq!(health.status, HealthState::Ready);
+        assert!(health.definitions > 0, "{health:?}");
+        assert!(health.calls > 0, "{health:?}");
+        assert_eq!(health.watched_roots, 0, "tests never start a watcher");
+        assert!(health.last_reindex_ms.is_some(), "{health:?}");
+        assert_eq!(
+            health.watcher,
+            WatcherCounters::default(),
+            "the initial build is not a reindex and no watcher runs"
+        );
+
+        join_all_roots(workspace);
+    }
+
+    /// A health request that arrives while the first snapshot is building
+    /// is answered `indexing` at once; other messages wait for the main loop.
+    #[test]
+    fn health_request_during_the_first_build_reports_indexing() {
+        use crate::lsp::custom::{HealthState, HealthStatus};
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_fixture_workspace(dir.path());
+        let (server_conn, client_conn) = Connection::memory();
+        client_conn
+            .sender
+            .send(Message::Request(Request::new(
+                RequestId::from(1),
+                "al-call-hierarchy/health".to_string(),
+                Value::Null,
+            )))
+            .expect("send health");
+
+        let (workspace, deferred) = build_workspace_answering_health(
+            &[dir.path().to_path_buf()],
+            PositionEncoding::Utf8,
+            None,
+            &server_conn,
+        );
+
+        // Skip the initial diagnostics published alongside the answer.
+        let response = loop {
+            if let Message::Response(resp) = client_conn
+                .receiver
+                .recv_timeout(Duration::from_secs(5))
+                .expect("health must be answered")
+            {
+                break resp;
+            }
+        };
+        assert_eq!(response.id, RequestId::from(1));
+        let health: HealthStatus =
+            serde_json::from_value(response.result.expect("health result")).expect("HealthStatus");
+        assert_eq!(health.status, HealthState::Indexing);
+        assert_eq!(health.last_reindex_ms, None);
+        assert!(deferred.is_empty(), "{deferred:?}");
+        assert!(
+            workspace.roots[0]
+                .state
+                .as_ref()
+                .is_some_and(|st| !st.shared.is_indexing()),
+            "the published first snapshot clears indexing"
+        );
+
+        join_all_roots(workspace);
+    }
+
+    /// `al-call-hierarchy/reindex` rebuilds from disk: a file written behind
+    /// the server's back (no didSave, no watcher) shows up in the counts once
+    /// the rebuilt snapshot is swapped in.
+    #[test]
+    fn reindex_request_rebuilds_from_disk_and_recomputes_counts() {
+        use crate::lsp::custom::HealthStatus;
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_fixture_workspace(dir.path());
+        let (server_conn, _client_conn) = Connection::memory();
+        let workspace = build_workspace(
+            &[dir.path().to_path_buf()],
+            PositionEncoding::Utf8,
+            None,
+            &server_conn,
+        );
+        let health = |workspace: &Workspace| -> HealthStatus {
+            let req = Request::new(
+                RequestId::from(1),
+                "al-call-hierarchy/health".to_string(),
+                Value::Null,
+            );
+            serde_json::from_value(dispatch_request(&req, workspace).expect("health"))
+                .expect("response must be a HealthStatus")
+        };
+        let before = health(&workspace);
+
+        std::fs::write(
+            dir.path().join("Delta.al"),
+            r#"codeunit 50103 "Delta"
+{
+    procedure One()
+    begin
+        Two();
+    end;
+
+    procedure Two()
+    begin
+    end;
+}
+"#,
+        )
+        .expect("write Delta.al");
+        assert_eq!(
+            health(&workspace).definitions,
+            before.definitions,
+            "nothing notices the new file until a reindex"
+        );
+
+        let req = Request::new(
+            RequestId::from(2),
+            "al-call-hierarchy/reindex".to_string(),
+            Value::Null,
+        );
+        let result = dispatch_request(&req, &workspace).expect("reindex never errors");
+        assert_eq!(result, serde_json::json!({ "roots": 1 }));
+
+        let shared = &workspace.roots[0]
+            .state
+            .as_ref()
+            .expect("root built")
+            .shared;
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while shared.get().generation == 0 && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(shared.get().generation, 1, "one rebuild, one swap");
+        let after = health(&workspace);
+        assert_eq!(after.definitions, before.definitions + 2, "{after:?}");
+        assert_eq!(after.calls, before.calls + 1, "{after:?}");
+
+        join_all_roots(workspace);
+    }
+
+    /// `al-call-hierarchy/reindex` with `reconcile: true` applies only the
+    /// drift between disk and the index: a file added and one deleted behind
+    /// the server's back are picked up in a single incremental swap.
+    #[test]
+    fn reindex_request_with_reconcile_applies_only_the_drift() {
+        use crate::lsp::custom::HealthStatus;
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_fixture_workspace(dir.path());
+        let (server_conn, _client_conn) = Connection::memory();
+        let workspace = build_workspace(
+            &[dir.path().to_path_buf()],
+            PositionEncoding::Utf8,
+            None,
+            &server_conn,
+        );
+        let health = |workspace: &Workspace| -> HealthStatus {
+            let req = Request::new(
+                RequestId::from(1),
+                "al-call-hierarchy/health".to_string(),
+                Value::Null,
+            );
+            serde_json::from_value(dispatch_request(&req, workspace).expect("health"))
+                .expect("response must be a HealthStatus")
+        };
+        let before = health(&workspace);
+
+        std::fs::write(
+            dir.path().join("Delta.al"),
+            "codeunit 50103 \"Delta\"\n{\n    procedure One()\n    begin\n    end;\n}\n",
+        )
+        .expect("write Delta.al");
+
+        let req = Request::new(
+            RequestId::from(2),
+            "al-call-hierarchy/reindex".to_string(),
+            serde_json::json!({ "reconcile": true }),
+        );
+        let result = dispatch_request(&req, &workspace).expect("reindex never errors");
+        assert_eq!(result, serde_json::json!({ "roots": 1 }));
+
+        let shared = &workspace.roots[0]
+            .state
+            .as_ref()
+            .expect("root built")
+            .shared;
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while shared.get().generation == 0 && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(shared.get().generation, 1, "one reconcile, one swap");
+        assert!(shared.get().parsed.contains_key("Delta.al"));
+        assert_eq!(
+            health(&workspace).definitions,
+            before.definitions + 1,
+            "the added file's procedure is indexed"
+        );
+
+        // Nothing drifted since: a second reconcile publishes nothing.
+        let req = Request::new(
+            RequestId::from(3),
+            "al-call-hierarchy/reindex".to_string(),
+            serde_json::json!({ "reconcile": true }),
+        );
+        dispatch_request(&req, &workspace).expect("reindex never errors");
+        thread::sleep(Duration::from_millis(300));
+        assert_eq!(shared.get().generation, 1, "no drift, no swap");
+
+        join_all_roots(workspace);
+    }
+
+    /// `didChange` edits reach the index through the retained parse: two
+    /// ranged changes to an open buffer (rename `Extra` to `Ghost`, then
+    /// call it from `DoWork`) are applied incrementally and swapped in, and
+    /// `didClose` reindexes the untouched file from disk.
+    #[test]
+    fn did_change_reindexes_the_edited_buffer_and_did_close_restores_disk() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_fixture_workspace(dir.path());
+        let (server_conn, _client_conn) = Connection::memory();
+        let workspace = build_workspace(
+            &[dir.path().to_path_buf()],
+            PositionEncoding::Utf8,
+            None,
+            &server_conn,
+        );
+        let shared = &workspace.roots[0]
+            .state
+            .as_ref()
+            .expect("root built")
+            .shared;
+        let alpha_uri = path_to_uri(&dir.path().join("Alpha.al"));
+        let alpha_decls = || -> Vec<String> {
+            let mut names: Vec<String> = shared.get().decls_by_file["Alpha.al"]
+                .iter()
+                .map(|d| d.name.clone())
+                .collect();
+            names.sort();
+            names
+        };
+        let wait_for_decls = |want: &[&str]| {
+            let deadline = Instant::now() + Duration::from_secs(2);
+            while alpha_decls() != want && Instant::now() < deadline {
+                thread::sleep(Duration::from_millis(20));
+            }
+            assert_eq!(alpha_decls(), want);
+        };
+        let on_disk = std::fs::read_to_string(dir.path().join("Alpha.al")).expect("read Alpha.al");
+        let (extra_line, extra_col) = on_disk
+            .lines()
+            .enumerate()
+            .find_map(|(i, l)| l.find("Extra").map(|c| (i, c)))
+            .expect("Extra declared");
+        let (call_line, _) = on_disk
+            .lines()
+            .enumerate()
+            .find(|(_, l)| l.contains("Beta.Process();"))
+            .expect("DoWork calls Beta.Process");
+
+        handle_notification(
+            &workspace,
+            &Notification::new(
+                "textDocument/didOpen".to_string(),
+                serde_json::json!({ "textDocument": {
+                    "uri": alpha_uri.as_str(),
+                    "languageId": "al",
+                    "version": 1,
+                    "text": on_disk
+                }}),
+            ),
+        );
+        handle_notification(
+            &workspace,
+            &Notification::new(
+                "textDocument/didChange".to_string(),
+                serde_json::json!({
+                    "textDocument": { "uri": alpha_uri.as_str(), "version": 2 },
+                    "contentChanges": [
+                        {
+                            "range": {
+                                "start": { "line": extra_line, "character": extra_col },
+                                "end": { "line": extra_line, "character": extra_col + 5 }
+                            },
+                            "text": "Ghost"
+                        },
+                        {
+                            "range": {
+                                "start": { "line": call_line + 1, "character": 0 },
+                                "end": { "line": call_line + 1, "character": 0 }
+                            },
+                            "text": "        Ghost();\n"
+                        }
+                    ]
+                }),
+            ),
+        );
+        wait_for_decls(&["DoWork", "Ghost"]);
+        let edited = shared.get();
+        let ghost = edited.decls_by_file["Alpha.al"]
+            .iter()
+            .find(|d| d.name == "Ghost")
+            .expect("Ghost decl");
+        assert!(
+            edited
+                .incoming
+                .get(&ghost.id)
+                .is_some_and(|callers| !callers.is_empty()),
+            "the inserted call from DoWork must resolve to Ghost"
+        );
+
+        handle_notification(
+            &workspace,
+            &Notification::new(
+                "textDocument/didClose".to_string(),
+                serde_json::json!({ "textDocument": {"uri": alpha_uri.as_str()} }),
+            ),
+        );
+        wait_for_decls(&["DoWork", "Extra"]);
+
+        join_all_roots(workspace);
+    }
+
     // ── Multi-root tests (feat/multi-root-lsp) ─────────────────────────────
     //
     // Mirror the mechanism above: `Connection::memory()` stands in for
@@ -1475,6 +2467,7 @@ mod tests {
         let workspace = build_workspace(
             &[dir_a.path().to_path_buf(), dir_b],
             PositionEncoding::Utf8,
+            None,
             &server_conn,
         );
         assert_eq!(workspace.roots.len(), 2);
@@ -1654,6 +2647,7 @@ mod tests {
         let workspace = build_workspace(
             &[dir_a.path().to_path_buf(), dir_b],
             PositionEncoding::Utf8,
+            None,
             &server_conn,
         );
         assert_eq!(workspace.roots.len(), 2);
@@ -1724,6 +2718,7 @@ mod tests {
         let workspace = build_workspace(
             &[dir_a.path().to_path_buf(), dir_broken.path().to_path_buf()],
             PositionEncoding::Utf8,
+            None,
             &server_conn,
         );
         assert_eq!(workspace.roots.len(), 2);
@@ -1872,4 +2867,68 @@ mod tests {
             "a pre-3.6 client's root_uri must still work when workspace_folders is absent"
         );
     }
+
+    /// `--socket`: the session that serves stdio completes a full
+    /// initialize/shutdown/exit round trip with a client connected over a
+    /// local TCP socket. The listener stays bound from the port pick through
+    /// the accept, so the client never races a rebind.
+    #[test]
+    fn socket_transport_completes_initialize_and_shutdown() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind a free local port");
+        let addr = listener.local_addr().expect("bound address");
+        let server = thread::spawn(move || serve_socket(&listener, true, true, None));
+
+        let (client, _client_io) = Connection::connect(addr).expect("connect to the server");
+        let response_to = |id: i32| loop {
+            match client
+                .receiver
+                .recv_timeout(Duration::from_secs(30))
+                .expect("server must answer")
+            {
+                Message::Response(resp) if resp.id == RequestId::from(id) => break resp,
+                _ => {}
+            }
+        };
+
+        client
+            .sender
+            .send(Message::Request(Request::new(
+                RequestId::from(1),
+                "initialize".to_string(),
+                InitializeParams::default(),
+            )))
+            .expect("send initialize");
+        let init = response_to(1).result.expect("initialize result");
+        assert_eq!(init["serverInfo"]["name"], "al-call-hierarchy");
+        assert_eq!(init["capabilities"]["callHierarchyProvider"], true);
+        client
+            .sender
+            .send(Message::Notification(Notification::new(
+                "initialized".to_string(),
+                serde_json::json!({}),
+            )))
+            .expect("send initialized");
+
+        client
+            .sender
+            .send(Message::Request(Request::new(
+                RequestId::from(2),
+                "shutdown".to_string(),
+                (),
+            )))
+            .expect("send shutdown");
+        assert!(response_to(2).error.is_none());
+        client
+            .sender
+            .send(Message::Notification(Notification::new(
+                "exit".to_string(),
+                (),
+            )))
+            .expect("send exit");
+
+        server
+            .join()
+            .expect("server thread")
+            .expect("run_server must exit cleanly after shutdown/exit");
+    }
 }
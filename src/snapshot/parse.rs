@@ -129,6 +129,7 @@ mod tests {
         let snap = crate::snapshot::SnapshotBuilder {
             workspace_root: ws,
             local_providers: vec![],
+            referenced_dependencies_only: false,
         }
         .build()
         .unwrap();
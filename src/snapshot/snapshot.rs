@@ -3,7 +3,7 @@
 //! source set.
 
 use crate::app_package::ParsedAppPackage;
-use crate::dependencies::load_all_apps;
+use crate::dependencies::{ReferencedObjects, load_all_apps, load_all_apps_filtered};
 use crate::snapshot::compilation::{
     CompilationContext, context_from_app_json, context_from_metadata,
 };
@@ -71,6 +71,9 @@ pub struct SnapshotBuilder {
     pub workspace_root: PathBuf,
     /// Local source checkouts to prefer over embedded source, keyed by `AppId`.
     pub local_providers: Vec<(AppId, PathBuf)>,
+    /// Load only the dependency objects the workspace source refers to
+    /// (`resolution.lazyDependencies`).
+    pub referenced_dependencies_only: bool,
 }
 
 impl SnapshotBuilder {
@@ -172,7 +175,12 @@ impl SnapshotBuilder {
         // ------------------------------------------------------------------
         // Dependency units
         // ------------------------------------------------------------------
-        let (resolved_deps, dropped_dep_versions) = load_all_apps(ws)?;
+        let (resolved_deps, dropped_dep_versions) = if self.referenced_dependencies_only {
+            let referenced = ReferencedObjects::scan(ws);
+            load_all_apps_filtered(ws, &|name, id| referenced.contains(name, id))?
+        } else {
+            load_all_apps(ws)?
+        };
 
         let mut apps: Vec<AppUnit> = Vec::with_capacity(1 + resolved_deps.len());
         apps.push(ws_unit);
@@ -388,6 +396,7 @@ mod tests {
         let (snap, dropped) = (SnapshotBuilder {
             workspace_root: dir.path().to_path_buf(),
             local_providers: vec![],
+            referenced_dependencies_only: false,
         })
         .build_with_diagnostics()
         .expect("snapshot build");
@@ -455,6 +464,7 @@ mod tests {
         let (snap, dropped) = (SnapshotBuilder {
             workspace_root: project,
             local_providers: vec![],
+            referenced_dependencies_only: false,
         })
         .build_with_diagnostics()
         .expect("snapshot build");
@@ -487,6 +497,48 @@ mod tests {
         );
     }
 
+    /// `referenced_dependencies_only` drops the package objects the
+    /// workspace never names or numbers; the package itself still loads.
+    #[test]
+    fn referenced_dependencies_only_keeps_just_the_objects_the_source_uses() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_app_json(dir.path());
+        let alpackages = dir.path().join(".alpackages");
+        std::fs::create_dir_all(&alpackages).unwrap();
+        let guid = "dddddddd-4444-4444-4444-444444444444";
+        write_minimal_app(
+            &alpackages,
+            "Pub_Dep_1.0.0.0.app",
+            guid,
+            "Dep",
+            "Pub",
+            "1.0.0.0",
+        );
+        let dep_objects = |source: &str, referenced_only: bool| -> usize {
+            std::fs::write(dir.path().join("Caller.al"), source).unwrap();
+            let snap = SnapshotBuilder {
+                workspace_root: dir.path().to_path_buf(),
+                local_providers: vec![],
+                referenced_dependencies_only: referenced_only,
+            }
+            .build()
+            .expect("snapshot build");
+            let dep = snap
+                .apps
+                .iter()
+                .find(|u| u.id.guid == guid)
+                .expect("dep unit");
+            dep.abi.as_ref().expect("dep abi").objects.len()
+        };
+
+        let unrelated = "codeunit 50200 Caller { procedure Go() begin end; }";
+        assert_eq!(dep_objects(unrelated, false), 1);
+        assert_eq!(dep_objects(unrelated, true), 0, "DupCU is never referenced");
+        let by_number =
+            "codeunit 50200 Caller { procedure Go() begin Codeunit.Run(Codeunit::50100); end; }";
+        assert_eq!(dep_objects(by_number, true), 1, "50100 is DupCU's number");
+    }
+
     /// CDO pin (Tier-1 remediation, Task T1.2, H-2 re-measure protocol):
     /// names the real duplicate-GUID dependencies the fix found and dropped
     /// on the frozen CDO workspace, so the fix's real-world effect (not just
@@ -513,6 +565,7 @@ mod tests {
         let (_snap, dropped) = (SnapshotBuilder {
             workspace_root: ws,
             local_providers: vec![],
+            referenced_dependencies_only: false,
         })
         .build_with_diagnostics()
         .expect("snapshot build");
@@ -559,6 +612,7 @@ mod tests {
         let snap = SnapshotBuilder {
             workspace_root: ws,
             local_providers: vec![],
+            referenced_dependencies_only: false,
         }
         .build()
         .expect("snapshot");
@@ -628,6 +682,7 @@ mod tests {
         let snap = SnapshotBuilder {
             workspace_root: ws,
             local_providers: vec![],
+            referenced_dependencies_only: false,
         }
         .build()
         .expect("snapshot");
@@ -676,6 +731,7 @@ mod tests {
         let snap = SnapshotBuilder {
             workspace_root: dir.path().to_path_buf(),
             local_providers: vec![],
+            referenced_dependencies_only: false,
         }
         .build()
         .expect("snapshot build");
@@ -705,6 +761,7 @@ mod tests {
         let snap = SnapshotBuilder {
             workspace_root: dir.path().to_path_buf(),
             local_providers: vec![],
+            referenced_dependencies_only: false,
         }
         .build()
         .expect("snapshot build");
@@ -734,6 +791,7 @@ mod tests {
         let snap = SnapshotBuilder {
             workspace_root: dir.path().to_path_buf(),
             local_providers: vec![],
+            referenced_dependencies_only: false,
         }
         .build()
         .expect("snapshot build");
@@ -2,13 +2,19 @@
 
 use anyhow::Result;
 use log::{debug, error, info};
-use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use notify::{Config, Event, EventKind, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher};
 use std::path::{Path, PathBuf};
-use std::sync::mpsc::{Receiver, channel};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{Receiver, Sender, channel};
 use std::time::Duration;
 
+use crate::config::WatcherFileConfig;
+use crate::lsp::custom::WatcherCounters;
+use crate::lsp::updater::DEFAULT_DEBOUNCE;
+
 /// File change event
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum FileChange {
     /// File was created or modified
     Modified(PathBuf),
@@ -44,69 +50,195 @@ fn is_relevant_path(path: &Path) -> bool {
     is_al || under_alpackages
 }
 
+/// Environment override for [`WatcherConfig::poll_interval`], in
+/// milliseconds; `0` selects the native backend. Takes precedence over the
+/// config files' `watcher.pollIntervalMs`.
+pub const POLL_INTERVAL_ENV: &str = "AL_CALL_HIERARCHY_POLL_INTERVAL_MS";
+
+/// Poll interval the native backend falls back to on platforms where
+/// `notify` has no native event source.
+const NATIVE_FALLBACK_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How an [`AlFileWatcher`] observes the filesystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatcherConfig {
+    /// `Some(interval)` rescans the tree every `interval` (network shares,
+    /// container mounts and other filesystems whose native events are
+    /// unreliable); `None` uses the OS-native event backend.
+    pub poll_interval: Option<Duration>,
+    /// Debounce window the updater coalesces changes over; `0` reindexes
+    /// on every change.
+    pub debounce: Duration,
+}
+
+impl Default for WatcherConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: None,
+            debounce: DEFAULT_DEBOUNCE,
+        }
+    }
+}
+
+impl WatcherConfig {
+    /// Resolve the watcher settings for `workspace_root`: the
+    /// [`POLL_INTERVAL_ENV`] variable if set and numeric, otherwise the merged
+    /// `watcher.pollIntervalMs` from the config files; the debounce window
+    /// from `watcher.debounceMs`.
+    pub fn load(workspace_root: &Path) -> Self {
+        let file = WatcherFileConfig::load_merged(workspace_root);
+        let from_env = std::env::var(POLL_INTERVAL_ENV)
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok());
+        let mut config = Self::from_millis(from_env.or(file.poll_interval_ms));
+        if let Some(ms) = file.debounce_ms {
+            config.debounce = Duration::from_millis(ms);
+        }
+        config
+    }
+
+    fn from_millis(millis: Option<u64>) -> Self {
+        Self {
+            poll_interval: millis.filter(|&ms| ms > 0).map(Duration::from_millis),
+            ..Self::default()
+        }
+    }
+}
+
+/// Running totals for one root's watch pipeline, shared between the
+/// watcher's event handler and the updater's swap callback and read by
+/// `al-call-hierarchy/health`.
+#[derive(Debug, Default)]
+pub struct WatcherStats {
+    events_received: AtomicU64,
+    changes_emitted: AtomicU64,
+    reindexes: AtomicU64,
+}
+
+impl WatcherStats {
+    /// One raw event arrived from the backend, relevant or not.
+    pub fn record_event(&self) {
+        self.events_received.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// One [`FileChange`] was forwarded to the consumer.
+    pub fn record_change(&self) {
+        self.changes_emitted.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// The updater published a new snapshot.
+    pub fn record_reindex(&self) {
+        self.reindexes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// The current totals.
+    pub fn counters(&self) -> WatcherCounters {
+        WatcherCounters {
+            events_received: self.events_received.load(Ordering::Relaxed),
+            changes_emitted: self.changes_emitted.load(Ordering::Relaxed),
+            reindexes: self.reindexes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Translate one backend callback into [`FileChange`]s on `tx`, counting
+/// the event and every change sent into `stats`.
+fn forward_event(
+    result: Result<Event, notify::Error>,
+    tx: &Sender<FileChange>,
+    stats: &WatcherStats,
+) {
+    let event = match result {
+        Ok(event) => event,
+        Err(e) => {
+            error!("Watch error: {:?}", e);
+            return;
+        }
+    };
+    stats.record_event();
+
+    if event.need_rescan() {
+        debug!("Watcher reported a rescan/overflow condition");
+        if tx.send(FileChange::Overflow).is_err() {
+            error!("Failed to send overflow event");
+        } else {
+            stats.record_change();
+        }
+        return;
+    }
+
+    // Filter for AL files + dependency (`.alpackages`) files.
+    for path in event.paths.iter().filter(|p| is_relevant_path(p)) {
+        let change = match event.kind {
+            EventKind::Create(_) | EventKind::Modify(_) => {
+                debug!("File modified: {}", path.display());
+                FileChange::Modified(path.clone())
+            }
+            EventKind::Remove(_) => {
+                debug!("File deleted: {}", path.display());
+                FileChange::Deleted(path.clone())
+            }
+            _ => continue,
+        };
+
+        if tx.send(change).is_err() {
+            error!("Failed to send file change event");
+        } else {
+            stats.record_change();
+        }
+    }
+}
+
 /// File system watcher for AL files
 pub struct AlFileWatcher {
-    _watcher: RecommendedWatcher,
+    _watcher: Box<dyn Watcher>,
     receiver: Receiver<FileChange>,
 }
 
 impl AlFileWatcher {
-    /// Create a new watcher for the given directory
+    /// Create a new watcher for the given directory using native events
     pub fn new(root: &Path) -> Result<Self> {
+        Self::with_config(root, WatcherConfig::default())
+    }
+
+    /// Create a new watcher for the given directory with explicit settings
+    pub fn with_config(root: &Path, config: WatcherConfig) -> Result<Self> {
+        Self::with_stats(root, config, Arc::new(WatcherStats::default()))
+    }
+
+    /// [`Self::with_config`], counting events and forwarded changes into
+    /// `stats`.
+    pub fn with_stats(
+        root: &Path,
+        config: WatcherConfig,
+        stats: Arc<WatcherStats>,
+    ) -> Result<Self> {
         let (tx, rx) = channel();
 
-        let mut watcher = RecommendedWatcher::new(
-            move |result: Result<Event, notify::Error>| {
-                match result {
-                    Ok(event) => {
-                        if event.need_rescan() {
-                            debug!("Watcher reported a rescan/overflow condition");
-                            if tx.send(FileChange::Overflow).is_err() {
-                                error!("Failed to send overflow event");
-                            }
-                            return;
-                        }
-
-                        // Filter for AL files + dependency (`.alpackages`) files.
-                        let relevant_paths: Vec<_> = event
-                            .paths
-                            .iter()
-                            .filter(|p| is_relevant_path(p))
-                            .cloned()
-                            .collect();
-
-                        if relevant_paths.is_empty() {
-                            return;
-                        }
-
-                        for path in relevant_paths {
-                            let change = match event.kind {
-                                EventKind::Create(_) | EventKind::Modify(_) => {
-                                    debug!("File modified: {}", path.display());
-                                    FileChange::Modified(path)
-                                }
-                                EventKind::Remove(_) => {
-                                    debug!("File deleted: {}", path.display());
-                                    FileChange::Deleted(path)
-                                }
-                                _ => continue,
-                            };
-
-                            if tx.send(change).is_err() {
-                                error!("Failed to send file change event");
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        error!("Watch error: {:?}", e);
-                    }
-                }
-            },
-            Config::default().with_poll_interval(Duration::from_secs(2)),
-        )?;
+        let handler = move |result: Result<Event, notify::Error>| {
+            forward_event(result, &tx, &stats);
+        };
+
+        let mut watcher: Box<dyn Watcher> = match config.poll_interval {
+            Some(interval) => Box::new(PollWatcher::new(
+                handler,
+                Config::default().with_poll_interval(interval),
+            )?),
+            None => Box::new(RecommendedWatcher::new(
+                handler,
+                Config::default().with_poll_interval(NATIVE_FALLBACK_POLL_INTERVAL),
+            )?),
+        };
 
         watcher.watch(root, RecursiveMode::Recursive)?;
-        info!("Watching for file changes in: {}", root.display());
+        match config.poll_interval {
+            Some(interval) => info!(
+                "Polling for file changes every {:?} in: {}",
+                interval,
+                root.display()
+            ),
+            None => info!("Watching for file changes in: {}", root.display()),
+        }
 
         Ok(Self {
             _watcher: watcher,
@@ -139,6 +271,100 @@ mod tests {
         assert!(watcher.is_ok());
     }
 
+    #[test]
+    fn test_watcher_with_custom_poll_interval() {
+        let dir = tempdir().unwrap();
+        let config = WatcherConfig {
+            poll_interval: Some(Duration::from_millis(50)),
+            ..WatcherConfig::default()
+        };
+        let stats = Arc::new(WatcherStats::default());
+        let watcher = AlFileWatcher::with_stats(dir.path(), config, Arc::clone(&stats)).unwrap();
+
+        let al_file = dir.path().join("Polled.al");
+        fs::write(&al_file, "codeunit 50001 Polled {}").unwrap();
+
+        assert_eq!(
+            watcher.recv_timeout(Duration::from_secs(3)),
+            Some(FileChange::Modified(al_file)),
+            "the poller reports the new file"
+        );
+        let counters = stats.counters();
+        assert!(counters.events_received >= 1, "{counters:?}");
+        assert!(counters.changes_emitted >= 1, "{counters:?}");
+        assert_eq!(counters.reindexes, 0, "only the updater counts reindexes");
+    }
+
+    #[test]
+    fn watcher_config_zero_interval_selects_native_events() {
+        assert_eq!(WatcherConfig::from_millis(None).poll_interval, None);
+        assert_eq!(WatcherConfig::from_millis(Some(0)).poll_interval, None);
+        assert_eq!(
+            WatcherConfig::from_millis(Some(250)).poll_interval,
+            Some(Duration::from_millis(250))
+        );
+    }
+
+    #[test]
+    fn forward_event_counts_events_and_emitted_changes() {
+        use notify::event::{CreateKind, Flag, RemoveKind};
+
+        let (tx, rx) = channel();
+        let stats = WatcherStats::default();
+
+        forward_event(
+            Ok(Event::new(EventKind::Create(CreateKind::File)).add_path("A.al".into())),
+            &tx,
+            &stats,
+        );
+        forward_event(
+            Ok(Event::new(EventKind::Create(CreateKind::File)).add_path("README.md".into())),
+            &tx,
+            &stats,
+        );
+        forward_event(
+            Ok(Event::new(EventKind::Remove(RemoveKind::File))
+                .add_path("A.al".into())
+                .add_path("B.al".into())),
+            &tx,
+            &stats,
+        );
+        forward_event(
+            Ok(Event::new(EventKind::Other).set_flag(Flag::Rescan)),
+            &tx,
+            &stats,
+        );
+        stats.record_reindex();
+
+        assert_eq!(
+            stats.counters(),
+            WatcherCounters {
+                events_received: 4,
+                changes_emitted: 4,
+                reindexes: 1,
+            }
+        );
+        assert_eq!(
+            rx.try_iter().collect::<Vec<_>>(),
+            vec![
+                FileChange::Modified("A.al".into()),
+                FileChange::Deleted("A.al".into()),
+                FileChange::Deleted("B.al".into()),
+                FileChange::Overflow,
+            ],
+            "README.md is filtered out; everything else arrives in order"
+        );
+    }
+
+    #[test]
+    fn watcher_config_defaults_to_the_standard_debounce() {
+        assert_eq!(WatcherConfig::default().debounce, DEFAULT_DEBOUNCE);
+        assert_eq!(
+            WatcherConfig::from_millis(Some(250)).debounce,
+            DEFAULT_DEBOUNCE
+        );
+    }
+
     #[test]
     fn is_relevant_path_accepts_al_files_and_alpackages_contents_rejects_everything_else() {
         assert!(is_relevant_path(Path::new("Codeunit1.al")));
@@ -253,3 +253,62 @@ fn aldump_l3_call_graph_stats_good_path_still_succeeds() {
         "good-path histogram must carry legacyL3UnknownRate, got: {v}"
     );
 }
+
+/// `--graphify-export` writes workspace `source_file`s relative to the
+/// workspace root (`/` separated, e.g. `src/publisher.al`), so the export is
+/// portable across machines; `--absolute-paths` writes them as absolute paths
+/// under the root.
+#[test]
+fn aldump_graphify_export_source_paths() {
+    let bin = env!("CARGO_BIN_EXE_aldump");
+    let ws = ws_d2_dir();
+
+    let source_files = |absolute: bool| -> Vec<String> {
+        let mut cmd = Command::new(bin);
+        cmd.arg("--graphify-export");
+        if absolute {
+            cmd.arg("--absolute-paths");
+        }
+        let out = cmd
+            .arg(&ws)
+            .output()
+            .unwrap_or_else(|e| panic!("spawn aldump: {e}"));
+        assert!(
+            out.status.success(),
+            "aldump --graphify-export must exit 0: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        let v: serde_json::Value =
+            serde_json::from_slice(&out.stdout).expect("aldump emits a valid graphify document");
+        let mut files: Vec<String> = v["nodes"]
+            .as_array()
+            .expect("nodes array")
+            .iter()
+            .filter_map(|n| n["source_file"].as_str())
+            .filter(|f| !f.is_empty())
+            .map(str::to_string)
+            .collect();
+        files.sort();
+        files.dedup();
+        files
+    };
+
+    let relative = source_files(false);
+    assert!(
+        !relative.is_empty(),
+        "ws-d2's call edges locate at least one workspace file"
+    );
+    assert!(
+        relative.iter().all(|f| f.starts_with("src/")),
+        "no absolute path may leak into a relative export, got: {relative:?}"
+    );
+
+    let absolute = source_files(true);
+    assert_eq!(
+        absolute,
+        relative
+            .iter()
+            .map(|f| ws.join(f).display().to_string())
+            .collect::<Vec<_>>()
+    );
+}
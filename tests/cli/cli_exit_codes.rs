@@ -0,0 +1,176 @@
+//! Exit-code contract of the `al-call-hierarchy` CLI: 0 success, 1 generic
+//! error, 2 `--fail-on` threshold met, 3 indexing error. Invokes the real
+//! binary so the codes scripts see are what is asserted.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_al-call-hierarchy"))
+        .args(args)
+        .output()
+        .expect("run al-call-hierarchy")
+}
+
+fn code_of(out: &Output) -> i32 {
+    out.status.code().expect("exit code")
+}
+
+/// An app whose one procedure takes five parameters — a `warning`-severity
+/// `too_many_parameters` finding and nothing critical.
+fn write_workspace(dir: &Path) {
+    std::fs::write(
+        dir.join("app.json"),
+        r#"{
+    "id": "77777777-0000-0000-0000-000000002208",
+    "name": "Exit Code Fixture",
+    "publisher": "probe",
+    "version": "1.0.0.0"
+}"#,
+    )
+    .expect("write app.json");
+    std::fs::write(
+        dir.join("Wide.al"),
+        r#"codeunit 50100 Wide
+{
+    procedure Wide(A: Integer; B: Integer; C: Integer; D: Integer; E: Integer)
+    begin
+    end;
+}
+"#,
+    )
+    .expect("write Wide.al");
+}
+
+#[test]
+fn analysis_without_fail_on_exits_0() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    write_workspace(dir.path());
+    let project = dir.path().to_str().unwrap();
+
+    let out = run(&["--project", project, "--analyze", "--format", "json"]);
+    assert_eq!(
+        code_of(&out),
+        0,
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+#[test]
+fn findings_at_the_fail_on_severity_exit_2() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    write_workspace(dir.path());
+    let project = dir.path().to_str().unwrap();
+
+    let out = run(&[
+        "--project",
+        project,
+        "--analyze",
+        "--format",
+        "json",
+        "--fail-on",
+        "warning",
+    ]);
+    assert_eq!(
+        code_of(&out),
+        2,
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+
+    // Only a warning was found, so a `critical` threshold is not met.
+    let out = run(&[
+        "--project",
+        project,
+        "--analyze",
+        "--format",
+        "json",
+        "--fail-on",
+        "critical",
+    ]);
+    assert_eq!(
+        code_of(&out),
+        0,
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+#[test]
+fn generic_errors_exit_1() {
+    let out = run(&["--analyze"]);
+    assert_eq!(code_of(&out), 1);
+    assert!(
+        String::from_utf8_lossy(&out.stderr).contains("--analyze requires --project"),
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+#[test]
+fn unparsable_config_file_exits_1() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    write_workspace(dir.path());
+    let project = dir.path().to_str().unwrap();
+    let config = dir.path().join("ci.json");
+    std::fs::write(&config, r#"{ "cli": { "format": "json" "#).expect("write ci.json");
+
+    let out = run(&[
+        "--project",
+        project,
+        "--analyze",
+        "--config",
+        config.to_str().unwrap(),
+    ]);
+    assert_eq!(code_of(&out), 1);
+    assert!(
+        String::from_utf8_lossy(&out.stderr).contains("--config"),
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+#[test]
+fn no_flags_override_the_config_file() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    write_workspace(dir.path());
+    let project = dir.path().to_str().unwrap();
+    let config = dir.path().join("ci.json");
+    std::fs::write(&config, r#"{ "cli": { "summaryOnly": true } }"#).expect("write ci.json");
+    let config = config.to_str().unwrap();
+    let analyze = |extra: &[&str]| {
+        let mut args = vec!["--project", project, "--analyze", "--format", "json"];
+        args.extend(["--config", config]);
+        args.extend(extra);
+        let out = run(&args);
+        assert_eq!(
+            code_of(&out),
+            0,
+            "stderr={}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        serde_json::from_slice::<serde_json::Value>(&out.stdout).expect("json report")
+    };
+
+    assert!(
+        analyze(&[]).get("metrics").is_none(),
+        "the file's summaryOnly"
+    );
+    assert!(analyze(&["--no-summary-only"]).get("metrics").is_some());
+}
+
+#[test]
+fn unindexable_project_exits_3() {
+    // No app.json — no program snapshot can be built.
+    let dir = tempfile::tempdir().expect("tempdir");
+    let project = dir.path().to_str().unwrap();
+
+    let out = run(&["--project", project, "--list"]);
+    assert_eq!(
+        code_of(&out),
+        3,
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
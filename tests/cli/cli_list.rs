@@ -0,0 +1,55 @@
+//! `--list` prints `Object.Routine<TAB>path:line`; `--stable-ids` appends
+//! the content-derived stable id as a third column.
+
+use std::process::Command;
+
+fn list(project: &std::path::Path, extra: &[&str]) -> Vec<String> {
+    let out = Command::new(env!("CARGO_BIN_EXE_al-call-hierarchy"))
+        .args(["--project", project.to_str().unwrap(), "--list"])
+        .args(extra)
+        .output()
+        .expect("run al-call-hierarchy");
+    assert!(
+        out.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8(out.stdout)
+        .expect("utf-8 stdout")
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn stable_ids_are_an_opt_in_third_column() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        dir.path().join("app.json"),
+        r#"{
+    "id": "77777777-0000-0000-0000-000000002220",
+    "name": "List Fixture",
+    "publisher": "probe",
+    "version": "1.0.0.0"
+}"#,
+    )
+    .expect("write app.json");
+    std::fs::write(
+        dir.path().join("Cu.al"),
+        "codeunit 50100 Util\n{\n    procedure Run()\n    begin\n    end;\n}\n",
+    )
+    .expect("write Cu.al");
+
+    let plain = list(dir.path(), &[]);
+    assert_eq!(plain.len(), 1, "{plain:?}");
+    let cols: Vec<&str> = plain[0].split('\t').collect();
+    assert_eq!(cols.len(), 2, "default line format is unchanged: {cols:?}");
+    assert_eq!(cols[0], "Util.Run");
+    assert!(cols[1].ends_with("Cu.al:3"), "{cols:?}");
+
+    let with_ids = list(dir.path(), &["--stable-ids"]);
+    let cols: Vec<&str> = with_ids[0].split('\t').collect();
+    assert_eq!(cols.len(), 3, "{cols:?}");
+    assert_eq!(cols[..2].join("\t"), plain[0]);
+    assert_eq!(cols[2].len(), 16, "16 hex digits: {cols:?}");
+}
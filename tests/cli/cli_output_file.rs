@@ -0,0 +1,95 @@
+//! `--output <file>`: the analysis report — or a symbol-only package's object
+//! inventory — is written to the file (parent directories created) instead of
+//! stdout.
+
+use std::process::Command;
+
+#[test]
+fn analysis_json_is_written_to_the_output_file() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        dir.path().join("app.json"),
+        r#"{
+    "id": "77777777-0000-0000-0000-000000002255",
+    "name": "Output File Fixture",
+    "publisher": "probe",
+    "version": "1.0.0.0"
+}"#,
+    )
+    .expect("write app.json");
+    std::fs::write(
+        dir.path().join("Wide.al"),
+        r#"codeunit 50100 Wide
+{
+    procedure Wide(A: Integer; B: Integer; C: Integer; D: Integer; E: Integer)
+    begin
+    end;
+}
+"#,
+    )
+    .expect("write Wide.al");
+    let report = dir
+        .path()
+        .join("reports")
+        .join("nested")
+        .join("analysis.json");
+
+    let out = Command::new(env!("CARGO_BIN_EXE_al-call-hierarchy"))
+        .args(["--project", dir.path().to_str().unwrap(), "--analyze"])
+        .args(["--format", "json", "--output", report.to_str().unwrap()])
+        .output()
+        .expect("run al-call-hierarchy");
+    assert!(
+        out.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(
+        out.stdout.is_empty(),
+        "the report must not also go to stdout: {}",
+        String::from_utf8_lossy(&out.stdout)
+    );
+
+    let text = std::fs::read_to_string(&report).expect("report written");
+    let json: serde_json::Value = serde_json::from_str(&text).expect("report is JSON");
+    assert_eq!(json["summary"]["total_procedures"], 1, "{json}");
+    assert!(
+        json["metrics"]
+            .as_array()
+            .is_some_and(|m| m.iter().any(|m| m["procedure_name"] == "Wide")),
+        "{json}"
+    );
+}
+
+#[test]
+fn symbol_only_package_inventory_is_written_to_the_output_file() {
+    let package = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/lsp-diff-deps/.alpackages")
+        .join("aaaaaaaa-0000-0000-0000-000000000001.app");
+    let dir = tempfile::tempdir().expect("tempdir");
+    let report = dir.path().join("reports").join("inventory.csv");
+
+    let out = Command::new(env!("CARGO_BIN_EXE_al-call-hierarchy"))
+        .args(["--project", package.to_str().unwrap(), "--analyze"])
+        .args(["--format", "csv", "--output", report.to_str().unwrap()])
+        .output()
+        .expect("run al-call-hierarchy");
+    assert!(
+        out.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(
+        out.stdout.is_empty(),
+        "the inventory must not also go to stdout: {}",
+        String::from_utf8_lossy(&out.stdout)
+    );
+
+    let text = std::fs::read_to_string(&report).expect("inventory written");
+    let mut lines = text.lines();
+    assert_eq!(lines.next(), Some("object_type,id,name,methods"), "{text}");
+    assert!(
+        lines.next().is_some(),
+        "the package's objects are listed: {text}"
+    );
+}
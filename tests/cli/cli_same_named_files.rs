@@ -0,0 +1,97 @@
+//! `--analyze` on a project with two same-named files in different folders:
+//! the per-procedure facts joined from the call graph stay with their own
+//! file even though the default report prints bare file names.
+
+use std::path::Path;
+use std::process::Command;
+
+fn write_fixture(dir: &Path, a_body: &str) {
+    std::fs::write(
+        dir.join("app.json"),
+        r#"{
+    "id": "77777777-0000-0000-0000-000000002243",
+    "name": "Same Named Fixture",
+    "publisher": "probe",
+    "version": "1.0.0.0"
+}"#,
+    )
+    .expect("write app.json");
+    std::fs::write(
+        dir.join("Helper.al"),
+        "codeunit 50110 Helper\n{\n    procedure Help()\n    begin\n    end;\n}\n",
+    )
+    .expect("write Helper.al");
+    for (folder, id, body) in [("a", 50100, a_body), ("b", 50101, "")] {
+        std::fs::create_dir_all(dir.join(folder)).unwrap();
+        std::fs::write(
+            dir.join(folder).join("Shared.al"),
+            format!(
+                "codeunit {id} \"{folder} Shared\"\n{{\n    procedure Work()\n    var\n        H: Codeunit Helper;\n    begin\n{body}    end;\n}}\n"
+            ),
+        )
+        .expect("write Shared.al");
+    }
+}
+
+fn analyze(dir: &Path) -> serde_json::Value {
+    let out = Command::new(env!("CARGO_BIN_EXE_al-call-hierarchy"))
+        .args(["--project", dir.to_str().unwrap(), "--analyze"])
+        .args(["--format", "json"])
+        .output()
+        .expect("run al-call-hierarchy");
+    assert!(
+        out.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    serde_json::from_slice(&out.stdout).expect("report is JSON")
+}
+
+#[test]
+fn coupling_stays_with_its_own_same_named_file() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    write_fixture(dir.path(), "        H.Help();\n");
+
+    let json = analyze(dir.path());
+    let coupling = |object: &str| {
+        json["metrics"]
+            .as_array()
+            .expect("metrics")
+            .iter()
+            .find(|m| m["object_name"] == object)
+            .map(|m| m["coupling"].clone())
+            .unwrap_or_else(|| panic!("no metrics for {object}: {json}"))
+    };
+    assert_eq!(coupling("a Shared"), 1, "a/Shared.al calls Helper");
+    assert_eq!(
+        coupling("b Shared"),
+        0,
+        "b/Shared.al calls nothing, though Work sits on the same Shared.al line"
+    );
+}
+
+#[test]
+fn callers_stay_with_their_own_same_named_file() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    write_fixture(dir.path(), "");
+    // Two callers of a/Shared.al's Work; b/Shared.al's Work, on the same
+    // line of a file with the same name, has none.
+    std::fs::write(
+        dir.path().join("Callers.al"),
+        "codeunit 50120 Callers\n{\n    procedure One()\n    var\n        S: Codeunit \"a Shared\";\n    begin\n        S.Work();\n    end;\n\n    procedure Two()\n    var\n        S: Codeunit \"a Shared\";\n    begin\n        S.Work();\n    end;\n}\n",
+    )
+    .expect("write Callers.al");
+
+    let json = analyze(dir.path());
+    let procedures = |section: &str| -> Vec<String> {
+        json[section]
+            .as_array()
+            .unwrap_or_else(|| panic!("no {section}: {json}"))
+            .iter()
+            .map(|e| e["procedure"].as_str().unwrap().to_string())
+            .collect()
+    };
+    assert_eq!(procedures("blast_radius"), ["a Shared.Work"], "{json}");
+    assert_eq!(procedures("churn_risk"), ["a Shared.Work"], "{json}");
+    assert_eq!(json["churn_risk"][0]["incoming_calls"], 2, "{json}");
+}
@@ -0,0 +1,118 @@
+//! `--since-git-ref <ref>`: only procedures in files changed since the ref
+//! are reported, matched on project-relative paths even when the report
+//! prints bare file names.
+
+use std::path::Path;
+use std::process::Command;
+
+fn git(dir: &Path, args: &[&str]) -> bool {
+    Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args([
+            "-c",
+            "user.name=probe",
+            "-c",
+            "user.email=probe@example.com",
+        ])
+        .args(args)
+        .output()
+        .is_ok_and(|o| o.status.success())
+}
+
+/// A fresh git repository holding a fixture `app.json`, or `None` when
+/// `git` is not available.
+fn init_project() -> Option<tempfile::TempDir> {
+    let dir = tempfile::tempdir().expect("tempdir");
+    if !git(dir.path(), &["init", "-q"]) {
+        eprintln!("Skipping: git is not available");
+        return None;
+    }
+    std::fs::write(
+        dir.path().join("app.json"),
+        r#"{
+    "id": "77777777-0000-0000-0000-000000002237",
+    "name": "Since Ref Fixture",
+    "publisher": "probe",
+    "version": "1.0.0.0"
+}"#,
+    )
+    .expect("write app.json");
+    Some(dir)
+}
+
+/// `(procedure_name, file)` for each metric of `--analyze --since-git-ref
+/// HEAD` over `project`.
+fn analyzed_since_head(project: &Path) -> Vec<(String, String)> {
+    let out = Command::new(env!("CARGO_BIN_EXE_al-call-hierarchy"))
+        .args(["--project", project.to_str().unwrap(), "--analyze"])
+        .args(["--since-git-ref", "HEAD", "--format", "json"])
+        .output()
+        .expect("run al-call-hierarchy");
+    assert!(
+        out.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&out.stdout).expect("report is JSON");
+    json["metrics"]
+        .as_array()
+        .expect("metrics")
+        .iter()
+        .map(|m| {
+            (
+                m["procedure_name"].as_str().unwrap().to_string(),
+                m["file"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn same_named_files_in_other_folders_stay_out_of_scope() {
+    let Some(dir) = init_project() else {
+        return;
+    };
+    for (folder, id, procedure) in [("a", 50100, "InA"), ("b", 50101, "InB")] {
+        std::fs::create_dir_all(dir.path().join(folder)).unwrap();
+        std::fs::write(
+            dir.path().join(folder).join("Shared.al"),
+            format!("codeunit {id} \"{folder} Shared\"\n{{\n    procedure {procedure}()\n    begin\n    end;\n}}\n"),
+        )
+        .expect("write Shared.al");
+    }
+    assert!(git(dir.path(), &["add", "-A"]));
+    assert!(git(dir.path(), &["commit", "-q", "-m", "fixture"]));
+    let changed = dir.path().join("a").join("Shared.al");
+    let text = std::fs::read_to_string(&changed).unwrap();
+    std::fs::write(&changed, format!("// touched\n{text}")).unwrap();
+
+    assert_eq!(
+        analyzed_since_head(dir.path()),
+        [("InA".to_string(), "Shared.al".to_string())],
+        "b/Shared.al is unchanged even though it shares a/Shared.al's file name"
+    );
+}
+
+#[test]
+fn non_ascii_file_names_are_in_scope() {
+    let Some(dir) = init_project() else {
+        return;
+    };
+    let changed = dir.path().join("Überweisung.al");
+    std::fs::write(
+        &changed,
+        "codeunit 50100 \"Überweisung\"\n{\n    procedure Buchen()\n    begin\n    end;\n}\n",
+    )
+    .expect("write Überweisung.al");
+    assert!(git(dir.path(), &["add", "-A"]));
+    assert!(git(dir.path(), &["commit", "-q", "-m", "fixture"]));
+    let text = std::fs::read_to_string(&changed).unwrap();
+    std::fs::write(&changed, format!("// touched\n{text}")).unwrap();
+
+    assert_eq!(
+        analyzed_since_head(dir.path()),
+        [("Buchen".to_string(), "Überweisung.al".to_string())],
+        "git's default core.quotePath would print \"\\303\\234berweisung.al\""
+    );
+}
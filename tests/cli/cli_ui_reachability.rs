@@ -0,0 +1,72 @@
+//! `--analyze` marks each procedure `ui_reachable` when a page or report
+//! trigger reaches it through the resolved call graph.
+
+use std::process::Command;
+
+#[test]
+fn metrics_mark_procedures_reached_from_a_page_trigger() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        dir.path().join("app.json"),
+        r#"{
+    "id": "77777777-0000-0000-0000-000000002180",
+    "name": "UI Reach Fixture",
+    "publisher": "probe",
+    "version": "1.0.0.0"
+}"#,
+    )
+    .expect("write app.json");
+    std::fs::write(
+        dir.path().join("DemoCard.al"),
+        r#"page 50100 "Demo Card"
+{
+    trigger OnOpenPage()
+    var
+        W: Codeunit Worker;
+    begin
+        W.FromUi();
+    end;
+}
+"#,
+    )
+    .expect("write DemoCard.al");
+    std::fs::write(
+        dir.path().join("Worker.al"),
+        r#"codeunit 50101 Worker
+{
+    procedure FromUi()
+    begin
+    end;
+
+    procedure Backend()
+    begin
+    end;
+}
+"#,
+    )
+    .expect("write Worker.al");
+
+    let out = Command::new(env!("CARGO_BIN_EXE_al-call-hierarchy"))
+        .args(["--project", dir.path().to_str().unwrap(), "--analyze"])
+        .args(["--format", "json"])
+        .output()
+        .expect("run al-call-hierarchy");
+    assert!(
+        out.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&out.stdout).expect("report is JSON");
+    let reachable = |procedure: &str| {
+        json["metrics"]
+            .as_array()
+            .expect("metrics")
+            .iter()
+            .find(|m| m["procedure_name"] == procedure)
+            .map(|m| m["ui_reachable"].clone())
+            .unwrap_or_else(|| panic!("no metrics for {procedure}: {json}"))
+    };
+    assert_eq!(reachable("OnOpenPage"), true);
+    assert_eq!(reachable("FromUi"), true);
+    assert_eq!(reachable("Backend"), false);
+}
@@ -38,8 +38,15 @@ mod cli_b_snapshot_differential;
 mod cli_c_cache_differential;
 mod cli_c_events_differential;
 mod cli_c_policy_differential;
+mod cli_exit_codes;
+mod cli_list;
+mod cli_output_file;
 mod cli_p1_enclosing_member;
 mod cli_p1_inventory;
+mod cli_same_named_files;
+#[cfg(feature = "git")]
+mod cli_since_git_ref;
+mod cli_ui_reachability;
 mod d1_downgraded_to_info_oracle;
 mod gate_prsummary_differential;
 mod gate_sarif_differential;
@@ -1,5 +1,5 @@
-// 1B.3b Task 2: ApplicTriggerCaller.Go() inserts into a `Record "ApplicTable"`
-// variable, firing ApplicTable's OnInsert trigger — a real
+// 1B.3b Task 2: ApplicTriggerCaller.Go() inserts (RunTrigger = true) into a
+// `Record "ApplicTable"` variable, firing ApplicTable's OnInsert trigger — a real
 // DispatchShape::Multicast EdgeKind::ImplicitTrigger edge.
 // route_applicability's ported implicit_trigger_route_applicable teeth must
 // find this route applicable: implicit_trigger_violations stays 0.
@@ -26,6 +26,6 @@ codeunit 50705 "ApplicTriggerCaller"
     var
         MyRec: Record "ApplicTable";
     begin
-        MyRec.Insert();
+        MyRec.Insert(true);
     end;
 }
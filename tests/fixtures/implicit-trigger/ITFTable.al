@@ -4,14 +4,14 @@
 // TableExtension 50501 "ITFTableExt" ALSO declares OnInsert (fan-out target —
 // every insert into ITFTable must fire BOTH the base table's and the
 // extension's OnInsert). Codeunit 50502 "ITFCaller" performs one Insert,
-// Modify, and Delete on a local `Record ITFTable` variable.
+// Modify, and Delete (RunTrigger = true) on a local `Record ITFTable` variable.
 //
 // Expected fresh ImplicitTrigger resolution (frozen in
 // `tests/goldens/semantic-edges/implicit-trigger-fixture.json` — L3-independent,
 // no oracle involved):
-//   • MyRec.Insert() -> {Table ITFTable.OnInsert, TableExtension ITFTableExt.OnInsert}
-//   • MyRec.Modify() -> {Table ITFTable.OnModify}
-//   • MyRec.Delete() -> {Table ITFTable.OnDelete}
+//   • MyRec.Insert(true) -> {Table ITFTable.OnInsert, TableExtension ITFTableExt.OnInsert}
+//   • MyRec.Modify(true) -> {Table ITFTable.OnModify}
+//   • MyRec.Delete(true) -> {Table ITFTable.OnDelete}
 table 50500 "ITFTable"
 {
     fields
@@ -45,8 +45,8 @@ codeunit 50502 "ITFCaller"
     var
         MyRec: Record "ITFTable";
     begin
-        MyRec.Insert();
-        MyRec.Modify();
-        MyRec.Delete();
+        MyRec.Insert(true);
+        MyRec.Modify(true);
+        MyRec.Delete(true);
     end;
 }
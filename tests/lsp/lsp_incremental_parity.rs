@@ -1301,6 +1301,8 @@ fn canon_edge_distinguishes_kind_shape_completeness_and_conditions() {
                 callee_fp: edge.site.callee_fingerprint,
             },
             edge,
+            referenced_objects: Vec::new(),
+            resolution_kind: None,
         }
     }
 
@@ -98,7 +98,7 @@ fn indexes_with_expected_definitions_and_hub_fan_in() {
         .expect("hub Proc0 decl")
         .id
         .clone();
-    let incoming = handlers::incoming(&snap, PositionEncoding::Utf8, &ItemData { node: hub_proc0 });
+    let incoming = handlers::incoming(&snap, PositionEncoding::Utf8, &ItemData::new(hub_proc0));
     assert_eq!(incoming.len(), file_count - 1);
 
     // A non-hub file's Proc0 must have exactly 3 outgoing calls (1 cross-file
@@ -110,7 +110,7 @@ fn indexes_with_expected_definitions_and_hub_fan_in() {
         .expect("file-1 Proc0 decl")
         .id
         .clone();
-    let outgoing = handlers::outgoing(&snap, PositionEncoding::Utf8, &ItemData { node: f1_proc0 });
+    let outgoing = handlers::outgoing(&snap, PositionEncoding::Utf8, &ItemData::new(f1_proc0));
     assert_eq!(outgoing.len(), 3);
 
     // Sanity: `prepare` at the hub's Proc0 name-token position resolves.
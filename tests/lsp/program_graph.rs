@@ -16,6 +16,7 @@ fn cdo_program_graph_is_app_qualified_and_panic_free() {
     let snap = al_call_hierarchy::snapshot::SnapshotBuilder {
         workspace_root: ws,
         local_providers: vec![],
+        referenced_dependencies_only: false,
     }
     .build()
     .expect("snapshot");
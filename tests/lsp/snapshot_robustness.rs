@@ -16,6 +16,7 @@ fn cdo_snapshot_deep_parse_is_panic_free() {
     let snap = al_call_hierarchy::snapshot::SnapshotBuilder {
         workspace_root: ws,
         local_providers: vec![],
+        referenced_dependencies_only: false,
     }
     .build()
     .expect("snapshot builds");
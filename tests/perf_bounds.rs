@@ -414,7 +414,7 @@ mod release_checks {
             .expect("hub Proc0 decl")
             .id
             .clone();
-        let data = ItemData { node: hub_proc0 };
+        let data = ItemData::new(hub_proc0);
 
         // Warm-up.
         let _ = handlers::incoming(&snap, PositionEncoding::Utf8, &data);
@@ -454,7 +454,7 @@ mod release_checks {
             .expect("file-1 Proc0 decl")
             .id
             .clone();
-        let data = ItemData { node: proc0 };
+        let data = ItemData::new(proc0);
 
         // Warm-up.
         let _ = handlers::outgoing(&snap, PositionEncoding::Utf8, &data);
@@ -3197,6 +3197,7 @@ fn task2_dump_argtype_dispatch_flips_on_cdo() {
     let snap = (SnapshotBuilder {
         workspace_root: ws.clone(),
         local_providers: vec![],
+        referenced_dependencies_only: false,
     })
     .build()
     .expect("snapshot build must succeed on CDO_WS");
@@ -5882,6 +5883,7 @@ fn ws_overload_collision_graph_preserves_both_overloads() {
     let snap = (SnapshotBuilder {
         workspace_root: fixture,
         local_providers: vec![],
+        referenced_dependencies_only: false,
     })
     .build()
     .expect("snapshot must build for ws-overload-collision");
@@ -7292,6 +7294,7 @@ fn sigfp_identity_agrees_across_all_four_live_sites() {
     let snap = SnapshotBuilder {
         workspace_root: fixture.clone(),
         local_providers: vec![],
+        referenced_dependencies_only: false,
     }
     .build()
     .expect("snapshot must build");